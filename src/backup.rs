@@ -1,3 +1,6 @@
+use gloo::net::http::{Request, RequestBuilder};
+use gloo::storage::{LocalStorage, Storage};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::prelude::*;
 use web_sys::{IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode};
@@ -5,6 +8,23 @@ use web_sys::{IdbDatabase, IdbOpenDbRequest, IdbRequest, IdbTransactionMode};
 const DB_NAME: &str = "treening_backup";
 const STORE_NAME: &str = "app_data";
 const BACKUP_KEY: &str = "backup";
+/// Separate slot for snapshots taken before destructive bulk actions, so the
+/// rolling automatic backup can't overwrite them.
+const SNAPSHOT_KEY: &str = "pre_delete_snapshot";
+const LAST_FILE_BACKUP_KEY: &str = "treening_last_file_backup";
+/// Kept outside the user config so the token never ends up in exports or sync.
+const REMOTE_BACKUP_KEY: &str = "treening_remote_backup";
+const LAST_REMOTE_BACKUP_KEY: &str = "treening_last_remote_backup";
+const DAY_MS: f64 = 24.0 * 60.0 * 60.0 * 1000.0;
+
+#[wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = encryptBackup)]
+    fn encrypt_backup(plaintext: &str, passphrase: &str) -> js_sys::Promise;
+
+    #[wasm_bindgen(js_name = decryptBackup)]
+    fn decrypt_backup(text: &str, passphrase: &str) -> js_sys::Promise;
+}
 
 fn open_db(on_success: impl FnOnce(IdbDatabase) + 'static) {
     let window = gloo::utils::window();
@@ -55,6 +75,24 @@ fn open_db(on_success: impl FnOnce(IdbDatabase) + 'static) {
 }
 
 pub fn save_backup(data: &str) {
+    put(BACKUP_KEY, data);
+}
+
+pub fn load_backup(on_loaded: impl FnOnce(Option<String>) + 'static) {
+    get(BACKUP_KEY, on_loaded);
+}
+
+/// Keep a copy of all data before a bulk delete or a restore from URL;
+/// restored with `load_snapshot`.
+pub fn save_snapshot(data: &str) {
+    put(SNAPSHOT_KEY, data);
+}
+
+pub fn load_snapshot(on_loaded: impl FnOnce(Option<String>) + 'static) {
+    get(SNAPSHOT_KEY, on_loaded);
+}
+
+fn put(key: &'static str, data: &str) {
     let data = data.to_string();
     open_db(move |db: IdbDatabase| {
         let tx = match db.transaction_with_str_and_mode(STORE_NAME, IdbTransactionMode::Readwrite) {
@@ -65,11 +103,11 @@ pub fn save_backup(data: &str) {
             Ok(s) => s,
             Err(_) => return,
         };
-        let _ = store.put_with_key(&JsValue::from_str(&data), &JsValue::from_str(BACKUP_KEY));
+        let _ = store.put_with_key(&JsValue::from_str(&data), &JsValue::from_str(key));
     });
 }
 
-pub fn load_backup(on_loaded: impl FnOnce(Option<String>) + 'static) {
+fn get(key: &'static str, on_loaded: impl FnOnce(Option<String>) + 'static) {
     open_db(move |db: IdbDatabase| {
         let tx = match db.transaction_with_str_and_mode(STORE_NAME, IdbTransactionMode::Readonly) {
             Ok(t) => t,
@@ -85,7 +123,7 @@ pub fn load_backup(on_loaded: impl FnOnce(Option<String>) + 'static) {
                 return;
             }
         };
-        let get_req: IdbRequest = match store.get(&JsValue::from_str(BACKUP_KEY)) {
+        let get_req: IdbRequest = match store.get(&JsValue::from_str(key)) {
             Ok(r) => r,
             Err(_) => {
                 on_loaded(None);
@@ -144,3 +182,152 @@ pub fn request_persistent_storage() {
         });
     }
 }
+
+/// Timestamp (ms since epoch) of the last backup file download, if any.
+pub fn last_file_backup() -> Option<f64> {
+    LocalStorage::get(LAST_FILE_BACKUP_KEY).ok()
+}
+
+/// Whether an automatic file backup is due. `interval_days == 0` disables it.
+pub fn file_backup_due(interval_days: u32) -> bool {
+    if interval_days == 0 {
+        return false;
+    }
+    match last_file_backup() {
+        Some(last) => js_sys::Date::now() - last >= interval_days as f64 * DAY_MS,
+        None => true,
+    }
+}
+
+/// Download all app data as `treening-backup-YYYY-MM-DD.json` and record the time.
+/// Returns the filename on success.
+pub fn download_backup_file() -> Option<String> {
+    let data = crate::storage::export_all_data();
+    let filename = format!("treening-backup-{}.json", crate::dates::today_str());
+    save_json_file(&filename, &data)?;
+    let _ = LocalStorage::set(LAST_FILE_BACKUP_KEY, js_sys::Date::now());
+    Some(filename)
+}
+
+/// Download all app data encrypted with `passphrase` (AES-GCM) as
+/// `treening-backup-YYYY-MM-DD.enc.json`. Returns the filename on success.
+pub async fn download_encrypted_backup_file(passphrase: &str) -> Result<String, String> {
+    let data = crate::storage::export_all_data();
+    let encrypted = call_crypto(encrypt_backup(&data, passphrase)).await?;
+    let filename = format!("treening-backup-{}.enc.json", crate::dates::today_str());
+    save_json_file(&filename, &encrypted).ok_or("Could not save the file")?;
+    let _ = LocalStorage::set(LAST_FILE_BACKUP_KEY, js_sys::Date::now());
+    Ok(filename)
+}
+
+/// Whether `text` is an encrypted backup rather than plain export JSON.
+pub fn is_encrypted(text: &str) -> bool {
+    serde_json::from_str::<serde_json::Value>(text)
+        .map(|v| v.get("treening_encrypted").is_some())
+        .unwrap_or(false)
+}
+
+/// Decrypt an encrypted backup back to export JSON. Fails with a readable
+/// message on a wrong passphrase or damaged file.
+pub async fn decrypt(text: &str, passphrase: &str) -> Result<String, String> {
+    call_crypto(decrypt_backup(text, passphrase)).await
+}
+
+async fn call_crypto(promise: js_sys::Promise) -> Result<String, String> {
+    match wasm_bindgen_futures::JsFuture::from(promise).await {
+        Ok(val) => val.as_string().ok_or_else(|| "Unexpected result".to_string()),
+        Err(err) => Err(err
+            .as_string()
+            .unwrap_or_else(|| "Encryption is not available in this browser".to_string())),
+    }
+}
+
+/// A user-run endpoint (WebDAV share, small server, etc.) that receives a copy
+/// of every automatic backup.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RemoteBackup {
+    pub url: String,
+    /// Sent as `Authorization: Bearer <token>` when non-empty.
+    #[serde(default)]
+    pub token: String,
+}
+
+pub fn remote_backup() -> RemoteBackup {
+    LocalStorage::get(REMOTE_BACKUP_KEY).unwrap_or_default()
+}
+
+pub fn save_remote_backup(remote: &RemoteBackup) {
+    let _ = LocalStorage::set(REMOTE_BACKUP_KEY, remote);
+}
+
+/// Timestamp (ms since epoch) of the last successful upload, if any.
+pub fn last_remote_backup() -> Option<f64> {
+    LocalStorage::get(LAST_REMOTE_BACKUP_KEY).ok()
+}
+
+/// Upload `data` in the background if a remote endpoint is configured.
+pub fn push_remote_backup(data: &str) {
+    let remote = remote_backup();
+    if remote.url.trim().is_empty() {
+        return;
+    }
+    let data = data.to_string();
+    wasm_bindgen_futures::spawn_local(async move {
+        if let Err(err) = upload_remote_backup(&remote, &data).await {
+            log::warn!("Remote backup failed: {}", err);
+        }
+    });
+}
+
+/// POST `data` as JSON to the configured endpoint and record the time.
+pub async fn upload_remote_backup(remote: &RemoteBackup, data: &str) -> Result<(), String> {
+    let request = with_auth(Request::post(remote.url.trim()), &remote.token)
+        .header("Content-Type", "application/json")
+        .body(data)
+        .map_err(|e| e.to_string())?;
+    let response = request.send().await.map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!("Server replied {} {}", response.status(), response.status_text()));
+    }
+    let _ = LocalStorage::set(LAST_REMOTE_BACKUP_KEY, js_sys::Date::now());
+    Ok(())
+}
+
+/// GET the backup stored at the configured endpoint. The result may be plain
+/// export JSON or an encrypted backup (see `is_encrypted`).
+pub async fn fetch_remote_backup(remote: &RemoteBackup) -> Result<String, String> {
+    let response = with_auth(Request::get(remote.url.trim()), &remote.token)
+        .send()
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.ok() {
+        return Err(format!("Server replied {} {}", response.status(), response.status_text()));
+    }
+    response.text().await.map_err(|e| e.to_string())
+}
+
+fn with_auth(builder: RequestBuilder, token: &str) -> RequestBuilder {
+    let token = token.trim();
+    if token.is_empty() {
+        builder
+    } else {
+        builder.header("Authorization", &format!("Bearer {}", token))
+    }
+}
+
+/// Offer `data` as a JSON file download named `filename`.
+pub fn save_json_file(filename: &str, data: &str) -> Option<()> {
+    let blob_parts = js_sys::Array::new();
+    blob_parts.push(&JsValue::from_str(data));
+    let opts = web_sys::BlobPropertyBag::new();
+    opts.set_type("application/json");
+    let blob = web_sys::Blob::new_with_str_sequence_and_options(&blob_parts, &opts).ok()?;
+    let url = web_sys::Url::create_object_url_with_blob(&blob).ok()?;
+    let elem = gloo::utils::document().create_element("a").ok()?;
+    let anchor: web_sys::HtmlAnchorElement = elem.unchecked_into();
+    anchor.set_href(&url);
+    anchor.set_download(filename);
+    anchor.click();
+    let _ = web_sys::Url::revoke_object_url(&url);
+    Some(())
+}
@@ -10,79 +10,167 @@ use yew::prelude::*;
 use yew::virtual_dom::VNode;
 use yew_router::prelude::*;
 
-/// Render simple markdown-like text to HTML nodes.
-/// Handles: **bold**, paragraphs (double newline), bullet lists (- or *), and numbered lists.
-fn render_markdown(text: &str) -> VNode {
-    let paragraphs: Vec<&str> = text.split("\n\n").collect();
-    let nodes: Vec<VNode> = paragraphs
-        .iter()
-        .map(|para| {
-            let trimmed = para.trim();
-            // Check if this paragraph is a list
-            let lines: Vec<&str> = trimmed.lines().collect();
-            let is_bullet_list = lines.iter().all(|l| {
-                let t = l.trim();
-                t.starts_with("- ") || t.starts_with("* ") || t.is_empty()
-            }) && lines.iter().any(|l| {
-                let t = l.trim();
-                t.starts_with("- ") || t.starts_with("* ")
-            });
-            let is_numbered_list = lines.iter().all(|l| {
-                let t = l.trim();
-                t.is_empty()
-                    || t.chars()
-                        .next()
-                        .map(|c| c.is_ascii_digit())
-                        .unwrap_or(false)
-                        && t.contains(". ")
-            }) && lines.iter().any(|l| {
-                let t = l.trim();
-                t.chars()
-                    .next()
-                    .map(|c| c.is_ascii_digit())
-                    .unwrap_or(false)
-                    && t.contains(". ")
+/// A block of the markdown subset the AI replies use.
+#[derive(Debug, PartialEq)]
+enum Block<'a> {
+    /// Level (1-3) and text
+    Heading(usize, &'a str),
+    BulletList(Vec<&'a str>),
+    NumberedList(Vec<&'a str>),
+    /// Lines rendered with line breaks between them
+    Paragraph(Vec<&'a str>),
+}
+
+/// An inline span within a block.
+#[derive(Debug, PartialEq)]
+enum Inline<'a> {
+    Text(&'a str),
+    Bold(Vec<Inline<'a>>),
+    Italic(&'a str),
+    Code(&'a str),
+}
+
+/// Split markdown-like text into blocks.
+/// Handles: `#`/`##`/`###` headings, paragraphs (double newline), bullet
+/// lists (- or *), and numbered lists.
+fn parse_blocks(text: &str) -> Vec<Block<'_>> {
+    let mut out = Vec::new();
+    for para in text.split("\n\n") {
+        let mut lines: Vec<&str> = para.trim().lines().collect();
+
+        // Leading headings are peeled off so a list right under a heading
+        // is still detected as a list
+        while let Some(heading) = lines.first().and_then(|l| parse_heading(l)) {
+            out.push(heading);
+            lines.remove(0);
+        }
+        if lines.is_empty() {
+            continue;
+        }
+
+        // Check if this paragraph is a list
+        let is_bullet = |t: &str| t.starts_with("- ") || t.starts_with("* ");
+        let is_numbered =
+            |t: &str| t.chars().next().is_some_and(|c| c.is_ascii_digit()) && t.contains(". ");
+        let items = || lines.iter().map(|l| l.trim()).filter(|t| !t.is_empty());
+
+        if items().all(is_bullet) {
+            out.push(Block::BulletList(
+                items().map(|t| t.trim_start_matches("- ").trim_start_matches("* ")).collect(),
+            ));
+        } else if items().all(is_numbered) {
+            // Strip "1. " prefix
+            out.push(Block::NumberedList(
+                items().map(|t| t.find(". ").map_or(t, |pos| &t[pos + 2..])).collect(),
+            ));
+        } else {
+            // Regular paragraph - a heading line mid-paragraph splits it
+            let mut para_lines = Vec::new();
+            for line in &lines {
+                if let Some(heading) = parse_heading(line) {
+                    if !para_lines.is_empty() {
+                        out.push(Block::Paragraph(std::mem::take(&mut para_lines)));
+                    }
+                    out.push(heading);
+                    continue;
+                }
+                para_lines.push(line.trim());
+            }
+            if !para_lines.is_empty() {
+                out.push(Block::Paragraph(para_lines));
+            }
+        }
+    }
+    out
+}
+
+/// A `#`, `##` or `###` heading line; `None` if the line isn't one.
+fn parse_heading(line: &str) -> Option<Block<'_>> {
+    let t = line.trim();
+    let level = t.chars().take_while(|c| *c == '#').count();
+    if !(1..=3).contains(&level) {
+        return None;
+    }
+    Some(Block::Heading(level, t[level..].strip_prefix(' ')?.trim()))
+}
+
+/// Split a line into **bold**, *italic* and `code` spans. Markers without a
+/// partner stay literal text.
+fn parse_inline(text: &str) -> Vec<Inline<'_>> {
+    let mut out = Vec::new();
+    // Start of text not yet emitted, and where to look for the next marker
+    let mut plain = 0;
+    let mut pos = 0;
+
+    while let Some(offset) = text[pos..].find(['*', '`']) {
+        let start = pos + offset;
+        let rest = &text[start..];
+        let (span, len) = if let Some(after) = rest.strip_prefix('`') {
+            // `code` — contents are kept verbatim
+            match after.find('`') {
+                Some(end) => (Some(Inline::Code(&after[..end])), end + 2),
+                None => (None, 1),
+            }
+        } else if let Some(after) = rest.strip_prefix("**") {
+            match after.find("**") {
+                Some(end) => (Some(Inline::Bold(parse_inline(&after[..end]))), end + 4),
+                None => (None, 2),
+            }
+        } else {
+            // Single *: italic only when it hugs the text, so "3 * 4" stays literal
+            let after = &rest[1..];
+            let end = after.find('*').filter(|&end| {
+                end > 0
+                    && !after.starts_with(char::is_whitespace)
+                    && !after[..end].ends_with(char::is_whitespace)
             });
+            match end {
+                Some(end) => (Some(Inline::Italic(&after[..end])), end + 2),
+                None => (None, 1),
+            }
+        };
+        if let Some(span) = span {
+            if plain < start {
+                out.push(Inline::Text(&text[plain..start]));
+            }
+            out.push(span);
+            plain = start + len;
+        }
+        pos = start + len;
+    }
+    if plain < text.len() {
+        out.push(Inline::Text(&text[plain..]));
+    }
+    out
+}
 
-            if is_bullet_list {
-                let items: Vec<VNode> = lines
-                    .iter()
-                    .filter(|l| !l.trim().is_empty())
-                    .map(|l| {
-                        let content = l.trim().trim_start_matches("- ").trim_start_matches("* ");
-                        html! { <li class="ml-4">{render_inline(content)}</li> }
-                    })
-                    .collect();
-                html! { <ul class="list-disc pl-2 space-y-0.5">{items}</ul> }
-            } else if is_numbered_list {
-                let items: Vec<VNode> = lines
-                    .iter()
-                    .filter(|l| !l.trim().is_empty())
-                    .map(|l| {
-                        let content = l.trim();
-                        // Strip "1. " prefix
-                        let content = if let Some(pos) = content.find(". ") {
-                            &content[pos + 2..]
-                        } else {
-                            content
-                        };
-                        html! { <li class="ml-4">{render_inline(content)}</li> }
-                    })
-                    .collect();
-                html! { <ol class="list-decimal pl-2 space-y-0.5">{items}</ol> }
-            } else {
-                // Regular paragraph - handle single newlines as line breaks
-                let line_nodes: Vec<VNode> = lines
-                    .iter()
-                    .enumerate()
-                    .flat_map(|(i, line)| {
-                        let mut nodes = vec![render_inline(line.trim())];
-                        if i < lines.len() - 1 {
-                            nodes.push(html! { <br /> });
-                        }
-                        nodes
-                    })
-                    .collect();
+/// Render simple markdown-like text to HTML nodes, as split by `parse_blocks`.
+fn render_markdown(text: &str) -> VNode {
+    let nodes: Vec<VNode> = parse_blocks(text)
+        .into_iter()
+        .map(|block| match block {
+            Block::Heading(1, content) => html! { <h3 class="text-base font-bold">{render_inline(content)}</h3> },
+            Block::Heading(2, content) => html! { <h4 class="text-sm font-bold">{render_inline(content)}</h4> },
+            Block::Heading(_, content) => html! { <h5 class="text-sm font-semibold">{render_inline(content)}</h5> },
+            Block::BulletList(items) => html! {
+                <ul class="list-disc pl-2 space-y-0.5">
+                    { for items.into_iter().map(|item| html! { <li class="ml-4">{render_inline(item)}</li> }) }
+                </ul>
+            },
+            Block::NumberedList(items) => html! {
+                <ol class="list-decimal pl-2 space-y-0.5">
+                    { for items.into_iter().map(|item| html! { <li class="ml-4">{render_inline(item)}</li> }) }
+                </ol>
+            },
+            Block::Paragraph(lines) => {
+                // Single newlines become line breaks
+                let mut line_nodes: Vec<VNode> = Vec::new();
+                for line in lines {
+                    if !line_nodes.is_empty() {
+                        line_nodes.push(html! { <br /> });
+                    }
+                    line_nodes.push(render_inline(line));
+                }
                 html! { <p class="mb-1.5 last:mb-0">{line_nodes}</p> }
             }
         })
@@ -91,31 +179,23 @@ fn render_markdown(text: &str) -> VNode {
     html! { <div class="space-y-2">{nodes}</div> }
 }
 
-/// Render inline formatting: **bold** and *italic*
+/// Render inline formatting: **bold**, *italic* and `code`
 fn render_inline(text: &str) -> VNode {
-    let mut nodes: Vec<VNode> = Vec::new();
-    let mut remaining = text;
-
-    while !remaining.is_empty() {
-        // Look for **bold**
-        if let Some(start) = remaining.find("**") {
-            if start > 0 {
-                nodes.push(html! { <>{&remaining[..start]}</> });
-            }
-            let after_start = &remaining[start + 2..];
-            if let Some(end) = after_start.find("**") {
-                nodes.push(html! { <strong class="font-bold">{&after_start[..end]}</strong> });
-                remaining = &after_start[end + 2..];
-            } else {
-                nodes.push(html! { <>{"**"}</> });
-                remaining = after_start;
-            }
-        } else {
-            nodes.push(html! { <>{remaining}</> });
-            break;
-        }
-    }
+    render_spans(parse_inline(text))
+}
 
+fn render_spans(spans: Vec<Inline<'_>>) -> VNode {
+    let nodes: Vec<VNode> = spans
+        .into_iter()
+        .map(|span| match span {
+            Inline::Text(t) => html! { <>{t}</> },
+            Inline::Bold(inner) => html! { <strong class="font-bold">{render_spans(inner)}</strong> },
+            Inline::Italic(t) => html! { <em class="italic">{t}</em> },
+            Inline::Code(t) => html! {
+                <code class="px-1 py-0.5 rounded bg-gray-200 dark:bg-gray-700 font-mono text-[0.85em]">{t}</code>
+            },
+        })
+        .collect();
     html! { <>{nodes}</> }
 }
 
@@ -177,11 +257,35 @@ struct ChatThread {
 const THREADS_KEY: &str = "treening_ai_chat_threads";
 const ACTIVE_THREAD_KEY: &str = "treening_ai_active_thread";
 const OLD_CHAT_HISTORY_KEY: &str = "treening_ai_chat_history";
-const MAX_THREADS: usize = 20;
+
+/// (threads to keep, days before a thread is auto-deleted; 0 keeps them forever)
+fn thread_limits() -> (usize, u32) {
+    let config = storage::load_user_config();
+    (config.ai_max_threads.max(1) as usize, config.ai_thread_max_age_days)
+}
+
+/// Drop threads past the configured age, except the active one. Returns
+/// whether anything was removed. The `ai_max_threads` cap is only applied
+/// when a new thread is started, after asking.
+fn prune_threads(threads: &mut Vec<ChatThread>) -> bool {
+    let (_, max_age_days) = thread_limits();
+    let before = threads.len();
+    if max_age_days > 0 {
+        let cutoff = (crate::dates::today() - chrono::Duration::days(max_age_days as i64))
+            .format("%Y-%m-%d")
+            .to_string();
+        let active = get_active_thread_id();
+        threads.retain(|t| t.created_at >= cutoff || active.as_deref() == Some(t.id.as_str()));
+    }
+    threads.len() != before
+}
 
 fn load_threads() -> Vec<ChatThread> {
     let mut threads: Vec<ChatThread> =
         gloo::storage::LocalStorage::get(THREADS_KEY).unwrap_or_default();
+    if prune_threads(&mut threads) {
+        save_threads(&threads);
+    }
 
     // Migrate old chat history if it exists
     if threads.is_empty() {
@@ -204,7 +308,7 @@ fn load_threads() -> Vec<ChatThread> {
                 id: uuid(),
                 title,
                 messages: old_msgs,
-                created_at: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                created_at: crate::dates::today_str(),
             };
             threads.push(thread);
             save_threads(&threads);
@@ -215,7 +319,9 @@ fn load_threads() -> Vec<ChatThread> {
 }
 
 fn save_threads(threads: &[ChatThread]) {
-    let _ = gloo::storage::LocalStorage::set(THREADS_KEY, threads);
+    let mut kept = threads.to_vec();
+    prune_threads(&mut kept);
+    let _ = gloo::storage::LocalStorage::set(THREADS_KEY, kept);
 }
 
 fn get_active_thread_id() -> Option<String> {
@@ -244,13 +350,10 @@ fn get_or_create_active_thread(threads: &mut Vec<ChatThread>) -> usize {
         id: uuid(),
         title: "New Chat".to_string(),
         messages: Vec::new(),
-        created_at: chrono::Local::now().format("%Y-%m-%d").to_string(),
+        created_at: crate::dates::today_str(),
     };
     let id = thread.id.clone();
     threads.insert(0, thread);
-    if threads.len() > MAX_THREADS {
-        threads.truncate(MAX_THREADS);
-    }
     set_active_thread_id(&id);
     save_threads(threads);
     0
@@ -305,7 +408,7 @@ fn generate_quick_prompts() -> Vec<String> {
     }
 
     // Rotate by day of week for variety: shift the list
-    let day = chrono::Local::now().weekday().num_days_from_monday() as usize;
+    let day = crate::dates::today().weekday().num_days_from_monday() as usize;
     if prompts.len() > 2 {
         let shift = day % (prompts.len() - 1);
         // Keep first 2 fixed, rotate the rest
@@ -322,6 +425,43 @@ fn generate_quick_prompts() -> Vec<String> {
 }
 
 // --- Actionable AI Outputs ---
+
+/// Whether `needle` occurs in `haystack` as whole words, so short aliases like
+/// "dl" don't match inside other words. Both are expected lowercase.
+fn contains_words(haystack: &str, needle: &str) -> bool {
+    haystack.match_indices(needle).any(|(i, _)| {
+        let before = haystack[..i].chars().next_back();
+        let after = haystack[i + needle.len()..].chars().next();
+        !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+    })
+}
+
+/// Exercise whose alias best matches `text` (lowercase): an alias equal to the
+/// whole text wins, then the longest alias found in it as whole words, so
+/// "split squat" resolves to the split squat rather than through "squat".
+fn find_by_alias<'a>(
+    exercises: impl Iterator<Item = &'a models::Exercise> + Clone,
+    text: &str,
+) -> Option<&'a models::Exercise> {
+    exercises
+        .clone()
+        .find(|ex| ex.aliases.iter().any(|a| a.to_lowercase() == text))
+        .or_else(|| {
+            exercises
+                .filter_map(|ex| {
+                    ex.aliases
+                        .iter()
+                        .map(|a| a.to_lowercase())
+                        .filter(|a| contains_words(text, a))
+                        .map(|a| a.len())
+                        .max()
+                        .map(|len| (len, ex))
+                })
+                .max_by_key(|(len, _)| *len)
+                .map(|(_, ex)| ex)
+        })
+}
+
 fn parse_workout_exercises(text: &str) -> Vec<(String, String)> {
     // Returns vec of (exercise_id, exercise_name) for matched exercises
     let all_exercises = {
@@ -357,14 +497,15 @@ fn parse_workout_exercises(text: &str) -> Vec<(String, String)> {
         };
 
         let content_lower = content.to_lowercase();
-        // Try to match against known exercises (case-insensitive substring)
-        for ex in &all_exercises {
-            let ex_lower = ex.name.to_lowercase();
-            if content_lower.contains(&ex_lower) && !seen_ids.contains(&ex.id) {
-                seen_ids.insert(ex.id.clone());
-                matched.push((ex.id.clone(), ex.name.clone()));
-                break;
-            }
+        // Try to match against known exercises (case-insensitive substring),
+        // falling back to aliases so "- OHP: 3x5" still resolves
+        let unseen = || all_exercises.iter().filter(|ex| !seen_ids.contains(&ex.id));
+        let found = unseen()
+            .find(|ex| content_lower.contains(&ex.name.to_lowercase()))
+            .or_else(|| find_by_alias(unseen(), &content_lower));
+        if let Some(ex) = found {
+            seen_ids.insert(ex.id.clone());
+            matched.push((ex.id.clone(), ex.name.clone()));
         }
     }
 
@@ -408,17 +549,21 @@ fn parse_workout_log(text: &str) -> Option<Vec<(String, String, Vec<models::Work
         let ex_name_raw = content[..colon_pos].trim();
         let sets_str = content[colon_pos + 1..].trim();
 
-        // Match exercise name case-insensitively
+        // Match exercise name or alias case-insensitively
         let ex_lower = ex_name_raw.to_lowercase();
         let matched_ex = all_exercises
             .iter()
-            .find(|e| e.name.to_lowercase() == ex_lower)
+            .find(|e| {
+                e.name.to_lowercase() == ex_lower
+                    || e.aliases.iter().any(|a| a.to_lowercase() == ex_lower)
+            })
             .or_else(|| {
                 all_exercises.iter().find(|e| {
                     e.name.to_lowercase().contains(&ex_lower)
                         || ex_lower.contains(&e.name.to_lowercase())
                 })
-            });
+            })
+            .or_else(|| find_by_alias(all_exercises.iter(), &ex_lower));
 
         let (ex_id, ex_display_name) = match matched_ex {
             Some(ex) => (ex.id.clone(), ex.name.clone()),
@@ -446,6 +591,10 @@ fn parse_workout_log(text: &str) -> Option<Vec<(String, String, Vec<models::Work
                     duration_secs: None,
                     distance: None,
                     note: None,
+                    rest_taken_secs: None,
+                    failure: None,
+                    bodyweight: None,
+                    completed_at: None,
                 });
             }
             // Try duration "30s" or "2min"
@@ -457,6 +606,10 @@ fn parse_workout_log(text: &str) -> Option<Vec<(String, String, Vec<models::Work
                     duration_secs: Some(secs),
                     distance: None,
                     note: None,
+                    rest_taken_secs: None,
+                    failure: None,
+                    bodyweight: None,
+                    completed_at: None,
                 });
             }
         }
@@ -497,6 +650,10 @@ fn parse_weight_reps_token(token: &str, us: &models::UnitSystem) -> Option<model
         duration_secs: None,
         distance: None,
         note: None,
+        rest_taken_secs: None,
+        failure: None,
+        bodyweight: None,
+        completed_at: None,
     })
 }
 
@@ -524,14 +681,99 @@ fn parse_duration_token(token: &str) -> Option<u32> {
     }
 }
 
+/// Recognize phrases like "last 30 days", "past 2 weeks" or "this month" and
+/// return the matching range in days.
+pub fn detect_date_range(text: &str) -> Option<i64> {
+    let lower = text.to_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let unit_days = |w: &str| -> Option<i64> {
+        match w.trim_end_matches('s') {
+            "day" => Some(1),
+            "week" => Some(7),
+            "month" => Some(30),
+            "year" => Some(365),
+            _ => None,
+        }
+    };
+    for (i, w) in words.iter().enumerate() {
+        if !matches!(*w, "last" | "past" | "this") {
+            continue;
+        }
+        let Some(next) = words.get(i + 1) else {
+            continue;
+        };
+        if let Ok(n) = next.parse::<i64>() {
+            if let Some(days) = words.get(i + 2).and_then(|u| unit_days(u)) {
+                if n > 0 {
+                    return Some(n * days);
+                }
+            }
+        } else if let Some(days) = unit_days(next) {
+            return Some(days);
+        }
+    }
+    None
+}
+
+/// Below this many logged workouts the coach is told its data is too thin
+/// for trends or PRs.
+const SPARSE_DATA_WORKOUTS: usize = 3;
+
+/// Whether the message asks for concrete numbers (PRs, maxes, totals) that
+/// the model may be tempted to make up when nothing has been logged.
+fn asks_for_stats(text: &str) -> bool {
+    let lower = text.to_lowercase();
+    const KEYWORDS: &[&str] = &[
+        "pr", "prs", "record", "records", "max", "1rm", "best", "heaviest", "volume", "stats",
+        "progress", "average", "total", "how much", "how many",
+    ];
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    KEYWORDS.iter().any(|k| {
+        if k.contains(' ') {
+            lower.contains(k)
+        } else {
+            words.contains(k)
+        }
+    })
+}
+
+/// How close (in px) to the bottom of the chat still counts as "reading the
+/// latest", so streamed output keeps following.
+const NEAR_BOTTOM_PX: i32 = 80;
+
+/// Longest custom persona kept in the prompt, so it can't crowd out the data.
+pub const MAX_CUSTOM_PERSONA_CHARS: usize = 300;
+
 pub fn build_system_prompt() -> String {
+    build_system_prompt_for(None)
+}
+
+/// Build the coach prompt, optionally scoping workouts and body metrics to the
+/// last `range_days` days so smaller models get a shorter, focused context.
+pub fn build_system_prompt_for(range_days: Option<i64>) -> String {
     use std::collections::HashMap;
 
     let config = storage::load_user_config();
-    let workouts = storage::load_workouts();
-    let body_metrics = storage::load_body_metrics();
+    let mut workouts = storage::load_workouts();
+    let mut body_metrics = storage::load_body_metrics();
     let routines = storage::load_routines();
 
+    let range_start = range_days.map(|days| {
+        (crate::dates::today() - chrono::Duration::days(days - 1))
+            .format("%Y-%m-%d")
+            .to_string()
+    });
+    if let Some(start) = &range_start {
+        workouts.retain(|w| w.date.as_str() >= start.as_str());
+        body_metrics.retain(|m| m.date.as_str() >= start.as_str());
+    }
+
     let us = &config.unit_system;
     let wl = us.weight_label();
 
@@ -558,15 +800,17 @@ pub fn build_system_prompt() -> String {
     let mut sorted: Vec<&crate::models::Workout> = workouts.iter().collect();
     sorted.sort_by(|a, b| b.date.cmp(&a.date));
 
-    // --- Body metrics (latest + trend) ---
+    // --- Body metrics (latest + trend), in the body-weight unit ---
+    let bu = config.body_units();
+    let bwl = bu.weight_label();
     let mut body_str = String::new();
     if !body_metrics.is_empty() {
         let latest = &body_metrics[0];
         if let Some(w) = latest.weight {
             body_str.push_str(&format!(
                 "Current weight: {:.1}{} ({})",
-                us.display_weight(w),
-                wl,
+                bu.display_weight(w),
+                bwl,
                 latest.date
             ));
             // Find oldest weight for trend
@@ -577,8 +821,8 @@ pub fn build_system_prompt() -> String {
                     body_str.push_str(&format!(
                         ", change: {}{:.1}{}",
                         sign,
-                        us.display_weight(diff),
-                        wl
+                        bu.display_weight(diff),
+                        bwl
                     ));
                 }
             }
@@ -628,7 +872,7 @@ pub fn build_system_prompt() -> String {
         .collect();
 
     // --- Volume by muscle group (all-time + this week) ---
-    let today = chrono::Local::now().date_naive();
+    let today = crate::dates::today();
     let week_start = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
 
     let mut muscle_total: HashMap<String, f64> = HashMap::new();
@@ -808,6 +1052,37 @@ pub fn build_system_prompt() -> String {
         })
         .collect();
 
+    // --- Equipment the user actually trains with ---
+    let mut equipment_counts: HashMap<String, u32> = HashMap::new();
+    for w in &workouts {
+        for we in &w.exercises {
+            if let Some(ex) = find_ex(&we.exercise_id) {
+                *equipment_counts.entry(ex.equipment.to_string()).or_insert(0) += 1;
+            }
+        }
+    }
+    let equipment_info: Vec<String> = {
+        let mut items: Vec<(String, u32)> = equipment_counts.into_iter().collect();
+        items.sort_by(|a, b| b.1.cmp(&a.1));
+        items
+            .iter()
+            .map(|(eq, count)| format!("{} ({}x)", eq, count))
+            .collect()
+    };
+    let equipment_catalog: Vec<String> = models::Equipment::all()
+        .iter()
+        .map(|eq| {
+            let names: Vec<&str> = all_exercises
+                .iter()
+                .filter(|e| e.equipment == *eq && e.difficulty <= config.experience_level)
+                .take(8)
+                .map(|e| e.name.as_str())
+                .collect();
+            format!("{}: {}", eq, names.join(", "))
+        })
+        .filter(|line| !line.ends_with(": "))
+        .collect();
+
     // --- Routines with exercises ---
     let routine_info: Vec<String> = routines
         .iter()
@@ -817,9 +1092,58 @@ pub fn build_system_prompt() -> String {
         })
         .collect();
 
+    // --- Date context: small models miscount "this week" without it ---
+    let week_end = week_start + chrono::Duration::days(6);
+    let mut date_context = format!(
+        "TODAY: {} (timezone UTC{})\n\
+         CURRENT WEEK: Monday {} to Sunday {}. \"This week\" means this window; \
+         \"last week\" means the 7 days before it.\n",
+        today.format("%A, %Y-%m-%d"),
+        crate::dates::utc_offset(),
+        week_start.format("%Y-%m-%d"),
+        week_end.format("%Y-%m-%d"),
+    );
+    if !muscle_last_trained.is_empty() {
+        // A group trained today or yesterday is still inside the 48h recovery window
+        let (mut recovering, mut recovered): (Vec<&str>, Vec<&str>) = (Vec::new(), Vec::new());
+        for (group, date) in &muscle_last_trained {
+            let days_ago = chrono::NaiveDate::parse_from_str(date, "%Y-%m-%d")
+                .map(|d| (today - d).num_days())
+                .unwrap_or(i64::MAX);
+            if days_ago < 2 {
+                recovering.push(group);
+            } else {
+                recovered.push(group);
+            }
+        }
+        recovering.sort();
+        recovered.sort();
+        if recovered.is_empty() {
+            date_context.push_str(&format!(
+                "RECOVERY: likely a REST DAY - every muscle group they train was worked in the last 48h ({})\n",
+                recovering.join(", ")
+            ));
+        } else {
+            date_context.push_str(&format!(
+                "RECOVERY: training day - recovered (48h+): {}; still recovering: {}\n",
+                recovered.join(", "),
+                if recovering.is_empty() { "none".to_string() } else { recovering.join(", ") }
+            ));
+        }
+    }
+
     // === Build prompt ===
+    let custom_persona = config.ai_custom_persona.split_whitespace().collect::<Vec<_>>().join(" ");
+    let persona = match config.ai_persona {
+        models::AiPersona::Custom if !custom_persona.is_empty() => format!(
+            "a personal gym coach with this persona: {}",
+            custom_persona.chars().take(MAX_CUSTOM_PERSONA_CHARS).collect::<String>()
+        ),
+        p => p.description().to_string(),
+    };
     let mut prompt = format!(
-        "You are Coach T, a friendly and knowledgeable personal gym coach for {}. \
+        "{}\n\
+         You are Coach T, {}, for {}. \
          You have COMPLETE access to their workout data below. \
          Use this data to give accurate, specific answers.\n\n\
          RULES:\n\
@@ -829,7 +1153,10 @@ pub fn build_system_prompt() -> String {
          - Use bullet points or numbered lists when helpful\n\
          - Keep responses focused but thorough\n\
          - Units: {}\n\
+         - Always reply in {}, but keep [WORKOUT LOG] markers and exercise names exactly as listed\n\
          - If they have no data yet, welcome them and suggest getting started\n\
+         - NEVER invent numbers, PRs, dates or past workouts. If something isn't in USER DATA, \
+           say it hasn't been logged yet instead of guessing\n\
          - When suggesting a workout, format each exercise on its own line starting with \"- \" \
            followed by the exercise name, e.g. \"- Bench Press: 3x10\"\n\
          - When the user tells you about a workout they already completed (e.g. \"I did bench press 80kg 3x10\"), \
@@ -839,13 +1166,25 @@ pub fn build_system_prompt() -> String {
            - ExerciseName: weight x reps, weight x reps\n\
            [/WORKOUT LOG]\n\
            Use the user's unit system. Include all sets they mention. For bodyweight exercises use \"x reps\". \
-           For duration exercises use \"30s\" or \"2min\". Always use exact exercise names from the database.\n\n\
-         === USER DATA ===\n",
+           For duration exercises use \"30s\" or \"2min\". Always use exact exercise names from the database.\n\
+         - When the user mentions limited equipment (e.g. home workout, dumbbells only), only suggest exercises \
+           from the matching EXERCISES BY EQUIPMENT list\n\n\
+         === USER DATA ===\n{}",
+        date_context,
+        persona,
         config.nickname,
         if *us == models::UnitSystem::Metric {
             "kg/km"
         } else {
             "lbs/mi"
+        },
+        config.language.english_name(),
+        match (&range_start, range_days) {
+            (Some(start), Some(days)) => format!(
+                "DATA RANGE: last {} days only (since {}). Answer about this period.\n",
+                days, start
+            ),
+            _ => String::new(),
         }
     );
 
@@ -859,10 +1198,25 @@ pub fn build_system_prompt() -> String {
             streak,
             best_streak
         ));
+        if total_workouts < SPARSE_DATA_WORKOUTS {
+            prompt.push_str(&format!(
+                "DATA NOTE: only {} workout(s) logged - too few for trends or reliable PRs. \
+                 Do not extrapolate beyond what is listed\n",
+                total_workouts
+            ));
+        }
     } else {
-        prompt.push_str("OVERVIEW: New user, no workouts yet\n");
+        prompt.push_str(
+            "OVERVIEW: New user, no workouts yet. They have NO PRs, history or stats - \
+             do not make any up\n",
+        );
     }
 
+    prompt.push_str(&format!(
+        "EXPERIENCE: {} - don't suggest exercises harder than this level\n",
+        config.experience_level.display_name()
+    ));
+
     // Body
     if !body_str.is_empty() {
         prompt.push_str(&format!("BODY: {}\n", body_str));
@@ -917,6 +1271,17 @@ pub fn build_system_prompt() -> String {
         }
     }
 
+    // Equipment
+    if !equipment_info.is_empty() {
+        prompt.push_str(&format!("EQUIPMENT USED: {}\n", equipment_info.join(", ")));
+    }
+    if !equipment_catalog.is_empty() {
+        prompt.push_str("EXERCISES BY EQUIPMENT:\n");
+        for line in &equipment_catalog {
+            prompt.push_str(&format!("  {}\n", line));
+        }
+    }
+
     // Routines
     if !routine_info.is_empty() {
         prompt.push_str(&format!("ROUTINES: {}\n", routine_info.join("; ")));
@@ -925,6 +1290,377 @@ pub fn build_system_prompt() -> String {
     prompt
 }
 
+/// Sets assumed per exercise when a routine has no saved set targets.
+const ASSUMED_ROUTINE_SETS: usize = 3;
+
+/// Prompt asking the model to critique `routine` against the user's volume
+/// landmarks. Weekly effective sets per muscle are computed here so the model
+/// only has to reason about the numbers, not derive them.
+pub fn build_program_review(routine: &models::Routine) -> String {
+    use std::collections::HashMap;
+
+    let config = storage::load_user_config();
+    let custom = storage::load_custom_exercises();
+    let all_exercises = {
+        let mut exs = crate::data::default_exercises();
+        exs.extend(custom);
+        exs
+    };
+    let thresholds = crate::pages::muscles::get_thresholds();
+    let fractional = crate::pages::muscles::count_fractional();
+
+    let scheduled = config
+        .routine_schedule
+        .values()
+        .filter(|rid| **rid == routine.id)
+        .count();
+    let per_week = scheduled.max(1);
+
+    let mut prompt = format!(
+        "Critique my training program \"{}\" against my volume landmarks.\n\n",
+        routine.name
+    );
+    if scheduled > 0 {
+        prompt.push_str(&format!("FREQUENCY: {}x per week (from my weekly schedule)\n", per_week));
+    } else {
+        prompt.push_str("FREQUENCY: assumed 1x per week (not on my weekly schedule)\n");
+    }
+
+    prompt.push_str("EXERCISES (sets per session):\n");
+    let mut weekly: HashMap<&'static str, f64> = HashMap::new();
+    for eid in &routine.exercise_ids {
+        let ex = all_exercises.iter().find(|e| &e.id == eid);
+        let name = ex.map(|e| e.name.as_str()).unwrap_or(eid.as_str());
+        let planned = routine.targets.get(eid).map_or(0, |t| t.len());
+        let sets = if planned > 0 { planned } else { ASSUMED_ROUTINE_SETS };
+        prompt.push_str(&format!(
+            "- {}: {} sets{}\n",
+            name,
+            sets,
+            if planned > 0 { "" } else { " (assumed, no targets saved)" }
+        ));
+        let custom_mg = ex.filter(|e| e.is_custom).map(|e| e.muscle_groups.as_slice());
+        for (muscle, eff) in
+            crate::muscle_data::effective_sets_for_exercise(eid, sets * per_week, custom_mg, fractional)
+        {
+            *weekly.entry(muscle).or_insert(0.0) += eff;
+        }
+    }
+
+    prompt.push_str(
+        "\nWEEKLY EFFECTIVE SETS PER MUSCLE vs MY LANDMARKS (MEV = minimum effective volume, MRV = maximum recoverable volume):\n",
+    );
+    for muscle in crate::muscle_data::TRACKED_MUSCLES {
+        let sets = weekly.get(muscle).copied().unwrap_or(0.0);
+        let Some(&(mev, mrv)) = thresholds.get(*muscle) else {
+            continue;
+        };
+        let verdict = if sets < mev {
+            format!("BELOW MEV by {:.1}", mev - sets)
+        } else if sets > mrv {
+            format!("ABOVE MRV by {:.1}", sets - mrv)
+        } else {
+            "within range".to_string()
+        };
+        prompt.push_str(&format!(
+            "- {}: {:.1} sets (MEV {:.0}, MRV {:.0}) -> {}\n",
+            muscle, sets, mev, mrv, verdict
+        ));
+    }
+
+    prompt.push_str(
+        "\nFor every muscle below MEV or above MRV, cite the numbers, e.g. \"this routine gives chest 4 sets, below your MEV of 6\". \
+         Then suggest concrete fixes: exercises to add, drop or swap and how many sets. \
+         Mention anything already balanced only briefly. Keep it under 250 words.",
+    );
+    prompt
+}
+
+/// Deterministic markdown recap of the last `days` days of training, suitable
+/// for pasting to a coach. Built from local data only (no LLM involved).
+pub fn build_training_report(days: i64) -> String {
+    use std::collections::HashMap;
+
+    let config = storage::load_user_config();
+    let workouts = storage::load_workouts();
+    let us = &config.unit_system;
+    let wl = us.weight_label();
+
+    let all_exercises = {
+        let mut exs = crate::data::default_exercises();
+        exs.extend(storage::load_custom_exercises());
+        exs
+    };
+    let ex_name = |id: &str| -> String {
+        all_exercises
+            .iter()
+            .find(|e| e.id == id)
+            .map(|e| e.name.clone())
+            .unwrap_or_else(|| id.to_string())
+    };
+
+    let today = crate::dates::today();
+    let from = today - chrono::Duration::days(days - 1);
+    let parse = |d: &str| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok();
+
+    let mut period: Vec<&models::Workout> = workouts
+        .iter()
+        .filter(|w| parse(&w.date).map(|d| d >= from && d <= today).unwrap_or(false))
+        .collect();
+    period.sort_by(|a, b| a.date.cmp(&b.date));
+
+    let mut report = format!(
+        "# Training Recap: {} to {}\n\n",
+        from.format("%b %-d, %Y"),
+        today.format("%b %-d, %Y")
+    );
+    if !config.nickname.is_empty() {
+        report.push_str(&format!("Athlete: {}\n\n", config.nickname));
+    }
+
+    if period.is_empty() {
+        report.push_str("No workouts logged in this period.\n");
+        return report;
+    }
+
+    // --- Sessions ---
+    let sessions = period.len();
+    let total_volume: f64 = period.iter().map(|w| w.total_volume()).sum();
+    let total_mins: u32 = period.iter().map(|w| w.duration_mins).sum();
+    let total_sets: usize = period
+        .iter()
+        .flat_map(|w| w.exercises.iter())
+        .map(|we| we.sets.iter().filter(|s| s.completed).count())
+        .sum();
+    report.push_str("## Sessions\n");
+    report.push_str(&format!("- Workouts: {}\n", sessions));
+    report.push_str(&format!("- Completed sets: {}\n", total_sets));
+    report.push_str(&format!(
+        "- Total volume: {:.0}{}\n",
+        us.display_weight(total_volume),
+        wl
+    ));
+    if total_mins > 0 {
+        report.push_str(&format!(
+            "- Avg duration: {}min\n",
+            total_mins / sessions as u32
+        ));
+    }
+
+    // --- Adherence ---
+    let weeks = ((days as f64) / 7.0).ceil().max(1.0);
+    let mut active_weeks: HashSet<(i32, u32)> = HashSet::new();
+    let mut active_days: HashSet<String> = HashSet::new();
+    for w in &period {
+        if let Some(d) = parse(&w.date) {
+            let iso = d.iso_week();
+            active_weeks.insert((iso.year(), iso.week()));
+        }
+        active_days.insert(w.date.clone());
+    }
+    report.push_str("\n## Adherence\n");
+    report.push_str(&format!(
+        "- Training days: {} of {}\n",
+        active_days.len(),
+        days
+    ));
+    report.push_str(&format!(
+        "- Avg sessions/week: {:.1}\n",
+        sessions as f64 / weeks
+    ));
+    report.push_str(&format!(
+        "- Weeks with training: {} of {:.0}\n",
+        active_weeks.len(),
+        weeks
+    ));
+    report.push_str(&format!(
+        "- Current streak: {}d\n",
+        models::current_streak(&workouts)
+    ));
+
+    // --- Volume per muscle (effective sets) ---
+    let owned: Vec<models::Workout> = period.iter().map(|w| (*w).clone()).collect();
+    let muscle_sets =
+        crate::pages::muscles::compute_muscle_sets(&owned, &all_exercises, from, today);
+    let mut muscles: Vec<(String, f64)> = muscle_sets.into_iter().filter(|(_, v)| *v > 0.0).collect();
+    muscles.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    if !muscles.is_empty() {
+        report.push_str("\n## Volume per Muscle (effective sets)\n");
+        for (m, v) in &muscles {
+            report.push_str(&format!("- {}: {:.1} sets ({:.1}/week)\n", m, v, v / weeks));
+        }
+    }
+
+    // --- PRs set in this period ---
+    let mut best_before: HashMap<String, f64> = HashMap::new();
+    for w in &workouts {
+        if parse(&w.date).map(|d| d < from).unwrap_or(false) {
+            for we in &w.exercises {
+                for s in we.sets.iter().filter(|s| s.completed && s.weight > 0.0) {
+                    let e = best_before.entry(we.exercise_id.clone()).or_insert(0.0);
+                    if s.weight > *e {
+                        *e = s.weight;
+                    }
+                }
+            }
+        }
+    }
+    let mut period_prs: HashMap<String, (f64, u32, String)> = HashMap::new();
+    for w in &period {
+        for we in &w.exercises {
+            for s in we.sets.iter().filter(|s| s.completed && s.weight > 0.0) {
+                let prior = best_before.get(&we.exercise_id).copied().unwrap_or(0.0);
+                if s.weight <= prior {
+                    continue;
+                }
+                let e = period_prs
+                    .entry(we.exercise_id.clone())
+                    .or_insert((0.0, 0, String::new()));
+                if s.weight > e.0 {
+                    *e = (s.weight, s.reps, w.date.clone());
+                }
+            }
+        }
+    }
+    let mut pr_list: Vec<(String, f64, u32, String)> = period_prs
+        .into_iter()
+        .map(|(id, (w, r, d))| (id, w, r, d))
+        .collect();
+    pr_list.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    if !pr_list.is_empty() {
+        report.push_str("\n## Top PRs\n");
+        for (id, w, r, d) in pr_list.iter().take(5) {
+            report.push_str(&format!(
+                "- {}: {:.1}{} x{} ({})\n",
+                ex_name(id),
+                us.display_weight(*w),
+                wl,
+                r,
+                d
+            ));
+        }
+    }
+
+    // --- Progression trends (last 4 weeks) ---
+    let trends = crate::pages::muscles::overload_summary(&workouts, &all_exercises);
+    if !trends.is_empty() {
+        report.push_str("\n## Progression Trends (4 weeks, e1RM)\n");
+        for (name, label, e1rm) in &trends {
+            report.push_str(&format!(
+                "- {}: {} ({:.0}{})\n",
+                name,
+                label,
+                us.display_weight(*e1rm),
+                wl
+            ));
+        }
+    }
+
+    report
+}
+
+/// Short plain-text digest of the current week (Monday to today) compared with
+/// last week, for pasting into a journal.
+pub fn build_weekly_digest() -> String {
+    let config = storage::load_user_config();
+    let workouts = storage::load_workouts();
+    let us = &config.unit_system;
+    let wl = us.weight_label();
+
+    let all_exercises = {
+        let mut exs = crate::data::default_exercises();
+        exs.extend(storage::load_custom_exercises());
+        exs
+    };
+
+    let today = crate::dates::today();
+    let week_start = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+    let last_start = week_start - chrono::Duration::days(7);
+    let parse = |d: &str| chrono::NaiveDate::parse_from_str(d, "%Y-%m-%d").ok();
+    let in_range = |w: &&models::Workout, from: chrono::NaiveDate, to: chrono::NaiveDate| {
+        parse(&w.date).map(|d| d >= from && d <= to).unwrap_or(false)
+    };
+    let this_week: Vec<&models::Workout> = workouts
+        .iter()
+        .filter(|w| in_range(w, week_start, today))
+        .collect();
+    let last_week: Vec<&models::Workout> = workouts
+        .iter()
+        .filter(|w| in_range(w, last_start, week_start - chrono::Duration::days(1)))
+        .collect();
+
+    let mut digest = format!(
+        "Weekly digest: {} to {}\n",
+        week_start.format("%b %-d"),
+        today.format("%b %-d, %Y")
+    );
+    digest.push_str(&format!(
+        "- Sessions: {} (last week {})\n",
+        this_week.len(),
+        last_week.len()
+    ));
+
+    let volume: f64 = this_week.iter().map(|w| w.total_volume()).sum();
+    let last_volume: f64 = last_week.iter().map(|w| w.total_volume()).sum();
+    let change = if last_volume > 0.0 {
+        format!(" ({:+.0}% vs last week)", (volume - last_volume) / last_volume * 100.0)
+    } else {
+        String::new()
+    };
+    digest.push_str(&format!(
+        "- Volume: {:.0}{}{}\n",
+        us.display_weight(volume),
+        wl,
+        change
+    ));
+
+    let top_lift = this_week
+        .iter()
+        .flat_map(|w| w.exercises.iter())
+        .flat_map(|we| {
+            we.sets
+                .iter()
+                .filter(|s| s.completed && s.weight > 0.0)
+                .map(move |s| (&we.exercise_id, s.weight, s.reps))
+        })
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    if let Some((id, weight, reps)) = top_lift {
+        let name = all_exercises
+            .iter()
+            .find(|e| e.id == *id)
+            .map(|e| e.name.clone())
+            .unwrap_or_else(|| id.clone());
+        digest.push_str(&format!(
+            "- Top lift: {} {:.1}{} x{}\n",
+            name,
+            us.display_weight(weight),
+            wl,
+            reps
+        ));
+    }
+
+    // One tip: a stalled lift first, then muscle balance
+    let trends = crate::pages::muscles::overload_summary(&workouts, &all_exercises);
+    let (undertrained, overtrained) =
+        crate::pages::muscles::muscle_balance_summary(&workouts, &all_exercises);
+    let tip = if let Some((name, label, _)) = trends.iter().find(|(_, l, _)| *l == "Regressing") {
+        format!("{} is {} - consider a lighter week or fewer sets to recover.", name, label.to_lowercase())
+    } else if let Some((name, _, _)) = trends.iter().find(|(_, l, _)| *l == "Stagnant") {
+        format!("{} has stalled - try adding a rep or a small weight jump.", name)
+    } else if undertrained > 0 {
+        format!("{} muscle group(s) are below minimum volume - add a few sets.", undertrained)
+    } else if overtrained > 0 {
+        format!("{} muscle group(s) are above max volume - ease off a little.", overtrained)
+    } else if this_week.is_empty() {
+        "No sessions yet this week - a short one still counts.".to_string()
+    } else {
+        "Everything is on track - keep it up.".to_string()
+    };
+    digest.push_str(&format!("- Tip: {}\n", tip));
+
+    digest
+}
+
 #[function_component(AiChat)]
 pub fn ai_chat() -> Html {
     let threads = use_state(load_threads);
@@ -939,14 +1675,28 @@ pub fn ai_chat() -> Html {
     let input_text = use_state(String::new);
     let input_ref = use_node_ref();
     let messages_end_ref = use_node_ref();
+    let dot_motion = if storage::reduce_motion(&storage::load_user_config()) {
+        ""
+    } else {
+        "animate-bounce"
+    };
     let chat_container_ref = use_node_ref();
     let scroll_trigger = use_state(|| 0u32);
+    // Follow new output only while the user is reading at the bottom
+    let stick_to_bottom = use_mut_ref(|| true);
+    let show_jump_latest = use_state(|| false);
     let show_thread_list = use_state(|| false);
+    // Titles of the oldest threads a new chat would push out, awaiting confirmation
+    let pending_evict = use_state(|| None::<Vec<String>>);
+    let show_program_review = use_state(|| false);
     let is_recording = use_state(|| false);
     let show_export_toast = use_state(|| false);
     let speaking_msg_idx = use_state(|| Option::<usize>::None);
     let saved_workout_indices = use_state(HashSet::<usize>::new);
+    // (message index, exercise id) unticked in a suggested workout's checklist
+    let skipped_suggestions = use_state(HashSet::<(usize, String)>::new);
     let streaming_content = use_state(String::new);
+    let context_range = use_state(|| Option::<i64>::None);
     let navigator = use_navigator().unwrap();
 
     // Get active thread messages
@@ -968,20 +1718,70 @@ pub fn ai_chat() -> Html {
             .unwrap_or_else(|| "New Chat".to_string())
     };
 
-    // Auto-scroll to bottom when messages change or scroll_trigger fires
+    // A new message (sent or thread switched) always jumps back to the bottom
+    {
+        let stick_to_bottom = stick_to_bottom.clone();
+        let msgs_len = active_messages.len();
+        let tid = (*active_thread_id).clone();
+        use_effect_with((msgs_len, tid), move |_| {
+            *stick_to_bottom.borrow_mut() = true;
+            || ()
+        });
+    }
+
+    // Auto-scroll to bottom when messages change or scroll_trigger fires,
+    // unless the user has scrolled up to read
     {
         let chat_container_ref = chat_container_ref.clone();
+        let stick_to_bottom = stick_to_bottom.clone();
+        let show_jump_latest = show_jump_latest.clone();
         let msgs_len = active_messages.len();
         let scroll_val = *scroll_trigger;
         let model_state_dep = (*model_state).clone();
         use_effect_with((msgs_len, model_state_dep, scroll_val), move |_| {
-            if let Some(el) = chat_container_ref.cast::<web_sys::HtmlElement>() {
-                el.set_scroll_top(el.scroll_height());
+            if *stick_to_bottom.borrow() {
+                if let Some(el) = chat_container_ref.cast::<web_sys::HtmlElement>() {
+                    el.set_scroll_top(el.scroll_height());
+                }
+                if *show_jump_latest {
+                    show_jump_latest.set(false);
+                }
+            } else if !*show_jump_latest {
+                show_jump_latest.set(true);
             }
             || ()
         });
     }
 
+    let on_chat_scroll = {
+        let chat_container_ref = chat_container_ref.clone();
+        let stick_to_bottom = stick_to_bottom.clone();
+        let show_jump_latest = show_jump_latest.clone();
+        Callback::from(move |_: Event| {
+            if let Some(el) = chat_container_ref.cast::<web_sys::HtmlElement>() {
+                let gap = el.scroll_height() - el.scroll_top() - el.client_height();
+                let near_bottom = gap <= NEAR_BOTTOM_PX;
+                *stick_to_bottom.borrow_mut() = near_bottom;
+                if near_bottom && *show_jump_latest {
+                    show_jump_latest.set(false);
+                }
+            }
+        })
+    };
+
+    let on_jump_latest = {
+        let chat_container_ref = chat_container_ref.clone();
+        let stick_to_bottom = stick_to_bottom.clone();
+        let show_jump_latest = show_jump_latest.clone();
+        Callback::from(move |_: MouseEvent| {
+            *stick_to_bottom.borrow_mut() = true;
+            if let Some(el) = chat_container_ref.cast::<web_sys::HtmlElement>() {
+                el.set_scroll_top(el.scroll_height());
+            }
+            show_jump_latest.set(false);
+        })
+    };
+
     // Ensure there's an active thread on first render
     {
         let threads = threads.clone();
@@ -1056,10 +1856,18 @@ pub fn ai_chat() -> Html {
         let input_ref = input_ref.clone();
         let scroll_trigger = scroll_trigger.clone();
         let streaming_content = streaming_content.clone();
+        let speaking_msg_idx = speaking_msg_idx.clone();
+        let context_range = *context_range;
         move |text: String| {
             if text.is_empty() {
                 return;
             }
+            // Don't talk over the user's next question
+            stop_speaking();
+            speaking_msg_idx.set(None);
+            // A range mentioned in the message wins over the selected scope
+            let range_days = detect_date_range(&text).or(context_range);
+            let wants_stats = asks_for_stats(&text);
 
             let tid = (*active_thread_id).clone();
             let mut ts = load_threads();
@@ -1093,6 +1901,7 @@ pub fn ai_chat() -> Html {
                 content: String::new(),
             });
 
+            let reply_idx = ts[idx].messages.len() - 1;
             save_threads(&ts);
             threads.set(ts.clone());
             model_state.set(ModelState::Generating);
@@ -1103,9 +1912,16 @@ pub fn ai_chat() -> Html {
             let scroll_trigger = scroll_trigger.clone();
             let streaming_content = streaming_content.clone();
             let tid = tid.clone();
+            let speaking_msg_idx = speaking_msg_idx.clone();
 
             wasm_bindgen_futures::spawn_local(async move {
-                let system_prompt = build_system_prompt();
+                let mut system_prompt = build_system_prompt_for(range_days);
+                if wants_stats && storage::load_workouts().len() < SPARSE_DATA_WORKOUTS {
+                    system_prompt.push_str(
+                        "\nNOTE: The user is asking for specific numbers but has little or no logged data. \
+                         Tell them plainly what is missing rather than estimating.\n",
+                    );
+                }
                 // Get current thread messages (excluding the empty assistant message)
                 let ts = load_threads();
                 let thread = ts.iter().find(|t| t.id == tid);
@@ -1165,13 +1981,28 @@ pub fn ai_chat() -> Html {
                         if let Some(thread) = ts.iter_mut().find(|t| t.id == tid) {
                             if let Some(last) = thread.messages.last_mut() {
                                 if last.role == "assistant" {
-                                    last.content = final_content;
+                                    last.content = final_content.clone();
                                 }
                             }
                         }
                         save_threads(&ts);
                         threads_handle.set(ts);
                         model_state.set(ModelState::Ready);
+
+                        // Hands-free mode: read the reply aloud, unless the user moved on
+                        if storage::load_user_config().ai_auto_speak
+                            && speech_synthesis_supported()
+                            && !final_content.is_empty()
+                            && get_active_thread_id().as_deref() == Some(tid.as_str())
+                        {
+                            speaking_msg_idx.set(Some(reply_idx));
+                            let smi = speaking_msg_idx.clone();
+                            let on_end = Closure::wrap(Box::new(move || {
+                                smi.set(None);
+                            }) as Box<dyn FnMut()>);
+                            speak_text(&final_content, on_end.as_ref().unchecked_ref());
+                            on_end.forget();
+                        }
                     }
                     Err(e) => {
                         streaming_content.set(String::new());
@@ -1254,24 +2085,56 @@ pub fn ai_chat() -> Html {
         }
     };
 
+    let on_narrate_recap = {
+        let do_send = do_send.clone();
+        Callback::from(move |_: MouseEvent| {
+            let report = build_training_report(30);
+            do_send(format!(
+                "Here is my training report for the last 30 days. Turn it into a short, encouraging narrative recap I can send to my coach, highlighting wins and what to focus on next.\n\n{}",
+                report
+            ));
+        })
+    };
+
+    let on_toggle_program_review = {
+        let show_program_review = show_program_review.clone();
+        Callback::from(move |_: MouseEvent| show_program_review.set(!*show_program_review))
+    };
+
+    let on_review_routine = {
+        let do_send = do_send.clone();
+        let show_program_review = show_program_review.clone();
+        move |routine: models::Routine| {
+            let do_send = do_send.clone();
+            let show_program_review = show_program_review.clone();
+            Callback::from(move |_: MouseEvent| {
+                show_program_review.set(false);
+                do_send(build_program_review(&routine));
+            })
+        }
+    };
+
     // --- Thread management ---
-    let on_new_thread = {
+    let start_new_thread = {
         let threads = threads.clone();
         let active_thread_id = active_thread_id.clone();
         let show_thread_list = show_thread_list.clone();
-        Callback::from(move |_: MouseEvent| {
+        let speaking_msg_idx = speaking_msg_idx.clone();
+        let pending_evict = pending_evict.clone();
+        Callback::from(move |_: ()| {
+            pending_evict.set(None);
+            stop_speaking();
+            speaking_msg_idx.set(None);
             let thread = ChatThread {
                 id: uuid(),
                 title: "New Chat".to_string(),
                 messages: Vec::new(),
-                created_at: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                created_at: crate::dates::today_str(),
             };
             let id = thread.id.clone();
             let mut ts = load_threads();
             ts.insert(0, thread);
-            if ts.len() > MAX_THREADS {
-                ts.truncate(MAX_THREADS);
-            }
+            ts.truncate(thread_limits().0);
             save_threads(&ts);
             set_active_thread_id(&id);
             active_thread_id.set(id);
@@ -1284,9 +2147,29 @@ pub fn ai_chat() -> Html {
         })
     };
 
+    // At the cap, ask before the oldest threads are dropped
+    let on_new_thread = {
+        let start_new_thread = start_new_thread.clone();
+        let pending_evict = pending_evict.clone();
+        let show_thread_list = show_thread_list.clone();
+        Callback::from(move |_: MouseEvent| {
+            let ts = load_threads();
+            // Keeping `max - 1` old threads leaves room for the new one
+            let keep = thread_limits().0.saturating_sub(1);
+            if ts.len() > keep {
+                pending_evict.set(Some(ts[keep..].iter().map(|t| t.title.clone()).collect()));
+                show_thread_list.set(true);
+            } else {
+                start_new_thread.emit(());
+            }
+        })
+    };
+
     let on_toggle_thread_list = {
         let show_thread_list = show_thread_list.clone();
+        let pending_evict = pending_evict.clone();
         Callback::from(move |_: MouseEvent| {
+            pending_evict.set(None);
             show_thread_list.set(!*show_thread_list);
         })
     };
@@ -1295,11 +2178,15 @@ pub fn ai_chat() -> Html {
         let threads = threads.clone();
         let active_thread_id = active_thread_id.clone();
         let show_thread_list = show_thread_list.clone();
+        let speaking_msg_idx = speaking_msg_idx.clone();
         move |tid: String| {
             let threads = threads.clone();
             let active_thread_id = active_thread_id.clone();
             let show_thread_list = show_thread_list.clone();
+            let speaking_msg_idx = speaking_msg_idx.clone();
             Callback::from(move |_: MouseEvent| {
+                stop_speaking();
+                speaking_msg_idx.set(None);
                 set_active_thread_id(&tid);
                 active_thread_id.set(tid.clone());
                 threads.set(load_threads());
@@ -1315,7 +2202,10 @@ pub fn ai_chat() -> Html {
     let on_delete_thread = {
         let threads = threads.clone();
         let active_thread_id = active_thread_id.clone();
+        let speaking_msg_idx = speaking_msg_idx.clone();
         Callback::from(move |_: MouseEvent| {
+            stop_speaking();
+            speaking_msg_idx.set(None);
             let tid = (*active_thread_id).clone();
             let mut ts = load_threads();
             ts.retain(|t| t.id != tid);
@@ -1327,7 +2217,7 @@ pub fn ai_chat() -> Html {
                     id: uuid(),
                     title: "New Chat".to_string(),
                     messages: Vec::new(),
-                    created_at: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                    created_at: crate::dates::today_str(),
                 };
                 let id = thread.id.clone();
                 ts.push(thread);
@@ -1410,18 +2300,13 @@ pub fn ai_chat() -> Html {
         })
     };
 
-    // --- Actionable outputs: start workout button ---
-    let render_start_workout_btn = {
+    // --- Actionable outputs: shared actions ---
+    // Open the workout page pre-filled with the suggested exercises
+    let make_start_workout = {
         let navigator = navigator.clone();
-        move |text: &str| -> Html {
-            let matched = parse_workout_exercises(text);
-            if matched.len() < 2 {
-                return html! {};
-            }
-            let count = matched.len();
-            let exercise_ids: Vec<String> = matched.iter().map(|(id, _)| id.clone()).collect();
+        move |exercise_ids: Vec<String>| -> Callback<MouseEvent> {
             let nav = navigator.clone();
-            let on_start = Callback::from(move |_: MouseEvent| {
+            Callback::from(move |_: MouseEvent| {
                 // Build WorkoutExercise list
                 let exercises: Vec<models::WorkoutExercise> = exercise_ids
                     .iter()
@@ -1435,23 +2320,119 @@ pub fn ai_chat() -> Html {
                                 duration_secs: None,
                                 distance: None,
                                 note: None,
+                                rest_taken_secs: None,
+                                failure: None,
+                                bodyweight: None,
+                                completed_at: None,
                             };
                             3
                         ],
                         notes: String::new(),
                         superset_group: None,
                         rest_seconds_override: None,
+                        tempo: None,
                     })
                     .collect();
                 if let Ok(json) = serde_json::to_string(&exercises) {
                     let _ = gloo::storage::LocalStorage::set("treening_active_repeat", json);
                 }
                 nav.push(&Route::Workout);
+            })
+        }
+    };
+
+    // Save a parsed [WORKOUT LOG] as today's workout and mark the message saved
+    let make_save_log = {
+        let saved_workout_indices = saved_workout_indices.clone();
+        move |exercises_for_save: Vec<(String, Vec<models::WorkoutSet>)>, msg_idx: usize| -> Callback<MouseEvent> {
+            let saved_indices2 = saved_workout_indices.clone();
+            Callback::from(move |_: MouseEvent| {
+                let workout_exercises: Vec<models::WorkoutExercise> = exercises_for_save
+                    .iter()
+                    .map(|(id, sets)| models::WorkoutExercise {
+                        exercise_id: id.clone(),
+                        sets: sets.clone(),
+                        notes: String::new(),
+                        superset_group: None,
+                        rest_seconds_override: None,
+                        tempo: None,
+                    })
+                    .collect();
+
+                let workout = models::Workout {
+                    id: uuid(),
+                    date: crate::dates::today_str(),
+                    name: "Coach T Workout".to_string(),
+                    exercises: workout_exercises,
+                    duration_mins: 0,
+                    gym: None,
+                    notes: String::new(),
+                    started_at: None,
+                };
+
+                let mut workouts = storage::load_workouts();
+                workouts.push(workout);
+                storage::save_workouts(&workouts);
+
+                let mut saved = (*saved_indices2).clone();
+                saved.insert(msg_idx);
+                saved_indices2.set(saved);
+            })
+        }
+    };
+
+    // Exercise ids still ticked in a suggestion's checklist
+    let suggested_ids = {
+        let skipped_suggestions = skipped_suggestions.clone();
+        move |matched: &[(String, String)], msg_idx: usize| -> Vec<String> {
+            matched
+                .iter()
+                .filter(|(id, _)| !skipped_suggestions.contains(&(msg_idx, id.clone())))
+                .map(|(id, _)| id.clone())
+                .collect()
+        }
+    };
+
+    // --- Actionable outputs: start workout button ---
+    let render_start_workout_btn = {
+        let skipped_suggestions = skipped_suggestions.clone();
+        let make_start_workout = make_start_workout.clone();
+        let suggested_ids = suggested_ids.clone();
+        move |text: &str, msg_idx: usize| -> Html {
+            let matched = parse_workout_exercises(text);
+            if matched.len() < 2 {
+                return html! {};
+            }
+            let exercise_ids = suggested_ids(&matched, msg_idx);
+            let count = exercise_ids.len();
+            let checklist = matched.iter().map(|(id, name)| {
+                let key = (msg_idx, id.clone());
+                let checked = !skipped_suggestions.contains(&key);
+                let skipped = skipped_suggestions.clone();
+                let on_toggle = Callback::from(move |_: Event| {
+                    let mut set = (*skipped).clone();
+                    if !set.remove(&key) {
+                        set.insert(key.clone());
+                    }
+                    skipped.set(set);
+                });
+                html! {
+                    <label class="flex items-center gap-2 text-xs cursor-pointer">
+                        <input type="checkbox" class="w-4 h-4 accent-green-600" checked={checked} onchange={on_toggle} />
+                        <span class={classes!(if checked { "" } else { "line-through text-gray-400" })}>{name}</span>
+                    </label>
+                }
             });
+            let on_start = make_start_workout(exercise_ids);
             html! {
+                <>
+                <div class="mt-2 space-y-1 border-t border-gray-200 dark:border-gray-700/50 pt-2">
+                    { for checklist }
+                </div>
                 <button
                     onclick={on_start}
-                    class="mt-2 w-full py-2 bg-green-600 hover:bg-green-700 text-white rounded-lg text-xs font-bold transition neu-btn btn-press flex items-center justify-center gap-1"
+                    disabled={count == 0}
+                    class="mt-2 w-full py-2 bg-green-600 hover:bg-green-700 disabled:opacity-50 text-white rounded-lg text-xs font-bold transition neu-btn btn-press flex items-center justify-center gap-1"
                 >
                     <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
                         <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M14.752 11.168l-3.197-2.132A1 1 0 0010 9.87v4.263a1 1 0 001.555.832l3.197-2.132a1 1 0 000-1.664z" />
@@ -1459,6 +2440,7 @@ pub fn ai_chat() -> Html {
                     </svg>
                     {format!("Start workout ({} exercises)", count)}
                 </button>
+                </>
             }
         }
     };
@@ -1466,6 +2448,7 @@ pub fn ai_chat() -> Html {
     // --- Actionable outputs: save workout log button ---
     let render_save_workout_btn = {
         let saved_workout_indices = saved_workout_indices.clone();
+        let make_save_log = make_save_log.clone();
         move |text: &str, msg_idx: usize| -> Html {
             let parsed = parse_workout_log(text);
             let exercises_data = match parsed {
@@ -1492,35 +2475,7 @@ pub fn ai_chat() -> Html {
                 .iter()
                 .map(|(id, _, sets)| (id.clone(), sets.clone()))
                 .collect();
-            let saved_indices2 = saved_workout_indices.clone();
-            let on_save = Callback::from(move |_: MouseEvent| {
-                let workout_exercises: Vec<models::WorkoutExercise> = exercises_for_save
-                    .iter()
-                    .map(|(id, sets)| models::WorkoutExercise {
-                        exercise_id: id.clone(),
-                        sets: sets.clone(),
-                        notes: String::new(),
-                        superset_group: None,
-                        rest_seconds_override: None,
-                    })
-                    .collect();
-
-                let workout = models::Workout {
-                    id: uuid(),
-                    date: chrono::Local::now().format("%Y-%m-%d").to_string(),
-                    name: "Coach T Workout".to_string(),
-                    exercises: workout_exercises,
-                    duration_mins: 0,
-                };
-
-                let mut workouts = storage::load_workouts();
-                workouts.push(workout);
-                storage::save_workouts(&workouts);
-
-                let mut saved = (*saved_indices2).clone();
-                saved.insert(msg_idx);
-                saved_indices2.set(saved);
-            });
+            let on_save = make_save_log(exercises_for_save, msg_idx);
 
             html! {
                 <button
@@ -1536,6 +2491,50 @@ pub fn ai_chat() -> Html {
         }
     };
 
+    // Pinned copy of the latest reply's actions, so they stay reachable after
+    // a long reply scrolls them away. Replaced as soon as a new message lands.
+    let pinned_actions: Html = match active_messages.last() {
+        Some(last)
+            if last.role == "assistant"
+                && !last.content.is_empty()
+                && *model_state != ModelState::Generating =>
+        {
+            let msg_idx = active_messages.len() - 1;
+            let save_btn = parse_workout_log(&last.content)
+                .filter(|data| !data.is_empty() && !saved_workout_indices.contains(&msg_idx))
+                .map(|data| {
+                    let exercises_for_save = data.into_iter().map(|(id, _, sets)| (id, sets)).collect();
+                    html! {
+                        <button
+                            onclick={make_save_log(exercises_for_save, msg_idx)}
+                            class="flex-1 py-2 bg-green-600 hover:bg-green-700 text-white rounded-lg text-xs font-bold transition neu-btn btn-press"
+                        >{"\u{2713} Save workout"}</button>
+                    }
+                });
+            let matched = parse_workout_exercises(&last.content);
+            let exercise_ids = suggested_ids(&matched, msg_idx);
+            let start_btn = (matched.len() >= 2 && !exercise_ids.is_empty()).then(|| {
+                html! {
+                    <button
+                        onclick={make_start_workout(exercise_ids)}
+                        class="flex-1 py-2 bg-green-600 hover:bg-green-700 text-white rounded-lg text-xs font-bold transition neu-btn btn-press"
+                    >{"\u{25b6} Start workout"}</button>
+                }
+            });
+            if save_btn.is_none() && start_btn.is_none() {
+                html! {}
+            } else {
+                html! {
+                    <div class="px-4 py-2 flex gap-2 border-t border-gray-200 dark:border-gray-700/50">
+                        { save_btn.unwrap_or_default() }
+                        { start_btn.unwrap_or_default() }
+                    </div>
+                }
+            }
+        }
+        _ => html! {},
+    };
+
     let quick_prompts_dep = active_messages.len();
     let quick_prompts = use_memo(quick_prompts_dep, |_| generate_quick_prompts());
 
@@ -1562,6 +2561,26 @@ pub fn ai_chat() -> Html {
                 </div>
                 if *model_state == ModelState::Ready || *model_state == ModelState::Generating {
                     <div class="flex items-center gap-1 flex-shrink-0">
+                        <button
+                            onclick={on_narrate_recap}
+                            disabled={*model_state == ModelState::Generating}
+                            class="text-xs text-gray-500 hover:text-gray-700 dark:hover:text-gray-300 px-2 py-1 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-800 transition disabled:opacity-50"
+                            title="Narrate monthly recap"
+                        >
+                            <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 17v-2m3 2v-4m3 4v-6m2 10H7a2 2 0 01-2-2V5a2 2 0 012-2h5.586a1 1 0 01.707.293l5.414 5.414a1 1 0 01.293.707V19a2 2 0 01-2 2z" />
+                            </svg>
+                        </button>
+                        <button
+                            onclick={on_toggle_program_review}
+                            disabled={*model_state == ModelState::Generating}
+                            class="text-xs text-gray-500 hover:text-gray-700 dark:hover:text-gray-300 px-2 py-1 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-800 transition disabled:opacity-50"
+                            title="Fix my program"
+                        >
+                            <svg class="w-4 h-4" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M9 5H7a2 2 0 00-2 2v12a2 2 0 002 2h10a2 2 0 002-2V7a2 2 0 00-2-2h-2M9 5a2 2 0 002 2h2a2 2 0 002-2M9 5a2 2 0 012-2h2a2 2 0 012 2m-6 9l2 2 4-4" />
+                            </svg>
+                        </button>
                         <button
                             onclick={on_export}
                             class="text-xs text-gray-500 hover:text-gray-700 dark:hover:text-gray-300 px-2 py-1 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-800 transition"
@@ -1597,7 +2616,11 @@ pub fn ai_chat() -> Html {
             if *show_thread_list {
                 <div class="absolute inset-0 z-50 bg-black/30" onclick={
                     let stl = show_thread_list.clone();
-                    Callback::from(move |_: MouseEvent| stl.set(false))
+                    let pending_evict = pending_evict.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        pending_evict.set(None);
+                        stl.set(false);
+                    })
                 }>
                     <div class="absolute top-14 left-4 right-4 bg-white dark:bg-gray-800 rounded-xl shadow-xl max-h-80 overflow-y-auto neu-flat"
                         onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
@@ -1611,6 +2634,27 @@ pub fn ai_chat() -> Html {
                                 {"+ New Chat"}
                             </button>
                         </div>
+                        if let Some(titles) = (*pending_evict).clone() {
+                            <div class="p-3 bg-amber-50 dark:bg-amber-900/20 border-b border-amber-300 dark:border-amber-700/50 space-y-2">
+                                <p class="text-xs text-amber-700 dark:text-amber-400">
+                                    { if let [title] = titles.as_slice() {
+                                        format!("You're at your limit of {} chats. Starting a new one deletes the oldest, \u{201c}{}\u{201d}. Raise the limit in Settings to keep it.", thread_limits().0, title)
+                                    } else {
+                                        format!("You're over your limit of {} chats. Starting a new one deletes the {} oldest: {}. Raise the limit in Settings to keep them.", thread_limits().0, titles.len(), titles.iter().map(|t| format!("\u{201c}{}\u{201d}", t)).collect::<Vec<_>>().join(", "))
+                                    }}
+                                </p>
+                                <div class="flex gap-2">
+                                    <button
+                                        class="flex-1 py-1.5 rounded-lg text-xs font-bold bg-red-600 text-white hover:bg-red-700 neu-btn"
+                                        onclick={let start = start_new_thread.clone(); Callback::from(move |_: MouseEvent| start.emit(()))}
+                                    >{"Delete & Start New"}</button>
+                                    <button
+                                        class="flex-1 py-1.5 rounded-lg text-xs font-bold bg-gray-200 dark:bg-gray-700 text-gray-700 dark:text-gray-300 neu-btn"
+                                        onclick={let p = pending_evict.clone(); Callback::from(move |_: MouseEvent| p.set(None))}
+                                    >{"Cancel"}</button>
+                                </div>
+                            </div>
+                        }
                         { for (*threads).iter().map(|t| {
                             let is_active = t.id == *active_thread_id;
                             let tid = t.id.clone();
@@ -1631,8 +2675,47 @@ pub fn ai_chat() -> Html {
                 </div>
             }
 
+            // Routine picker for the program review
+            if *show_program_review {
+                <div class="absolute inset-0 z-50 bg-black/30" onclick={
+                    let spr = show_program_review.clone();
+                    Callback::from(move |_: MouseEvent| spr.set(false))
+                }>
+                    <div class="absolute top-14 left-4 right-4 bg-white dark:bg-gray-800 rounded-xl shadow-xl max-h-80 overflow-y-auto neu-flat"
+                        onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
+                    >
+                        <div class="p-3 border-b border-gray-200 dark:border-gray-700/50">
+                            <span class="text-sm font-bold text-gray-700 dark:text-gray-300">{"Fix my program"}</span>
+                            <p class="text-[10px] text-gray-400 mt-0.5">{"Pick a routine to check against your MEV/MRV landmarks."}</p>
+                        </div>
+                        {{
+                            let routines = storage::load_routines();
+                            if routines.is_empty() {
+                                html! { <p class="px-3 py-4 text-sm text-gray-500 text-center">{"No routines yet."}</p> }
+                            } else {
+                                html! {
+                                    { for routines.into_iter().map(|r| {
+                                        let name = r.name.clone();
+                                        let count = r.exercise_ids.len();
+                                        html! {
+                                            <button
+                                                onclick={on_review_routine(r)}
+                                                class="w-full text-left px-3 py-2.5 border-b border-gray-100 dark:border-gray-700/30 hover:bg-gray-50 dark:hover:bg-gray-700/50 transition"
+                                            >
+                                                <div class="text-sm font-medium text-gray-800 dark:text-gray-200 truncate">{name}</div>
+                                                <div class="text-[10px] text-gray-400 mt-0.5">{format!("{} exercises", count)}</div>
+                                            </button>
+                                        }
+                                    })}
+                                }
+                            }
+                        }}
+                    </div>
+                </div>
+            }
+
             // Main content area
-            <div ref={chat_container_ref} class="flex-1 overflow-y-auto px-4 py-4 space-y-4">
+            <div ref={chat_container_ref} onscroll={on_chat_scroll} class="flex-1 overflow-y-auto px-4 py-4 space-y-4">
                 { match &*model_state {
                     ModelState::Unsupported => html! {
                         <div class="bg-yellow-50 dark:bg-yellow-900/20 rounded-2xl p-6 neu-flat">
@@ -1832,9 +2915,9 @@ pub fn ai_chat() -> Html {
                                             } else if is_empty_assistant {
                                                 html! {
                                                     <div class="flex gap-1.5 items-center">
-                                                        <div class="w-2 h-2 bg-gray-400 rounded-full animate-bounce" style="animation-delay: 0ms;" />
-                                                        <div class="w-2 h-2 bg-gray-400 rounded-full animate-bounce" style="animation-delay: 150ms;" />
-                                                        <div class="w-2 h-2 bg-gray-400 rounded-full animate-bounce" style="animation-delay: 300ms;" />
+                                                        <div class={classes!("w-2", "h-2", "bg-gray-400", "rounded-full", dot_motion)} style="animation-delay: 0ms;" />
+                                                        <div class={classes!("w-2", "h-2", "bg-gray-400", "rounded-full", dot_motion)} style="animation-delay: 150ms;" />
+                                                        <div class={classes!("w-2", "h-2", "bg-gray-400", "rounded-full", dot_motion)} style="animation-delay: 300ms;" />
                                                     </div>
                                                 }
                                             } else {
@@ -1845,7 +2928,7 @@ pub fn ai_chat() -> Html {
                                                             html! {
                                                                 <>
                                                                     {render_save_workout_btn(&content, idx)}
-                                                                    {render_start_workout_btn(&content)}
+                                                                    {render_start_workout_btn(&content, idx)}
                                                                 </>
                                                             }
                                                         } else { html! {} }}
@@ -1864,6 +2947,17 @@ pub fn ai_chat() -> Html {
                 }}
             </div>
 
+            if *show_jump_latest {
+                <div class="relative">
+                    <button
+                        class="absolute bottom-2 left-1/2 -translate-x-1/2 z-10 px-3 py-1.5 rounded-full bg-blue-600 text-white text-xs font-bold shadow-lg hover:bg-blue-700 transition-colors"
+                        onclick={on_jump_latest}
+                    >{"\u{2193} Jump to latest"}</button>
+                </div>
+            }
+
+            {pinned_actions}
+
             // Export toast
             if *show_export_toast {
                 <div class="fixed top-4 left-4 right-4 z-50 bg-green-600 text-white px-4 py-2 rounded-xl shadow-lg text-center text-sm font-bold" style="animation: modalContentIn 200ms ease-out;">
@@ -1881,6 +2975,22 @@ pub fn ai_chat() -> Html {
             // Input bar (only show when Ready or Generating)
             if *model_state == ModelState::Ready || *model_state == ModelState::Generating {
                 <div class="px-4 py-3 border-t border-gray-200 dark:border-gray-700/50">
+                    <div class="flex items-center gap-1.5 mb-2 overflow-x-auto">
+                        <span class="text-[10px] text-gray-500 uppercase font-bold flex-shrink-0">{"Data:"}</span>
+                        { for [(None, "All time"), (Some(7), "7d"), (Some(30), "30d"), (Some(90), "90d")].into_iter().map(|(range, label)| {
+                            let context_range = context_range.clone();
+                            let active = *context_range == range;
+                            html! {
+                                <button
+                                    onclick={Callback::from(move |_| context_range.set(range))}
+                                    class={classes!(
+                                        "px-2", "py-0.5", "rounded-full", "text-[10px]", "font-bold", "flex-shrink-0", "transition",
+                                        if active { "neu-chip-active bg-blue-600 text-white" } else { "neu-chip bg-gray-100 dark:bg-gray-800 text-gray-600 dark:text-gray-400" }
+                                    )}
+                                >{label}</button>
+                            }
+                        })}
+                    </div>
                     <div class="flex gap-2 items-center">
                         <input
                             ref={input_ref}
@@ -1926,3 +3036,69 @@ pub fn ai_chat() -> Html {
         </div>
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn heading_then_list_is_a_heading_and_a_list() {
+        let blocks = parse_blocks("### Today's plan\n- Squat 3x5\n- Bench 3x5\n\nRest well.");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Heading(3, "Today's plan"),
+                Block::BulletList(vec!["Squat 3x5", "Bench 3x5"]),
+                Block::Paragraph(vec!["Rest well."]),
+            ]
+        );
+    }
+
+    #[test]
+    fn heading_mid_paragraph_splits_it() {
+        let blocks = parse_blocks("Good session.\n## Next\n1. Deadlift\nKeep it light");
+        assert_eq!(
+            blocks,
+            vec![
+                Block::Paragraph(vec!["Good session."]),
+                Block::Heading(2, "Next"),
+                Block::Paragraph(vec!["1. Deadlift", "Keep it light"]),
+            ]
+        );
+        assert_eq!(
+            parse_blocks("1. Squat\n2. Press"),
+            vec![Block::NumberedList(vec!["Squat", "Press"])]
+        );
+    }
+
+    #[test]
+    fn inline_code_keeps_asterisks_verbatim() {
+        assert_eq!(
+            parse_inline("Log it as `3*5 @ 80` then **rest**"),
+            vec![
+                Inline::Text("Log it as "),
+                Inline::Code("3*5 @ 80"),
+                Inline::Text(" then "),
+                Inline::Bold(vec![Inline::Text("rest")]),
+            ]
+        );
+    }
+
+    #[test]
+    fn unmatched_backtick_stays_literal() {
+        assert_eq!(
+            parse_inline("use the ` key and *then* go"),
+            vec![
+                Inline::Text("use the ` key and "),
+                Inline::Italic("then"),
+                Inline::Text(" go"),
+            ]
+        );
+    }
+
+    #[test]
+    fn spaced_asterisk_is_not_italic() {
+        assert_eq!(parse_inline("3 * 4 = 12"), vec![Inline::Text("3 * 4 = 12")]);
+        assert_eq!(parse_inline("a * b *c*"), vec![Inline::Text("a * b "), Inline::Italic("c")]);
+    }
+}
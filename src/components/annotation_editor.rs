@@ -0,0 +1,115 @@
+use crate::models::{Annotation, AnnotationKind};
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub annotations: Vec<Annotation>,
+    pub on_change: Callback<Vec<Annotation>>,
+}
+
+/// List and add dated chart notes ("back tweak", "deload week") that show up
+/// as markers on the analytics line charts.
+#[function_component(AnnotationEditor)]
+pub fn annotation_editor(props: &Props) -> Html {
+    let show_form = use_state(|| false);
+    let date = use_state(crate::dates::today_str);
+    let label = use_state(String::new);
+    let kind = use_state(AnnotationKind::default);
+
+    let on_add = {
+        let show_form = show_form.clone();
+        let date = date.clone();
+        let label = label.clone();
+        let kind = kind.clone();
+        let annotations = props.annotations.clone();
+        let on_change = props.on_change.clone();
+        Callback::from(move |_: MouseEvent| {
+            let text = label.trim().to_string();
+            if text.is_empty() || date.is_empty() {
+                return;
+            }
+            let mut list = annotations.clone();
+            list.push(Annotation {
+                id: uuid::Uuid::new_v4().to_string(),
+                date: (*date).clone(),
+                label: text,
+                kind: *kind,
+            });
+            list.sort_by(|a, b| a.date.cmp(&b.date));
+            on_change.emit(list);
+            label.set(String::new());
+            show_form.set(false);
+        })
+    };
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
+            <div class="flex justify-between items-center mb-3">
+                <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Chart Notes"}</h3>
+                <button
+                    class="text-xs font-bold text-blue-600 dark:text-blue-400 hover:underline"
+                    onclick={let s = show_form.clone(); Callback::from(move |_| s.set(!*s))}
+                >{ if *show_form { "Cancel" } else { "+ Note" } }</button>
+            </div>
+            if *show_form {
+                <div class="mb-3 p-3 bg-white dark:bg-gray-700/50 rounded-lg space-y-2">
+                    <div class="flex gap-2">
+                        <input
+                            type="date"
+                            class="bg-gray-100 dark:bg-gray-700 rounded-lg px-2 py-1.5 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                            value={(*date).clone()}
+                            oninput={let d = date.clone(); Callback::from(move |ev: InputEvent| d.set(ev.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
+                        />
+                        <select
+                            class="flex-1 bg-gray-100 dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg px-2 py-1.5 text-sm outline-none neu-pressed"
+                            onchange={let k = kind.clone(); Callback::from(move |ev: Event| k.set(AnnotationKind::from_str(&ev.target_unchecked_into::<web_sys::HtmlSelectElement>().value())))}
+                        >
+                            { for AnnotationKind::all().into_iter().map(|k| html! {
+                                <option value={k.to_key()} selected={*kind == k}>{k.display_name()}</option>
+                            })}
+                        </select>
+                    </div>
+                    <input
+                        type="text" placeholder="e.g. Back tweak, deload week"
+                        class="w-full bg-gray-100 dark:bg-gray-700 rounded-lg px-2 py-1.5 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                        value={(*label).clone()}
+                        oninput={let l = label.clone(); Callback::from(move |ev: InputEvent| l.set(ev.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
+                    />
+                    <button
+                        class="w-full py-1.5 bg-blue-600 text-white rounded-lg text-sm font-bold hover:bg-blue-700 neu-btn transition-colors"
+                        onclick={on_add}
+                    >{"Add Note"}</button>
+                </div>
+            }
+            if props.annotations.is_empty() {
+                <p class="text-xs text-gray-500">{"Mark injuries, deloads or time off to explain dips in your charts."}</p>
+            } else {
+                <div class="space-y-1.5 max-h-48 overflow-y-auto">
+                    { for props.annotations.iter().rev().map(|a| {
+                        let on_remove = {
+                            let id = a.id.clone();
+                            let annotations = props.annotations.clone();
+                            let on_change = props.on_change.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                on_change.emit(annotations.iter().filter(|x| x.id != id).cloned().collect());
+                            })
+                        };
+                        html! {
+                            <div class="flex items-center gap-2 text-xs">
+                                <span class="w-2 h-2 rounded-full flex-shrink-0" style={format!("background-color: {}", a.kind.color())}></span>
+                                <span class="font-mono text-gray-500 dark:text-gray-400">{&a.date}</span>
+                                <span class="flex-1 truncate text-gray-800 dark:text-gray-200">{&a.label}</span>
+                                <span class="text-gray-400">{a.kind.display_name()}</span>
+                                <button
+                                    class="text-gray-400 hover:text-red-500 transition-colors"
+                                    title="Remove note"
+                                    onclick={on_remove}
+                                >{"\u{00d7}"}</button>
+                            </div>
+                        }
+                    })}
+                </div>
+            }
+        </div>
+    }
+}
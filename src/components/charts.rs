@@ -1,3 +1,4 @@
+use crate::i18n::{format_compact, format_number};
 use yew::prelude::*;
 
 // ── StatCard ────────────────────────────────────────────────────────────────
@@ -7,6 +8,9 @@ pub struct StatCardProps {
     pub label: AttrValue,
     pub value: AttrValue,
     pub icon: AttrValue,
+    /// Optional one-line explanation shown under the label.
+    #[prop_or_default]
+    pub hint: Option<AttrValue>,
 }
 
 #[function_component(StatCard)]
@@ -16,10 +20,93 @@ pub fn stat_card(props: &StatCardProps) -> Html {
             <span class="text-2xl">{&props.icon}</span>
             <span class="text-xl font-bold text-gray-900 dark:text-gray-100">{&props.value}</span>
             <span class="text-xs text-gray-500 dark:text-gray-400 text-center">{&props.label}</span>
+            if let Some(hint) = &props.hint {
+                <span class="text-[10px] text-gray-400 dark:text-gray-500 text-center leading-tight">{hint}</span>
+            }
         </div>
     }
 }
 
+// ── Tooltips ────────────────────────────────────────────────────────────────
+
+/// Pointer x position in viewBox units for a chart `chart_w` units wide.
+fn pointer_chart_x(svg: &NodeRef, client_x: i32, chart_w: f64) -> Option<f64> {
+    let rect = svg.cast::<web_sys::Element>()?.get_bounding_client_rect();
+    if rect.width() <= 0.0 {
+        return None;
+    }
+    Some((client_x as f64 - rect.left()) / rect.width() * chart_w)
+}
+
+/// Exact value for tooltips: up to two decimals, trailing zeros dropped.
+fn tooltip_value(val: f64) -> String {
+    let s = format!("{:.2}", val);
+    let trimmed = s.trim_end_matches('0').trim_end_matches('.');
+    let decimals = trimmed.split_once('.').map(|(_, f)| f.len()).unwrap_or(0);
+    format_number(val, decimals)
+}
+
+/// Least-squares fit of `(x, y)` points, as (slope, intercept). Needs at
+/// least two distinct x values.
+pub fn linear_regression(points: &[(f64, f64)]) -> Option<(f64, f64)> {
+    if points.len() < 2 {
+        return None;
+    }
+    let n = points.len() as f64;
+    let mean_x = points.iter().map(|(x, _)| x).sum::<f64>() / n;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / n;
+    let (mut sxy, mut sxx) = (0.0, 0.0);
+    for (x, y) in points {
+        sxy += (x - mean_x) * (y - mean_y);
+        sxx += (x - mean_x) * (x - mean_x);
+    }
+    if sxx <= 0.0 {
+        return None;
+    }
+    let slope = sxy / sxx;
+    Some((slope, mean_y - slope * mean_x))
+}
+
+/// Label/value callout above (x, y) in viewBox units, kept inside the chart.
+fn tooltip(x: f64, y: f64, text: String, chart_w: f64) -> Html {
+    let w = text.chars().count() as f64 * 5.2 + 10.0;
+    let left = (x - w / 2.0).clamp(0.0, (chart_w - w).max(0.0));
+    let top = (y - 24.0).max(0.0);
+    html! {
+        <g pointer-events="none">
+            <rect x={format!("{}", left)} y={format!("{}", top)}
+                  width={format!("{}", w)} height="16" rx="3"
+                  fill="#111827" fill-opacity="0.9"/>
+            <text x={format!("{}", left + w / 2.0)} y={format!("{}", top + 11.0)}
+                  text-anchor="middle" fill="#ffffff" font-size="9" font-weight="bold">{text}</text>
+        </g>
+    }
+}
+
+/// Mouse/tap handlers that track the data point nearest the pointer.
+/// `nearest` maps a viewBox x position to a data index.
+fn point_tracking(
+    svg: &NodeRef,
+    active: &UseStateHandle<Option<usize>>,
+    chart_w: f64,
+    nearest: impl Fn(f64) -> usize + 'static,
+) -> (Callback<MouseEvent>, Callback<MouseEvent>) {
+    let on_point = {
+        let svg = svg.clone();
+        let active = active.clone();
+        Callback::from(move |e: MouseEvent| {
+            if let Some(x) = pointer_chart_x(&svg, e.client_x(), chart_w) {
+                active.set(Some(nearest(x)));
+            }
+        })
+    };
+    let on_leave = {
+        let active = active.clone();
+        Callback::from(move |_: MouseEvent| active.set(None))
+    };
+    (on_point, on_leave)
+}
+
 // ── BarChart ─────────────────────────────────────────────────────────────────
 
 #[derive(Properties, PartialEq)]
@@ -35,6 +122,9 @@ pub struct BarChartProps {
 
 #[function_component(BarChart)]
 pub fn bar_chart(props: &BarChartProps) -> Html {
+    let svg_ref = use_node_ref();
+    let active = use_state_eq(|| None::<usize>);
+
     if props.data.is_empty() {
         return html! {
             <div class="text-gray-500 dark:text-gray-400 text-center py-8 text-sm transition-colors">{"No data yet"}</div>
@@ -59,12 +149,24 @@ pub fn bar_chart(props: &BarChartProps) -> Html {
     let total_h = chart_h;
     let viewbox = format!("0 0 {} {}", total_w, total_h);
 
+    let (on_point, on_leave) = point_tracking(&svg_ref, &active, chart_w, move |x| {
+        let i = ((x - padding_left - bar_gap / 2.0) / (bar_w + bar_gap)).floor();
+        i.clamp(0.0, (n - 1) as f64) as usize
+    });
+    let active_bar = (*active).filter(|i| *i < n).map(|i| {
+        let (label, val) = &props.data[i];
+        let x = padding_left + bar_gap + (i as f64) * (bar_w + bar_gap) + bar_w / 2.0;
+        let y = padding_top + draw_h - (val / max_val) * draw_h;
+        (x, y, format!("{}: {}", label, tooltip_value(*val)))
+    });
+
     html! {
         <div class="w-full">
             if !props.title.is_empty() {
                 <h3 class="text-sm font-semibold text-gray-600 dark:text-gray-300 mb-2 transition-colors">{&props.title}</h3>
             }
-            <svg viewBox={viewbox} class="w-full" preserveAspectRatio="xMidYMid meet">
+            <svg ref={svg_ref} viewBox={viewbox} class="w-full cursor-crosshair" preserveAspectRatio="xMidYMid meet"
+                 onmousemove={on_point.clone()} onclick={on_point} onmouseleave={on_leave}>
                 // baseline
                 <line x1={format!("{}", padding_left)}
                       y1={format!("{}", padding_top + draw_h)}
@@ -78,16 +180,13 @@ pub fn bar_chart(props: &BarChartProps) -> Html {
                     let label_x = x + bar_w / 2.0;
                     let label_y = padding_top + draw_h + 14.0;
                     let val_y = y - 4.0;
-                    let val_text = if *val == val.floor() {
-                        format!("{}", *val as i64)
-                    } else {
-                        format!("{:.0}", val)
-                    };
+                    let val_text = format_number(*val, 0);
                     html! {
                         <>
                             <rect x={format!("{}", x)} y={format!("{}", y)}
                                   width={format!("{}", bar_w)} height={format!("{}", bar_h)}
-                                  fill={props.color.to_string()} rx="2"/>
+                                  fill={props.color.to_string()} rx="2"
+                                  fill-opacity={if active_bar.is_some() && *active != Some(i) { "0.5" } else { "1" }}/>
                             <text x={format!("{}", label_x)} y={format!("{}", label_y)}
                                   text-anchor="middle" fill="currentColor" font-size="9" class="text-gray-500 dark:text-gray-400">{label}</text>
                             if *val > 0.0 {
@@ -97,6 +196,9 @@ pub fn bar_chart(props: &BarChartProps) -> Html {
                         </>
                     }
                 })}
+                if let Some((x, y, text)) = active_bar {
+                    { tooltip(x, y, text, chart_w) }
+                }
             </svg>
         </div>
     }
@@ -113,18 +215,59 @@ pub struct LineChartProps {
     pub color: AttrValue,
     #[prop_or_default]
     pub title: AttrValue,
+    /// Dashed horizontal guides as (value, label), e.g. MEV/MRV landmarks.
+    #[prop_or_default]
+    pub reference_lines: Vec<(f64, AttrValue)>,
+    /// Second series drawn over `data` at the same x positions, e.g. a moving
+    /// average, as (values, color). Values past the end of `data` are ignored.
+    #[prop_or_default]
+    pub overlay: Option<(Vec<f64>, AttrValue)>,
+    /// Vertical markers as (data index, label, color), e.g. dated annotations.
+    #[prop_or_default]
+    pub markers: Vec<(usize, AttrValue, AttrValue)>,
+    /// Unit for one step along x, e.g. "/wk". When set, a toggleable
+    /// linear-regression trendline is drawn and its slope shown per step.
+    #[prop_or_default]
+    pub trend_unit: Option<AttrValue>,
 }
 
 #[function_component(LineChart)]
 pub fn line_chart(props: &LineChartProps) -> Html {
+    let svg_ref = use_node_ref();
+    let active = use_state_eq(|| None::<usize>);
+    let show_trend = use_state(|| true);
+
     if props.data.is_empty() {
         return html! {
             <div class="text-gray-500 dark:text-gray-400 text-center py-8 text-sm transition-colors">{"No data yet"}</div>
         };
     }
 
-    let max_val = props.data.iter().map(|(_, v)| *v).fold(0.0_f64, f64::max);
-    let min_val = props.data.iter().map(|(_, v)| *v).fold(f64::MAX, f64::min);
+    let n = props.data.len();
+    let trend = props
+        .trend_unit
+        .as_ref()
+        .and_then(|_| {
+            let points: Vec<(f64, f64)> = props.data.iter().enumerate().map(|(i, (_, v))| (i as f64, *v)).collect();
+            linear_regression(&points)
+        });
+    // Fitted values at both ends; the line is straight so these are enough
+    let trend_ends = trend
+        .filter(|_| *show_trend)
+        .map(|(slope, intercept)| (intercept, intercept + slope * (n - 1) as f64));
+
+    // Reference lines and the trendline share the scale so they always land inside the chart
+    let values = || {
+        props
+            .data
+            .iter()
+            .map(|(_, v)| *v)
+            .chain(props.reference_lines.iter().map(|(v, _)| *v))
+            .chain(props.overlay.iter().flat_map(|(vals, _)| vals.iter().copied()))
+            .chain(trend_ends.iter().flat_map(|(a, b)| [*a, *b]))
+    };
+    let max_val = values().fold(0.0_f64, f64::max);
+    let min_val = values().fold(f64::MAX, f64::min);
     let range = if (max_val - min_val).abs() < 0.001 {
         1.0
     } else {
@@ -141,7 +284,6 @@ pub fn line_chart(props: &LineChartProps) -> Html {
     let draw_h = chart_h - padding_top - padding_bottom;
     let viewbox = format!("0 0 {} {}", chart_w, chart_h);
 
-    let n = props.data.len();
     let step_x = if n > 1 { draw_w / (n - 1) as f64 } else { 0.0 };
 
     let points: Vec<(f64, f64)> = props
@@ -161,33 +303,138 @@ pub fn line_chart(props: &LineChartProps) -> Html {
         .collect::<Vec<_>>()
         .join(" ");
 
+    let overlay_points: Option<(String, AttrValue)> = props.overlay.as_ref().map(|(vals, color)| {
+        let pts = vals
+            .iter()
+            .take(n)
+            .enumerate()
+            .map(|(i, val)| {
+                let x = padding_left + i as f64 * step_x;
+                let y = padding_top + draw_h - ((val - min_val) / range) * draw_h;
+                format!("{},{}", x, y)
+            })
+            .collect::<Vec<_>>()
+            .join(" ");
+        (pts, color.clone())
+    });
+
+    let trend_line = trend_ends.map(|(start, end)| {
+        let y = |val: f64| padding_top + draw_h - ((val - min_val) / range) * draw_h;
+        (padding_left, y(start), padding_left + (n - 1) as f64 * step_x, y(end))
+    });
+    let trend_label = trend.zip(props.trend_unit.as_ref()).map(|((slope, _), unit)| {
+        let magnitude = if slope.abs() >= 1000.0 {
+            format_compact(slope.abs())
+        } else {
+            tooltip_value((slope.abs() * 10.0).round() / 10.0)
+        };
+        let sign = if slope >= 0.0 { "+" } else { "\u{2212}" };
+        (format!("Trend {}{}{}", sign, magnitude, unit), slope >= 0.0)
+    });
+    let on_toggle_trend = {
+        let show_trend = show_trend.clone();
+        Callback::from(move |_: MouseEvent| show_trend.set(!*show_trend))
+    };
+
+    let (on_point, on_leave) = point_tracking(&svg_ref, &active, chart_w, move |x| {
+        if step_x <= 0.0 {
+            return 0;
+        }
+        ((x - padding_left) / step_x).round().clamp(0.0, (n - 1) as f64) as usize
+    });
+    let active_point = (*active).filter(|i| *i < n).map(|i| {
+        let (x, y) = points[i];
+        let (label, val) = &props.data[i];
+        (x, y, format!("{}: {}", label, tooltip_value(*val)))
+    });
+
     html! {
         <div class="w-full">
-            if !props.title.is_empty() {
-                <h3 class="text-sm font-semibold text-gray-600 dark:text-gray-300 mb-2 transition-colors">{&props.title}</h3>
+            if !props.title.is_empty() || trend_label.is_some() {
+                <div class="flex justify-between items-baseline gap-2 mb-2">
+                    <h3 class="text-sm font-semibold text-gray-600 dark:text-gray-300 transition-colors">{&props.title}</h3>
+                    if let Some((text, rising)) = trend_label {
+                        <button
+                            class={classes!(
+                                "text-[10px]", "font-bold", "whitespace-nowrap", "transition-opacity",
+                                if rising { "text-green-600 dark:text-green-400" } else { "text-red-600 dark:text-red-400" },
+                                (!*show_trend).then_some("opacity-50")
+                            )}
+                            title={if *show_trend { "Hide trendline" } else { "Show trendline" }}
+                            onclick={on_toggle_trend}
+                        >{text}</button>
+                    }
+                </div>
             }
-            <svg viewBox={viewbox} class="w-full" preserveAspectRatio="xMidYMid meet">
+            <svg ref={svg_ref} viewBox={viewbox} class="w-full cursor-crosshair" preserveAspectRatio="xMidYMid meet"
+                 onmousemove={on_point.clone()} onclick={on_point} onmouseleave={on_leave}>
                 // baseline
                 <line x1={format!("{}", padding_left)}
                       y1={format!("{}", padding_top + draw_h)}
                       x2={format!("{}", padding_left + draw_w)}
                       y2={format!("{}", padding_top + draw_h)}
                       stroke="currentColor" stroke-width="1" class="text-gray-300 dark:text-gray-600"/>
+                // reference lines
+                { for props.reference_lines.iter().map(|(val, label)| {
+                    let y = padding_top + draw_h - ((val - min_val) / range) * draw_h;
+                    html! {
+                        <>
+                            <line x1={format!("{}", padding_left)} y1={format!("{}", y)}
+                                  x2={format!("{}", padding_left + draw_w)} y2={format!("{}", y)}
+                                  stroke="currentColor" stroke-width="1" stroke-dasharray="4 3"
+                                  class="text-gray-400 dark:text-gray-500"/>
+                            <text x={format!("{}", padding_left + draw_w)} y={format!("{}", y - 3.0)}
+                                  text-anchor="end" fill="currentColor" font-size="8" class="text-gray-500 dark:text-gray-400">{label}</text>
+                        </>
+                    }
+                })}
+                // annotation markers
+                { for props.markers.iter().filter(|(i, _, _)| *i < n).map(|(i, label, color)| {
+                    let x = padding_left + *i as f64 * step_x;
+                    // Keep the label inside the chart near either edge
+                    let anchor = if x < chart_w * 0.2 {
+                        "start"
+                    } else if x > chart_w * 0.8 {
+                        "end"
+                    } else {
+                        "middle"
+                    };
+                    html! {
+                        <>
+                            <line x1={format!("{}", x)} y1={format!("{}", padding_top)}
+                                  x2={format!("{}", x)} y2={format!("{}", padding_top + draw_h)}
+                                  stroke={color.clone()} stroke-width="1" stroke-dasharray="3 2" stroke-opacity="0.8"/>
+                            <text x={format!("{}", x)} y={format!("{}", padding_top - 8.0)}
+                                  text-anchor={anchor} fill={color.clone()} font-size="8">{label}</text>
+                        </>
+                    }
+                })}
                 // line
                 <polyline points={polyline_points} fill="none"
                           stroke={props.color.to_string()} stroke-width="2"
                           stroke-linejoin="round" stroke-linecap="round"/>
+                if let Some((pts, color)) = overlay_points {
+                    <polyline points={pts} fill="none"
+                              stroke={color} stroke-width="2.5"
+                              stroke-linejoin="round" stroke-linecap="round"/>
+                }
+                if let Some((x1, y1, x2, y2)) = trend_line {
+                    <line x1={format!("{}", x1)} y1={format!("{}", y1)}
+                          x2={format!("{}", x2)} y2={format!("{}", y2)}
+                          stroke={props.color.to_string()} stroke-width="1.5" stroke-dasharray="6 4"
+                          stroke-opacity="0.7" pointer-events="none"/>
+                }
                 // dots + labels
                 { for points.iter().enumerate().map(|(i, (x, y))| {
                     let label = &props.data[i].0;
                     let val = props.data[i].1;
                     let label_y = padding_top + draw_h + 14.0;
                     let val_text = if val >= 1000.0 {
-                        format!("{:.0}k", val / 1000.0)
+                        format_compact(val)
                     } else if val == val.floor() {
-                        format!("{}", val as i64)
+                        format_number(val, 0)
                     } else {
-                        format!("{:.1}", val)
+                        format_number(val, 1)
                     };
                     html! {
                         <>
@@ -200,6 +447,16 @@ pub fn line_chart(props: &LineChartProps) -> Html {
                         </>
                     }
                 })}
+                // tooltip for the point nearest the pointer
+                if let Some((x, y, text)) = active_point {
+                    <line x1={format!("{}", x)} y1={format!("{}", padding_top)}
+                          x2={format!("{}", x)} y2={format!("{}", padding_top + draw_h)}
+                          stroke="currentColor" stroke-width="1" stroke-dasharray="2 2"
+                          class="text-gray-400 dark:text-gray-500" pointer-events="none"/>
+                    <circle cx={format!("{}", x)} cy={format!("{}", y)} r="5"
+                            fill={props.color.to_string()} stroke="#ffffff" stroke-width="1.5" pointer-events="none"/>
+                    { tooltip(x, y, text, chart_w) }
+                }
             </svg>
         </div>
     }
@@ -239,11 +496,7 @@ pub fn horizontal_bar_chart(props: &HorizontalBarChartProps) -> Html {
                 { for props.data.iter().map(|(label, val, color)| {
                     let pct = (val / max_val) * 100.0;
                     let width_style = format!("width: {}%", pct);
-                    let count = if *val == val.floor() {
-                        format!("{}", *val as i64)
-                    } else {
-                        format!("{:.0}", val)
-                    };
+                    let count = format_number(*val, 0);
                     html! {
                         <div class="flex items-center gap-2">
                             <span class="text-xs text-gray-500 dark:text-gray-400 w-20 text-right transition-colors">{label}</span>
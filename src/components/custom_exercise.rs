@@ -1,5 +1,6 @@
-use crate::models::{Category, Equipment, Exercise, ExerciseTrackingType};
+use crate::models::{Category, Difficulty, Equipment, Exercise, ExerciseTrackingType};
 use crate::muscle_data::TRACKED_MUSCLES;
+use crate::storage;
 use gloo::file::callbacks::{self, FileReader};
 use web_sys::HtmlInputElement;
 use yew::prelude::*;
@@ -21,13 +22,27 @@ pub fn custom_exercise_form(props: &Props) -> Html {
     let name = use_state(String::new);
     let category = use_state(|| Category::Chest);
     let equipment = use_state(|| Equipment::Barbell);
-    let tracking_type = use_state(|| ExerciseTrackingType::Strength);
+    let tracking_type = use_state(|| {
+        let config = storage::load_user_config();
+        config
+            .default_tracking_type
+            .or(config.last_tracking_type)
+            .unwrap_or_default()
+    });
     let muscle_entries = use_state(Vec::<MuscleEntry>::new);
     let selected_muscle = use_state(String::new);
     let selected_role = use_state(|| "primary".to_string());
     let custom_muscle_name = use_state(String::new);
     let description = use_state(String::new);
+    let instructions = use_state(String::new);
     let image = use_state(|| None::<String>);
+    let bar_weight = use_state(String::new);
+    let assisted = use_state(|| false);
+    let per_hand = use_state(|| false);
+    let time_only = use_state(|| false);
+    let difficulty = use_state(Difficulty::default);
+    let aliases = use_state(String::new);
+    let unit_system = storage::load_user_config().unit_system;
     let reader = use_state(|| None::<FileReader>);
 
     let on_file_select = {
@@ -89,7 +104,15 @@ pub fn custom_exercise_form(props: &Props) -> Html {
         let tracking_type = tracking_type.clone();
         let muscle_entries = muscle_entries.clone();
         let description = description.clone();
+        let instructions = instructions.clone();
         let image = image.clone();
+        let bar_weight = bar_weight.clone();
+        let assisted = assisted.clone();
+        let per_hand = per_hand.clone();
+        let time_only = time_only.clone();
+        let difficulty = difficulty.clone();
+        let aliases = aliases.clone();
+        let unit_system = unit_system.clone();
         let cb = props.on_save.clone();
         Callback::from(move |_| {
             if !name.is_empty() {
@@ -103,6 +126,9 @@ pub fn custom_exercise_form(props: &Props) -> Html {
                         }
                     })
                     .collect();
+                let mut config = storage::load_user_config();
+                config.last_tracking_type = Some((*tracking_type).clone());
+                storage::save_user_config(&config);
                 cb.emit(Exercise {
                     id: format!("custom-{}", uuid::Uuid::new_v4()),
                     name: (*name).clone(),
@@ -113,6 +139,28 @@ pub fn custom_exercise_form(props: &Props) -> Html {
                     is_custom: true,
                     image: (*image).clone(),
                     tracking_type: (*tracking_type).clone(),
+                    bar_weight_override: if *equipment == Equipment::Barbell {
+                        bar_weight
+                            .trim()
+                            .parse::<f64>()
+                            .ok()
+                            .filter(|w| *w > 0.0)
+                            .map(|w| unit_system.to_kg(w))
+                    } else {
+                        None
+                    },
+                    aliases: aliases
+                        .split(',')
+                        .map(|a| a.trim().to_string())
+                        .filter(|a| !a.is_empty())
+                        .collect(),
+                    instructions: Some(instructions.trim().to_string()).filter(|i| !i.is_empty()),
+                    assisted: *assisted && *tracking_type == ExerciseTrackingType::Strength,
+                    per_hand: *per_hand
+                        && *equipment == Equipment::Dumbbell
+                        && *tracking_type == ExerciseTrackingType::Strength,
+                    time_only: *time_only && *tracking_type == ExerciseTrackingType::Cardio,
+                    difficulty: *difficulty,
                 });
             }
         })
@@ -143,6 +191,19 @@ pub fn custom_exercise_form(props: &Props) -> Html {
                         })}
                     />
                 </div>
+                <div>
+                    <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Aliases (optional)"}</label>
+                    <input
+                        type="text"
+                        placeholder="Comma-separated, e.g. OHP, Military Press"
+                        class="w-full px-3 py-2 bg-white dark:bg-gray-700 rounded text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
+                        value={(*aliases).clone()}
+                        oninput={let a = aliases.clone(); Callback::from(move |e: InputEvent| {
+                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                            a.set(input.value());
+                        })}
+                    />
+                </div>
                 <div class="grid grid-cols-2 gap-4">
                     <div>
                         <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Category"}</label>
@@ -198,20 +259,45 @@ pub fn custom_exercise_form(props: &Props) -> Html {
                         </select>
                     </div>
                 </div>
+                { if *equipment == Equipment::Barbell {
+                    html! {
+                        <div>
+                            <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{format!("Bar Weight ({}, optional)", unit_system.weight_label())}</label>
+                            <input
+                                type="number"
+                                step="0.5"
+                                placeholder="Use default from Settings"
+                                class="w-full px-3 py-2 bg-white dark:bg-gray-700 rounded text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
+                                value={(*bar_weight).clone()}
+                                oninput={let bw = bar_weight.clone(); Callback::from(move |e: InputEvent| {
+                                    let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                    bw.set(input.value());
+                                })}
+                            />
+                        </div>
+                    }
+                } else { html! {} }}
+                <div>
+                    <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Difficulty"}</label>
+                    <select
+                        class="w-full px-3 py-2 bg-white dark:bg-gray-700 rounded text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
+                        onchange={let d = difficulty.clone(); Callback::from(move |e: Event| {
+                            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+                            d.set(Difficulty::from_key(&input.value()));
+                        })}
+                    >
+                        { for Difficulty::all().into_iter().map(|level| {
+                            html! { <option value={level.to_key()} selected={*difficulty == level}>{level.display_name()}</option> }
+                        })}
+                    </select>
+                </div>
                 <div>
                     <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Tracking Type"}</label>
                     <select
                         class="w-full px-3 py-2 bg-white dark:bg-gray-700 rounded text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
                         onchange={let tt = tracking_type.clone(); Callback::from(move |e: Event| {
                             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-                            let val = match input.value().as_str() {
-                                "Strength" => ExerciseTrackingType::Strength,
-                                "Cardio" => ExerciseTrackingType::Cardio,
-                                "Duration" => ExerciseTrackingType::Duration,
-                                "Bodyweight" => ExerciseTrackingType::Bodyweight,
-                                _ => ExerciseTrackingType::Strength,
-                            };
-                            tt.set(val);
+                            tt.set(ExerciseTrackingType::from_key(&input.value()).unwrap_or_default());
                         })}
                     >
                         <option value="Strength" selected={*tracking_type == ExerciseTrackingType::Strength}>{"Strength (Weight + Reps)"}</option>
@@ -220,6 +306,48 @@ pub fn custom_exercise_form(props: &Props) -> Html {
                         <option value="Bodyweight" selected={*tracking_type == ExerciseTrackingType::Bodyweight}>{"Bodyweight (Reps only)"}</option>
                     </select>
                 </div>
+                if *tracking_type == ExerciseTrackingType::Strength {
+                    <label class="flex items-start gap-2 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            class="mt-0.5 w-4 h-4 accent-blue-600"
+                            checked={*assisted}
+                            onchange={let a = assisted.clone(); Callback::from(move |_| a.set(!*a))}
+                        />
+                        <span>
+                            <span class="block text-sm font-medium text-gray-800 dark:text-gray-200">{"Assisted (lower is better)"}</span>
+                            <span class="block text-xs text-gray-500 dark:text-gray-400">{"Weight logged is assistance, e.g. an assisted pull-up machine. PRs and progress count less assistance as stronger."}</span>
+                        </span>
+                    </label>
+                }
+                if *tracking_type == ExerciseTrackingType::Cardio {
+                    <label class="flex items-start gap-2 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            class="mt-0.5 w-4 h-4 accent-blue-600"
+                            checked={*time_only}
+                            onchange={let t = time_only.clone(); Callback::from(move |_| t.set(!*t))}
+                        />
+                        <span>
+                            <span class="block text-sm font-medium text-gray-800 dark:text-gray-200">{"Time only (no distance)"}</span>
+                            <span class="block text-xs text-gray-500 dark:text-gray-400">{"For cardio like a stair climber or air bike where distance doesn't apply."}</span>
+                        </span>
+                    </label>
+                }
+                if *tracking_type == ExerciseTrackingType::Strength && *equipment == Equipment::Dumbbell {
+                    <label class="flex items-start gap-2 cursor-pointer">
+                        <input
+                            type="checkbox"
+                            class="mt-0.5 w-4 h-4 accent-blue-600"
+                            checked={*per_hand}
+                            onchange={let p = per_hand.clone(); Callback::from(move |_| p.set(!*p))}
+                        />
+                        <span>
+                            <span class="block text-sm font-medium text-gray-800 dark:text-gray-200">{"Dumbbell weight per hand"}</span>
+                            <span class="block text-xs text-gray-500 dark:text-gray-400">{"Enter one dumbbell's weight; volume and PRs count both."}</span>
+                        </span>
+                    </label>
+                }
                 <div>
                     <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Muscle Groups"}</label>
                     // Current muscle tags
@@ -315,6 +443,19 @@ pub fn custom_exercise_form(props: &Props) -> Html {
                         })}
                     ></textarea>
                 </div>
+                <div>
+                    <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Form Cues (optional)"}</label>
+                    <textarea
+                        rows="3"
+                        placeholder="e.g. Brace before each rep, elbows tucked"
+                        class="w-full px-3 py-2 bg-white dark:bg-gray-700 rounded text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
+                        value={(*instructions).clone()}
+                        oninput={let i = instructions.clone(); Callback::from(move |e: InputEvent| {
+                            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                            i.set(input.value());
+                        })}
+                    ></textarea>
+                </div>
                 <div>
                     <label class="block text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-500 mb-1">{"Image"}</label>
                     <input
@@ -1,4 +1,5 @@
-use crate::models::Exercise;
+use crate::models::{Equipment, Exercise, ExerciseTrackingType};
+use crate::storage;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -7,12 +8,71 @@ pub struct Props {
     pub on_back: Callback<()>,
     #[prop_or_default]
     pub on_share: Option<Callback<Exercise>>,
+    /// Saves edited form cues; only offered for custom exercises.
+    #[prop_or_default]
+    pub on_save_instructions: Option<Callback<Option<String>>>,
 }
 
 #[function_component(ExerciseDetail)]
 pub fn exercise_detail(props: &Props) -> Html {
     let ex = &props.exercise;
     let on_back = props.on_back.clone();
+    let cues_open = use_state(|| false);
+    let cues_draft = use_state(|| None::<String>);
+    let per_hand = use_state(|| storage::load_user_config().is_per_hand(ex));
+    // The user's own bar for this exercise, on top of any set on a custom exercise
+    let bar_override =
+        use_state(|| storage::load_user_config().bar_weight_overrides.get(&ex.id).copied());
+    let dumbbell_strength =
+        ex.equipment == Equipment::Dumbbell && ex.tracking_type == ExerciseTrackingType::Strength;
+    let on_toggle_per_hand = {
+        let per_hand = per_hand.clone();
+        let ex = ex.clone();
+        Callback::from(move |_: Event| {
+            let value = !*per_hand;
+            storage::set_per_hand(&ex, value);
+            per_hand.set(value);
+        })
+    };
+
+    // Empty clears the override so the default bar is used again
+    let on_change_bar_weight = {
+        let bar_override = bar_override.clone();
+        let id = ex.id.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut config = storage::load_user_config();
+            let value = input
+                .value()
+                .parse::<f64>()
+                .ok()
+                .filter(|v| *v >= 0.0)
+                .map(|v| config.unit_system.to_kg(v));
+            match value {
+                Some(kg) => config.bar_weight_overrides.insert(id.clone(), kg),
+                None => config.bar_weight_overrides.remove(&id),
+            };
+            storage::save_user_config(&config);
+            bar_override.set(value);
+        })
+    };
+
+    let (units, rounding, default_bar) = {
+        let config = storage::load_user_config();
+        (config.unit_system.clone(), config.weight_rounding, config.effective_bar_weight())
+    };
+
+    let editable = ex.is_custom && props.on_save_instructions.is_some();
+    let on_save_cues = {
+        let cues_draft = cues_draft.clone();
+        let cb = props.on_save_instructions.clone();
+        Callback::from(move |_: MouseEvent| {
+            if let (Some(cb), Some(draft)) = (&cb, &*cues_draft) {
+                cb.emit(Some(draft.trim().to_string()).filter(|d| !d.is_empty()));
+            }
+            cues_draft.set(None);
+        })
+    };
 
     html! {
         <div class="px-4 py-4 transition-colors duration-200">
@@ -40,6 +100,11 @@ pub fn exercise_detail(props: &Props) -> Html {
             <div class="flex flex-wrap gap-2 mb-6">
                 <span class="px-2 py-1 bg-blue-100 dark:bg-blue-900/40 text-blue-700 dark:text-blue-300 border border-blue-200 dark:border-transparent rounded text-xs font-bold uppercase tracking-wider">{ex.category.to_string()}</span>
                 <span class="px-2 py-1 bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 rounded text-xs font-bold uppercase tracking-wider neu-chip">{ex.equipment.to_string()}</span>
+                { if let Some(bw) = bar_override.or(ex.bar_weight_override) {
+                    html! { <span class="px-2 py-1 bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 rounded text-xs font-bold uppercase tracking-wider neu-chip">{format!("{}{} bar", units.format_weight(bw, rounding), units.weight_label())}</span> }
+                } else {
+                    html! {}
+                }}
                 { if ex.is_custom {
                     html! { <span class="px-2 py-1 bg-amber-100 dark:bg-amber-900/40 text-amber-700 dark:text-amber-300 border border-amber-200 dark:border-transparent rounded text-xs font-bold uppercase tracking-wider">{"Custom"}</span> }
                 } else {
@@ -59,6 +124,35 @@ pub fn exercise_detail(props: &Props) -> Html {
             } else {
                 html! {}
             }}
+            if dumbbell_strength {
+                <label class="mb-6 flex items-start gap-2 cursor-pointer bg-gray-100 dark:bg-gray-800/50 rounded-2xl p-4 neu-flat transition-colors">
+                    <input
+                        type="checkbox"
+                        class="mt-0.5 w-4 h-4 accent-blue-600"
+                        checked={*per_hand}
+                        onchange={on_toggle_per_hand}
+                    />
+                    <span>
+                        <span class="block text-sm font-medium text-gray-800 dark:text-gray-200">{"Log weight per hand"}</span>
+                        <span class="block text-xs text-gray-500 dark:text-gray-400">{"Enter one dumbbell's weight in workouts; volume and PRs count both. Switching rescales what you've already logged for this exercise."}</span>
+                    </span>
+                </label>
+            }
+            if ex.equipment == Equipment::Barbell {
+                <label class="mb-6 flex items-center justify-between gap-2 bg-gray-100 dark:bg-gray-800/50 rounded-2xl p-4 neu-flat transition-colors">
+                    <span>
+                        <span class="block text-sm font-medium text-gray-800 dark:text-gray-200">{format!("Bar weight ({})", units.weight_label())}</span>
+                        <span class="block text-xs text-gray-500 dark:text-gray-400">{"For a specialty bar on this lift, used by the plate calculator and warm-ups. Leave blank for the default."}</span>
+                    </span>
+                    <input
+                        type="number" min="0" step="0.1" autocomplete="off"
+                        class="w-20 bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm text-center rounded-lg px-2 py-1 outline-none neu-pressed"
+                        placeholder={units.format_weight(ex.bar_weight_override.unwrap_or(default_bar), rounding)}
+                        value={bar_override.map(|kg| format!("{:.1}", units.display_weight(kg))).unwrap_or_default()}
+                        onchange={on_change_bar_weight}
+                    />
+                </label>
+            }
             <div class="mb-6">
                 <h3 class="text-lg font-bold mb-2 text-gray-900 dark:text-gray-100">{"Muscles Worked"}</h3>
                 <div class="flex flex-wrap gap-2">
@@ -71,6 +165,66 @@ pub fn exercise_detail(props: &Props) -> Html {
                 <h3 class="text-lg font-bold mb-2 text-gray-900 dark:text-gray-100">{"How to Perform"}</h3>
                 <p class="text-gray-600 dark:text-gray-400 leading-relaxed text-sm">{&ex.description}</p>
             </div>
+            if ex.instructions.is_some() || editable {
+                <div class="mt-4 bg-gray-100 dark:bg-gray-800/50 rounded-2xl p-4 neu-flat transition-colors">
+                    <button
+                        class="w-full flex justify-between items-center text-lg font-bold text-gray-900 dark:text-gray-100"
+                        onclick={let o = cues_open.clone(); Callback::from(move |_| o.set(!*o))}
+                    >
+                        <span>{"Form Cues"}</span>
+                        <span class="text-sm text-gray-400">{if *cues_open { "\u{25b2}" } else { "\u{25bc}" }}</span>
+                    </button>
+                    if *cues_open {
+                        { if let Some(ref draft) = *cues_draft {
+                            html! {
+                                <div class="mt-2 space-y-2">
+                                    <textarea
+                                        rows="4"
+                                        class="w-full px-3 py-2 bg-white dark:bg-gray-700 rounded text-sm text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
+                                        value={draft.clone()}
+                                        oninput={let d = cues_draft.clone(); Callback::from(move |e: InputEvent| {
+                                            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+                                            d.set(Some(input.value()));
+                                        })}
+                                    ></textarea>
+                                    <div class="flex gap-2">
+                                        <button
+                                            class="px-3 py-1.5 bg-blue-600 text-white rounded-lg text-sm font-bold hover:bg-blue-700 neu-btn transition-colors"
+                                            onclick={on_save_cues}
+                                        >{"Save"}</button>
+                                        <button
+                                            class="px-3 py-1.5 bg-gray-200 dark:bg-gray-700 text-gray-700 dark:text-gray-300 rounded-lg text-sm font-bold hover:bg-gray-300 dark:hover:bg-gray-600 neu-btn transition-colors"
+                                            onclick={let d = cues_draft.clone(); Callback::from(move |_| d.set(None))}
+                                        >{"Cancel"}</button>
+                                    </div>
+                                </div>
+                            }
+                        } else {
+                            let current = ex.instructions.clone().unwrap_or_default();
+                            let on_edit = {
+                                let cues_draft = cues_draft.clone();
+                                let current = current.clone();
+                                Callback::from(move |_: MouseEvent| cues_draft.set(Some(current.clone())))
+                            };
+                            html! {
+                                <div class="mt-2">
+                                    if current.is_empty() {
+                                        <p class="text-sm text-gray-500 dark:text-gray-500 italic">{"No cues yet."}</p>
+                                    } else {
+                                        <p class="text-gray-600 dark:text-gray-400 leading-relaxed text-sm whitespace-pre-line">{current}</p>
+                                    }
+                                    if editable {
+                                        <button
+                                            class="mt-2 text-sm text-blue-600 dark:text-blue-400 font-medium hover:underline"
+                                            onclick={on_edit}
+                                        >{"Edit cues"}</button>
+                                    }
+                                </div>
+                            }
+                        }}
+                    }
+                </div>
+            }
         </div>
     }
 }
@@ -1,5 +1,7 @@
-use crate::models::{Category, Exercise};
+use crate::models::{Category, Difficulty, Equipment, Exercise};
 use crate::muscle_data::{exercise_muscles, TRACKED_MUSCLES};
+use crate::storage;
+use std::collections::HashMap;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -103,7 +105,7 @@ fn edit_distance(a: &str, b: &str) -> u32 {
 }
 
 /// Score an exercise against a search query. Returns the best (lowest) score
-/// across name, muscle groups, equipment, and category.
+/// across name, aliases, muscle groups, equipment, and category.
 fn exercise_score(e: &Exercise, query: &str) -> Option<u32> {
     let mut best: Option<u32> = None;
     let mut consider = |s: &str| {
@@ -112,6 +114,9 @@ fn exercise_score(e: &Exercise, query: &str) -> Option<u32> {
         }
     };
     consider(&e.name);
+    for a in &e.aliases {
+        consider(a);
+    }
     for m in &e.muscle_groups {
         consider(m);
     }
@@ -120,11 +125,84 @@ fn exercise_score(e: &Exercise, query: &str) -> Option<u32> {
     best
 }
 
+/// Recent sessions plotted in each exercise's sparkline.
+const SPARKLINE_SESSIONS: usize = 8;
+
+/// Epley formula: weight * (1 + reps/30)
+fn estimate_1rm(weight: f64, reps: u32) -> f64 {
+    weight * (1.0 + reps as f64 / 30.0)
+}
+
+/// Best est. 1RM per session, oldest first, for each exercise with weighted
+/// sets in the log. Exercises never performed get no entry.
+fn e1rm_history() -> HashMap<String, Vec<f64>> {
+    let mut workouts = storage::load_workouts();
+    workouts.sort_by(|a, b| a.date.cmp(&b.date));
+    let mut history: HashMap<String, Vec<f64>> = HashMap::new();
+    for w in &workouts {
+        for we in &w.exercises {
+            let best = we
+                .sets
+                .iter()
+                .filter(|s| s.completed && s.weight > 0.0 && s.reps > 0)
+                .map(|s| estimate_1rm(s.weight, s.reps))
+                .fold(0.0_f64, f64::max);
+            if best > 0.0 {
+                history.entry(we.exercise_id.clone()).or_default().push(best);
+            }
+        }
+    }
+    for points in history.values_mut() {
+        if points.len() > SPARKLINE_SESSIONS {
+            points.drain(..points.len() - SPARKLINE_SESSIONS);
+        }
+    }
+    history
+}
+
+/// Tiny trend line for the list row; green when the latest point is at or
+/// above the first, red when it dropped.
+fn sparkline(points: &[f64]) -> Html {
+    if points.len() < 2 {
+        return html! {};
+    }
+    let (w, h) = (40.0, 14.0);
+    let max = points.iter().copied().fold(f64::MIN, f64::max);
+    let min = points.iter().copied().fold(f64::MAX, f64::min);
+    let range = if max - min < 0.001 { 1.0 } else { max - min };
+    let step = w / (points.len() - 1) as f64;
+    let coords = points
+        .iter()
+        .enumerate()
+        .map(|(i, v)| format!("{:.1},{:.1}", i as f64 * step, h - 1.0 - (v - min) / range * (h - 2.0)))
+        .collect::<Vec<_>>()
+        .join(" ");
+    let color = if points[points.len() - 1] >= points[0] { "#22c55e" } else { "#ef4444" };
+    html! {
+        <svg viewBox={format!("0 0 {} {}", w, h)} class="w-10 h-3.5 flex-shrink-0" aria-hidden="true">
+            <polyline points={coords} fill="none" stroke={color} stroke-width="1.5"
+                      stroke-linejoin="round" stroke-linecap="round"/>
+        </svg>
+    }
+}
+
 #[function_component(ExerciseList)]
 pub fn exercise_list(props: &Props) -> Html {
     let search = use_state(String::new);
     let category_filter = use_state(|| None::<Category>);
     let muscle_filter = use_state(|| None::<String>);
+    let equipment_filter = use_state(|| None::<Equipment>);
+    let difficulty_filter = use_state(|| None::<Difficulty>);
+    let favorites = use_state(|| storage::load_user_config().favorite_exercises);
+    // Active gym's equipment list, when it restricts equipment
+    let gym_equipment = use_memo((), |_| {
+        storage::load_user_config()
+            .active_gym()
+            .filter(|g| !g.equipment.is_empty())
+            .map(|g| (g.name.clone(), g.equipment.clone()))
+    });
+    let gym_only = use_state(|| true);
+    let e1rm_trends = use_memo((), |_| e1rm_history());
 
     let mut scored: Vec<(&Exercise, u32)> = props
         .exercises
@@ -137,6 +215,22 @@ pub fn exercise_list(props: &Props) -> Html {
             if !cat_match {
                 return None;
             }
+            if let Some(ref eq) = *equipment_filter {
+                if e.equipment != *eq {
+                    return None;
+                }
+            }
+            if let Some(level) = *difficulty_filter {
+                if e.difficulty != level {
+                    return None;
+                }
+            }
+            // Bodyweight moves work anywhere, so the gym filter never hides them
+            if let Some((_, available)) = (*gym_equipment).as_ref().filter(|_| *gym_only) {
+                if e.equipment != Equipment::Bodyweight && !available.contains(&e.equipment) {
+                    return None;
+                }
+            }
             // Muscle filter
             if let Some(ref muscle) = *muscle_filter {
                 let contributions = exercise_muscles(&e.id);
@@ -158,7 +252,8 @@ pub fn exercise_list(props: &Props) -> Html {
             exercise_score(e, &search).map(|s| (e, s))
         })
         .collect();
-    scored.sort_by_key(|(_, s)| *s);
+    // Favorites first, then by match quality
+    scored.sort_by_key(|(e, s)| (!favorites.contains(&e.id), *s));
     let filtered: Vec<&Exercise> = scored.into_iter().map(|(e, _)| e).collect();
 
     let on_search = {
@@ -246,6 +341,80 @@ pub fn exercise_list(props: &Props) -> Html {
                     }
                 })}
             </div>
+            <div class="px-4 pb-2 flex gap-2 overflow-x-auto scrollbar-hide">
+                if let Some((gym_name, _)) = (*gym_equipment).as_ref() {
+                    <button
+                        class={if *gym_only {
+                            "px-3 py-1 rounded-full text-sm bg-amber-600 text-white whitespace-nowrap font-medium neu-chip-active transition-colors"
+                        } else {
+                            "px-3 py-1 rounded-full text-sm bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 whitespace-nowrap neu-chip hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                        }}
+                        title="Only show exercises this gym has equipment for"
+                        onclick={let g = gym_only.clone(); Callback::from(move |_| g.set(!*g))}
+                    >{format!("\u{1f4cd} {}", gym_name)}</button>
+                }
+                {
+                    {
+                        let ef = equipment_filter.clone();
+                        html! {
+                            <button
+                                class={if ef.is_none() {
+                                    "px-3 py-1 rounded-full text-sm bg-amber-600 text-white whitespace-nowrap font-medium neu-chip-active transition-colors"
+                                } else {
+                                    "px-3 py-1 rounded-full text-sm bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 whitespace-nowrap neu-chip hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                                }}
+                                onclick={let ef = ef.clone(); Callback::from(move |_| ef.set(None))}
+                            >{"All Equipment"}</button>
+                        }
+                    }
+                }
+                { for Equipment::all().into_iter().map(|eq| {
+                    let ef = equipment_filter.clone();
+                    let active = *ef == Some(eq.clone());
+                    let label = eq.to_string();
+                    html! {
+                        <button
+                            class={if active {
+                                "px-3 py-1 rounded-full text-sm bg-amber-600 text-white whitespace-nowrap font-medium neu-chip-active transition-colors"
+                            } else {
+                                "px-3 py-1 rounded-full text-sm bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 whitespace-nowrap neu-chip hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            }}
+                            onclick={Callback::from(move |_| ef.set(Some(eq.clone())))}
+                        >{label}</button>
+                    }
+                })}
+            </div>
+            <div class="px-4 pb-2 flex gap-2 overflow-x-auto scrollbar-hide">
+                {
+                    {
+                        let df = difficulty_filter.clone();
+                        html! {
+                            <button
+                                class={if df.is_none() {
+                                    "px-3 py-1 rounded-full text-sm bg-emerald-600 text-white whitespace-nowrap font-medium neu-chip-active transition-colors"
+                                } else {
+                                    "px-3 py-1 rounded-full text-sm bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 whitespace-nowrap neu-chip hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                                }}
+                                onclick={let df = df.clone(); Callback::from(move |_| df.set(None))}
+                            >{"All Levels"}</button>
+                        }
+                    }
+                }
+                { for Difficulty::all().into_iter().map(|level| {
+                    let df = difficulty_filter.clone();
+                    let active = *df == Some(level);
+                    html! {
+                        <button
+                            class={if active {
+                                "px-3 py-1 rounded-full text-sm bg-emerald-600 text-white whitespace-nowrap font-medium neu-chip-active transition-colors"
+                            } else {
+                                "px-3 py-1 rounded-full text-sm bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300 whitespace-nowrap neu-chip hover:bg-gray-200 dark:hover:bg-gray-600 transition-colors"
+                            }}
+                            onclick={Callback::from(move |_| df.set(Some(level)))}
+                        >{level.display_name()}</button>
+                    }
+                })}
+            </div>
             <div class="px-4 space-y-2 pb-4">
                 { for filtered.iter().enumerate().map(|(i, exercise)| {
                     let ex = (*exercise).clone();
@@ -254,6 +423,20 @@ pub fn exercise_list(props: &Props) -> Html {
                     let show_add = props.show_add_button;
                     let ex2 = ex.clone();
                     let ex3 = ex.clone();
+                    let is_favorite = favorites.contains(&ex.id);
+                    let on_toggle_favorite = {
+                        let favorites = favorites.clone();
+                        let id = ex.id.clone();
+                        Callback::from(move |e: MouseEvent| {
+                            e.stop_propagation();
+                            let mut config = storage::load_user_config();
+                            if !config.favorite_exercises.remove(&id) {
+                                config.favorite_exercises.insert(id.clone());
+                            }
+                            storage::save_user_config(&config);
+                            favorites.set(config.favorite_exercises);
+                        })
+                    };
                     let delay = format!("animation-delay: {}ms", i.min(10) * 30);
                     html! {
                         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-3 flex justify-between items-center neu-flat transition-colors list-item-enter" style={delay}>
@@ -272,12 +455,24 @@ pub fn exercise_list(props: &Props) -> Html {
                                     }
                                 }}
                                 <div>
-                                    <div class="font-medium text-gray-900 dark:text-gray-100">{&ex.name}</div>
+                                    <div class="font-medium text-gray-900 dark:text-gray-100 flex items-center gap-2">
+                                        <span>{&ex.name}</span>
+                                        { e1rm_trends.get(&ex.id).map(|points| sparkline(points)).unwrap_or_default() }
+                                    </div>
                                     <div class="text-sm text-gray-500 dark:text-gray-400">
-                                        {ex.category.to_string()}{" · "}{ex.equipment.to_string()}
+                                        {ex.category.to_string()}{" · "}{ex.equipment.to_string()}{" · "}{ex.difficulty.display_name()}
                                     </div>
                                 </div>
                             </div>
+                            <button
+                                class={if is_favorite {
+                                    "ml-2 p-1 text-lg leading-none text-yellow-400 hover:text-yellow-300 transition-colors"
+                                } else {
+                                    "ml-2 p-1 text-lg leading-none text-gray-400 dark:text-gray-500 hover:text-yellow-400 transition-colors"
+                                }}
+                                title={if is_favorite { "Remove from favorites" } else { "Add to favorites" }}
+                                onclick={on_toggle_favorite}
+                            >{if is_favorite { "\u{2605}" } else { "\u{2606}" }}</button>
                             { if show_add {
                                 html! {
                                     <button
@@ -1,9 +1,13 @@
 use crate::components::share_modal::ShareModal;
-use crate::models::{Exercise, ExerciseTrackingType, Workout, WorkoutSet};
+use crate::dates::{iso_week_key, iso_week_label};
+use crate::i18n::format_number;
+use crate::models::{Exercise, ExerciseTrackingType, UnitSystem, Workout, WorkoutSet};
 use crate::sharing::{self, ShareableData};
 use crate::storage;
 use crate::Route;
+use chrono::Datelike;
 use gloo::storage::{LocalStorage, Storage};
+use std::collections::{HashMap, HashSet};
 use yew::prelude::*;
 use yew_router::prelude::*;
 
@@ -13,6 +17,27 @@ pub struct Props {
     pub all_exercises: Vec<Exercise>,
     pub on_delete: Callback<String>,
     pub on_update: Callback<Workout>,
+    /// Workout to expand and scroll to; the counter lets the same one be focused again.
+    #[prop_or_default]
+    pub focus: Option<(String, u32)>,
+}
+
+/// How workouts are bucketed under headers in the history list.
+#[derive(Clone, Copy, PartialEq)]
+enum HistoryGrouping {
+    Day,
+    Week,
+    Month,
+}
+
+/// Group volume for section headers, e.g. "12.3k kg".
+fn format_volume(units: &UnitSystem, kg: f64) -> String {
+    let v = units.display_weight(kg);
+    if v >= 10_000.0 {
+        format!("{}k {}", format_number(v / 1000.0, 1), units.weight_label())
+    } else {
+        format!("{} {}", format_number(v, 0), units.weight_label())
+    }
 }
 
 #[function_component(HistoryList)]
@@ -21,7 +46,27 @@ pub fn history_list(props: &Props) -> Html {
     let editing = use_state(|| None::<Workout>);
     let share_target = use_state(|| None::<(ShareableData, String)>);
     let navigator = use_navigator().unwrap();
-    let units = storage::load_user_config().unit_system;
+    let group_by = use_state(|| HistoryGrouping::Day);
+    // Week/month section keys the user has folded away
+    let collapsed = use_state(HashSet::<String>::new);
+
+    {
+        let expanded = expanded.clone();
+        let collapsed = collapsed.clone();
+        use_effect_with(props.focus.clone(), move |focus| {
+            if let Some((id, _)) = focus {
+                expanded.set(Some(id.clone()));
+                collapsed.set(HashSet::new());
+                if let Some(el) = gloo::utils::document().get_element_by_id(&format!("workout-{}", id)) {
+                    el.scroll_into_view();
+                }
+            }
+            || ()
+        });
+    }
+    let config = storage::load_user_config();
+    let units = config.unit_system.clone();
+    let rounding = config.weight_rounding;
 
     let find_exercise = |id: &str| -> String {
         props
@@ -39,6 +84,14 @@ pub fn history_list(props: &Props) -> Html {
             .map(|e| e.tracking_type.clone())
             .unwrap_or(ExerciseTrackingType::Strength)
     };
+    // Per-hand dumbbell weights show one dumbbell, as they were entered
+    let find_hands = |id: &str| -> f64 {
+        props
+            .all_exercises
+            .iter()
+            .find(|e| e.id == id)
+            .map_or(1.0, |e| config.hands(e))
+    };
 
     let mut workouts = props.workouts.clone();
     workouts.sort_by(|a, b| b.date.cmp(&a.date));
@@ -83,12 +136,107 @@ pub fn history_list(props: &Props) -> Html {
         key.to_string()
     };
 
+    // Week/month section (key, label) of each day group; none in day view
+    let day_sections: Vec<Option<(String, String)>> = grouped
+        .iter()
+        .map(|(date_key, _)| {
+            let date = chrono::NaiveDate::parse_from_str(date_key, "%Y-%m-%d").ok();
+            match (*group_by, date) {
+                (HistoryGrouping::Day, _) => None,
+                (HistoryGrouping::Week, Some(d)) => {
+                    let (year, week) = iso_week_key(d);
+                    let monday = d - chrono::Duration::days(d.weekday().num_days_from_monday() as i64);
+                    Some((
+                        format!("{}-W{:02}", year, week),
+                        format!("{} \u{00b7} {}", iso_week_label(d), format_date(&monday.format("%Y-%m-%d").to_string())),
+                    ))
+                }
+                (HistoryGrouping::Month, Some(d)) => {
+                    Some((d.format("%Y-%m").to_string(), d.format("%B %Y").to_string()))
+                }
+                (_, None) => Some(("other".to_string(), "Other".to_string())),
+            }
+        })
+        .collect();
+
+    // Sessions and volume per section, for its header
+    let mut section_totals: HashMap<String, (usize, f64)> = HashMap::new();
+    for ((_, day_workouts), section) in grouped.iter().zip(&day_sections) {
+        if let Some((key, _)) = section {
+            let totals = section_totals.entry(key.clone()).or_default();
+            totals.0 += day_workouts.len();
+            totals.1 += day_workouts.iter().map(|w| w.total_volume()).sum::<f64>();
+        }
+    }
+
+    let section_header = |key: &str, label: &str| -> Html {
+        let is_collapsed = collapsed.contains(key);
+        let (sessions, volume) = section_totals.get(key).copied().unwrap_or_default();
+        let on_toggle = {
+            let collapsed = collapsed.clone();
+            let key = key.to_string();
+            Callback::from(move |_: MouseEvent| {
+                let mut set = (*collapsed).clone();
+                if !set.remove(&key) {
+                    set.insert(key.clone());
+                }
+                collapsed.set(set);
+            })
+        };
+        html! {
+            <button
+                class="w-full flex items-center justify-between gap-2 px-3 py-2 bg-gray-200 dark:bg-gray-700/60 rounded-lg text-left transition-colors"
+                onclick={on_toggle}
+            >
+                <span class="text-sm font-bold text-gray-900 dark:text-gray-100">
+                    {if is_collapsed { "\u{25b8} " } else { "\u{25be} " }}{label.to_string()}
+                </span>
+                <span class="text-xs text-gray-500 dark:text-gray-400">
+                    {format!("{} session{} \u{00b7} {}", sessions, if sessions == 1 { "" } else { "s" }, format_volume(&units, volume))}
+                </span>
+            </button>
+        }
+    };
+
+    let grouping_chip = |grouping: HistoryGrouping, label: &'static str| {
+        let group_by = group_by.clone();
+        let class = if *group_by == grouping {
+            "px-3 py-1 rounded-full text-xs bg-blue-600 text-white font-medium neu-chip-active transition-colors"
+        } else {
+            "px-3 py-1 rounded-full text-xs bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-300 neu-chip hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+        };
+        html! {
+            <button {class} onclick={Callback::from(move |_| group_by.set(grouping))}>{label}</button>
+        }
+    };
+
     html! {
         <div class="space-y-3 px-4 pb-4">
-            { for grouped.iter().map(|(date_key, day_workouts)| {
+            if !workouts.is_empty() {
+                <div class="flex gap-2">
+                    {grouping_chip(HistoryGrouping::Day, "Day")}
+                    {grouping_chip(HistoryGrouping::Week, "Week")}
+                    {grouping_chip(HistoryGrouping::Month, "Month")}
+                </div>
+            }
+            { for grouped.iter().enumerate().map(|(day_idx, (date_key, day_workouts))| {
+                // In week/month view a header opens each section, and a folded
+                // section hides its days
+                let section = day_sections[day_idx].as_ref();
+                let opens_section = section.is_some_and(|(key, _)| {
+                    day_idx == 0 || day_sections[day_idx - 1].as_ref().map(|(k, _)| k) != Some(key)
+                });
+                let header = match section {
+                    Some((key, label)) if opens_section => section_header(key, label),
+                    _ => html! {},
+                };
+                if section.is_some_and(|(key, _)| collapsed.contains(key)) {
+                    return header;
+                }
                 let label = format_date(date_key);
                 html! {
                     <div>
+                        {header}
                         <div class="sticky top-0 z-10 py-2">
                             <h3 class="text-xs font-bold uppercase tracking-wider text-gray-500 dark:text-gray-400">{label}</h3>
                         </div>
@@ -114,7 +262,7 @@ pub fn history_list(props: &Props) -> Html {
                 };
 
                 html! {
-                    <div class="bg-gray-100 dark:bg-gray-800 rounded-xl overflow-hidden neu-flat transition-colors">
+                    <div id={format!("workout-{}", w.id)} class="bg-gray-100 dark:bg-gray-800 rounded-xl overflow-hidden neu-flat transition-colors">
                         <div
                             class="p-4 cursor-pointer hover:bg-gray-200 dark:hover:bg-gray-700 transition-colors"
                             onclick={Callback::from(move |_| {
@@ -124,6 +272,9 @@ pub fn history_list(props: &Props) -> Html {
                             <div class="flex justify-between items-start">
                                 <div class="flex-1 min-w-0 mr-3">
                                     <div class="text-sm text-gray-900 dark:text-gray-100 truncate font-medium">{names_summary}</div>
+                                    if let Some(gym) = &w.gym {
+                                        <div class="text-xs text-gray-500 dark:text-gray-400 truncate">{format!("\u{1f4cd} {}", gym)}</div>
+                                    }
                                 </div>
                                 <div class="text-right text-sm text-gray-500 dark:text-gray-400 font-medium flex-shrink-0">
                                     <div>{total_sets}{" sets"}</div>
@@ -163,6 +314,7 @@ pub fn history_list(props: &Props) -> Html {
                                         { for edit_workout.exercises.iter().enumerate().map(|(ex_idx, we)| {
                                             let name = find_exercise(&we.exercise_id);
                                             let tt = find_tracking_type(&we.exercise_id);
+                                            let hands = find_hands(&we.exercise_id);
                                             let editing = editing_state.clone();
                                             let workout = edit_workout.clone();
 
@@ -190,7 +342,7 @@ pub fn history_list(props: &Props) -> Html {
                                                             { match tt {
                                                                 ExerciseTrackingType::Strength => html! {
                                                                     <>
-                                                                        <div class="col-span-5">{"Weight"}</div>
+                                                                        <div class="col-span-5">{if hands > 1.0 { "Weight/hand" } else { "Weight" }}</div>
                                                                         <div class="col-span-4">{"Reps"}</div>
                                                                     </>
                                                                 },
@@ -229,7 +381,7 @@ pub fn history_list(props: &Props) -> Html {
                                                                                     <input
                                                                                         type="text" inputmode="decimal"
                                                                                         class={input_class}
-                                                                                        value={set.weight.to_string()}
+                                                                                        value={(set.weight / hands).to_string()}
                                                                                         onclick={Callback::from(|e: MouseEvent| e.stop_propagation())}
                                                                                         onchange={Callback::from(move |e: Event| {
                                                                                             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
@@ -237,7 +389,7 @@ pub fn history_list(props: &Props) -> Html {
                                                                                             let mut w = workout.clone();
                                                                                             if let Some(we) = w.exercises.get_mut(ex_idx) {
                                                                                                 if let Some(s) = we.sets.get_mut(set_idx) {
-                                                                                                    s.weight = val;
+                                                                                                    s.weight = val * hands;
                                                                                                 }
                                                                                             }
                                                                                             editing.set(Some(w));
@@ -380,7 +532,7 @@ pub fn history_list(props: &Props) -> Html {
                                                                 if let Some(we) = updated.exercises.get_mut(ex_idx) {
                                                                     let last = we.sets.last().cloned().unwrap_or(WorkoutSet {
                                                                         weight: 0.0, reps: 10, completed: false,
-                                                                        distance: None, duration_secs: None, note: None,
+                                                                        distance: None, duration_secs: None, note: None, rest_taken_secs: None, failure: None, bodyweight: None, completed_at: None,
                                                                     });
                                                                     we.sets.push(WorkoutSet {
                                                                         weight: last.weight,
@@ -389,6 +541,10 @@ pub fn history_list(props: &Props) -> Html {
                                                                         distance: last.distance,
                                                                         duration_secs: last.duration_secs,
                                                                         note: None,
+                                                                        rest_taken_secs: None,
+                                                                        failure: None,
+                                                                        bodyweight: None,
+                                                                        completed_at: None,
                                                                     });
                                                                 }
                                                                 editing.set(Some(updated));
@@ -431,29 +587,55 @@ pub fn history_list(props: &Props) -> Html {
                                 let repeat_workout = w.clone();
                                 html! {
                                     <div class="px-4 pb-4 border-t border-gray-200 dark:border-gray-700 pt-3 transition-colors">
+                                        if !display_workout.notes.is_empty() {
+                                            <div class="mb-3 text-sm text-gray-600 dark:text-gray-300 italic whitespace-pre-line">{&display_workout.notes}</div>
+                                        }
                                         { for display_workout.exercises.iter().map(|we| {
                                             let name = find_exercise(&we.exercise_id);
                                             let tt = find_tracking_type(&we.exercise_id);
+                                            let hands = find_hands(&we.exercise_id);
+                                            let per_hand = if hands > 1.0 { "/hand" } else { "" };
                                             html! {
                                                 <div class="mb-4">
                                                     <div class="font-bold text-sm text-gray-800 dark:text-gray-200 mb-1.5">{name}</div>
                                                     <div class="space-y-1">
                                                         { for we.sets.iter().enumerate().map(|(i, s)| {
                                                             let detail = match tt {
-                                                                ExerciseTrackingType::Strength => {
-                                                                    format!("{:.1}{} x {}", units.display_weight(s.weight), units.weight_label(), s.reps)
+                                                                ExerciseTrackingType::Strength => match s.cluster_reps() {
+                                                                    Some(parts) => format!(
+                                                                        "{}{}{} x {} ({})",
+                                                                        units.format_weight(s.weight / hands, rounding), units.weight_label(), per_hand,
+                                                                        parts.iter().sum::<u32>(),
+                                                                        parts.iter().map(|p| p.to_string()).collect::<Vec<_>>().join("+")
+                                                                    ),
+                                                                    None => format!("{}{}{} x {}", units.format_weight(s.weight / hands, rounding), units.weight_label(), per_hand, s.reps),
                                                                 },
                                                                 ExerciseTrackingType::Cardio => {
-                                                                    let dist = s.distance.unwrap_or(0.0);
                                                                     let mins = s.duration_secs.unwrap_or(0) / 60;
-                                                                    format!("{:.1}{} · {}min", units.display_distance(dist), units.distance_label(), mins)
+                                                                    match s.distance {
+                                                                        Some(dist) if dist > 0.0 => format!("{:.1}{} · {}min", units.display_distance(dist), units.distance_label(), mins),
+                                                                        // Time-only cardio
+                                                                        _ => format!("{}min", mins),
+                                                                    }
                                                                 },
                                                                 ExerciseTrackingType::Duration => {
                                                                     let secs = s.duration_secs.unwrap_or(0);
                                                                     format!("{}s", secs)
                                                                 },
                                                                 ExerciseTrackingType::Bodyweight => {
-                                                                    format!("{} reps", s.reps)
+                                                                    let reps = match s.cluster_reps() {
+                                                                        Some(parts) => format!(
+                                                                            "{} reps ({})",
+                                                                            parts.iter().sum::<u32>(),
+                                                                            parts.iter().map(|p| p.to_string()).collect::<Vec<_>>().join("+")
+                                                                        ),
+                                                                        None => format!("{} reps", s.reps),
+                                                                    };
+                                                                    if s.weight > 0.0 {
+                                                                        format!("+{}{} x {}", units.format_weight(s.weight, rounding), units.weight_label(), reps)
+                                                                    } else {
+                                                                        reps
+                                                                    }
                                                                 },
                                                             };
                                                             html! {
@@ -461,6 +643,12 @@ pub fn history_list(props: &Props) -> Html {
                                                                     <span class="w-10 font-medium">{"Set "}{i+1}{":"}</span>
                                                                     <span class="font-bold text-gray-800 dark:text-gray-200">{detail}</span>
                                                                     { if s.completed { html!{<span class="text-green-600 dark:text-green-400 text-sm font-bold">{" \u{2713}"}</span>} } else { html!{} } }
+                                                                    { if let Some(kind) = s.failure {
+                                                                        html!{<span class="px-1.5 rounded bg-orange-500/20 text-orange-600 dark:text-orange-400 font-bold" title={kind.display_name().to_string()}>{kind.short_label()}</span>}
+                                                                    } else { html!{} } }
+                                                                    { if let Some(rest) = s.rest_taken_secs {
+                                                                        html!{<span class="text-gray-400 dark:text-gray-500">{format!("rest {}:{:02}", rest / 60, rest % 60)}</span>}
+                                                                    } else { html!{} } }
                                                                 </div>
                                                             }
                                                         })}
@@ -1,14 +1,17 @@
 pub mod achievements;
 pub mod ai_chat;
+pub mod annotation_editor;
 pub mod charts;
 pub mod custom_exercise;
 pub mod exercise_detail;
 pub mod exercise_list;
 pub mod history;
 pub mod nav;
+pub mod print_plan;
 pub mod routine_editor;
 pub mod settings;
 pub mod share_modal;
+pub mod streak_calendar;
 pub mod sync;
 pub mod toast;
 pub mod workout_log;
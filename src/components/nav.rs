@@ -1,3 +1,5 @@
+use crate::models::current_streak;
+use crate::storage;
 use crate::Route;
 use yew::prelude::*;
 use yew_router::prelude::*;
@@ -6,6 +8,13 @@ use yew_router::prelude::*;
 pub fn bottom_nav() -> Html {
     let route: Route = use_route().unwrap_or(Route::Home);
     let navigator = use_navigator().unwrap();
+    // Recomputed on navigation, which also happens right after a workout is saved
+    let stats = use_memo(route.clone(), |_| {
+        let workouts = storage::load_workouts();
+        let today = crate::dates::today_str();
+        (current_streak(&workouts), workouts.iter().any(|w| w.date == today))
+    });
+    let (streak, trained_today) = *stats;
 
     let nav_item = |r: Route, label: &str, icon: &str| {
         let active = route == r;
@@ -28,6 +37,17 @@ pub fn bottom_nav() -> Html {
 
     html! {
         <nav class="fixed bottom-0 left-0 right-0 bg-gray-100 dark:bg-gray-800 neu-nav px-1 py-2 flex justify-around items-center z-50 safe-bottom transition-colors duration-200">
+            <div
+                class="flex flex-col items-center text-xs"
+                title={if trained_today { "Trained today" } else { "Not trained yet today" }}
+            >
+                <span class={classes!("text-sm", "font-bold", if streak > 0 { "text-orange-500" } else { "text-gray-400 dark:text-gray-500" })}>
+                    {format!("\u{1f525}{}", streak)}
+                </span>
+                <span class={classes!("mt-0.5", if trained_today { "text-green-600 dark:text-green-400" } else { "text-gray-500 dark:text-gray-400" })}>
+                    { if trained_today { "\u{2713} Today" } else { "Today" } }
+                </span>
+            </div>
             {nav_item(Route::Home, "Home", "🏠")}
             {nav_item(Route::Exercises, "Exercises", "💪")}
             {nav_item(Route::Routines, "Routines", "📋")}
@@ -0,0 +1,108 @@
+use crate::models::{Exercise, ExerciseTrackingType, Routine};
+use crate::storage;
+use yew::prelude::*;
+
+/// Blank rows printed for an exercise with no stored targets.
+const BLANK_SETS: usize = 4;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub routine: Routine,
+    pub all_exercises: Vec<Exercise>,
+    pub on_close: Callback<()>,
+}
+
+/// Paper copy of a routine: each exercise with empty boxes to fill in by hand.
+/// Only the `.print-area` is printed; the toolbar is hidden via `.no-print`.
+#[function_component(PrintPlan)]
+pub fn print_plan(props: &Props) -> Html {
+    let config = storage::load_user_config();
+    let units = config.unit_system;
+    let rounding = config.weight_rounding;
+
+    let on_print = Callback::from(|_: MouseEvent| {
+        if let Some(window) = web_sys::window() {
+            let _ = window.print();
+        }
+    });
+    let on_close = {
+        let cb = props.on_close.clone();
+        Callback::from(move |_: MouseEvent| cb.emit(()))
+    };
+
+    let box_cell = "border border-gray-400 h-8";
+
+    html! {
+        <div class="print-area fixed inset-0 z-50 overflow-y-auto bg-white text-gray-900">
+            <div class="no-print sticky top-0 bg-white border-b border-gray-200 px-4 py-3 flex justify-between items-center">
+                <button class="text-blue-600 font-medium hover:underline" onclick={on_close}>{"\u{2190} Back"}</button>
+                <button
+                    class="px-4 py-2 bg-blue-600 text-white rounded-lg text-sm font-bold hover:bg-blue-700"
+                    onclick={on_print}
+                >{"\u{1f5a8} Print"}</button>
+            </div>
+            <div class="px-6 py-4 space-y-5">
+                <div class="flex justify-between items-end border-b-2 border-gray-900 pb-2">
+                    <h1 class="text-2xl font-bold">{&props.routine.name}</h1>
+                    <span class="text-sm">{"Date: ______________"}</span>
+                </div>
+                { for props.routine.exercise_ids.iter().map(|eid| {
+                    let exercise = props.all_exercises.iter().find(|e| e.id == *eid);
+                    let name = exercise.map(|e| e.name.clone()).unwrap_or_else(|| eid.clone());
+                    let tracking = exercise.map(|e| e.tracking_type.clone()).unwrap_or_default();
+                    let targets = props.routine.targets.get(eid).cloned().unwrap_or_default();
+                    let rows = if targets.is_empty() { BLANK_SETS } else { targets.len() };
+                    let columns: &[&str] = match tracking {
+                        ExerciseTrackingType::Strength => &["Weight", "Reps"],
+                        ExerciseTrackingType::Cardio if exercise.is_some_and(|e| e.time_only) => &["Time"],
+                        ExerciseTrackingType::Cardio => &["Distance", "Time"],
+                        ExerciseTrackingType::Duration => &["Time"],
+                        ExerciseTrackingType::Bodyweight => &["Reps"],
+                    };
+                    html! {
+                        <div class="break-inside-avoid">
+                            <h2 class="font-bold text-lg">{name}</h2>
+                            <table class="w-full border-collapse text-sm mt-1">
+                                <thead>
+                                    <tr>
+                                        <th class="border border-gray-400 w-12 py-1">{"Set"}</th>
+                                        if !targets.is_empty() {
+                                            <th class="border border-gray-400 py-1">{"Target"}</th>
+                                        }
+                                        { for columns.iter().map(|c| html! { <th class="border border-gray-400 py-1">{*c}</th> }) }
+                                        <th class="border border-gray-400 w-12 py-1">{"\u{2713}"}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    { for (0..rows).map(|i| {
+                                        let target = targets.get(i).map(|t| {
+                                            if t.weight > 0.0 {
+                                                format!("{} {} \u{d7} {}", units.format_weight(t.weight, rounding), units.weight_label(), t.reps)
+                                            } else {
+                                                format!("{} reps", t.reps)
+                                            }
+                                        });
+                                        html! {
+                                            <tr>
+                                                <td class="border border-gray-400 text-center">{(i + 1).to_string()}</td>
+                                                if let Some(target) = target {
+                                                    <td class="border border-gray-400 text-center text-gray-600">{target}</td>
+                                                }
+                                                { for columns.iter().map(|_| html! { <td class={box_cell}></td> }) }
+                                                <td class={box_cell}></td>
+                                            </tr>
+                                        }
+                                    })}
+                                </tbody>
+                            </table>
+                        </div>
+                    }
+                })}
+                <div>
+                    <h2 class="font-bold">{"Notes"}</h2>
+                    <div class="border border-gray-400 h-24 mt-1"></div>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -1,6 +1,8 @@
+use crate::components::print_plan::PrintPlan;
 use crate::components::share_modal::ShareModal;
-use crate::models::{Exercise, Routine};
+use crate::models::{Exercise, Routine, WEEKDAY_KEYS};
 use crate::sharing::{self, ShareableData};
+use crate::storage;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
@@ -10,13 +12,18 @@ pub struct Props {
     pub on_save: Callback<Routine>,
     pub on_delete: Callback<String>,
     pub on_start_workout: Callback<Routine>,
+    pub on_reorder: Callback<Vec<Routine>>,
 }
 
 #[function_component(RoutineEditor)]
 pub fn routine_editor(props: &Props) -> Html {
     let editing = use_state(|| None::<Routine>);
     let show_exercise_picker = use_state(|| false);
+    // Position in the routine being swapped out via the picker, if any
+    let replace_target = use_state(|| None::<usize>);
     let share_target = use_state(|| None::<(ShareableData, String)>);
+    let print_target = use_state(|| None::<Routine>);
+    let schedule = use_state(|| storage::load_user_config().routine_schedule);
 
     let find_exercise = |id: &str| -> String {
         props
@@ -34,10 +41,13 @@ pub fn routine_editor(props: &Props) -> Html {
                 id: uuid::Uuid::new_v4().to_string(),
                 name: String::new(),
                 exercise_ids: Vec::new(),
+                targets: Default::default(),
             }));
         })
     };
 
+    let routine_count = props.routines.len();
+
     let edit_existing = |r: Routine| {
         let editing = editing.clone();
         Callback::from(move |_| {
@@ -81,42 +91,87 @@ pub fn routine_editor(props: &Props) -> Html {
                                 let name = find_exercise(eid);
                                 let editing = editing3.clone();
                                 let routine = routine.clone();
+                                let target_count = routine.targets.get(eid.as_str()).map_or(0, |t| t.len());
+                                let is_replacing = *replace_target == Some(i);
+                                let on_replace = {
+                                    let replace_target = replace_target.clone();
+                                    let show_picker = show_picker.clone();
+                                    Callback::from(move |_| {
+                                        show_picker.set(false);
+                                        replace_target.set(if is_replacing { None } else { Some(i) });
+                                    })
+                                };
+                                let replace_target = replace_target.clone();
                                 html! {
-                                    <div class="flex justify-between items-center bg-white dark:bg-gray-700 rounded-lg px-3 py-2 neu-pressed transition-colors">
-                                        <span class="text-sm text-gray-800 dark:text-gray-100">{name}</span>
-                                        <button
-                                            class="text-red-600 dark:text-red-400 text-sm hover:text-red-500 dark:hover:text-red-300"
-                                            onclick={Callback::from(move |_| {
-                                                let mut r = routine.clone();
-                                                r.exercise_ids.remove(i);
-                                                editing.set(Some(r));
-                                            })}
-                                        >{"\u{2715}"}</button>
+                                    <div class={classes!(
+                                        "flex", "justify-between", "items-center", "bg-white", "dark:bg-gray-700", "rounded-lg", "px-3", "py-2", "neu-pressed", "transition-colors",
+                                        is_replacing.then_some("ring-2 ring-blue-500")
+                                    )}>
+                                        <span class="text-sm text-gray-800 dark:text-gray-100">
+                                            {name}
+                                            if target_count > 0 {
+                                                <span class="ml-2 text-xs text-gray-500 dark:text-gray-400">{format!("{} target sets", target_count)}</span>
+                                            }
+                                        </span>
+                                        <div class="flex items-center gap-3">
+                                            <button
+                                                class="text-blue-600 dark:text-blue-400 text-sm hover:text-blue-500 dark:hover:text-blue-300"
+                                                title="Replace exercise"
+                                                onclick={on_replace}
+                                            >{"\u{21c4}"}</button>
+                                            <button
+                                                class="text-red-600 dark:text-red-400 text-sm hover:text-red-500 dark:hover:text-red-300"
+                                                onclick={Callback::from(move |_| {
+                                                    let mut r = routine.clone();
+                                                    r.exercise_ids.remove(i);
+                                                    editing.set(Some(r));
+                                                    replace_target.set(None);
+                                                })}
+                                            >{"\u{2715}"}</button>
+                                        </div>
                                     </div>
                                 }
                             })}
                         </div>
 
-                        { if *show_exercise_picker {
+                        { if *show_exercise_picker || replace_target.is_some() {
                             let exercises = props.all_exercises.clone();
                             let show_picker2 = show_picker.clone();
                             let editing5 = editing4.clone();
                             let routine2 = routine.clone();
+                            let replacing = (*replace_target).filter(|i| *i < routine.exercise_ids.len());
                             html! {
                                 <div class="bg-white dark:bg-gray-700 rounded-xl p-3 mb-3 max-h-60 overflow-y-auto neu-pressed transition-colors">
+                                    { if let Some(i) = replacing {
+                                        let replace_target = replace_target.clone();
+                                        html! {
+                                            <div class="flex justify-between items-center px-2 pb-2 mb-1 border-b border-gray-200 dark:border-gray-600 text-xs text-gray-500 dark:text-gray-400">
+                                                <span>{format!("Replace {} with:", find_exercise(&routine.exercise_ids[i]))}</span>
+                                                <button
+                                                    class="font-medium hover:underline"
+                                                    onclick={Callback::from(move |_| replace_target.set(None))}
+                                                >{"Cancel"}</button>
+                                            </div>
+                                        }
+                                    } else { html! {} }}
                                     { for exercises.iter().map(|ex| {
                                         let eid = ex.id.clone();
                                         let editing = editing5.clone();
                                         let routine = routine2.clone();
                                         let show_picker = show_picker2.clone();
+                                        let replace_target = replace_target.clone();
                                         html! {
                                             <button
                                                 class="block w-full text-left px-2 py-1.5 hover:bg-gray-100 dark:hover:bg-gray-600 rounded text-sm text-gray-800 dark:text-gray-100 transition-colors"
                                                 onclick={Callback::from(move |_| {
                                                     let mut r = routine.clone();
-                                                    r.exercise_ids.push(eid.clone());
+                                                    match replacing {
+                                                        Some(i) => r.exercise_ids[i] = eid.clone(),
+                                                        None => r.exercise_ids.push(eid.clone()),
+                                                    }
                                                     editing.set(Some(r));
                                                     show_picker.set(false);
+                                                    replace_target.set(None);
                                                 })}
                                             >
                                                 {&ex.name}
@@ -141,9 +196,15 @@ pub fn routine_editor(props: &Props) -> Html {
                                 onclick={{
                                     let routine = routine.clone();
                                     let editing = editing.clone();
+                                    let replace_target = replace_target.clone();
                                     Callback::from(move |_| {
                                         if !routine.name.is_empty() {
-                                            on_save.emit(routine.clone());
+                                            // Drop targets for exercises no longer in the routine
+                                            let mut routine = routine.clone();
+                                            let ids = routine.exercise_ids.clone();
+                                            routine.targets.retain(|id, _| ids.contains(id));
+                                            on_save.emit(routine);
+                                            replace_target.set(None);
                                             editing.set(None);
                                         }
                                     })
@@ -151,7 +212,14 @@ pub fn routine_editor(props: &Props) -> Html {
                             >{"Save"}</button>
                             <button
                                 class="flex-1 py-2 bg-gray-200 dark:bg-gray-700 text-gray-800 dark:text-gray-200 rounded-lg font-medium hover:bg-gray-300 dark:hover:bg-gray-600 neu-btn transition-colors"
-                                onclick={let e = editing.clone(); Callback::from(move |_| e.set(None))}
+                                onclick={
+                                    let e = editing.clone();
+                                    let replace_target = replace_target.clone();
+                                    Callback::from(move |_| {
+                                        replace_target.set(None);
+                                        e.set(None);
+                                    })
+                                }
                             >{"Cancel"}</button>
                         </div>
                     </div>
@@ -163,7 +231,7 @@ pub fn routine_editor(props: &Props) -> Html {
                             class="w-full py-3 bg-blue-600 rounded-lg font-medium hover:bg-blue-700"
                             onclick={start_new}
                         >{"+ New Routine"}</button>
-                        { for props.routines.iter().map(|r| {
+                        { for props.routines.iter().enumerate().map(|(idx, r)| {
                             let r2 = r.clone();
                             let r3 = r.clone();
                             let on_delete = props.on_delete.clone();
@@ -173,7 +241,40 @@ pub fn routine_editor(props: &Props) -> Html {
                                 <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
                                     <div class="flex justify-between items-start mb-2">
                                         <h3 class="font-semibold">{&r.name}</h3>
-                                        <span class="text-sm text-gray-400">{r.exercise_ids.len()}{" exercises"}</span>
+                                        <div class="flex items-center gap-2">
+                                            <span class="text-sm text-gray-400">{r.exercise_ids.len()}{" exercises"}</span>
+                                            // Reorder buttons
+                                            { if idx > 0 {
+                                                let routines = props.routines.clone();
+                                                let on_reorder = props.on_reorder.clone();
+                                                html! {
+                                                    <button
+                                                        class="text-gray-400 hover:text-gray-200 text-sm transition-colors"
+                                                        title="Move routine up"
+                                                        onclick={Callback::from(move |_| {
+                                                            let mut rs = routines.clone();
+                                                            rs.swap(idx, idx - 1);
+                                                            on_reorder.emit(rs);
+                                                        })}
+                                                    >{"\u{2191}"}</button>
+                                                }
+                                            } else { html! {} }}
+                                            { if idx + 1 < routine_count {
+                                                let routines = props.routines.clone();
+                                                let on_reorder = props.on_reorder.clone();
+                                                html! {
+                                                    <button
+                                                        class="text-gray-400 hover:text-gray-200 text-sm transition-colors"
+                                                        title="Move routine down"
+                                                        onclick={Callback::from(move |_| {
+                                                            let mut rs = routines.clone();
+                                                            rs.swap(idx, idx + 1);
+                                                            on_reorder.emit(rs);
+                                                        })}
+                                                    >{"\u{2193}"}</button>
+                                                }
+                                            } else { html! {} }}
+                                        </div>
                                     </div>
                                     <div class="text-sm text-gray-400 mb-3">
                                         { for r.exercise_ids.iter().map(|eid| {
@@ -181,6 +282,34 @@ pub fn routine_editor(props: &Props) -> Html {
                                             html! { <span class="mr-2">{name}{","}</span> }
                                         })}
                                     </div>
+                                    // Weekday schedule: one routine per day, tapping a day assigns or clears it
+                                    <div class="flex gap-1 mb-3">
+                                        { for WEEKDAY_KEYS.into_iter().zip(["M", "T", "W", "T", "F", "S", "S"]).map(|(day, letter)| {
+                                            let assigned = schedule.get(day) == Some(&r.id);
+                                            let schedule = schedule.clone();
+                                            let rid = r.id.clone();
+                                            html! {
+                                                <button
+                                                    class={if assigned {
+                                                        "w-7 h-7 rounded-full text-xs font-bold bg-blue-600 text-white neu-chip-active transition-colors"
+                                                    } else {
+                                                        "w-7 h-7 rounded-full text-xs bg-gray-200 dark:bg-gray-700 text-gray-500 dark:text-gray-400 neu-chip hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+                                                    }}
+                                                    title={format!("Schedule on {}", day)}
+                                                    onclick={Callback::from(move |_| {
+                                                        let mut config = storage::load_user_config();
+                                                        if assigned {
+                                                            config.routine_schedule.remove(day);
+                                                        } else {
+                                                            config.routine_schedule.insert(day.to_string(), rid.clone());
+                                                        }
+                                                        storage::save_user_config(&config);
+                                                        schedule.set(config.routine_schedule);
+                                                    })}
+                                                >{letter}</button>
+                                            }
+                                        })}
+                                    </div>
                                     <div class="flex gap-2">
                                         <button
                                             class="flex-1 py-2 bg-green-700 rounded text-sm font-medium hover:bg-green-600"
@@ -190,6 +319,15 @@ pub fn routine_editor(props: &Props) -> Html {
                                             class="px-3 py-2 bg-gray-700 rounded text-sm hover:bg-gray-600"
                                             onclick={edit_existing(r3.clone())}
                                         >{"Edit"}</button>
+                                        <button
+                                            class="px-3 py-2 bg-gray-700 rounded text-sm hover:bg-gray-600"
+                                            onclick={{
+                                                let mut copy = r.clone();
+                                                copy.id = uuid::Uuid::new_v4().to_string();
+                                                copy.name = format!("{} (copy)", r.name);
+                                                edit_existing(copy)
+                                            }}
+                                        >{"Duplicate"}</button>
                                         <button
                                             class="px-3 py-2 bg-gray-700 rounded text-sm hover:bg-gray-600"
                                             onclick={{
@@ -204,6 +342,11 @@ pub fn routine_editor(props: &Props) -> Html {
                                                 })
                                             }}
                                         >{"Share"}</button>
+                                        <button
+                                            class="px-3 py-2 bg-gray-700 rounded text-sm hover:bg-gray-600"
+                                            title="Printable plan with blank boxes to fill in"
+                                            onclick={let p = print_target.clone(); let r = r.clone(); Callback::from(move |_| p.set(Some(r.clone())))}
+                                        >{"Print"}</button>
                                         <button
                                             class="px-3 py-2 bg-red-900 rounded text-sm hover:bg-red-800"
                                             onclick={Callback::from(move |_| on_delete.emit(rid.clone()))}
@@ -224,6 +367,13 @@ pub fn routine_editor(props: &Props) -> Html {
                     </>
                 }
             }}
+            if let Some(routine) = &*print_target {
+                <PrintPlan
+                    routine={routine.clone()}
+                    all_exercises={props.all_exercises.clone()}
+                    on_close={let p = print_target.clone(); Callback::from(move |_| p.set(None))}
+                />
+            }
             { if let Some((ref data, ref text)) = *share_target {
                 let share_target = share_target.clone();
                 html! {
@@ -1,3 +1,4 @@
+use crate::i18n::t;
 use crate::storage;
 use wasm_bindgen::closure::Closure;
 use wasm_bindgen::JsCast;
@@ -33,6 +34,16 @@ pub fn settings_panel(props: &Props) -> Html {
         })
     };
 
+    let on_toggle_auto_speak = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            new_config.ai_auto_speak = !new_config.ai_auto_speak;
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
     let on_change_ai_model = {
         let config = config.clone();
         Callback::from(move |e: Event| {
@@ -44,6 +55,193 @@ pub fn settings_panel(props: &Props) -> Html {
         })
     };
 
+    let on_change_ai_persona = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.ai_persona = crate::models::AiPersona::from_str(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_ai_max_threads = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.ai_max_threads = input.value().parse().unwrap_or(20);
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_ai_thread_age = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.ai_thread_max_age_days = input.value().parse().unwrap_or(0);
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_custom_persona = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlTextAreaElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.ai_custom_persona = input.value().trim().to_string();
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_rounding = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.weight_rounding = crate::models::WeightRounding::from_str(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_volume_metric = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.volume_metric = crate::models::VolumeMetric::from_str(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_prefill_mode = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.prefill_mode = crate::models::PrefillMode::from_str(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_quick_add = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.quick_add = crate::models::QuickAddSource::from_str(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_experience = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.experience_level = crate::models::Difficulty::from_key(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_toggle_auto_complete = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            new_config.auto_complete_sets = !new_config.auto_complete_sets;
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_toggle_discard_incomplete = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            new_config.discard_incomplete_sets = !new_config.discard_incomplete_sets;
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let vibration_supported = crate::pages::workout::vibration_supported();
+    let on_toggle_haptics = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            new_config.haptic_feedback = !new_config.haptic_feedback;
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_toggle_mixed_plates = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            new_config.mixed_plates = !new_config.mixed_plates;
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let reduce_motion = storage::reduce_motion(&config);
+    let on_toggle_reduce_motion = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            // Once toggled, the explicit choice overrides the OS preference
+            new_config.reduce_motion = Some(!reduce_motion);
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_language = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.language = crate::models::Language::from_str(&input.value());
+            storage::save_user_config(&new_config);
+            crate::i18n::set_language(new_config.language);
+            // Translated strings are read at render time, so reload to redraw every page
+            let _ = gloo::utils::window().location().reload();
+        })
+    };
+
+    let on_change_tracking_type = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.default_tracking_type = crate::models::ExerciseTrackingType::from_key(&input.value());
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_change_rest_days = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.rest_days_per_week = input.value().parse().unwrap_or(3);
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
     let on_change_theme = {
         let config = config.clone();
         Callback::from(move |e: Event| {
@@ -92,89 +290,797 @@ pub fn settings_panel(props: &Props) -> Html {
             })
             .forget();
 
-            config.set(new_config);
-        })
-    };
+            config.set(new_config);
+        })
+    };
+
+    let on_change_body_weight = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.body_weight = input
+                .value()
+                .trim()
+                .parse::<f64>()
+                .ok()
+                .filter(|w| *w > 0.0)
+                .map(|w| new_config.body_units().to_kg(w));
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let make_on_category_met = |category: String| {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            match input.value().trim().parse::<f64>() {
+                Ok(met) if met > 0.0 => {
+                    new_config.category_mets.insert(category.clone(), met);
+                }
+                _ => {
+                    new_config.category_mets.remove(&category);
+                }
+            }
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_toggle_min_rest_warning = {
+        let config = config.clone();
+        Callback::from(move |_| {
+            let mut new_config = (*config).clone();
+            new_config.min_rest_warning = !new_config.min_rest_warning;
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let make_on_category_min_rest = |category: String| {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            match input.value().trim().parse::<u32>() {
+                Ok(secs) => {
+                    new_config.min_rest_secs.insert(category.clone(), secs);
+                }
+                _ => {
+                    new_config.min_rest_secs.remove(&category);
+                }
+            }
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let make_on_category_color = |category: crate::models::Category| {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            let color = input.value();
+            if color.eq_ignore_ascii_case(category.default_color()) {
+                new_config.category_colors.remove(&category.to_string());
+            } else {
+                new_config.category_colors.insert(category.to_string(), color);
+            }
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let on_reset_category_colors = {
+        let config = config.clone();
+        Callback::from(move |_: MouseEvent| {
+            let mut new_config = (*config).clone();
+            new_config.category_colors.clear();
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let make_on_category_rest = |category: String| {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            match input.value().trim().parse::<u32>() {
+                Ok(secs) if secs > 0 => {
+                    new_config.category_rest_seconds.insert(category.clone(), secs);
+                }
+                _ => {
+                    new_config.category_rest_seconds.remove(&category);
+                }
+            }
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let last_backup = use_state(crate::backup::last_file_backup);
+
+    let on_export = {
+        let last_backup = last_backup.clone();
+        Callback::from(move |_| {
+            if crate::backup::download_backup_file().is_some() {
+                last_backup.set(crate::backup::last_file_backup());
+            }
+        })
+    };
+
+    let on_change_auto_backup = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            let mut new_config = (*config).clone();
+            new_config.auto_backup_days = input.value().parse().unwrap_or(0);
+            storage::save_user_config(&new_config);
+            config.set(new_config);
+        })
+    };
+
+    let last_backup_text = match *last_backup {
+        Some(ms) => {
+            let d = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms));
+            format!(
+                "Last backup file: {:04}-{:02}-{:02}",
+                d.get_full_year(),
+                d.get_month() + 1,
+                d.get_date()
+            )
+        }
+        None => "No backup file downloaded yet.".to_string(),
+    };
+
+    // Passphrase for encrypted export/import; never stored
+    let passphrase = use_state(String::new);
+    let export_status = use_state(|| None::<Result<String, String>>);
+
+    let on_export_encrypted = {
+        let passphrase = passphrase.clone();
+        let export_status = export_status.clone();
+        let last_backup = last_backup.clone();
+        Callback::from(move |_| {
+            if passphrase.is_empty() {
+                export_status.set(Some(Err("Enter a passphrase first.".to_string())));
+                return;
+            }
+            let pass = (*passphrase).clone();
+            let export_status = export_status.clone();
+            let last_backup = last_backup.clone();
+            wasm_bindgen_futures::spawn_local(async move {
+                match crate::backup::download_encrypted_backup_file(&pass).await {
+                    Ok(filename) => {
+                        last_backup.set(crate::backup::last_file_backup());
+                        export_status.set(Some(Ok(format!("Saved {}", filename))));
+                    }
+                    Err(err) => export_status.set(Some(Err(err))),
+                }
+            });
+        })
+    };
+
+    // Optional self-hosted endpoint that mirrors the automatic backup
+    let remote = use_state(crate::backup::remote_backup);
+    let remote_status = use_state(|| None::<Result<String, String>>);
+    let remote_busy = use_state(|| false);
+    // Restore replaces everything, so it takes a second tap
+    let remote_confirm = use_state(|| false);
+    let remote_undo = use_state(|| false);
+
+    let make_on_remote_input = |set_token: bool| {
+        let remote = remote.clone();
+        let remote_confirm = remote_confirm.clone();
+        Callback::from(move |e: InputEvent| {
+            let value = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+            let mut new_remote = (*remote).clone();
+            if set_token {
+                new_remote.token = value;
+            } else {
+                new_remote.url = value;
+            }
+            crate::backup::save_remote_backup(&new_remote);
+            remote.set(new_remote);
+            remote_confirm.set(false);
+        })
+    };
+    let on_remote_url = make_on_remote_input(false);
+    let on_remote_token = make_on_remote_input(true);
+
+    let on_remote_push = {
+        let remote = remote.clone();
+        let remote_status = remote_status.clone();
+        let remote_busy = remote_busy.clone();
+        Callback::from(move |_| {
+            if remote.url.trim().is_empty() {
+                remote_status.set(Some(Err("Enter a backup URL first.".to_string())));
+                return;
+            }
+            let remote = (*remote).clone();
+            let remote_status = remote_status.clone();
+            let remote_busy = remote_busy.clone();
+            remote_busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let data = storage::export_all_data();
+                match crate::backup::upload_remote_backup(&remote, &data).await {
+                    Ok(()) => remote_status.set(Some(Ok("Backup uploaded.".to_string()))),
+                    Err(err) => remote_status.set(Some(Err(format!("Upload failed: {}", err)))),
+                }
+                remote_busy.set(false);
+            });
+        })
+    };
+
+    let on_remote_restore = {
+        let remote = remote.clone();
+        let remote_status = remote_status.clone();
+        let remote_busy = remote_busy.clone();
+        let remote_confirm = remote_confirm.clone();
+        let remote_undo = remote_undo.clone();
+        let passphrase = passphrase.clone();
+        let config = config.clone();
+        let on_import_complete = props.on_import_complete.clone();
+        Callback::from(move |_| {
+            if remote.url.trim().is_empty() {
+                remote_status.set(Some(Err("Enter a backup URL first.".to_string())));
+                return;
+            }
+            if !*remote_confirm {
+                remote_confirm.set(true);
+                return;
+            }
+            remote_confirm.set(false);
+            let remote = (*remote).clone();
+            let pass = (*passphrase).clone();
+            let remote_status = remote_status.clone();
+            let remote_busy = remote_busy.clone();
+            let remote_undo = remote_undo.clone();
+            let config = config.clone();
+            let on_done = on_import_complete.clone();
+            remote_busy.set(true);
+            wasm_bindgen_futures::spawn_local(async move {
+                let result = match crate::backup::fetch_remote_backup(&remote).await {
+                    Ok(text) if crate::backup::is_encrypted(&text) => {
+                        if pass.is_empty() {
+                            Err("This backup is encrypted. Enter its passphrase above and try again.".to_string())
+                        } else {
+                            crate::backup::decrypt(&text, &pass).await
+                        }
+                    }
+                    other => other,
+                };
+                // Snapshot first so a wrong URL or stale file can be undone
+                let result = result.and_then(|text| {
+                    crate::backup::save_snapshot(&storage::export_all_data());
+                    storage::import_all_data(&text)
+                });
+                match result {
+                    Ok(()) => {
+                        remote_status.set(Some(Ok("Data restored from URL.".to_string())));
+                        remote_undo.set(true);
+                        config.set(storage::load_user_config());
+                        on_done.emit(());
+                    }
+                    Err(err) => remote_status.set(Some(Err(format!("Restore failed: {}", err)))),
+                }
+                remote_busy.set(false);
+            });
+        })
+    };
+
+    let on_remote_undo = {
+        let remote_status = remote_status.clone();
+        let remote_undo = remote_undo.clone();
+        Callback::from(move |_| {
+            let remote_status = remote_status.clone();
+            let remote_undo = remote_undo.clone();
+            crate::backup::load_snapshot(move |data| {
+                match data.map(|d| storage::import_all_data(&d)) {
+                    Some(Ok(())) => {
+                        let _ = gloo::utils::window().location().reload();
+                    }
+                    _ => {
+                        remote_status.set(Some(Err("Couldn't restore the snapshot.".to_string())));
+                        remote_undo.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    let last_remote_text = match crate::backup::last_remote_backup() {
+        Some(ms) => {
+            let d = js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms));
+            format!(
+                "Last upload: {:04}-{:02}-{:02} {:02}:{:02}",
+                d.get_full_year(),
+                d.get_month() + 1,
+                d.get_date(),
+                d.get_hours(),
+                d.get_minutes()
+            )
+        }
+        None => "Nothing uploaded yet.".to_string(),
+    };
+
+    let make_on_import = |merge: bool| {
+        let import_status = import_status.clone();
+        let on_import_complete = props.on_import_complete.clone();
+        let config = config.clone();
+        let passphrase = passphrase.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            if let Some(files) = input.files() {
+                if let Some(file) = files.get(0) {
+                    let reader = web_sys::FileReader::new().unwrap();
+                    let reader_clone = reader.clone();
+                    let status = import_status.clone();
+                    let on_done = on_import_complete.clone();
+                    let config = config.clone();
+                    let pass = (*passphrase).clone();
+
+                    let closure = Closure::wrap(Box::new(move || {
+                        let Some(text) = reader_clone.result().ok().and_then(|r| r.as_string()) else {
+                            return;
+                        };
+                        let apply = {
+                            let status = status.clone();
+                            let config = config.clone();
+                            let on_done = on_done.clone();
+                            move |text: String| {
+                                let result = if merge {
+                                    storage::merge_all_data(&text).map(|summary| summary.to_string())
+                                } else {
+                                    storage::import_all_data(&text)
+                                        .map(|()| "Data imported successfully!".to_string())
+                                };
+                                match result {
+                                    Ok(msg) => {
+                                        status.set(Some(msg));
+                                        config.set(storage::load_user_config());
+                                        on_done.emit(());
+                                    }
+                                    Err(err) => {
+                                        status.set(Some(format!("Import error: {}", err)));
+                                    }
+                                }
+                            }
+                        };
+                        if !crate::backup::is_encrypted(&text) {
+                            apply(text);
+                            return;
+                        }
+                        if pass.is_empty() {
+                            status.set(Some("Import error: this backup is encrypted. Enter its passphrase and pick the file again.".to_string()));
+                            return;
+                        }
+                        let status = status.clone();
+                        let pass = pass.clone();
+                        wasm_bindgen_futures::spawn_local(async move {
+                            match crate::backup::decrypt(&text, &pass).await {
+                                Ok(plain) => apply(plain),
+                                Err(err) => status.set(Some(format!("Import error: {}", err))),
+                            }
+                        });
+                    }) as Box<dyn Fn()>);
+
+                    reader.set_onload(Some(closure.as_ref().unchecked_ref()));
+                    closure.forget();
+                    let _ = reader.read_as_text(&file);
+                }
+            }
+            // Allow picking the same file again
+            input.set_value("");
+        })
+    };
+    let on_import_merge = make_on_import(true);
+    let on_import_replace = make_on_import(false);
+
+    // Bulk delete by date range (inclusive)
+    let bulk_from = use_state(String::new);
+    let bulk_to = use_state(String::new);
+    let bulk_confirm = use_state(|| false);
+    let bulk_status = use_state(|| None::<String>);
+    let bulk_undo = use_state(|| false);
+    let in_bulk_range = {
+        let (from, to) = ((*bulk_from).clone(), (*bulk_to).clone());
+        move |date: &str| !from.is_empty() && !to.is_empty() && date >= from.as_str() && date <= to.as_str()
+    };
+    let bulk_count = storage::load_workouts()
+        .iter()
+        .filter(|w| in_bulk_range(w.date.as_str()))
+        .count();
+
+    let make_on_bulk_date = |state: UseStateHandle<String>| {
+        let bulk_confirm = bulk_confirm.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            state.set(input.value());
+            bulk_confirm.set(false);
+        })
+    };
+    let on_bulk_from = make_on_bulk_date(bulk_from.clone());
+    let on_bulk_to = make_on_bulk_date(bulk_to.clone());
+
+    let on_bulk_delete = {
+        let bulk_confirm = bulk_confirm.clone();
+        let bulk_status = bulk_status.clone();
+        let bulk_undo = bulk_undo.clone();
+        let on_done = props.on_import_complete.clone();
+        Callback::from(move |_| {
+            if !*bulk_confirm {
+                bulk_confirm.set(true);
+                return;
+            }
+            // Snapshot first so the delete can be undone even after the
+            // automatic backup has caught up with it
+            crate::backup::save_snapshot(&storage::export_all_data());
+            let mut workouts = storage::load_workouts();
+            let before = workouts.len();
+            workouts.retain(|w| !in_bulk_range(w.date.as_str()));
+            storage::save_workouts(&workouts);
+            bulk_confirm.set(false);
+            bulk_status.set(Some(format!("Deleted {} workouts.", before - workouts.len())));
+            bulk_undo.set(true);
+            on_done.emit(());
+        })
+    };
+
+    let on_bulk_undo = {
+        let bulk_status = bulk_status.clone();
+        let bulk_undo = bulk_undo.clone();
+        Callback::from(move |_| {
+            let bulk_status = bulk_status.clone();
+            let bulk_undo = bulk_undo.clone();
+            crate::backup::load_snapshot(move |data| {
+                match data.map(|d| storage::import_all_data(&d)) {
+                    Some(Ok(())) => {
+                        let _ = gloo::utils::window().location().reload();
+                    }
+                    _ => {
+                        bulk_status.set(Some("Couldn't restore the snapshot.".to_string()));
+                        bulk_undo.set(false);
+                    }
+                }
+            });
+        })
+    };
+
+    html! {
+        <div class="space-y-4 transition-colors duration-200">
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Features"}</h3>
+                <div class="flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Social Features"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Enable friend syncing and leaderboards"}</div>
+                    </div>
+                    <button
+                        onclick={on_toggle_social}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                            if config.social_enabled { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if config.social_enabled { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Coach T"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Your personal workout coach, powered by local AI"}</div>
+                    </div>
+                    <button
+                        onclick={on_toggle_ai}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                            if config.ai_enabled { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if config.ai_enabled { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
+
+                if config.ai_enabled {
+                    <div class="pt-3 mt-3 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                        <div>
+                            <div class="font-medium text-gray-800 dark:text-gray-200">{"AI Model"}</div>
+                            <div class="text-sm text-gray-500 dark:text-gray-400">{"Larger = better quality, bigger download"}</div>
+                        </div>
+                        <select
+                            onchange={on_change_ai_model}
+                            class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                        >
+                            { for crate::models::AiModel::all().iter().map(|m| {
+                                html! {
+                                    <option value={m.to_key()} selected={config.ai_model == *m}>
+                                        {m.display_name()}
+                                    </option>
+                                }
+                            })}
+                        </select>
+                    </div>
+                    <div class="pt-3 mt-3 border-t border-gray-200 dark:border-gray-700/50">
+                        <div class="flex items-center justify-between">
+                            <div>
+                                <div class="font-medium text-gray-800 dark:text-gray-200">{"Coach Persona"}</div>
+                                <div class="text-sm text-gray-500 dark:text-gray-400">{"The tone Coach T answers in"}</div>
+                            </div>
+                            <select
+                                onchange={on_change_ai_persona}
+                                class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                            >
+                                { for crate::models::AiPersona::all().iter().map(|p| {
+                                    html! {
+                                        <option value={p.to_key()} selected={config.ai_persona == *p}>
+                                            {p.display_name()}
+                                        </option>
+                                    }
+                                })}
+                            </select>
+                        </div>
+                        if config.ai_persona == crate::models::AiPersona::Custom {
+                            <textarea
+                                rows="2"
+                                maxlength={crate::components::ai_chat::MAX_CUSTOM_PERSONA_CHARS.to_string()}
+                                placeholder="e.g. a retired powerlifter who loves dad jokes"
+                                class="w-full mt-2 px-3 py-2 bg-white dark:bg-gray-700 rounded-lg text-sm text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
+                                value={config.ai_custom_persona.clone()}
+                                onchange={on_change_custom_persona}
+                            ></textarea>
+                        }
+                    </div>
+                    <div class="pt-3 mt-3 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                        <div>
+                            <div class="font-medium text-gray-800 dark:text-gray-200">{"Keep Chats"}</div>
+                            <div class="text-sm text-gray-500 dark:text-gray-400">{"Older chats are only removed when you start a new one, after asking"}</div>
+                        </div>
+                        <select
+                            onchange={on_change_ai_max_threads}
+                            class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                        >
+                            { for [5u32, 10, 20, 50, 100].iter().map(|n| html! {
+                                <option value={n.to_string()} selected={config.ai_max_threads == *n}>{format!("{} chats", n)}</option>
+                            })}
+                        </select>
+                    </div>
+                    <div class="pt-3 mt-3 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                        <div>
+                            <div class="font-medium text-gray-800 dark:text-gray-200">{"Auto-delete Chats"}</div>
+                            <div class="text-sm text-gray-500 dark:text-gray-400">{"Remove chats started longer ago than this"}</div>
+                        </div>
+                        <select
+                            onchange={on_change_ai_thread_age}
+                            class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                        >
+                            { for [(0u32, "Never"), (7, "After 1 week"), (30, "After 30 days"), (90, "After 90 days"), (365, "After 1 year")].iter().map(|(days, label)| html! {
+                                <option value={days.to_string()} selected={config.ai_thread_max_age_days == *days}>{*label}</option>
+                            })}
+                        </select>
+                    </div>
+                    <div class="pt-3 mt-3 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                        <div>
+                            <div class="font-medium text-gray-800 dark:text-gray-200">{"Auto-speak Replies"}</div>
+                            <div class="text-sm text-gray-500 dark:text-gray-400">{"Read each answer aloud for hands-free use"}</div>
+                        </div>
+                        <button
+                            onclick={on_toggle_auto_speak}
+                            class={classes!(
+                                "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                                if config.ai_auto_speak { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                            )}
+                        >
+                            <span
+                                class={classes!(
+                                    "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                    if config.ai_auto_speak { "translate-x-6" } else { "translate-x-1" }
+                                )}
+                            />
+                        </button>
+                    </div>
+                }
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"App Theme"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Choose your preferred appearance"}</div>
+                    </div>
+                    <select
+                        onchange={on_change_theme}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        <option value="dark" selected={config.theme == crate::models::Theme::Dark}>{"Dark"}</option>
+                        <option value="light" selected={config.theme == crate::models::Theme::Light}>{"Light"}</option>
+                        <option value="system" selected={config.theme == crate::models::Theme::System}>{"System"}</option>
+                        <option value="amoled" selected={config.theme == crate::models::Theme::AmoledBlack}>{"AMOLED Black"}</option>
+                        <option value="treen" selected={config.theme == crate::models::Theme::Treen}>{"Treen (Wood)"}</option>
+                    </select>
+                </div>
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Reduce Motion"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">
+                            { if config.reduce_motion.is_none() {
+                                "Skip PR flashes, page fades and typing dots (following system setting)"
+                            } else {
+                                "Skip PR flashes, page fades and typing dots"
+                            }}
+                        </div>
+                    </div>
+                    <button
+                        onclick={on_toggle_reduce_motion}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                            if reduce_motion { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if reduce_motion { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Weight Rounding"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Round displayed weights; stored values stay exact"}</div>
+                    </div>
+                    <select
+                        onchange={on_change_rounding}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for crate::models::WeightRounding::all().iter().map(|r| {
+                            html! {
+                                <option value={r.to_key()} selected={config.weight_rounding == *r}>
+                                    {r.display_name()}
+                                </option>
+                            }
+                        })}
+                    </select>
+                </div>
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Volume Metric"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"What \"volume\" means in Analytics charts and totals"}</div>
+                    </div>
+                    <select
+                        onchange={on_change_volume_metric}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for crate::models::VolumeMetric::all().iter().map(|m| {
+                            html! {
+                                <option value={m.to_key()} selected={config.volume_metric == *m}>
+                                    {m.display_name()}
+                                </option>
+                            }
+                        })}
+                    </select>
+                </div>
 
-    let on_export = Callback::from(|_| {
-        let data = storage::export_all_data();
-        let blob_parts = js_sys::Array::new();
-        blob_parts.push(&wasm_bindgen::JsValue::from_str(&data));
-        let opts = web_sys::BlobPropertyBag::new();
-        opts.set_type("application/json");
-        if let Ok(blob) = web_sys::Blob::new_with_str_sequence_and_options(&blob_parts, &opts) {
-            if let Ok(url) = web_sys::Url::create_object_url_with_blob(&blob) {
-                let document = gloo::utils::document();
-                if let Ok(elem) = document.create_element("a") {
-                    let anchor: web_sys::HtmlAnchorElement = elem.unchecked_into();
-                    anchor.set_href(&url);
-                    anchor.set_download("treening-backup.json");
-                    anchor.click();
-                    let _ = web_sys::Url::revoke_object_url(&url);
-                }
-            }
-        }
-    });
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Prefill Weights From"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{format!("Best recent looks at your last {} sessions, skipping past deloads", crate::models::PREFILL_RECENT_SESSIONS)}</div>
+                    </div>
+                    <select
+                        onchange={on_change_prefill_mode}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for crate::models::PrefillMode::all().iter().map(|m| {
+                            html! {
+                                <option value={m.to_key()} selected={config.prefill_mode == *m}>
+                                    {m.display_name()}
+                                </option>
+                            }
+                        })}
+                    </select>
+                </div>
 
-    let on_import = {
-        let import_status = import_status.clone();
-        let on_import_complete = props.on_import_complete.clone();
-        let config = config.clone();
-        Callback::from(move |e: Event| {
-            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
-            if let Some(files) = input.files() {
-                if let Some(file) = files.get(0) {
-                    let reader = web_sys::FileReader::new().unwrap();
-                    let reader_clone = reader.clone();
-                    let status = import_status.clone();
-                    let on_done = on_import_complete.clone();
-                    let config = config.clone();
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Quick Add"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"One-tap exercise buttons on the Workout page"}</div>
+                    </div>
+                    <select
+                        onchange={on_change_quick_add}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for crate::models::QuickAddSource::all().iter().map(|q| {
+                            html! {
+                                <option value={q.to_key()} selected={config.quick_add == *q}>
+                                    {q.display_name()}
+                                </option>
+                            }
+                        })}
+                    </select>
+                </div>
 
-                    let closure = Closure::wrap(Box::new(move || {
-                        if let Ok(result) = reader_clone.result() {
-                            if let Some(text) = result.as_string() {
-                                match storage::import_all_data(&text) {
-                                    Ok(()) => {
-                                        status.set(Some("Data imported successfully!".to_string()));
-                                        config.set(storage::load_user_config());
-                                        on_done.emit(());
-                                    }
-                                    Err(err) => {
-                                        status.set(Some(format!("Import error: {}", err)));
-                                    }
-                                }
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Experience Level"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Recommendations and Coach T skip exercises above this level"}</div>
+                    </div>
+                    <select
+                        onchange={on_change_experience}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for crate::models::Difficulty::all().iter().map(|d| {
+                            html! {
+                                <option value={d.to_key()} selected={config.experience_level == *d}>
+                                    {d.display_name()}
+                                </option>
                             }
-                        }
-                    }) as Box<dyn Fn()>);
+                        })}
+                    </select>
+                </div>
 
-                    reader.set_onload(Some(closure.as_ref().unchecked_ref()));
-                    closure.forget();
-                    let _ = reader.read_as_text(&file);
-                }
-            }
-        })
-    };
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Auto-complete Sets"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Tick a strength set as done and start rest once weight and reps are filled in"}</div>
+                    </div>
+                    <button
+                        onclick={on_toggle_auto_complete}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                            if config.auto_complete_sets { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if config.auto_complete_sets { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
 
-    html! {
-        <div class="space-y-4 transition-colors duration-200">
-            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
-                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Features"}</h3>
-                <div class="flex items-center justify-between">
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
                     <div>
-                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Social Features"}</div>
-                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Enable friend syncing and leaderboards"}</div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Haptic Feedback"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">
+                            { if !vibration_supported {
+                                "Not supported on this device"
+                            } else if reduce_motion {
+                                "Short buzz when a set is ticked done (paused while Reduce Motion is on)"
+                            } else {
+                                "Short buzz when a set is ticked done"
+                            }}
+                        </div>
                     </div>
                     <button
-                        onclick={on_toggle_social}
+                        onclick={on_toggle_haptics}
+                        disabled={!vibration_supported}
                         class={classes!(
                             "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
-                            if config.social_enabled { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                            if !vibration_supported { "opacity-50 cursor-not-allowed" } else { "" },
+                            if config.haptic_feedback { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
                         )}
                     >
                         <span
                             class={classes!(
                                 "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
-                                if config.social_enabled { "translate-x-6" } else { "translate-x-1" }
+                                if config.haptic_feedback { "translate-x-6" } else { "translate-x-1" }
                             )}
                         />
                     </button>
@@ -182,60 +1088,93 @@ pub fn settings_panel(props: &Props) -> Html {
 
                 <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
                     <div>
-                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Coach T"}</div>
-                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Your personal workout coach, powered by local AI"}</div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Discard Unchecked Sets"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"When finishing a workout, drop sets not marked done and exercises left empty"}</div>
                     </div>
                     <button
-                        onclick={on_toggle_ai}
+                        onclick={on_toggle_discard_incomplete}
                         class={classes!(
                             "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
-                            if config.ai_enabled { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                            if config.discard_incomplete_sets { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
                         )}
                     >
                         <span
                             class={classes!(
                                 "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
-                                if config.ai_enabled { "translate-x-6" } else { "translate-x-1" }
+                                if config.discard_incomplete_sets { "translate-x-6" } else { "translate-x-1" }
                             )}
                         />
                     </button>
                 </div>
 
-                if config.ai_enabled {
-                    <div class="pt-3 mt-3 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
-                        <div>
-                            <div class="font-medium text-gray-800 dark:text-gray-200">{"AI Model"}</div>
-                            <div class="text-sm text-gray-500 dark:text-gray-400">{"Larger = better quality, bigger download"}</div>
-                        </div>
-                        <select
-                            onchange={on_change_ai_model}
-                            class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
-                        >
-                            { for crate::models::AiModel::all().iter().map(|m| {
-                                html! {
-                                    <option value={m.to_key()} selected={config.ai_model == *m}>
-                                        {m.display_name()}
-                                    </option>
-                                }
-                            })}
-                        </select>
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Mixed Plates"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Also show the closest lb-plate loading for gyms with both"}</div>
                     </div>
-                }
+                    <button
+                        onclick={on_toggle_mixed_plates}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                            if config.mixed_plates { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if config.mixed_plates { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
 
                 <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
                     <div>
-                        <div class="font-medium text-gray-800 dark:text-gray-200">{"App Theme"}</div>
-                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Choose your preferred appearance"}</div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Rest Days per Week"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Planned days off; the Home calendar marks extra ones as missed"}</div>
                     </div>
                     <select
-                        onchange={on_change_theme}
+                        onchange={on_change_rest_days}
                         class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
                     >
-                        <option value="dark" selected={config.theme == crate::models::Theme::Dark}>{"Dark"}</option>
-                        <option value="light" selected={config.theme == crate::models::Theme::Light}>{"Light"}</option>
-                        <option value="system" selected={config.theme == crate::models::Theme::System}>{"System"}</option>
-                        <option value="amoled" selected={config.theme == crate::models::Theme::AmoledBlack}>{"AMOLED Black"}</option>
-                        <option value="treen" selected={config.theme == crate::models::Theme::Treen}>{"Treen (Wood)"}</option>
+                        { for (0..=6u32).map(|n| html! {
+                            <option value={n.to_string()} selected={config.rest_days_per_week == n}>{n}</option>
+                        })}
+                    </select>
+                </div>
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{"New Exercise Tracking"}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{"Tracking type preselected when creating a custom exercise"}</div>
+                    </div>
+                    <select
+                        onchange={on_change_tracking_type}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for [("", "Last used"), ("Strength", "Strength"), ("Cardio", "Cardio"), ("Duration", "Duration"), ("Bodyweight", "Bodyweight")].iter().map(|(key, label)| {
+                            let selected = crate::models::ExerciseTrackingType::from_key(key) == config.default_tracking_type;
+                            html! { <option value={*key} selected={selected}>{*label}</option> }
+                        })}
+                    </select>
+                </div>
+
+                <div class="pt-4 mt-4 border-t border-gray-200 dark:border-gray-700/50 flex items-center justify-between">
+                    <div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{t("settings.language")}</div>
+                        <div class="text-sm text-gray-500 dark:text-gray-400">{t("settings.language_hint")}</div>
+                    </div>
+                    <select
+                        onchange={on_change_language}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for crate::models::Language::all().iter().map(|l| {
+                            html! {
+                                <option value={l.to_key()} selected={config.language == *l}>
+                                    {l.display_name()}
+                                </option>
+                            }
+                        })}
                     </select>
                 </div>
 
@@ -249,12 +1188,149 @@ pub fn settings_panel(props: &Props) -> Html {
                 </p>
             </div>
 
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Rest Timer by Category"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">
+                    {format!("Compound lifts usually need longer rest than isolation work. Leave blank to use the global {}s; a per-exercise rest in a workout still wins.", config.rest_seconds)}
+                </p>
+                <div class="grid grid-cols-2 gap-x-4 gap-y-2">
+                    { for crate::models::Category::all().iter().filter(|c| **c != crate::models::Category::Cardio).map(|c| {
+                        let key = c.to_string();
+                        let value = config.category_rest_seconds.get(&key).map(|s| s.to_string()).unwrap_or_default();
+                        html! {
+                            <label class="flex items-center justify-between gap-2">
+                                <span class="text-sm text-gray-800 dark:text-gray-200">{&key}</span>
+                                <span class="flex items-center gap-1">
+                                    <input
+                                        type="number" min="0" autocomplete="off"
+                                        class="w-16 bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm text-center rounded-lg px-2 py-1 outline-none neu-pressed"
+                                        placeholder={config.rest_seconds.to_string()}
+                                        value={value}
+                                        onchange={make_on_category_rest(key.clone())}
+                                    />
+                                    <span class="text-xs text-gray-500">{"s"}</span>
+                                </span>
+                            </label>
+                        }
+                    })}
+                </div>
+            </div>
+
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Calorie Estimate"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">
+                    {"Analytics estimates calories as MET \u{00d7} body weight \u{00d7} session time. Your latest logged body weight is used first; the value here is the fallback. It's a ballpark, not a measurement."}
+                </p>
+                <label class="flex items-center justify-between gap-2 mb-3">
+                    <span class="text-sm text-gray-800 dark:text-gray-200">{format!("Body weight ({})", config.body_units().weight_label())}</span>
+                    <input
+                        type="number" min="0" step="0.1" autocomplete="off"
+                        class="w-20 bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm text-center rounded-lg px-2 py-1 outline-none neu-pressed"
+                        value={config.body_weight.map(|w| format!("{:.1}", config.body_units().display_weight(w))).unwrap_or_default()}
+                        onchange={on_change_body_weight}
+                    />
+                </label>
+                <p class="text-xs font-bold uppercase text-gray-500 mb-2">{"MET by category"}</p>
+                <div class="grid grid-cols-2 gap-x-4 gap-y-2">
+                    { for crate::models::Category::all().iter().map(|c| {
+                        let key = c.to_string();
+                        let value = config.category_mets.get(&key).map(|m| m.to_string()).unwrap_or_default();
+                        html! {
+                            <label class="flex items-center justify-between gap-2">
+                                <span class="text-sm text-gray-800 dark:text-gray-200">{&key}</span>
+                                <input
+                                    type="number" min="0" step="0.1" autocomplete="off"
+                                    class="w-16 bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm text-center rounded-lg px-2 py-1 outline-none neu-pressed"
+                                    placeholder={c.default_met().to_string()}
+                                    value={value}
+                                    onchange={make_on_category_met(key.clone())}
+                                />
+                            </label>
+                        }
+                    })}
+                </div>
+            </div>
+
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <div class="flex items-center justify-between mb-2">
+                    <h3 class="font-semibold text-gray-900 dark:text-gray-100">{"Short Rest Warning"}</h3>
+                    <button
+                        onclick={on_toggle_min_rest_warning}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none",
+                            if config.min_rest_warning { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if config.min_rest_warning { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">
+                    {"Warn when a strength set is ticked sooner than this after the previous set of the same exercise. 0 turns it off for a category."}
+                </p>
+                if config.min_rest_warning {
+                    <p class="text-xs font-bold uppercase text-gray-500 mb-2">{"Minimum rest by category (s)"}</p>
+                    <div class="grid grid-cols-2 gap-x-4 gap-y-2">
+                        { for crate::models::Category::all().iter().filter(|c| **c != crate::models::Category::Cardio).map(|c| {
+                            let key = c.to_string();
+                            let value = config.min_rest_secs.get(&key).map(|s| s.to_string()).unwrap_or_default();
+                            html! {
+                                <label class="flex items-center justify-between gap-2">
+                                    <span class="text-sm text-gray-800 dark:text-gray-200">{&key}</span>
+                                    <input
+                                        type="number" min="0" step="5" autocomplete="off"
+                                        class="w-16 bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm text-center rounded-lg px-2 py-1 outline-none neu-pressed"
+                                        placeholder={c.default_min_rest().to_string()}
+                                        value={value}
+                                        onchange={make_on_category_min_rest(key.clone())}
+                                    />
+                                </label>
+                            }
+                        })}
+                    </div>
+                }
+            </div>
+
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Category Colors"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">
+                    {"Colors used for each category in Analytics charts. Pick ones that read well on your theme."}
+                </p>
+                <div class="grid grid-cols-2 gap-x-4 gap-y-2">
+                    { for crate::models::Category::all().into_iter().map(|c| {
+                        let key = c.to_string();
+                        let value = config.color_for(&c);
+                        html! {
+                            <label class="flex items-center justify-between gap-2">
+                                <span class="text-sm text-gray-800 dark:text-gray-200">{&key}</span>
+                                <input
+                                    type="color"
+                                    class="w-10 h-7 bg-transparent rounded cursor-pointer"
+                                    value={value}
+                                    onchange={make_on_category_color(c)}
+                                />
+                            </label>
+                        }
+                    })}
+                </div>
+                if !config.category_colors.is_empty() {
+                    <button
+                        class="mt-3 text-xs text-blue-600 dark:text-blue-400 hover:underline"
+                        onclick={on_reset_category_colors}
+                    >{"Reset to defaults"}</button>
+                }
+            </div>
+
             <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
                 <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Export Data"}</h3>
                 <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Download all your workout data as a JSON file."}</p>
                 <button
                     class="w-full py-2 bg-blue-600 text-white rounded font-medium hover:bg-blue-700 neu-btn transition-colors"
-                    onclick={on_export}
+                    onclick={on_export.clone()}
                 >{"Export JSON"}</button>
                 <button
                     class="w-full py-2 mt-2 bg-green-600 text-white rounded font-medium hover:bg-green-700 neu-btn transition-colors"
@@ -279,20 +1355,175 @@ pub fn settings_panel(props: &Props) -> Html {
                     })}
                 >{"Export CSV"}</button>
             </div>
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Encrypted Backup"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Lock the backup with a passphrase so you can keep it in your own cloud storage and import it on another device. The same passphrase is needed to import it; it can't be recovered if forgotten."}</p>
+                <input
+                    type="password"
+                    autocomplete="new-password"
+                    placeholder="Passphrase"
+                    class="w-full px-3 py-2 mb-2 bg-white dark:bg-gray-700 rounded-lg text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                    value={(*passphrase).clone()}
+                    oninput={let p = passphrase.clone(); Callback::from(move |e: InputEvent| p.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
+                />
+                <button
+                    class="w-full py-2 bg-blue-600 text-white rounded font-medium hover:bg-blue-700 neu-btn transition-colors"
+                    onclick={on_export_encrypted}
+                >{"Export Encrypted"}</button>
+                <p class="text-xs text-gray-500 dark:text-gray-400 mt-2">{"To import, enter the passphrase here, then use Merge or Replace below."}</p>
+                { match &*export_status {
+                    Some(Ok(msg)) => html! { <p class="mt-2 text-sm text-green-600 dark:text-green-400 font-medium">{msg}</p> },
+                    Some(Err(err)) => html! { <p class="mt-2 text-sm text-red-600 dark:text-red-400 font-medium">{err}</p> },
+                    None => html! {},
+                }}
+            </div>
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Automatic Backup"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Get a reminder to save a dated backup file to your Downloads folder on a schedule, as a safety net if the browser clears its storage."}</p>
+                <div class="flex items-center justify-between mb-3">
+                    <span class="text-sm text-gray-800 dark:text-gray-200">{"Remind me"}</span>
+                    <select
+                        onchange={on_change_auto_backup}
+                        class="bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                    >
+                        { for [(0u32, "Never"), (1, "Every day"), (3, "Every 3 days"), (7, "Every week"), (14, "Every 2 weeks"), (30, "Every 30 days")].iter().map(|(days, label)| {
+                            html! {
+                                <option value={days.to_string()} selected={config.auto_backup_days == *days}>{*label}</option>
+                            }
+                        })}
+                    </select>
+                </div>
+                <p class="text-xs text-gray-500 dark:text-gray-400 mb-3">{last_backup_text}</p>
+                <button
+                    class="w-full py-2 bg-blue-600 text-white rounded font-medium hover:bg-blue-700 neu-btn transition-colors"
+                    onclick={on_export.clone()}
+                >{"Download Backup Now"}</button>
+            </div>
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Backup to URL"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Send a copy of every automatic backup to your own server or WebDAV share. The URL receives a POST with the backup JSON; Restore fetches it back with a GET and replaces all current data, keeping a snapshot so you can undo."}</p>
+                <input
+                    type="url"
+                    placeholder="https://example.com/treening-backup.json"
+                    class="w-full px-3 py-2 mb-2 bg-white dark:bg-gray-700 rounded-lg text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                    value={remote.url.clone()}
+                    oninput={on_remote_url}
+                />
+                <input
+                    type="password"
+                    autocomplete="off"
+                    placeholder="Bearer token (optional)"
+                    class="w-full px-3 py-2 mb-2 bg-white dark:bg-gray-700 rounded-lg text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                    value={remote.token.clone()}
+                    oninput={on_remote_token}
+                />
+                <p class="text-xs text-gray-500 dark:text-gray-400 mb-3">{last_remote_text}</p>
+                <div class="flex gap-2">
+                    <button
+                        class="flex-1 py-2 bg-blue-600 text-white rounded font-medium hover:bg-blue-700 neu-btn transition-colors disabled:opacity-50"
+                        disabled={*remote_busy}
+                        onclick={on_remote_push}
+                    >{"Back Up Now"}</button>
+                    <button
+                        class={classes!(
+                            "flex-1", "py-2", "rounded", "font-medium", "neu-btn", "transition-colors", "disabled:opacity-50",
+                            if *remote_confirm { "bg-red-600 text-white hover:bg-red-700" } else { "bg-gray-100 dark:bg-gray-700 text-gray-800 dark:text-gray-200 hover:bg-gray-200 dark:hover:bg-gray-600" }
+                        )}
+                        disabled={*remote_busy}
+                        onclick={on_remote_restore}
+                    >{ if *remote_confirm { "Tap again to replace all data" } else { "Restore from URL" } }</button>
+                </div>
+                { match &*remote_status {
+                    Some(Ok(msg)) => html! {
+                        <div class="mt-2 flex items-center justify-between text-sm">
+                            <span class="text-green-600 dark:text-green-400 font-medium">{msg}</span>
+                            if *remote_undo {
+                                <button class="text-blue-600 dark:text-blue-400 font-medium hover:underline" onclick={on_remote_undo}>{"Undo"}</button>
+                            }
+                        </div>
+                    },
+                    Some(Err(err)) => html! { <p class="mt-2 text-sm text-red-600 dark:text-red-400 font-medium">{err}</p> },
+                    None => html! {},
+                }}
+            </div>
             <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
                 <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Import Data"}</h3>
-                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Restore data from a previously exported JSON file. This will replace current data."}</p>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Merge adds only new workouts, routines and custom exercises from a previously exported JSON file. Replace overwrites all current data."}</p>
                 <label class="block w-full py-2 bg-gray-100 dark:bg-gray-700 text-gray-800 dark:text-gray-200 rounded font-medium text-center cursor-pointer hover:bg-gray-200 dark:hover:bg-gray-600 neu-btn transition-colors">
-                    {"Choose File"}
+                    {"Merge From File"}
+                    <input
+                        type="file"
+                        accept=".json"
+                        class="hidden"
+                        onchange={on_import_merge}
+                    />
+                </label>
+                <label class="block w-full py-2 mt-2 bg-gray-100 dark:bg-gray-700 text-red-600 dark:text-red-400 rounded font-medium text-center cursor-pointer hover:bg-gray-200 dark:hover:bg-gray-600 neu-btn transition-colors">
+                    {"Replace With File"}
                     <input
                         type="file"
                         accept=".json"
                         class="hidden"
-                        onchange={on_import}
+                        onchange={on_import_replace}
                     />
                 </label>
                 { if let Some(status) = &*import_status {
-                    html! { <p class="mt-2 text-sm text-green-600 dark:text-green-400 font-medium">{status}</p> }
+                    let color = if status.starts_with("Import error") {
+                        "text-red-600 dark:text-red-400"
+                    } else {
+                        "text-green-600 dark:text-green-400"
+                    };
+                    html! { <p class={format!("mt-2 text-sm font-medium {}", color)}>{status}</p> }
+                } else { html! {} }}
+            </div>
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
+                <h3 class="font-semibold mb-2 text-gray-900 dark:text-gray-100">{"Delete Workouts by Date"}</h3>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{"Remove every workout between two dates (inclusive), e.g. test sessions. A snapshot is kept so you can undo."}</p>
+                <div class="grid grid-cols-2 gap-2 mb-3">
+                    <label class="text-xs text-gray-500 dark:text-gray-400">
+                        {"From"}
+                        <input
+                            type="date"
+                            value={(*bulk_from).clone()}
+                            onchange={on_bulk_from}
+                            class="mt-1 w-full bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                        />
+                    </label>
+                    <label class="text-xs text-gray-500 dark:text-gray-400">
+                        {"To"}
+                        <input
+                            type="date"
+                            value={(*bulk_to).clone()}
+                            onchange={on_bulk_to}
+                            class="mt-1 w-full bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1 outline-none neu-pressed"
+                        />
+                    </label>
+                </div>
+                <button
+                    class={classes!(
+                        "w-full", "py-2", "rounded", "font-medium", "neu-btn", "transition-colors", "disabled:opacity-50",
+                        if *bulk_confirm { "bg-red-600 text-white hover:bg-red-700" } else { "bg-gray-100 dark:bg-gray-700 text-red-600 dark:text-red-400 hover:bg-gray-200 dark:hover:bg-gray-600" }
+                    )}
+                    disabled={bulk_count == 0}
+                    onclick={on_bulk_delete}
+                >
+                    { if *bulk_confirm {
+                        format!("Tap again to delete {} workouts", bulk_count)
+                    } else if bulk_count == 1 {
+                        "Delete 1 workout".to_string()
+                    } else {
+                        format!("Delete {} workouts", bulk_count)
+                    }}
+                </button>
+                { if let Some(status) = &*bulk_status {
+                    html! {
+                        <div class="mt-2 flex items-center justify-between text-sm">
+                            <span class="text-gray-700 dark:text-gray-300">{status}</span>
+                            if *bulk_undo {
+                                <button class="text-blue-600 dark:text-blue-400 font-medium hover:underline" onclick={on_bulk_undo}>{"Undo"}</button>
+                            }
+                        </div>
+                    }
                 } else { html! {} }}
             </div>
         </div>
@@ -0,0 +1,112 @@
+use crate::models::Workout;
+use crate::storage;
+use chrono::{Datelike, NaiveDate};
+use std::collections::HashSet;
+use yew::prelude::*;
+
+/// Days shown in the strip, ending today.
+const CALENDAR_DAYS: i64 = 30;
+
+#[derive(Clone, Copy, PartialEq)]
+enum DayStatus {
+    Trained,
+    /// A day off within the week's rest allowance.
+    Rest,
+    /// A day off beyond the allowance.
+    Missed,
+    /// Today, not trained yet.
+    Pending,
+    /// Before the first logged workout.
+    Untracked,
+}
+
+/// Status of each of the last `CALENDAR_DAYS` days, oldest first. Within each
+/// Monday–Sunday week the first `allowance` days off are planned rest and the
+/// rest are missed.
+fn day_statuses(workouts: &[Workout], today: NaiveDate, allowance: u32) -> Vec<(NaiveDate, DayStatus)> {
+    let trained: HashSet<NaiveDate> = workouts
+        .iter()
+        .filter_map(|w| NaiveDate::parse_from_str(&w.date, "%Y-%m-%d").ok())
+        .collect();
+    let first = trained.iter().min().copied();
+    let start = today - chrono::Duration::days(CALENDAR_DAYS - 1);
+
+    // Days off already taken earlier in the first visible week
+    let week_start = start - chrono::Duration::days(start.weekday().num_days_from_monday() as i64);
+    let mut rest_used = week_start
+        .iter_days()
+        .take_while(|d| *d < start)
+        .filter(|d| !trained.contains(d))
+        .count() as u32;
+
+    start
+        .iter_days()
+        .take(CALENDAR_DAYS as usize)
+        .map(|d| {
+            if d.weekday().num_days_from_monday() == 0 {
+                rest_used = 0;
+            }
+            let status = if trained.contains(&d) {
+                DayStatus::Trained
+            } else if first.is_none_or(|f| d < f) {
+                DayStatus::Untracked
+            } else if d == today {
+                DayStatus::Pending
+            } else if rest_used < allowance {
+                rest_used += 1;
+                DayStatus::Rest
+            } else {
+                DayStatus::Missed
+            };
+            (d, status)
+        })
+        .collect()
+}
+
+#[function_component(StreakCalendar)]
+pub fn streak_calendar() -> Html {
+    let workouts = storage::load_workouts();
+    if workouts.is_empty() {
+        return html! {};
+    }
+    let allowance = storage::load_user_config().rest_days_per_week;
+    let today = crate::dates::today();
+    let days = day_statuses(&workouts, today, allowance);
+
+    let count = |status: DayStatus| days.iter().filter(|(_, s)| *s == status).count();
+    let trained = count(DayStatus::Trained);
+    let missed = count(DayStatus::Missed);
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800/50 rounded-2xl p-4 neu-flat transition-colors space-y-3">
+            <div class="flex justify-between items-baseline">
+                <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Last 30 Days"}</h3>
+                <span class="text-xs text-gray-500 dark:text-gray-400">
+                    {format!("{} trained \u{b7} {} missed", trained, missed)}
+                </span>
+            </div>
+            <div class="grid grid-cols-10 gap-1">
+                { for days.iter().map(|(d, status)| {
+                    let (class, label) = match status {
+                        DayStatus::Trained => ("bg-green-500", "trained"),
+                        DayStatus::Rest => ("bg-blue-300 dark:bg-blue-800", "rest day"),
+                        DayStatus::Missed => ("bg-red-400 dark:bg-red-700", "missed"),
+                        DayStatus::Pending => ("bg-gray-300 dark:bg-gray-600 ring-1 ring-blue-500", "today"),
+                        DayStatus::Untracked => ("bg-gray-200 dark:bg-gray-700", "before your first workout"),
+                    };
+                    html! {
+                        <div
+                            class={format!("aspect-square rounded {}", class)}
+                            title={format!("{} \u{2013} {}", d.format("%a %b %-d"), label)}
+                        />
+                    }
+                })}
+            </div>
+            <div class="flex flex-wrap gap-3 text-[10px] text-gray-500 dark:text-gray-400">
+                <span class="flex items-center gap-1"><span class="w-2.5 h-2.5 rounded-sm bg-green-500"></span>{"Trained"}</span>
+                <span class="flex items-center gap-1"><span class="w-2.5 h-2.5 rounded-sm bg-blue-300 dark:bg-blue-800"></span>{format!("Rest ({}/wk)", allowance)}</span>
+                <span class="flex items-center gap-1"><span class="w-2.5 h-2.5 rounded-sm bg-red-400 dark:bg-red-700"></span>{"Missed"}</span>
+            </div>
+        </div>
+    }
+}
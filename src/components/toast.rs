@@ -6,6 +6,9 @@ pub struct ToastProps {
     pub message: AttrValue,
     pub visible: bool,
     pub on_dismiss: Callback<()>,
+    /// Show as a warning rather than a celebration.
+    #[prop_or_default]
+    pub warning: bool,
 }
 
 #[function_component(Toast)]
@@ -36,8 +39,11 @@ pub fn toast(props: &ToastProps) -> Html {
             class="fixed top-4 left-4 right-4 z-[60] flex justify-center pointer-events-none"
             style="animation: modalContentIn 200ms ease-out;"
         >
-            <div class="bg-yellow-500 text-yellow-950 px-5 py-3 rounded-xl shadow-lg text-center font-bold text-sm pointer-events-auto neu-btn flex items-center gap-2">
-                <span class="text-lg">{"🏆"}</span>
+            <div class={classes!(
+                "px-5", "py-3", "rounded-xl", "shadow-lg", "text-center", "font-bold", "text-sm", "pointer-events-auto", "neu-btn", "flex", "items-center", "gap-2",
+                if props.warning { "bg-orange-600 text-white" } else { "bg-yellow-500 text-yellow-950" }
+            )}>
+                <span class="text-lg">{if props.warning { "\u{26a0}\u{fe0f}" } else { "🏆" }}</span>
                 <span>{&props.message}</span>
             </div>
         </div>
@@ -1,8 +1,14 @@
 use crate::models::{
-    Exercise, ExerciseTrackingType, UnitSystem, Workout, WorkoutExercise, WorkoutSet,
+    Exercise, ExerciseTrackingType, SetFailure, UnitSystem, WeightRounding, Workout,
+    WorkoutExercise, WorkoutSet,
 };
-use crate::pages::workout::generate_warmup_sets;
+use crate::pages::workout::{generate_warmup_sets, needs_warmup, try_vibrate, warmup_step};
+use crate::storage;
+use gloo::timers::callback::{Interval, Timeout};
+use std::cell::RefCell;
 use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
 /// Display an f64 cleanly: "60" instead of "60.0", "62.5" stays "62.5".
@@ -25,17 +31,54 @@ struct SetInputProps {
     pub display_value: AttrValue,
     /// Fires with the raw string when the user blurs the field.
     pub on_commit: Callback<String>,
+    /// Fires instead of `on_commit` when the blurred value was typed in, as
+    /// opposed to stepped or left as prefilled.
+    #[prop_or_default]
+    pub on_entry: Option<Callback<String>>,
     /// "decimal" or "numeric"
     #[prop_or(AttrValue::Static("numeric"))]
     pub inputmode: AttrValue,
     #[prop_or_default]
     pub class: AttrValue,
+    /// Last field of its set: Enter also toggles the set's completion.
+    #[prop_or_default]
+    pub completes_set: bool,
+    /// Show -/+ stepper buttons that change the value by this amount.
+    #[prop_or_default]
+    pub step: Option<f64>,
+}
+
+/// Delay before a held stepper button starts repeating, and the repeat rate.
+const STEPPER_HOLD_DELAY_MS: u32 = 400;
+const STEPPER_REPEAT_MS: u32 = 100;
+
+/// Stepper increment for weight inputs, in display units: the rounding step
+/// when one is set, otherwise the smallest common plate pair.
+fn weight_step(unit: &UnitSystem, rounding: WeightRounding) -> f64 {
+    rounding.step().unwrap_or(match unit {
+        UnitSystem::Metric => 2.5,
+        UnitSystem::Imperial => 5.0,
+    })
+}
+
+/// All set inputs on the page, in document (tab) order.
+fn set_input_fields() -> Vec<web_sys::HtmlElement> {
+    let nodes = match gloo::utils::document().query_selector_all("input[data-set-input]") {
+        Ok(n) => n,
+        Err(_) => return Vec::new(),
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i))
+        .filter_map(|n| n.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
 }
 
 #[function_component(SetInput)]
 fn set_input(props: &SetInputProps) -> Html {
     let text = use_state(|| props.display_value.to_string());
     let focused = use_state(|| false);
+    // Typed into since the field was focused
+    let edited = use_mut_ref(|| false);
 
     // Sync from parent ONLY when not focused (i.e. external data changed).
     {
@@ -52,33 +95,93 @@ fn set_input(props: &SetInputProps) -> Html {
 
     let oninput = {
         let text = text.clone();
+        let edited = edited.clone();
         Callback::from(move |e: InputEvent| {
             let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            *edited.borrow_mut() = true;
             text.set(input.value());
         })
     };
 
     let onfocus = {
         let focused = focused.clone();
-        Callback::from(move |_: FocusEvent| focused.set(true))
+        let edited = edited.clone();
+        Callback::from(move |_: FocusEvent| {
+            *edited.borrow_mut() = false;
+            focused.set(true);
+        })
     };
 
     let onblur = {
         let focused = focused.clone();
         let text = text.clone();
+        let edited = edited.clone();
         let on_commit = props.on_commit.clone();
+        let on_entry = props.on_entry.clone();
         Callback::from(move |_: FocusEvent| {
             focused.set(false);
-            on_commit.emit((*text).clone());
+            let entered = std::mem::take(&mut *edited.borrow_mut());
+            match &on_entry {
+                Some(on_entry) if entered => on_entry.emit((*text).clone()),
+                _ => on_commit.emit((*text).clone()),
+            }
+        })
+    };
+
+    // Enter commits and moves to the next set field; on the last field of a
+    // set it also ticks the set's checkbox, unless the commit already did.
+    let onkeydown = {
+        let completes_set = props.completes_set;
+        Callback::from(move |e: KeyboardEvent| {
+            if e.key() != "Enter" {
+                return;
+            }
+            e.prevent_default();
+            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+            let fields = set_input_fields();
+            let next = fields
+                .iter()
+                .position(|f| f.is_same_node(Some(input.as_ref())))
+                .and_then(|i| fields.get(i + 1).cloned());
+            let checkbox = if completes_set {
+                input
+                    .closest("[data-set-row]")
+                    .ok()
+                    .flatten()
+                    .and_then(|row| row.query_selector("input[type=checkbox]").ok().flatten())
+                    .and_then(|el| el.dyn_into::<web_sys::HtmlInputElement>().ok())
+            } else {
+                None
+            };
+            // Blur first so the value is committed before completion is toggled
+            let _ = input.blur();
+            Timeout::new(0, move || {
+                if let Some(cb) = checkbox.filter(|cb| !cb.checked()) {
+                    cb.click();
+                }
+                if let Some(next) = next {
+                    let _ = next.focus();
+                }
+            })
+            .forget();
         })
     };
 
+    let input_ref = use_node_ref();
+    let hold_timeout = use_mut_ref(|| None::<Timeout>);
+    let hold_interval = use_mut_ref(|| None::<Interval>);
+    // The parent hands out a new commit callback each render; a held repeat
+    // must use the latest one, not the one from when the press started
+    let latest_commit = use_mut_ref(|| props.on_commit.clone());
+    *latest_commit.borrow_mut() = props.on_commit.clone();
+
     let inputmode = props.inputmode.clone();
     let class = props.class.clone();
     let value = (*text).clone();
 
-    html! {
+    let input = html! {
         <input
+            ref={input_ref.clone()}
             type="text"
             inputmode={inputmode}
             class={class}
@@ -86,7 +189,84 @@ fn set_input(props: &SetInputProps) -> Html {
             {oninput}
             {onfocus}
             {onblur}
+            {onkeydown}
+            data-set-input="true"
         />
+    };
+
+    let step = match props.step {
+        Some(step) => step,
+        None => return input,
+    };
+
+    // Reads the live field value so held repeats build on each other
+    let apply_step: Rc<dyn Fn(f64)> = {
+        let input_ref = input_ref.clone();
+        let text = text.clone();
+        let latest_commit = latest_commit.clone();
+        Rc::new(move |delta: f64| {
+            let Some(el) = input_ref.cast::<web_sys::HtmlInputElement>() else {
+                return;
+            };
+            let current = el.value().trim().parse::<f64>().unwrap_or(0.0);
+            let next = ((current + delta).max(0.0) * 100.0).round() / 100.0;
+            let next = display_f64(next);
+            el.set_value(&next);
+            text.set(next.clone());
+            let on_commit = latest_commit.borrow().clone();
+            on_commit.emit(next);
+        })
+    };
+
+    let stop_hold = {
+        let hold_timeout = hold_timeout.clone();
+        let hold_interval = hold_interval.clone();
+        Callback::from(move |_: PointerEvent| {
+            hold_timeout.borrow_mut().take();
+            hold_interval.borrow_mut().take();
+        })
+    };
+
+    // Press steps once; holding repeats until release
+    let start_hold = |delta: f64| {
+        let apply_step = apply_step.clone();
+        let hold_timeout = hold_timeout.clone();
+        let hold_interval = hold_interval.clone();
+        Callback::from(move |e: PointerEvent| {
+            e.prevent_default();
+            apply_step(delta);
+            let apply_step = apply_step.clone();
+            let hold_interval = hold_interval.clone();
+            *hold_timeout.borrow_mut() = Some(Timeout::new(STEPPER_HOLD_DELAY_MS, move || {
+                *hold_interval.borrow_mut() = Some(Interval::new(STEPPER_REPEAT_MS, move || {
+                    apply_step(delta)
+                }));
+            }));
+        })
+    };
+
+    let button_class = "w-5 h-7 flex-shrink-0 rounded text-sm font-bold leading-none text-gray-500 dark:text-gray-400 bg-gray-200 dark:bg-gray-700 hover:text-blue-500 select-none touch-manipulation transition-colors";
+
+    html! {
+        <div class="flex items-center gap-0.5 flex-1 min-w-0">
+            <button
+                class={button_class}
+                tabindex="-1"
+                onpointerdown={start_hold(-step)}
+                onpointerup={stop_hold.clone()}
+                onpointerleave={stop_hold.clone()}
+                onpointercancel={stop_hold.clone()}
+            >{"\u{2212}"}</button>
+            {input}
+            <button
+                class={button_class}
+                tabindex="-1"
+                onpointerdown={start_hold(step)}
+                onpointerup={stop_hold.clone()}
+                onpointerleave={stop_hold.clone()}
+                onpointercancel={stop_hold}
+            >{"+"}</button>
+        </div>
     }
 }
 
@@ -95,19 +275,93 @@ fn estimate_1rm(weight: f64, reps: u32) -> f64 {
     weight * (1.0 + reps as f64 / 30.0)
 }
 
-/// Compute plates per side for a target weight given bar weight.
-pub fn compute_plates(target: f64, bar: f64) -> Vec<(f64, u32)> {
-    let available = [25.0, 20.0, 15.0, 10.0, 5.0, 2.5, 1.25];
-    let mut remaining = (target - bar) / 2.0;
-    if remaining <= 0.0 {
+/// Superset group colors, assigned in order of first appearance. Mid-tones
+/// that read on light, dark and AMOLED backgrounds.
+const SUPERSET_COLORS: [&str; 6] = [
+    "#a855f7", "#06b6d4", "#f59e0b", "#ec4899", "#22c55e", "#3b82f6",
+];
+
+/// Standard kg plate set, used unless the active gym profile lists its own.
+pub const KG_PLATES: [f64; 7] = [25.0, 20.0, 15.0, 10.0, 5.0, 2.5, 1.25];
+const LB_PLATES: [f64; 6] = [45.0, 35.0, 25.0, 10.0, 5.0, 2.5];
+const KG_PER_LB: f64 = 0.453_592_37;
+
+/// Compute plates per side for a target weight given bar weight and the
+/// available plate sizes (kg, heaviest first), each with the pairs owned
+/// (`None` = unlimited). Returns the plates and the loaded total in kg, which
+/// falls short of the target when the owned plates can't make it exactly.
+pub fn compute_plates(
+    target: f64,
+    bar: f64,
+    plates: &[(f64, Option<u32>)],
+) -> (Vec<(f64, u32)>, f64) {
+    let plates = best_plates((target - bar) / 2.0, plates);
+    let side: f64 = plates.iter().map(|(p, c)| p * *c as f64).sum();
+    (plates, bar + side * 2.0)
+}
+
+/// The standard kg plate set with no limit on pairs.
+pub fn standard_plates() -> Vec<(f64, Option<u32>)> {
+    KG_PLATES.iter().map(|p| (*p, None)).collect()
+}
+
+/// Closest lb-plate loading at or under a kg target, as plates per side in lb.
+/// The bar is still the kg bar; returns the plates and the loaded total in kg.
+fn compute_lb_plates(target: f64, bar: f64) -> (Vec<(f64, u32)>, f64) {
+    let per_side_lb = (target - bar) / 2.0 / KG_PER_LB;
+    let plates = best_plates(per_side_lb, &LB_PLATES.map(|p| (p, None)));
+    let side_lb: f64 = plates.iter().map(|(p, c)| p * *c as f64).sum();
+    (plates, bar + side_lb * 2.0 * KG_PER_LB)
+}
+
+/// Heaviest per-side loading that doesn't exceed `per_side`, as (plate, count)
+/// heaviest first. A bounded knapsack over the owned plate counts, so owning
+/// one pair of 20s and two of 15s still finds 15+15 for 30 per side.
+fn best_plates(per_side: f64, available: &[(f64, Option<u32>)]) -> Vec<(f64, u32)> {
+    if per_side <= 0.0 {
         return vec![];
     }
+    // Work in hundredths so 1.25 kg and 2.5 lb plates are exact; the small
+    // epsilon keeps unit conversion noise from dropping a plate
+    let target = ((per_side + 1e-6) * 100.0).floor() as usize;
+    let units: Vec<usize> = available.iter().map(|(p, _)| (p * 100.0).round() as usize).collect();
+
+    // reach[i][s]: whether plates i.. can make exactly s, built lightest first
+    let mut reach = vec![vec![false; target + 1]; available.len() + 1];
+    reach[available.len()][0] = true;
+    for i in (0..available.len()).rev() {
+        let max = available[i].1.unwrap_or(u32::MAX);
+        let w = units[i];
+        // Fewest of plate i needed on top of the lighter plates to make s
+        let mut used = vec![u32::MAX; target + 1];
+        for s in 0..=target {
+            if reach[i + 1][s] {
+                used[s] = 0;
+            } else if w > 0 && s >= w && used[s - w] < max {
+                used[s] = used[s - w] + 1;
+            }
+        }
+        reach[i] = used.iter().map(|c| *c != u32::MAX).collect();
+    }
+
+    let Some(mut s) = (0..=target).rev().find(|s| reach[0][*s]) else {
+        return vec![];
+    };
+    // Take as many of each plate as still leaves the rest makeable, so the
+    // loading favours heavy plates like a greedy fill would
     let mut result = Vec::new();
-    for &plate in &available {
-        let count = (remaining / plate).floor() as u32;
+    for (i, &(plate, pairs)) in available.iter().enumerate() {
+        let w = units[i];
+        if w == 0 {
+            continue;
+        }
+        let count = (0..=(s / w).min(pairs.unwrap_or(u32::MAX) as usize))
+            .rev()
+            .find(|k| reach[i + 1][s - k * w])
+            .unwrap_or(0);
         if count > 0 {
-            result.push((plate, count));
-            remaining -= plate * count as f64;
+            result.push((plate, count as u32));
+            s -= count * w;
         }
     }
     result
@@ -125,18 +379,172 @@ fn find_previous_exercise<'a>(
         .find(|we| we.exercise_id == exercise_id)
 }
 
-/// Compute max weight ever lifted for an exercise across all previous workouts.
-fn exercise_pr_weight(previous_workouts: &[Workout], exercise_id: &str) -> f64 {
+/// Compute max load ever lifted for an exercise across all previous workouts,
+/// or the least assistance ever needed for assisted exercises.
+fn exercise_pr_weight(previous_workouts: &[Workout], exercise_id: &str, assisted: bool) -> f64 {
+    let weights = previous_workouts
+        .iter()
+        .flat_map(|w| w.exercises.iter())
+        .filter(|we| we.exercise_id == exercise_id)
+        .flat_map(|we| we.sets.iter());
+    if assisted {
+        // Least assistance ever needed; 0 when there's no history yet
+        let least = weights
+            .filter(|s| s.completed && s.reps > 0)
+            .map(|s| s.weight)
+            .fold(f64::MAX, f64::min);
+        return if least == f64::MAX { 0.0 } else { least };
+    }
+    weights
+        .filter(|s| s.completed && s.load() > 0.0)
+        .map(|s| s.load())
+        .fold(0.0_f64, f64::max)
+}
+
+/// The figure a set's PR is judged on: assistance on assisted lifts, otherwise
+/// total load so bodyweight sets count with or without added weight.
+fn pr_load(set: &WorkoutSet, assisted: bool) -> f64 {
+    if assisted {
+        set.weight
+    } else {
+        set.load()
+    }
+}
+
+/// Whether `weight` beats `pr_weight`: heavier normally, less help on assisted lifts.
+/// Assisted lifts need some history first, so the first session isn't a "PR".
+fn beats_weight_pr(weight: f64, pr_weight: f64, assisted: bool) -> bool {
+    if assisted {
+        pr_weight > 0.0 && weight < pr_weight
+    } else {
+        weight > 0.0 && weight > pr_weight
+    }
+}
+
+/// Most completed reps per weight bracket for an exercise across all previous workouts.
+fn exercise_rep_bests(previous_workouts: &[Workout], exercise_id: &str) -> HashMap<i64, u32> {
+    let mut bests: HashMap<i64, u32> = HashMap::new();
+    for s in previous_workouts
+        .iter()
+        .flat_map(|w| w.exercises.iter())
+        .filter(|we| we.exercise_id == exercise_id)
+        .flat_map(|we| we.sets.iter())
+        .filter(|s| s.completed && s.reps > 0)
+    {
+        let best = bests.entry(s.weight_bracket()).or_insert(0);
+        *best = (*best).max(s.reps);
+    }
+    bests
+}
+
+/// Toast text when a set beats the most reps previously logged at its weight.
+/// Loads never done before don't count, otherwise every new weight would be a rep PR.
+fn rep_pr_message(rep_bests: &HashMap<i64, u32>, exercise_name: &str, s: &WorkoutSet) -> Option<String> {
+    let prev = rep_bests.get(&s.weight_bracket()).copied()?;
+    if s.reps <= prev {
+        return None;
+    }
+    Some(if s.weight > 0.0 {
+        format!("Rep PR! {} - {} reps @ {:.1}kg", exercise_name, s.reps, s.weight)
+    } else {
+        format!("Rep PR! {} - {} reps", exercise_name, s.reps)
+    })
+}
+
+/// Best estimated 1RM (kg) for an exercise across all previous workouts.
+fn exercise_best_e1rm(previous_workouts: &[Workout], exercise_id: &str) -> f64 {
     previous_workouts
         .iter()
         .flat_map(|w| w.exercises.iter())
         .filter(|we| we.exercise_id == exercise_id)
         .flat_map(|we| we.sets.iter())
-        .filter(|s| s.completed && s.weight > 0.0)
-        .map(|s| s.weight)
+        .filter(|s| s.completed && s.weight > 0.0 && s.reps > 0)
+        .map(|s| {
+            if s.reps == 1 {
+                s.weight
+            } else {
+                estimate_1rm(s.weight, s.reps)
+            }
+        })
         .fold(0.0_f64, f64::max)
 }
 
+/// Quick-fill percentages offered in the %1RM popup.
+const PERCENT_PRESETS: [f64; 7] = [60.0, 65.0, 70.0, 75.0, 80.0, 85.0, 90.0];
+
+/// `percent` of `e1rm` in kg, rounded to the nearest loadable jump over the bar.
+fn percent_load(e1rm: f64, percent: f64, bar: f64, step: f64) -> f64 {
+    let raw = e1rm * percent / 100.0;
+    if raw <= bar || step <= 0.0 {
+        return raw.max(0.0);
+    }
+    bar + ((raw - bar) / step).round() * step
+}
+
+/// Gaps longer than this between completed sets are treated as a break in the
+/// session rather than rest, and are not recorded on the set.
+const MAX_RECORDED_REST_SECS: u32 = 30 * 60;
+
+/// Length of the confirming vibration when a set is ticked done.
+const SET_DONE_VIBRATE_MS: u32 = 30;
+
+/// Record the rest since the previous completed set on `set` (which is being
+/// completed now) and remember now as the latest completion.
+fn stamp_rest_taken(set: &mut WorkoutSet, last_completed_at: &RefCell<Option<f64>>) {
+    let now = js_sys::Date::now();
+    let mut last = last_completed_at.borrow_mut();
+    set.rest_taken_secs = last
+        .map(|prev| ((now - prev) / 1000.0).round() as u32)
+        .filter(|secs| *secs <= MAX_RECORDED_REST_SECS);
+    *last = Some(now);
+}
+
+/// Latest completion time among the exercise's other ticked sets.
+fn previous_completion(sets: &[WorkoutSet], set_idx: usize) -> Option<f64> {
+    sets.iter()
+        .enumerate()
+        .filter(|(i, s)| *i != set_idx && s.completed)
+        .filter_map(|(_, s)| s.completed_at)
+        .reduce(f64::max)
+}
+
+/// Stamp `set` as completed now and, when that is under `min_rest` seconds
+/// after `previous` (the exercise's last completed set), return a warning.
+fn short_rest_warning(set: &mut WorkoutSet, previous: Option<f64>, min_rest: Option<u32>) -> Option<String> {
+    let now = js_sys::Date::now();
+    set.completed_at = Some(now);
+    let min_rest = min_rest.filter(|m| *m > 0)?;
+    let secs = ((now - previous?) / 1000.0).round() as u32;
+    (secs < min_rest).then(|| {
+        format!(
+            "Only {}s since your last set \u{2014} rest at least {}s for strength work",
+            secs, min_rest
+        )
+    })
+}
+
+/// Absolute cap (kg) above which any entry is treated as a likely typo.
+const MAX_PLAUSIBLE_WEIGHT_KG: f64 = 500.0;
+
+/// If `kg` looks like a fat-fingered entry (over the absolute cap, or more than
+/// 3x the exercise's historical max), suggest a corrected value in kg by
+/// dropping trailing digits until it is plausible again.
+fn suspicious_weight_suggestion(kg: f64, historical_max: f64) -> Option<f64> {
+    let limit = if historical_max > 0.0 {
+        (historical_max * 3.0).min(MAX_PLAUSIBLE_WEIGHT_KG)
+    } else {
+        MAX_PLAUSIBLE_WEIGHT_KG
+    };
+    if kg <= limit {
+        return None;
+    }
+    let mut suggestion = kg;
+    while suggestion > limit {
+        suggestion /= 10.0;
+    }
+    Some(suggestion)
+}
+
 #[derive(Properties, PartialEq)]
 pub struct Props {
     pub workout_exercises: Vec<WorkoutExercise>,
@@ -147,6 +555,8 @@ pub struct Props {
     pub previous_workouts: Vec<Workout>,
     #[prop_or(90)]
     pub rest_seconds: u32,
+    #[prop_or_default]
+    pub category_rest_seconds: HashMap<String, u32>,
     #[prop_or(20.0)]
     pub bar_weight: f64,
     #[prop_or_default]
@@ -157,12 +567,40 @@ pub struct Props {
     pub unit_system: UnitSystem,
     #[prop_or_default]
     pub on_pr: Callback<String>,
+    /// Warnings about a set just logged, such as too little rest.
+    #[prop_or_default]
+    pub on_warning: Callback<String>,
 }
 
 #[function_component(WorkoutLog)]
 pub fn workout_log(props: &Props) -> Html {
     let expanded_notes = use_state(HashSet::<(usize, usize)>::new);
+    // Exercise ids whose form cues panel is open
+    let open_cues = use_state(HashSet::<String>::new);
     let plate_calc_target = use_state(|| None::<(usize, usize)>);
+    // Set whose %1RM quick-fill is open, and the percentage typed into it
+    let percent_target = use_state(|| None::<(usize, usize)>);
+    let percent_value = use_state(|| 75.0_f64);
+    // (exercise index, set index, suggested weight in kg) for a suspicious entry
+    let weight_warning = use_state(|| None::<(usize, usize, f64)>);
+    let config = storage::load_user_config();
+    let rounding = config.weight_rounding;
+    let mixed_plates = config.mixed_plates;
+    let auto_complete_sets = config.auto_complete_sets;
+    let reduce_motion = storage::reduce_motion(&config);
+    // A tap-confirming buzz on set completion, off with reduced motion
+    let haptics = config.haptic_feedback && !reduce_motion;
+    // Current body weight, stored on ticked bodyweight sets for volume
+    let body_weight = storage::latest_body_weight(&config);
+    let plate_set = config.active_plates().unwrap_or_else(standard_plates);
+    let warmup_step = warmup_step(&config);
+    // Same jump for %1RM loads; imperial users load 5 lb at a time
+    let percent_step = match props.unit_system {
+        UnitSystem::Metric => warmup_step,
+        UnitSystem::Imperial => 5.0 * KG_PER_LB,
+    };
+    // Timestamp (ms) of the most recently completed set, used to stamp rest taken
+    let last_completed_at = use_mut_ref(|| None::<f64>);
 
     // Swipe state
     let touch_start = use_state(|| None::<(f64, f64)>);
@@ -196,6 +634,13 @@ pub fn workout_log(props: &Props) -> Html {
         |id: &str| -> Option<&Exercise> { props.all_exercises.iter().find(|e| e.id == id) };
 
     let exercise_count = props.workout_exercises.len();
+    // Superset groups lettered A, B, ... in order of appearance
+    let mut superset_order: Vec<u32> = Vec::new();
+    for g in props.workout_exercises.iter().filter_map(|we| we.superset_group) {
+        if !superset_order.contains(&g) {
+            superset_order.push(g);
+        }
+    }
 
     html! {
         <div class="space-y-4">
@@ -203,6 +648,13 @@ pub fn workout_log(props: &Props) -> Html {
                 let exercise = get_exercise(&we.exercise_id);
                 let name = exercise.map(|e| e.name.clone()).unwrap_or_else(|| we.exercise_id.clone());
                 let tracking_type = exercise.map(|e| e.tracking_type.clone()).unwrap_or(ExerciseTrackingType::Strength);
+                // Per-hand dumbbells: inputs show one dumbbell, sets store both
+                let hands = exercise.map_or(1.0, |e| config.hands(e));
+                let per_hand = hands > 1.0;
+                let time_only = exercise.is_some_and(|e| e.time_only);
+                let min_rest = exercise
+                    .filter(|_| config.min_rest_warning && tracking_type == ExerciseTrackingType::Strength)
+                    .map(|e| config.min_rest_for(&e.category));
 
                 let on_update = props.on_update.clone();
                 let on_remove = props.on_remove_exercise.clone();
@@ -210,20 +662,37 @@ pub fn workout_log(props: &Props) -> Html {
                 let on_set_completed = props.on_set_completed.clone();
                 let on_before_destructive = props.on_before_destructive.clone();
 
-                // Resolve rest seconds for this exercise
-                let resolved_rest = we.rest_seconds_override.unwrap_or(props.rest_seconds);
+                // Resolve bar weight for this exercise (per-exercise override or global default)
+                let ex_bar_weight = exercise.and_then(|e| config.bar_weight_override(e)).unwrap_or(props.bar_weight);
 
-                // Superset styling
-                let is_superset = we.superset_group.is_some();
-                let superset_border = if is_superset { "border-l-4 border-l-purple-500" } else { "" };
+                // Resolve rest seconds: per-exercise override → category default → global default
+                let category_rest = exercise
+                    .and_then(|e| props.category_rest_seconds.get(&e.category.to_string()).copied())
+                    .unwrap_or(props.rest_seconds);
+                let resolved_rest = we.rest_seconds_override.unwrap_or(category_rest);
+
+                // Superset styling: a color and letter per group, tinting the card,
+                // plus a connector down to the next exercise when it's in the same group
+                let superset_idx = we.superset_group.and_then(|g| superset_order.iter().position(|o| *o == g));
+                let is_superset = superset_idx.is_some();
+                let superset_color = superset_idx.map(|i| SUPERSET_COLORS[i % SUPERSET_COLORS.len()]);
+                let superset_letter = superset_idx.map(|i| (b'A' + (i % 26) as u8) as char);
+                let connects_next = is_superset
+                    && props.workout_exercises.get(ex_idx + 1).is_some_and(|next| next.superset_group == we.superset_group);
+                // Tint via background-image so it layers over the themed card color
+                let card_style = superset_color
+                    .map(|c| format!("border-left-color: {}; background-image: linear-gradient({}14, {}14)", c, c, c))
+                    .unwrap_or_default();
 
                 // Previous performance
                 let prev_exercise = find_previous_exercise(&props.previous_workouts, &we.exercise_id);
                 let unit_sys = &props.unit_system;
+                let bodyweight_tracked = tracking_type == ExerciseTrackingType::Bodyweight;
                 let prev_text = prev_exercise.map(|prev_we| {
                     prev_we.sets.iter().enumerate().map(|(i, s)| {
                         if s.weight > 0.0 {
-                            format!("S{}: {:.1}{} x{}", i + 1, unit_sys.display_weight(s.weight), unit_sys.weight_label(), s.reps)
+                            let plus = if bodyweight_tracked { "+" } else { "" };
+                            format!("S{}: {}{}{} x{}", i + 1, plus, unit_sys.format_weight(s.weight / hands, rounding), unit_sys.weight_label(), s.reps)
                         } else if let Some(d) = s.distance {
                             format!("S{}: {:.1}{}", i + 1, unit_sys.display_distance(d), unit_sys.distance_label())
                         } else if let Some(dur) = s.duration_secs {
@@ -235,23 +704,62 @@ pub fn workout_log(props: &Props) -> Html {
                 });
 
                 // PR weight for this exercise
-                let pr_weight = exercise_pr_weight(&props.previous_workouts, &we.exercise_id);
+                let assisted = exercise.is_some_and(|e| e.assisted);
+                let pr_weight = exercise_pr_weight(&props.previous_workouts, &we.exercise_id, assisted);
+                // %1RM loads make no sense when the weight is assistance
+                let best_e1rm = if assisted { 0.0 } else { exercise_best_e1rm(&props.previous_workouts, &we.exercise_id) };
+                let pr_suffix = if assisted {
+                    " assist"
+                } else if bodyweight_tracked {
+                    " total"
+                } else if per_hand {
+                    " per hand"
+                } else {
+                    ""
+                };
+                let rep_bests = Rc::new(exercise_rep_bests(&props.previous_workouts, &we.exercise_id));
 
                 // Warm-up: show button for strength exercises when first set weight > bar_weight
-                let show_warmup = matches!(tracking_type, ExerciseTrackingType::Strength)
-                    && we.sets.first().map(|s| s.weight > props.bar_weight).unwrap_or(false);
+                let show_warmup = needs_warmup(we, &tracking_type, ex_bar_weight, warmup_step);
 
                 // Per-exercise rest override
                 let rest_override_val = we.rest_seconds_override;
 
+                let cues = exercise.and_then(|e| e.instructions.clone());
+                let cues_open = open_cues.contains(&we.exercise_id);
+                let toggle_cues = {
+                    let open_cues = open_cues.clone();
+                    let id = we.exercise_id.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        let mut open = (*open_cues).clone();
+                        if !open.remove(&id) {
+                            open.insert(id.clone());
+                        }
+                        open_cues.set(open);
+                    })
+                };
+
                 html! {
-                    <div class={classes!("bg-gray-100", "dark:bg-gray-800", "rounded-2xl", "p-4", "neu-flat", "transition-colors", superset_border)}>
+                    <div class={classes!("relative", "bg-gray-100", "dark:bg-gray-800", "rounded-2xl", "p-4", "neu-flat", "transition-colors", is_superset.then_some("border-l-4"))} style={card_style}>
+                        if let Some(color) = superset_color.filter(|_| connects_next) {
+                            <div class="absolute left-6 -bottom-4 w-1 h-4 rounded-full" style={format!("background-color: {}", color)}></div>
+                        }
                         <div class="flex justify-between items-center mb-1">
                             <div class="flex items-center gap-2">
                                 <h3 class="font-semibold text-lg text-gray-900 dark:text-gray-100">{&name}</h3>
-                                { if is_superset {
-                                    html! { <span class="text-[10px] font-bold bg-purple-500/20 text-purple-400 px-1.5 py-0.5 rounded uppercase">{"Superset"}</span> }
-                                } else { html! {} }}
+                                if cues.is_some() {
+                                    <button
+                                        class={if cues_open { "text-[10px] font-bold px-1.5 py-0.5 rounded bg-blue-500/20 text-blue-500" } else { "text-[10px] font-bold px-1.5 py-0.5 rounded text-gray-400 hover:text-blue-500 transition-colors" }}
+                                        title="Form cues"
+                                        onclick={toggle_cues}
+                                    >{"Cues"}</button>
+                                }
+                                if let (Some(color), Some(letter)) = (superset_color, superset_letter) {
+                                    <span
+                                        class="text-[10px] font-bold px-1.5 py-0.5 rounded uppercase"
+                                        style={format!("background-color: {}33; color: {}", color, color)}
+                                    >{format!("Superset {}", letter)}</span>
+                                }
                             </div>
                             <div class="flex items-center gap-2">
                                 // Reorder buttons
@@ -344,6 +852,10 @@ pub fn workout_log(props: &Props) -> Html {
                             </div>
                         </div>
 
+                        if let (true, Some(text)) = (cues_open, &cues) {
+                            <div class="text-xs text-gray-600 dark:text-gray-400 bg-white dark:bg-gray-700/50 rounded-lg px-3 py-2 mb-2 whitespace-pre-line">{text}</div>
+                        }
+
                         // Previous performance overlay
                         { if let Some(ref text) = prev_text {
                             html! { <div class="text-[10px] text-gray-400 dark:text-gray-500 mb-2 font-mono">{"Last: "}{text}</div> }
@@ -355,10 +867,13 @@ pub fn workout_log(props: &Props) -> Html {
                                 { match tracking_type {
                                     ExerciseTrackingType::Strength => html! {
                                         <>
-                                            <div class="col-span-4">{format!("Weight ({})", props.unit_system.weight_label())}</div>
+                                            <div class="col-span-4">{format!("Weight ({}{})", props.unit_system.weight_label(), if per_hand { "/hand" } else { "" })}</div>
                                             <div class="col-span-3">{"Reps"}</div>
                                         </>
                                     },
+                                    ExerciseTrackingType::Cardio if time_only => html! {
+                                        <div class="col-span-7 text-center">{"Time (m)"}</div>
+                                    },
                                     ExerciseTrackingType::Cardio => html! {
                                         <>
                                             <div class="col-span-4">{format!("Dist ({})", props.unit_system.distance_label())}</div>
@@ -369,7 +884,10 @@ pub fn workout_log(props: &Props) -> Html {
                                         <div class="col-span-7 text-center">{"Duration (secs)"}</div>
                                     },
                                     ExerciseTrackingType::Bodyweight => html! {
-                                        <div class="col-span-7 text-center">{"Reps"}</div>
+                                        <>
+                                            <div class="col-span-3">{format!("+Weight ({})", props.unit_system.weight_label())}</div>
+                                            <div class="col-span-4">{"Reps"}</div>
+                                        </>
                                     },
                                 }}
                                 <div class="col-span-2 text-center">{"Done"}</div>
@@ -394,6 +912,97 @@ pub fn workout_log(props: &Props) -> Html {
                                 let pr_weight2 = pr_weight;
                                 let exercise_name_for_pr = name.clone();
                                 let resolved_rest2 = resolved_rest;
+                                let last_completed_at2 = last_completed_at.clone();
+                                let rep_bests2 = rep_bests.clone();
+                                let on_warning2 = props.on_warning.clone();
+                                // For the short-rest warning when this set is ticked
+                                let previous_completed_at = previous_completion(&we.sets, set_idx);
+
+                                // Opt-in: tick a strength set once weight and reps are both entered
+                                let auto_complete = {
+                                    let on_set_completed = on_set_completed.clone();
+                                    let on_pr = props.on_pr.clone();
+                                    let last_completed_at = last_completed_at.clone();
+                                    let exercise_name = name.clone();
+                                    let rep_bests = rep_bests.clone();
+                                    let on_warning = props.on_warning.clone();
+                                    move |s: &mut WorkoutSet| {
+                                        if !auto_complete_sets || s.completed || s.weight <= 0.0 || s.reps == 0 {
+                                            return;
+                                        }
+                                        s.completed = true;
+                                        if bodyweight_tracked {
+                                            s.bodyweight = body_weight;
+                                        }
+                                        if haptics {
+                                            try_vibrate(SET_DONE_VIBRATE_MS);
+                                        }
+                                        stamp_rest_taken(s, &last_completed_at);
+                                        let rest_warning = short_rest_warning(s, previous_completed_at, min_rest);
+                                        on_set_completed.emit(resolved_rest);
+                                        if beats_weight_pr(pr_load(s, assisted), pr_weight, assisted) {
+                                            on_pr.emit(format!("New PR! {} - {:.1}kg{}", exercise_name, pr_load(s, assisted) / hands, pr_suffix));
+                                        } else if let Some(msg) = rep_pr_message(&rep_bests, &exercise_name, s) {
+                                            on_pr.emit(msg);
+                                        } else if let Some(msg) = rest_warning {
+                                            on_warning.emit(msg);
+                                        }
+                                    }
+                                };
+
+                                // Weight and reps commits; only a typed entry auto-completes,
+                                // not a stepper tap or a prefilled value left as it was
+                                let commit_weight: Rc<dyn Fn(String, bool)> = {
+                                    let exercises = exercises.clone();
+                                    let on_update = on_update.clone();
+                                    let unit_sys = props.unit_system.clone();
+                                    let weight_warning = weight_warning.clone();
+                                    let auto_complete = auto_complete.clone();
+                                    Rc::new(move |v: String, entered: bool| {
+                                        if let Ok(val) = v.parse::<f64>() {
+                                            let kg = unit_sys.to_kg(val) * hands;
+                                            let suggestion = suspicious_weight_suggestion(kg, pr_weight);
+                                            let mut exs = exercises.clone();
+                                            if let Some(we) = exs.get_mut(ex_idx) {
+                                                if let Some(s) = we.sets.get_mut(set_idx) {
+                                                    s.weight = kg;
+                                                    // Don't tick a set whose weight looks like a typo
+                                                    if entered && suggestion.is_none() {
+                                                        auto_complete(s);
+                                                    }
+                                                }
+                                            }
+                                            // Keep the entry but flag likely typos for a one-tap fix
+                                            match suggestion {
+                                                Some(suggested) => weight_warning.set(Some((ex_idx, set_idx, suggested))),
+                                                None => {
+                                                    if matches!(*weight_warning, Some((e, s, _)) if e == ex_idx && s == set_idx) {
+                                                        weight_warning.set(None);
+                                                    }
+                                                }
+                                            }
+                                            on_update.emit(exs);
+                                        }
+                                    })
+                                };
+                                let commit_reps: Rc<dyn Fn(String, bool)> = {
+                                    let exercises = exercises.clone();
+                                    let on_update = on_update.clone();
+                                    Rc::new(move |v: String, entered: bool| {
+                                        if let Ok(val) = v.parse::<u32>() {
+                                            let mut exs = exercises.clone();
+                                            if let Some(we) = exs.get_mut(ex_idx) {
+                                                if let Some(s) = we.sets.get_mut(set_idx) {
+                                                    s.reps = val;
+                                                    if entered {
+                                                        auto_complete(s);
+                                                    }
+                                                }
+                                            }
+                                            on_update.emit(exs);
+                                        }
+                                    })
+                                };
 
                                 // 1RM calculation for completed strength sets
                                 let show_1rm = completed && set.weight > 0.0 && set.reps > 1
@@ -401,8 +1010,11 @@ pub fn workout_log(props: &Props) -> Html {
                                 let est_1rm = if show_1rm { estimate_1rm(set.weight, set.reps) } else { 0.0 };
 
                                 // PR highlight
-                                let is_pr = completed && set.weight > 0.0 && set.weight > pr_weight
-                                    && matches!(tt, ExerciseTrackingType::Strength);
+                                let is_pr = completed && beats_weight_pr(pr_load(set, assisted), pr_weight, assisted)
+                                    && matches!(tt, ExerciseTrackingType::Strength | ExerciseTrackingType::Bodyweight);
+                                let is_rep_pr = completed && !is_pr
+                                    && matches!(tt, ExerciseTrackingType::Strength | ExerciseTrackingType::Bodyweight)
+                                    && rep_bests.get(&set.weight_bracket()).is_some_and(|prev| set.reps > *prev);
 
                                 // Per-set note state
                                 let note_expanded = {
@@ -416,7 +1028,8 @@ pub fn workout_log(props: &Props) -> Html {
                                     let pc = plate_calc_target.clone();
                                     *pc == Some((ex_idx, set_idx))
                                 };
-                                let bar_weight = props.bar_weight;
+                                let bar_weight = ex_bar_weight;
+                                let show_percent = best_e1rm > 0.0 && *percent_target == Some((ex_idx, set_idx));
 
                                 // Swipe state for this row
                                 let offset = swipe_offsets.get(&(ex_idx, set_idx)).copied().unwrap_or(0.0);
@@ -494,9 +1107,12 @@ pub fn workout_log(props: &Props) -> Html {
                                             class={classes!(
                                                 "grid", "grid-cols-12", "gap-2", "items-center", "transition-opacity", "relative", "bg-gray-100", "dark:bg-gray-800",
                                                 if completed { "opacity-50" } else { "" },
-                                                if is_pr { "pr-flash ring-2 ring-yellow-400 rounded" } else { "" }
+                                                if is_pr { "ring-2 ring-yellow-400 rounded" } else { "" },
+                                                if is_rep_pr { "ring-2 ring-emerald-400 rounded" } else { "" },
+                                                if (is_pr || is_rep_pr) && !reduce_motion { "pr-flash" } else { "" }
                                             )}
                                             style={swipe_style}
+                                            data-set-row="true"
                                             ontouchstart={ontouchstart}
                                             ontouchmove={ontouchmove}
                                             ontouchend={ontouchend}
@@ -505,33 +1121,35 @@ pub fn workout_log(props: &Props) -> Html {
                                             {set_idx + 1}
                                             { if is_pr {
                                                 html! { <span class="text-yellow-500 text-[9px] font-bold">{"PR"}</span> }
+                                            } else if is_rep_pr {
+                                                html! { <span class="text-emerald-500 text-[9px] font-bold" title="Most reps ever at this weight">{"R+"}</span> }
                                             } else { html! {} }}
                                         </div>
 
                                         { {
-                                            let input_class = AttrValue::Static("w-full px-2 py-1 bg-white dark:bg-gray-700 rounded text-sm text-center text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors");
+                                            let input_class = AttrValue::Static("w-full min-w-0 px-2 py-1 bg-white dark:bg-gray-700 rounded text-sm text-center text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors");
                                             match tt {
                                             ExerciseTrackingType::Strength => html! {
                                                 <>
                                                     <div class="col-span-4 flex gap-1">
                                                         <SetInput
-                                                            display_value={display_f64(unit_sys2.display_weight(set.weight))}
+                                                            display_value={display_f64(unit_sys2.display_weight(set.weight / hands))}
                                                             inputmode="decimal"
                                                             class={input_class.clone()}
+                                                            step={Some(weight_step(&unit_sys2, rounding))}
                                                             on_commit={{
-                                                                let unit_sys = unit_sys2.clone();
-                                                                Callback::from(move |v: String| {
-                                                                    if let Ok(val) = v.parse::<f64>() {
-                                                                        let mut exs = exercises2.clone();
-                                                                        if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.weight = unit_sys.to_kg(val); } }
-                                                                        on_update2.emit(exs);
-                                                                    }
-                                                                })
+                                                                let commit_weight = commit_weight.clone();
+                                                                Callback::from(move |v: String| commit_weight(v, false))
+                                                            }}
+                                                            on_entry={{
+                                                                let commit_weight = commit_weight.clone();
+                                                                Some(Callback::from(move |v: String| commit_weight(v, true)))
                                                             }}
                                                         />
                                                         <button
                                                             class="text-gray-400 hover:text-blue-400 text-xs flex-shrink-0 transition-colors"
                                                             title="Plate calculator \u{2014} see plates per side"
+                                                            tabindex="-1"
                                                             onclick={{
                                                                 let pc = plate_calc_target.clone();
                                                                 Callback::from(move |_| {
@@ -543,23 +1161,60 @@ pub fn workout_log(props: &Props) -> Html {
                                                                 })
                                                             }}
                                                         >{"\u{1f3cb}"}</button>
+                                                        if best_e1rm > 0.0 {
+                                                            <button
+                                                                class="text-gray-400 hover:text-blue-400 text-xs font-bold flex-shrink-0 transition-colors"
+                                                                title="Fill weight from a percentage of your best est. 1RM"
+                                                                tabindex="-1"
+                                                                onclick={{
+                                                                    let pt = percent_target.clone();
+                                                                    Callback::from(move |_| {
+                                                                        if *pt == Some((ex_idx, set_idx)) {
+                                                                            pt.set(None);
+                                                                        } else {
+                                                                            pt.set(Some((ex_idx, set_idx)));
+                                                                        }
+                                                                    })
+                                                                }}
+                                                            >{"%"}</button>
+                                                        }
                                                     </div>
                                                     <div class="col-span-3">
                                                         <SetInput
                                                             display_value={set.reps.to_string()}
                                                             inputmode="numeric"
                                                             class={input_class}
-                                                            on_commit={Callback::from(move |v: String| {
-                                                                if let Ok(val) = v.parse::<u32>() {
-                                                                    let mut exs = exercises3.clone();
-                                                                    if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.reps = val; } }
-                                                                    on_update3.emit(exs);
-                                                                }
-                                                            })}
+                                                            completes_set={true}
+                                                            step={Some(1.0)}
+                                                            on_commit={{
+                                                                let commit_reps = commit_reps.clone();
+                                                                Callback::from(move |v: String| commit_reps(v, false))
+                                                            }}
+                                                            on_entry={{
+                                                                let commit_reps = commit_reps.clone();
+                                                                Some(Callback::from(move |v: String| commit_reps(v, true)))
+                                                            }}
                                                         />
                                                     </div>
                                                 </>
                                             },
+                                            ExerciseTrackingType::Cardio if time_only => html! {
+                                                <div class="col-span-7 px-4">
+                                                    <SetInput
+                                                        display_value={(set.duration_secs.unwrap_or(0) / 60).to_string()}
+                                                        inputmode="numeric"
+                                                        class={input_class}
+                                                        completes_set={true}
+                                                        on_commit={Callback::from(move |v: String| {
+                                                            if let Ok(val) = v.parse::<u32>() {
+                                                                let mut exs = exercises2.clone();
+                                                                if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.duration_secs = Some(val * 60); } }
+                                                                on_update2.emit(exs);
+                                                            }
+                                                        })}
+                                                    />
+                                                </div>
+                                            },
                                             ExerciseTrackingType::Cardio => html! {
                                                 <>
                                                     <div class="col-span-4">
@@ -584,6 +1239,7 @@ pub fn workout_log(props: &Props) -> Html {
                                                             display_value={(set.duration_secs.unwrap_or(0) / 60).to_string()}
                                                             inputmode="numeric"
                                                             class={input_class}
+                                                            completes_set={true}
                                                             on_commit={Callback::from(move |v: String| {
                                                                 if let Ok(val) = v.parse::<u32>() {
                                                                     let mut exs = exercises3.clone();
@@ -601,6 +1257,7 @@ pub fn workout_log(props: &Props) -> Html {
                                                         display_value={set.duration_secs.unwrap_or(0).to_string()}
                                                         inputmode="numeric"
                                                         class={input_class}
+                                                        completes_set={true}
                                                         on_commit={Callback::from(move |v: String| {
                                                             if let Ok(val) = v.parse::<u32>() {
                                                                 let mut exs = exercises2.clone();
@@ -612,20 +1269,45 @@ pub fn workout_log(props: &Props) -> Html {
                                                 </div>
                                             },
                                             ExerciseTrackingType::Bodyweight => html! {
-                                                <div class="col-span-7 px-4">
-                                                    <SetInput
-                                                        display_value={set.reps.to_string()}
-                                                        inputmode="numeric"
-                                                        class={input_class}
-                                                        on_commit={Callback::from(move |v: String| {
-                                                            if let Ok(val) = v.parse::<u32>() {
-                                                                let mut exs = exercises2.clone();
-                                                                if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.reps = val; } }
-                                                                on_update2.emit(exs);
-                                                            }
-                                                        })}
-                                                    />
-                                                </div>
+                                                <>
+                                                    // Optional load on top of body weight; 0 is a plain bodyweight set
+                                                    <div class="col-span-3">
+                                                        <SetInput
+                                                            display_value={display_f64(unit_sys2.display_weight(set.weight))}
+                                                            inputmode="decimal"
+                                                            class={input_class.clone()}
+                                                            step={Some(weight_step(&unit_sys2, rounding))}
+                                                            on_commit={{
+                                                                let unit_sys = unit_sys2.clone();
+                                                                Callback::from(move |v: String| {
+                                                                    if let Ok(val) = v.parse::<f64>() {
+                                                                        let mut exs = exercises2.clone();
+                                                                        if let Some(s) = exs.get_mut(ex_idx).and_then(|we| we.sets.get_mut(set_idx)) {
+                                                                            s.weight = unit_sys.to_kg(val).max(0.0);
+                                                                        }
+                                                                        on_update2.emit(exs);
+                                                                    }
+                                                                })
+                                                            }}
+                                                        />
+                                                    </div>
+                                                    <div class="col-span-4">
+                                                        <SetInput
+                                                            display_value={set.reps.to_string()}
+                                                            inputmode="numeric"
+                                                            class={input_class}
+                                                            completes_set={true}
+                                                            step={Some(1.0)}
+                                                            on_commit={Callback::from(move |v: String| {
+                                                                if let Ok(val) = v.parse::<u32>() {
+                                                                    let mut exs = exercises3.clone();
+                                                                    if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.reps = val; } }
+                                                                    on_update3.emit(exs);
+                                                                }
+                                                            })}
+                                                        />
+                                                    </div>
+                                                </>
                                             },
                                         }}}
 
@@ -634,6 +1316,7 @@ pub fn workout_log(props: &Props) -> Html {
                                                 type="checkbox"
                                                 checked={completed}
                                                 class="w-5 h-5 accent-blue-600 cursor-pointer"
+                                                tabindex="-1"
                                                 onchange={Callback::from(move |_| {
                                                     let mut exs = exercises4.clone();
                                                     if let Some(we) = exs.get_mut(ex_idx) {
@@ -641,10 +1324,25 @@ pub fn workout_log(props: &Props) -> Html {
                                                             let was_completed = s.completed;
                                                             s.completed = !s.completed;
                                                             if !was_completed && s.completed {
+                                                                if bodyweight_tracked {
+                                                                    s.bodyweight = body_weight;
+                                                                }
+                                                                if haptics {
+                                                                    try_vibrate(SET_DONE_VIBRATE_MS);
+                                                                }
+                                                                stamp_rest_taken(s, &last_completed_at2);
+                                                                let rest_warning = short_rest_warning(s, previous_completed_at, min_rest);
                                                                 on_set_completed2.emit(resolved_rest2);
-                                                                if s.weight > 0.0 && s.weight > pr_weight2 {
-                                                                    on_pr2.emit(format!("New PR! {} - {:.1}kg", exercise_name_for_pr, s.weight));
+                                                                if beats_weight_pr(pr_load(s, assisted), pr_weight2, assisted) {
+                                                                    on_pr2.emit(format!("New PR! {} - {:.1}kg{}", exercise_name_for_pr, pr_load(s, assisted) / hands, pr_suffix));
+                                                                } else if let Some(msg) = rep_pr_message(&rep_bests2, &exercise_name_for_pr, s) {
+                                                                    on_pr2.emit(msg);
+                                                                } else if let Some(msg) = rest_warning {
+                                                                    on_warning2.emit(msg);
                                                                 }
+                                                            } else if was_completed {
+                                                                s.rest_taken_secs = None;
+                                                                s.completed_at = None;
                                                             }
                                                         }
                                                     }
@@ -657,13 +1355,14 @@ pub fn workout_log(props: &Props) -> Html {
                                             <button
                                                 class={classes!(
                                                     "text-xs", "p-1", "transition-colors",
-                                                    if note_text.is_empty() && !note_expanded {
+                                                    if note_text.is_empty() && set.failure.is_none() && !note_expanded {
                                                         "text-gray-400 hover:text-gray-300"
                                                     } else {
                                                         "text-blue-400 hover:text-blue-300"
                                                     }
                                                 )}
-                                                title="Add a note to this set"
+                                                title="Add a note or failure flag to this set"
+                                                tabindex="-1"
                                                 onclick={{
                                                     let notes = expanded_notes.clone();
                                                     Callback::from(move |_| {
@@ -680,6 +1379,7 @@ pub fn workout_log(props: &Props) -> Html {
                                             >{"\u{1f4dd}"}</button>
                                             <button
                                                 class="text-red-600 dark:text-red-400 text-xs hover:text-red-500 dark:hover:text-red-300 p-1 transition-colors"
+                                                tabindex="-1"
                                                 onclick={Callback::from(move |_| {
                                                     on_before_destructive2.emit(exercises5.clone());
                                                     let mut exs = exercises5.clone();
@@ -693,12 +1393,106 @@ pub fn workout_log(props: &Props) -> Html {
 
                                     // 1RM estimate
                                     { if show_1rm {
-                                        html! { <div class="text-[10px] text-gray-400 dark:text-gray-500 pl-6 -mt-1 mb-1 font-mono">{format!("Est. 1RM: {:.1} {}", props.unit_system.display_weight(est_1rm), props.unit_system.weight_label())}</div> }
+                                        html! { <div class="text-[10px] text-gray-400 dark:text-gray-500 pl-6 -mt-1 mb-1 font-mono">{format!("Est. 1RM: {} {}", props.unit_system.format_weight(est_1rm, rounding), props.unit_system.weight_label())}</div> }
+                                    } else { html! {} }}
+
+                                    // Suspicious weight warning
+                                    { match *weight_warning {
+                                        Some((e, si, suggested)) if e == ex_idx && si == set_idx => {
+                                            let wl = props.unit_system.weight_label();
+                                            let suggested_display = props.unit_system.display_weight(suggested / hands);
+                                            let entered_display = props.unit_system.display_weight(set.weight / hands);
+                                            let exercises_fix = exercises.clone();
+                                            let on_update_fix = on_update.clone();
+                                            let ww_fix = weight_warning.clone();
+                                            let ww_keep = weight_warning.clone();
+                                            html! {
+                                                <div class="ml-6 mb-2 p-2 bg-yellow-500/10 border border-yellow-500/40 rounded text-xs text-yellow-700 dark:text-yellow-300 flex flex-wrap items-center gap-2">
+                                                    <span class="font-bold">{format!("{}{} looks unusually heavy. Did you mean {}{}?", display_f64(entered_display), wl, display_f64(suggested_display), wl)}</span>
+                                                    <button
+                                                        class="px-2 py-0.5 bg-yellow-500 text-white rounded font-bold hover:bg-yellow-600 transition-colors"
+                                                        onclick={Callback::from(move |_| {
+                                                            let mut exs = exercises_fix.clone();
+                                                            if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.weight = suggested; } }
+                                                            ww_fix.set(None);
+                                                            on_update_fix.emit(exs);
+                                                        })}
+                                                    >{format!("Use {}{}", display_f64(suggested_display), wl)}</button>
+                                                    <button
+                                                        class="px-2 py-0.5 bg-gray-200 dark:bg-gray-700 text-gray-700 dark:text-gray-300 rounded font-bold hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+                                                        onclick={Callback::from(move |_| ww_keep.set(None))}
+                                                    >{"Keep"}</button>
+                                                </div>
+                                            }
+                                        }
+                                        _ => html! {},
+                                    }}
+
+                                    // %1RM quick-fill popup
+                                    { if show_percent {
+                                        let wl = props.unit_system.weight_label();
+                                        let pct = *percent_value;
+                                        let load = percent_load(best_e1rm, pct, bar_weight, percent_step);
+                                        let on_apply = {
+                                            let exercises_pct = exercises.clone();
+                                            let on_update_pct = on_update.clone();
+                                            let pt = percent_target.clone();
+                                            Callback::from(move |_: MouseEvent| {
+                                                let mut exs = exercises_pct.clone();
+                                                if let Some(we) = exs.get_mut(ex_idx) { if let Some(s) = we.sets.get_mut(set_idx) { s.weight = load; } }
+                                                pt.set(None);
+                                                on_update_pct.emit(exs);
+                                            })
+                                        };
+                                        html! {
+                                            <div class="ml-6 mb-2 p-2 bg-gray-200 dark:bg-gray-700 rounded text-xs text-gray-700 dark:text-gray-300 space-y-2">
+                                                <div class="font-bold">{format!("Best est. 1RM: {} {}", props.unit_system.format_weight(best_e1rm, rounding), wl)}</div>
+                                                <div class="flex flex-wrap gap-1">
+                                                    { for PERCENT_PRESETS.iter().map(|&p| {
+                                                        let pv = percent_value.clone();
+                                                        html! {
+                                                            <button
+                                                                class={if (p - pct).abs() < 0.01 {
+                                                                    "px-2 py-0.5 rounded-full bg-blue-600 text-white font-bold transition-colors"
+                                                                } else {
+                                                                    "px-2 py-0.5 rounded-full bg-gray-100 dark:bg-gray-800 hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+                                                                }}
+                                                                onclick={Callback::from(move |_| pv.set(p))}
+                                                            >{format!("{}%", p)}</button>
+                                                        }
+                                                    })}
+                                                </div>
+                                                <div class="flex items-center gap-2">
+                                                    <input
+                                                        type="number" inputmode="decimal" min="1" max="120" autocomplete="off"
+                                                        class="w-16 px-2 py-1 bg-white dark:bg-gray-800 rounded text-center text-gray-900 dark:text-gray-100 outline-none neu-pressed"
+                                                        value={display_f64(pct)}
+                                                        oninput={{
+                                                            let pv = percent_value.clone();
+                                                            Callback::from(move |e: InputEvent| {
+                                                                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                                                if let Ok(v) = input.value().parse::<f64>() {
+                                                                    if v > 0.0 {
+                                                                        pv.set(v);
+                                                                    }
+                                                                }
+                                                            })
+                                                        }}
+                                                    />
+                                                    <span class="flex-1 font-mono">{format!("% \u{2192} {} {}", props.unit_system.format_weight(load, rounding), wl)}</span>
+                                                    <button
+                                                        class="px-2 py-0.5 bg-blue-600 text-white rounded font-bold hover:bg-blue-700 transition-colors"
+                                                        onclick={on_apply}
+                                                    >{"Apply"}</button>
+                                                </div>
+                                            </div>
+                                        }
                                     } else { html! {} }}
 
                                     // Plate calculator popup
                                     { if show_plate_calc && set.weight > bar_weight {
-                                        let plates = compute_plates(set.weight, bar_weight);
+                                        let (plates, loaded) = compute_plates(set.weight, bar_weight, &plate_set);
+                                        let short = set.weight - loaded;
                                         let wl = props.unit_system.weight_label();
                                         html! {
                                             <div class="ml-6 mb-2 p-2 bg-gray-200 dark:bg-gray-700 rounded text-xs text-gray-700 dark:text-gray-300">
@@ -714,6 +1508,39 @@ pub fn workout_log(props: &Props) -> Html {
                                                         </div>
                                                     }
                                                 }}
+                                                { if short >= 0.05 {
+                                                    html! {
+                                                        <div class="mt-1 text-yellow-500 font-bold">
+                                                            {format!("Closest with your plates: {:.1}{} ({:.1}{} short)", props.unit_system.display_weight(loaded), wl, props.unit_system.display_weight(short), wl)}
+                                                        </div>
+                                                    }
+                                                } else { html! {} }}
+                                                { if mixed_plates {
+                                                    let (lb_plates, loaded) = compute_lb_plates(set.weight, bar_weight);
+                                                    let diff = loaded - set.weight;
+                                                    html! {
+                                                        <div class="mt-2 pt-2 border-t border-gray-300 dark:border-gray-600">
+                                                            <div class="font-bold mb-1">{"With lb plates:"}</div>
+                                                            { if lb_plates.is_empty() {
+                                                                html! { <span class="text-gray-500">{"Bar only"}</span> }
+                                                            } else {
+                                                                html! {
+                                                                    <div class="flex flex-wrap gap-1">
+                                                                        { for lb_plates.iter().map(|(w, c)| {
+                                                                            html! { <span class="bg-amber-500/20 text-amber-500 px-1.5 py-0.5 rounded font-mono">{format!("{}lb x{}", w, c)}</span> }
+                                                                        })}
+                                                                    </div>
+                                                                }
+                                                            }}
+                                                            <div class="mt-1 text-gray-500">
+                                                                {format!("Loads {:.1}{}", props.unit_system.display_weight(loaded), wl)}
+                                                                { if diff.abs() >= 0.05 {
+                                                                    format!(" ({:+.1}{} vs target)", props.unit_system.display_weight(diff), wl)
+                                                                } else { String::new() }}
+                                                            </div>
+                                                        </div>
+                                                    }
+                                                } else { html! {} }}
                                             </div>
                                         }
                                     } else { html! {} }}
@@ -726,7 +1553,7 @@ pub fn workout_log(props: &Props) -> Html {
                                             <div class="ml-6 mb-2">
                                                 <input
                                                     type="text"
-                                                    placeholder="Set note..."
+                                                    placeholder="Set note... (start with 8+2 for cluster sets)"
                                                     class="w-full px-2 py-1 bg-white dark:bg-gray-700 rounded text-xs text-gray-900 dark:text-gray-100 outline-none neu-pressed transition-colors"
                                                     value={note_text}
                                                     oninput={Callback::from(move |e: InputEvent| {
@@ -741,10 +1568,46 @@ pub fn workout_log(props: &Props) -> Html {
                                                         on_update_note.emit(exs);
                                                     })}
                                                 />
+                                                <div class="flex gap-1.5 mt-1">
+                                                    { for SetFailure::all().into_iter().map(|kind| {
+                                                        let active = set.failure == Some(kind);
+                                                        let exercises_fail = exercises.clone();
+                                                        let on_update_fail = on_update.clone();
+                                                        html! {
+                                                            <button
+                                                                class={classes!(
+                                                                    "px-2", "py-0.5", "rounded-full", "text-[10px]", "font-bold", "transition-colors",
+                                                                    if active {
+                                                                        "bg-orange-500 text-white"
+                                                                    } else {
+                                                                        "bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-300"
+                                                                    }
+                                                                )}
+                                                                tabindex="-1"
+                                                                onclick={Callback::from(move |_| {
+                                                                    let mut exs = exercises_fail.clone();
+                                                                    if let Some(s) = exs.get_mut(ex_idx).and_then(|we| we.sets.get_mut(set_idx)) {
+                                                                        s.failure = if active { None } else { Some(kind) };
+                                                                    }
+                                                                    on_update_fail.emit(exs);
+                                                                })}
+                                                            >{kind.display_name()}</button>
+                                                        }
+                                                    })}
+                                                </div>
+                                            </div>
+                                        }
+                                    } else if set.failure.is_some() || !set.note.as_ref().is_none_or(|n| n.is_empty()) {
+                                        html! {
+                                            <div class="text-[10px] text-gray-400 dark:text-gray-500 pl-6 -mt-1 mb-1 flex items-center gap-1.5">
+                                                if let Some(kind) = set.failure {
+                                                    <span class="px-1.5 rounded bg-orange-500/20 text-orange-600 dark:text-orange-400 font-bold" title={kind.display_name().to_string()}>{kind.short_label()}</span>
+                                                }
+                                                if let Some(note) = set.note.as_ref().filter(|n| !n.is_empty()) {
+                                                    <span class="italic">{note}</span>
+                                                }
                                             </div>
                                         }
-                                    } else if !set.note.as_ref().is_none_or(|n| n.is_empty()) {
-                                        html! { <div class="text-[10px] text-gray-400 dark:text-gray-500 pl-6 -mt-1 mb-1 italic">{set.note.as_ref().unwrap()}</div> }
                                     } else { html! {} }}
                                     </>
                                 }
@@ -763,7 +1626,7 @@ pub fn workout_log(props: &Props) -> Html {
                                         let mut exs = exercises_wu.clone();
                                         if let Some(we) = exs.get_mut(ex_idx) {
                                             let working_weight = we.sets.first().map(|s| s.weight).unwrap_or(0.0);
-                                            let warmup = generate_warmup_sets(working_weight);
+                                            let warmup = generate_warmup_sets(working_weight, ex_bar_weight, warmup_step);
                                             // Prepend warmup sets
                                             let mut new_sets = warmup;
                                             new_sets.append(&mut we.sets);
@@ -784,7 +1647,7 @@ pub fn workout_log(props: &Props) -> Html {
                                     let mut exs = exercises.clone();
                                     if let Some(we) = exs.get_mut(ex_idx) {
                                         let last_set = we.sets.last().cloned().unwrap_or(WorkoutSet {
-                                            weight: 0.0, reps: 10, completed: false, distance: None, duration_secs: None, note: None,
+                                            weight: 0.0, reps: 10, completed: false, distance: None, duration_secs: None, note: None, rest_taken_secs: None, failure: None, bodyweight: None, completed_at: None,
                                         });
                                         we.sets.push(WorkoutSet {
                                             weight: last_set.weight,
@@ -793,6 +1656,10 @@ pub fn workout_log(props: &Props) -> Html {
                                             duration_secs: last_set.duration_secs,
                                             completed: false,
                                             note: None,
+                                            rest_taken_secs: None,
+                                            failure: None,
+                                            bodyweight: None,
+                                            completed_at: None,
                                         });
                                     }
                                     on_update.emit(exs);
@@ -804,7 +1671,10 @@ pub fn workout_log(props: &Props) -> Html {
                         { if matches!(tracking_type, ExerciseTrackingType::Strength) {
                             let exercises_rest = exercises.clone();
                             let on_update_rest = on_update.clone();
-                            let default_rest = props.rest_seconds;
+                            let default_rest = category_rest;
+                            let exercises_tempo = exercises.clone();
+                            let on_update_tempo = on_update.clone();
+                            let tempo_val = we.tempo.clone();
                             html! {
                                 <div class="mt-2 flex items-center gap-2">
                                     <span class="text-[10px] text-gray-500 uppercase font-bold">{"Rest:"}</span>
@@ -825,6 +1695,22 @@ pub fn workout_log(props: &Props) -> Html {
                                         })}
                                     />
                                     <span class="text-[10px] text-gray-500">{"s"}</span>
+                                    <span class="text-[10px] text-gray-500 uppercase font-bold ml-2">{"Tempo:"}</span>
+                                    <input
+                                        type="text"
+                                        placeholder="3-1-1-0"
+                                        class="w-20 px-1 py-0.5 bg-white dark:bg-gray-700 rounded text-xs text-center text-gray-900 dark:text-gray-100 outline-none neu-pressed"
+                                        value={tempo_val.unwrap_or_default()}
+                                        onchange={Callback::from(move |e: Event| {
+                                            let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                            let val = input.value().trim().to_string();
+                                            let mut exs = exercises_tempo.clone();
+                                            if let Some(we) = exs.get_mut(ex_idx) {
+                                                we.tempo = if crate::models::tempo_secs_per_rep(&val).is_some() { Some(val) } else { None };
+                                            }
+                                            on_update_tempo.emit(exs);
+                                        })}
+                                    />
                                 </div>
                             }
                         } else { html! {} }}
@@ -1,4 +1,6 @@
-use crate::models::{Category, Equipment, Exercise, ExerciseTrackingType};
+use crate::models::{
+    Category, Difficulty, Equipment, Exercise, ExerciseTrackingType, Routine, SetTarget,
+};
 
 fn ex(
     id: &str,
@@ -40,6 +42,96 @@ fn ex_t(
         is_custom: false,
         image: Some(image_path),
         tracking_type: tt,
+        bar_weight_override: None,
+        aliases: aliases(id).iter().map(|s| s.to_string()).collect(),
+        instructions: instructions(id).map(|s| s.to_string()),
+        assisted: id == "back-15",
+        per_hand: false,
+        // Machines that report floors or calories rather than distance
+        time_only: matches!(id, "cardio-05" | "cardio-09"),
+        difficulty: difficulty(id),
+    }
+}
+
+/// Skill level of built-in exercises; anything not listed is intermediate.
+fn difficulty(id: &str) -> Difficulty {
+    match id {
+        // Machines, cables and simple free-weight or bodyweight moves
+        "chest-04" | "chest-08" | "chest-09" | "chest-10" | "back-01" | "back-02" | "back-04"
+        | "back-11" | "back-14" | "back-15" | "back-17" | "legs-03" | "legs-04" | "legs-05"
+        | "legs-06" | "legs-10" | "legs-11" | "legs-12" | "legs-14" | "legs-16" | "legs-17"
+        | "legs-18" | "legs-19" | "legs-24" | "legs-28" | "legs-29" | "shldr-02" | "shldr-03"
+        | "shldr-04" | "shldr-07" | "shldr-14" | "arms-02" | "arms-03" | "arms-05" | "arms-07"
+        | "arms-15" | "arms-16" | "core-01" | "core-02" | "core-08" | "core-10" | "core-12"
+        | "core-13" | "core-14" | "core-17" | "core-22" | "cardio-01" | "cardio-02"
+        | "cardio-03" | "cardio-04" | "cardio-05" => Difficulty::Beginner,
+        // Strength or skill prerequisites
+        "chest-17" | "back-19" | "back-20" | "legs-23" | "legs-27" | "shldr-16" | "core-19"
+        | "core-20" | "core-23" => Difficulty::Advanced,
+        _ => Difficulty::Intermediate,
+    }
+}
+
+/// Form cues for the main compound lifts, where technique matters most.
+fn instructions(id: &str) -> Option<&'static str> {
+    match id {
+        "chest-01" => Some("Shoulder blades pinched and down. Feet planted, slight arch. Touch low chest, press up and slightly back."),
+        "back-03" => Some("Brace before each rep. Pull elbows back, not up. No torso swing to start the weight."),
+        "back-05" => Some("Bar over mid-foot. Take the slack out before pulling. Push the floor away, lock hips and knees together."),
+        "back-06" => Some("Soft knees, hips back. Keep the bar brushing your legs. Stop when hamstrings stop stretching, not when the back rounds."),
+        "back-07" => Some("Start from a dead hang. Drive elbows down to your pockets. Chest to the bar, full control on the way down."),
+        "legs-01" => Some("Big breath and brace before descending. Knees track over toes. Stay over mid-foot, drive up through the whole foot."),
+        "legs-13" => Some("Chin tucked, ribs down. Shins vertical at the top. Pause and squeeze glutes at lockout."),
+        "shldr-01" => Some("Squeeze glutes and brace. Move your head back, press straight up, then push your head through at lockout."),
+        _ => None,
+    }
+}
+
+/// Common gym shorthand for built-in exercises, used by the AI workout parsers.
+fn aliases(id: &str) -> &'static [&'static str] {
+    match id {
+        "chest-01" => &["Bench Press", "Flat Bench", "BB Bench"],
+        "chest-02" => &["Incline Bench", "Incline Bench Press"],
+        "chest-04" => &["DB Bench", "DB Bench Press"],
+        "chest-05" => &["Incline DB Press"],
+        "chest-06" => &["DB Fly", "Dumbbell Flyes"],
+        "chest-08" => &["Pec Deck"],
+        "chest-10" => &["Push Up", "Pushup", "Pushups"],
+        "chest-11" => &["Dips", "Chest Dips"],
+        "back-01" => &["Pulldown", "Lat Pull Down"],
+        "back-03" => &["Barbell Row", "BB Row", "Bent Over Row"],
+        "back-04" => &["DB Row", "One Arm Row"],
+        "back-05" => &["DL", "Conventional Deadlift"],
+        "back-06" => &["RDL", "Romanian DL"],
+        "back-07" => &["Pull Up", "Pullup", "Pullups"],
+        "back-08" => &["Chin Up", "Chinup", "Chinups"],
+        "legs-01" => &["Squat", "Back Squat", "BB Squat"],
+        "legs-04" => &["Leg Extensions", "Quad Extension"],
+        "legs-05" => &["Lying Leg Curl", "Hamstring Curl"],
+        "legs-08" => &["BSS", "Split Squat"],
+        "legs-09" => &["Lunges"],
+        "legs-13" => &["Hip Thrusts", "Barbell Hip Thrust"],
+        "legs-20" => &["Sumo DL"],
+        "shldr-01" => &["OHP", "Overhead Press", "Military Press", "Strict Press"],
+        "shldr-02" => &["DB Shoulder Press", "DB OHP"],
+        "shldr-03" => &["Lateral Raises", "Side Raise", "Lat Raise"],
+        "shldr-05" => &["Face Pulls"],
+        "shldr-06" => &["Rear Delt Fly", "Reverse Fly"],
+        "shldr-12" => &["Shrugs", "BB Shrugs"],
+        "arms-01" => &["BB Curl"],
+        "arms-02" => &["DB Curl", "Bicep Curl"],
+        "arms-07" => &["Pushdown", "Rope Pushdown"],
+        "arms-09" => &["Skullcrushers", "Lying Tricep Extension"],
+        "arms-12" => &["CGBP", "Close Grip Bench"],
+        "arms-18" => &["EZ Curl"],
+        "core-06" => &["Ab Wheel", "Ab Rollout"],
+        "core-18" => &["Farmers Walk", "Farmer Carry"],
+        "cardio-01" => &["Running"],
+        "cardio-03" => &["Cycling"],
+        "cardio-04" => &["Rower", "Row Erg"],
+        "cardio-09" => &["Assault Bike"],
+        "cardio-11" => &["KB Swing", "Kettlebell Swings"],
+        _ => &[],
     }
 }
 
@@ -524,3 +616,118 @@ pub fn default_exercises() -> Vec<Exercise> {
            ExerciseTrackingType::Strength),
     ]
 }
+
+/// One routine of a prebuilt program: exercise ids from `default_exercises`
+/// with target (sets, reps) for each.
+pub struct RoutineTemplate {
+    pub name: &'static str,
+    pub exercises: &'static [(&'static str, u32, u32)],
+}
+
+impl RoutineTemplate {
+    /// An editable copy with a fresh id. Targets carry reps only; the weight is
+    /// left at 0 for the user to fill in.
+    pub fn to_routine(&self, program: &str) -> Routine {
+        Routine {
+            id: uuid::Uuid::new_v4().to_string(),
+            name: format!("{} \u{2014} {}", program, self.name),
+            exercise_ids: self.exercises.iter().map(|(id, _, _)| id.to_string()).collect(),
+            targets: self
+                .exercises
+                .iter()
+                .map(|&(id, sets, reps)| {
+                    let target = SetTarget { weight: 0.0, reps };
+                    (id.to_string(), vec![target; sets as usize])
+                })
+                .collect(),
+        }
+    }
+}
+
+/// A beginner-friendly program offered on the Routines page.
+pub struct ProgramTemplate {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub routines: &'static [RoutineTemplate],
+}
+
+pub const PROGRAM_TEMPLATES: &[ProgramTemplate] = &[
+    ProgramTemplate {
+        name: "StrongLifts 5x5",
+        description: "Three full-body sessions a week, alternating A and B. Add weight every session.",
+        routines: &[
+            RoutineTemplate {
+                name: "Workout A",
+                exercises: &[("legs-01", 5, 5), ("chest-01", 5, 5), ("back-03", 5, 5)],
+            },
+            RoutineTemplate {
+                name: "Workout B",
+                exercises: &[("legs-01", 5, 5), ("shldr-01", 5, 5), ("back-05", 1, 5)],
+            },
+        ],
+    },
+    ProgramTemplate {
+        name: "Push Pull Legs",
+        description: "Three or six days a week, each muscle hit once per rotation.",
+        routines: &[
+            RoutineTemplate {
+                name: "Push",
+                exercises: &[
+                    ("chest-01", 4, 8),
+                    ("shldr-01", 3, 8),
+                    ("chest-05", 3, 10),
+                    ("shldr-03", 3, 15),
+                    ("arms-07", 3, 12),
+                ],
+            },
+            RoutineTemplate {
+                name: "Pull",
+                exercises: &[
+                    ("back-05", 3, 5),
+                    ("back-07", 3, 8),
+                    ("back-02", 3, 10),
+                    ("shldr-05", 3, 15),
+                    ("arms-01", 3, 10),
+                ],
+            },
+            RoutineTemplate {
+                name: "Legs",
+                exercises: &[
+                    ("legs-01", 4, 8),
+                    ("back-06", 3, 10),
+                    ("legs-03", 3, 12),
+                    ("legs-05", 3, 12),
+                    ("legs-10", 4, 15),
+                ],
+            },
+        ],
+    },
+    ProgramTemplate {
+        name: "Upper/Lower",
+        description: "Four days a week, each muscle trained twice.",
+        routines: &[
+            RoutineTemplate {
+                name: "Upper",
+                exercises: &[
+                    ("chest-01", 4, 6),
+                    ("back-03", 4, 8),
+                    ("shldr-02", 3, 10),
+                    ("back-01", 3, 10),
+                    ("arms-02", 2, 12),
+                    ("arms-08", 2, 12),
+                ],
+            },
+            RoutineTemplate {
+                name: "Lower",
+                exercises: &[
+                    ("legs-01", 4, 6),
+                    ("back-06", 3, 8),
+                    ("legs-08", 3, 10),
+                    ("legs-05", 3, 12),
+                    ("legs-10", 3, 15),
+                    ("core-03", 3, 12),
+                ],
+            },
+        ],
+    },
+];
@@ -0,0 +1,117 @@
+//! Calendar dates as stored on workouts, body metrics and notes. A stored date
+//! is always the device's local date at the moment the thing happened, so
+//! everything that creates one goes through here.
+
+use chrono::{DateTime, Datelike, Local, NaiveDate, TimeZone};
+
+/// Storage format for calendar dates.
+pub const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// Today's date in the device's local timezone.
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// Today's date in storage format.
+pub fn today_str() -> String {
+    format_date(today())
+}
+
+/// A date in storage format, e.g. "2026-03-14".
+pub fn format_date(date: NaiveDate) -> String {
+    date.format(DATE_FORMAT).to_string()
+}
+
+/// Short ISO week label, e.g. "W7".
+pub fn iso_week_label(d: NaiveDate) -> String {
+    let iso = d.iso_week();
+    format!("W{}", iso.week())
+}
+
+/// (ISO year, ISO week) of a date, for bucketing by week.
+pub fn iso_week_key(d: NaiveDate) -> (i32, u32) {
+    let iso = d.iso_week();
+    (iso.year(), iso.week())
+}
+
+/// The device's current UTC offset, e.g. "+02:00".
+pub fn utc_offset() -> String {
+    Local::now().format("%:z").to_string()
+}
+
+/// When a session started, from its `js_sys::Date::now()` start timestamp (ms
+/// since the epoch), or now if it has none. Uses the UTC offset in force at
+/// the start, so a session that runs past midnight or across a DST change
+/// keeps the date and time it began.
+pub fn session_start(started_ms: f64) -> DateTime<Local> {
+    session_start_in(&Local, started_ms, Local::now())
+}
+
+fn session_start_in<Tz: TimeZone>(tz: &Tz, started_ms: f64, now: DateTime<Tz>) -> DateTime<Tz> {
+    Some(started_ms)
+        .filter(|ms| *ms > 0.0)
+        .and_then(|ms| tz.timestamp_millis_opt(ms as i64).single())
+        .unwrap_or(now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    fn at(tz: &FixedOffset, y: i32, m: u32, d: u32, h: u32, min: u32) -> DateTime<FixedOffset> {
+        tz.with_ymd_and_hms(y, m, d, h, min, 0).unwrap()
+    }
+
+    fn ms(t: DateTime<FixedOffset>) -> f64 {
+        t.timestamp_millis() as f64
+    }
+
+    #[test]
+    fn late_night_session_is_dated_by_its_start() {
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+        let started = at(&cest, 2026, 6, 12, 23, 50);
+        let saved = at(&cest, 2026, 6, 13, 0, 40);
+
+        let start = session_start_in(&cest, ms(started), saved);
+        assert_eq!(format_date(start.date_naive()), "2026-06-12");
+        assert_eq!(start, started);
+    }
+
+    #[test]
+    fn session_without_a_start_is_dated_now() {
+        let cest = FixedOffset::east_opt(2 * 3600).unwrap();
+        let saved = at(&cest, 2026, 6, 13, 0, 40);
+
+        assert_eq!(session_start_in(&cest, 0.0, saved), saved);
+        assert_eq!(session_start_in(&cest, -1.0, saved), saved);
+    }
+
+    #[test]
+    fn session_keeps_the_offset_it_started_under() {
+        // US clocks spring forward at 02:00 on 2026-03-08: EST (-05:00) to EDT (-04:00)
+        let est = FixedOffset::west_opt(5 * 3600).unwrap();
+        let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+        let started = at(&est, 2026, 3, 8, 1, 30);
+        let saved = at(&edt, 2026, 3, 8, 3, 30);
+
+        let start = session_start_in(&est, ms(started), saved);
+        assert_eq!(format_date(start.date_naive()), "2026-03-08");
+        assert_eq!(start.to_rfc3339(), "2026-03-08T01:30:00-05:00");
+    }
+
+    #[test]
+    fn repeated_hour_on_fall_back_keeps_sessions_apart() {
+        // US clocks fall back at 02:00 on 2026-11-01, so 01:30 happens twice
+        let edt = FixedOffset::west_opt(4 * 3600).unwrap();
+        let est = FixedOffset::west_opt(5 * 3600).unwrap();
+        let saved = at(&est, 2026, 11, 1, 3, 0);
+        let first = session_start_in(&edt, ms(at(&edt, 2026, 11, 1, 1, 30)), saved);
+        let second = session_start_in(&est, ms(at(&est, 2026, 11, 1, 1, 30)), saved);
+
+        assert_eq!(format_date(first.date_naive()), "2026-11-01");
+        assert_eq!(format_date(first.date_naive()), format_date(second.date_naive()));
+        assert_ne!(first.to_rfc3339(), second.to_rfc3339());
+        assert!(second > first);
+    }
+}
@@ -0,0 +1,389 @@
+//! Lightweight UI translation layer.
+//!
+//! Strings are looked up by key with [`t`]. Each language has its own match
+//! table; a key missing from a translation falls back to English, and a key
+//! missing from English is returned as-is so gaps are easy to spot.
+
+use crate::models::Language;
+use crate::storage;
+use std::cell::Cell;
+
+thread_local! {
+    static CURRENT: Cell<Option<Language>> = const { Cell::new(None) };
+}
+
+/// Active UI language, read from the user config on first use.
+pub fn current_language() -> Language {
+    CURRENT.with(|c| match c.get() {
+        Some(lang) => lang,
+        None => {
+            let lang = storage::load_user_config().language;
+            c.set(Some(lang));
+            lang
+        }
+    })
+}
+
+/// Switch the active language for subsequent `t()` lookups.
+pub fn set_language(lang: Language) {
+    CURRENT.with(|c| c.set(Some(lang)));
+}
+
+/// Format a number with `decimals` places using the active language's decimal
+/// and thousands separators, e.g. 1234.5 -> "1,234.5" / "1.234,5".
+pub fn format_number(value: f64, decimals: usize) -> String {
+    let lang = current_language();
+    let raw = format!("{:.*}", decimals, value.abs());
+    let (int_part, frac_part) = match raw.split_once('.') {
+        Some((i, f)) => (i, Some(f)),
+        None => (raw.as_str(), None),
+    };
+    // Avoid "-0" when a tiny negative rounds away
+    let negative = value < 0.0 && raw.bytes().any(|b| b.is_ascii_digit() && b != b'0');
+    let mut out = String::new();
+    if negative {
+        out.push('-');
+    }
+    let len = int_part.len();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (len - i) % 3 == 0 {
+            out.push(lang.thousands_separator());
+        }
+        out.push(c);
+    }
+    if let Some(frac) = frac_part {
+        out.push(lang.decimal_separator());
+        out.push_str(frac);
+    }
+    out
+}
+
+/// Short form for large totals: "950", "12k", "1.5M" (localized).
+pub fn format_compact(value: f64) -> String {
+    if value >= 1_000_000.0 {
+        format!("{}M", format_number(value / 1_000_000.0, 1))
+    } else if value >= 1000.0 {
+        format!("{}k", format_number(value / 1000.0, 0))
+    } else {
+        format_number(value, 0)
+    }
+}
+
+/// Translate a UI string key into the active language.
+pub fn t(key: &'static str) -> &'static str {
+    let translated = match current_language() {
+        Language::En => None,
+        Language::Es => es(key),
+        Language::De => de(key),
+    };
+    translated.or_else(|| en(key)).unwrap_or(key)
+}
+
+fn en(key: &str) -> Option<&'static str> {
+    Some(match key {
+        // Common
+        "common.cancel" => "Cancel",
+        "common.delete" => "Delete",
+        "common.add" => "+ Add",
+        "common.select" => "Select...",
+        "common.exercises" => "exercises",
+        "common.sets" => "sets",
+
+        // Settings page
+        "settings.title" => "Settings",
+        "settings.profile" => "Personal Profile",
+        "settings.nickname" => "Nickname",
+        "settings.units" => "Units",
+        "settings.units_metric" => "Metric (kg, km, cm)",
+        "settings.units_imperial" => "Imperial (lbs, mi, in)",
+        "settings.height" => "Height",
+        "settings.gender" => "Gender",
+        "settings.gender_male" => "Male",
+        "settings.gender_female" => "Female",
+        "settings.gender_other" => "Other",
+        "settings.birth_date" => "Birth Date",
+        "settings.rest_timer" => "Rest Timer (sec)",
+        "settings.rest_timer_hint" => "Countdown after completing a set",
+        "settings.bar_weight" => "Bar Weight",
+        "settings.bar_weight_hint" => "Empty barbell weight, used for warm-ups and plate calculator",
+        "settings.update_profile" => "Update Profile",
+        "settings.body_progress" => "Body Progress",
+        "settings.log_weight" => "+ Log Weight",
+        "settings.weight" => "Weight",
+        "settings.body_fat" => "Body Fat %",
+        "settings.save_measurement" => "Save Measurement",
+        "settings.no_measurements" => "No measurements yet.",
+        "settings.app_info" => "App Info",
+        "settings.faq" => "Frequently Asked Questions",
+        "settings.version" => "Version: ",
+        "settings.release_notes" => "View Release Notes →",
+        "settings.custom_exercises" => "Custom Exercises",
+        "settings.no_custom_exercises" => "No custom exercises yet.",
+        "settings.buy_coffee" => "Buy Me a Coffee",
+        "settings.about" => "About Treening",
+        "settings.language" => "Language",
+        "settings.language_hint" => "Interface and Coach T language",
+
+        // Home page
+        "home.subtitle" => "Workout Tracker",
+        "home.start_workout" => "Start New Workout",
+        "home.plate_calc" => "Plate Calc",
+        "home.your_progress" => "Your Progress",
+        "home.full_analytics" => "Full Analytics →",
+        "home.sessions" => "Sessions",
+        "home.rel_volume" => "Rel. Volume",
+        "home.total_volume" => "Total Volume",
+        "home.day_streak" => "Day Streak",
+        "home.best" => "Best",
+        "home.community" => "Community",
+        "home.optional" => "Optional",
+        "home.community_pitch" => "Train with friends, share stats, and see your rankings in the community.",
+        "home.join_community" => "🚀 Join Community",
+        "home.friend_ranking" => "Friend Ranking",
+        "home.view_all" => "View All →",
+        "home.no_friends" => "No friends added yet.",
+        "home.add_first_friend" => "Add your first friend →",
+        "home.offline" => "Offline",
+        "home.weekly_summary" => "Coach T Weekly Summary",
+        "home.generating" => "Generating...",
+        "home.regenerate" => "Regenerate",
+        "home.generating_summary" => "Generating summary...",
+        "home.training_intelligence" => "Training Intelligence",
+        "home.all_balanced" => "All muscles balanced",
+        "home.undertrained" => "undertrained",
+        "home.overtrained" => "overtrained",
+        "home.routines" => "Routines",
+        "home.edit_all" => "Edit All →",
+        "home.last_workout" => "Last Workout",
+        "home.history" => "History →",
+        "home.summary" => "Summary",
+        "home.and" => "and ",
+        "home.more" => " more...",
+        "home.welcome" => "Welcome to Treening!",
+        "home.welcome_sub" => "Your privacy-first, offline workout tracker.",
+        "home.quick_start" => "Quick Start Guide",
+        "home.step1_title" => "Explore Exercises",
+        "home.step1_body" => "Browse over 80 built-in exercises with muscle group info and images.",
+        "home.step2_title" => "Create a Routine",
+        "home.step2_body" => "Save your favorite workouts (e.g., 'Push Day') for one-tap starting.",
+        "home.step3_title" => "Log Your Session",
+        "home.step3_body" => "Track sets, reps, and weight. Your data stays 100% on your device.",
+        "home.step4_title" => "Track Body Progress",
+        "home.step4_body" => "Log your weight in Settings to unlock 'Relative Volume' stats and charts.",
+        "home.step5_title" => "Meet Coach T",
+        "home.step5_body" => "Enable Coach T in Settings for a personal AI coach. Ask for advice, log workouts via chat, and get weekly summaries — all offline.",
+        "home.settings_sync" => "Settings & Sync",
+        "home.settings_sync_sub" => "App preferences, data backup, and P2P sync",
+        "home.treen_toast" => "🪵 Treen Mode activated! Wood grain unlocked.",
+        "home.todays_plan" => "Today's Plan",
+        "home.scheduled_today" => "Scheduled for today",
+        "home.suggested_today" => "Suggested — its exercises are the most rested",
+        "home.done_today" => "Done for today",
+        "home.start" => "Start",
+        _ => return None,
+    })
+}
+
+fn es(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "common.cancel" => "Cancelar",
+        "common.delete" => "Eliminar",
+        "common.add" => "+ Añadir",
+        "common.select" => "Seleccionar...",
+        "common.exercises" => "ejercicios",
+        "common.sets" => "series",
+
+        "settings.title" => "Ajustes",
+        "settings.profile" => "Perfil personal",
+        "settings.nickname" => "Apodo",
+        "settings.units" => "Unidades",
+        "settings.units_metric" => "Métrico (kg, km, cm)",
+        "settings.units_imperial" => "Imperial (lbs, mi, in)",
+        "settings.height" => "Altura",
+        "settings.gender" => "Género",
+        "settings.gender_male" => "Hombre",
+        "settings.gender_female" => "Mujer",
+        "settings.gender_other" => "Otro",
+        "settings.birth_date" => "Fecha de nacimiento",
+        "settings.rest_timer" => "Descanso (seg)",
+        "settings.rest_timer_hint" => "Cuenta atrás tras completar una serie",
+        "settings.bar_weight" => "Peso de la barra",
+        "settings.bar_weight_hint" => "Peso de la barra vacía, usado en calentamientos y la calculadora de discos",
+        "settings.update_profile" => "Actualizar perfil",
+        "settings.body_progress" => "Progreso corporal",
+        "settings.log_weight" => "+ Registrar peso",
+        "settings.weight" => "Peso",
+        "settings.body_fat" => "% de grasa",
+        "settings.save_measurement" => "Guardar medición",
+        "settings.no_measurements" => "Aún no hay mediciones.",
+        "settings.app_info" => "Información",
+        "settings.faq" => "Preguntas frecuentes",
+        "settings.version" => "Versión: ",
+        "settings.release_notes" => "Ver notas de la versión →",
+        "settings.custom_exercises" => "Ejercicios personalizados",
+        "settings.no_custom_exercises" => "Aún no hay ejercicios personalizados.",
+        "settings.buy_coffee" => "Invítame a un café",
+        "settings.about" => "Acerca de Treening",
+        "settings.language" => "Idioma",
+        "settings.language_hint" => "Idioma de la interfaz y de Coach T",
+
+        "home.subtitle" => "Registro de entrenamientos",
+        "home.start_workout" => "Nuevo entrenamiento",
+        "home.plate_calc" => "Discos",
+        "home.your_progress" => "Tu progreso",
+        "home.full_analytics" => "Ver estadísticas →",
+        "home.sessions" => "Sesiones",
+        "home.rel_volume" => "Vol. relativo",
+        "home.total_volume" => "Volumen total",
+        "home.day_streak" => "Racha (días)",
+        "home.best" => "Mejor",
+        "home.community" => "Comunidad",
+        "home.optional" => "Opcional",
+        "home.community_pitch" => "Entrena con amigos, comparte estadísticas y mira tu posición en la comunidad.",
+        "home.join_community" => "🚀 Unirse a la comunidad",
+        "home.friend_ranking" => "Ranking de amigos",
+        "home.view_all" => "Ver todo →",
+        "home.no_friends" => "Aún no has añadido amigos.",
+        "home.add_first_friend" => "Añade tu primer amigo →",
+        "home.offline" => "Desconectado",
+        "home.weekly_summary" => "Resumen semanal de Coach T",
+        "home.generating" => "Generando...",
+        "home.regenerate" => "Regenerar",
+        "home.generating_summary" => "Generando resumen...",
+        "home.training_intelligence" => "Análisis de entrenamiento",
+        "home.all_balanced" => "Todos los músculos equilibrados",
+        "home.undertrained" => "poco entrenados",
+        "home.overtrained" => "sobreentrenados",
+        "home.routines" => "Rutinas",
+        "home.edit_all" => "Editar todas →",
+        "home.last_workout" => "Último entrenamiento",
+        "home.history" => "Historial →",
+        "home.summary" => "Resumen",
+        "home.and" => "y ",
+        "home.more" => " más...",
+        "home.welcome" => "¡Bienvenido a Treening!",
+        "home.welcome_sub" => "Tu registro de entrenamientos privado y sin conexión.",
+        "home.quick_start" => "Guía rápida",
+        "home.step1_title" => "Explora ejercicios",
+        "home.step1_body" => "Más de 80 ejercicios incluidos con información muscular e imágenes.",
+        "home.step2_title" => "Crea una rutina",
+        "home.step2_body" => "Guarda tus entrenamientos favoritos (p. ej., 'Día de empuje') para empezar con un toque.",
+        "home.step3_title" => "Registra tu sesión",
+        "home.step3_body" => "Anota series, repeticiones y peso. Tus datos se quedan al 100% en tu dispositivo.",
+        "home.step4_title" => "Sigue tu progreso corporal",
+        "home.step4_body" => "Registra tu peso en Ajustes para desbloquear el 'Volumen relativo' y sus gráficas.",
+        "home.step5_title" => "Conoce a Coach T",
+        "home.step5_body" => "Activa Coach T en Ajustes para tener un entrenador con IA. Pide consejos, registra entrenamientos por chat y recibe resúmenes semanales, todo sin conexión.",
+        "home.settings_sync" => "Ajustes y sincronización",
+        "home.settings_sync_sub" => "Preferencias, copias de seguridad y sincronización P2P",
+        "home.treen_toast" => "🪵 ¡Modo Treen activado! Vetas de madera desbloqueadas.",
+        "home.todays_plan" => "Plan de hoy",
+        "home.scheduled_today" => "Programada para hoy",
+        "home.suggested_today" => "Sugerida: sus ejercicios son los más descansados",
+        "home.done_today" => "Hecho por hoy",
+        "home.start" => "Empezar",
+        _ => return None,
+    })
+}
+
+fn de(key: &str) -> Option<&'static str> {
+    Some(match key {
+        "common.cancel" => "Abbrechen",
+        "common.delete" => "Löschen",
+        "common.add" => "+ Hinzufügen",
+        "common.select" => "Auswählen...",
+        "common.exercises" => "Übungen",
+        "common.sets" => "Sätze",
+
+        "settings.title" => "Einstellungen",
+        "settings.profile" => "Persönliches Profil",
+        "settings.nickname" => "Spitzname",
+        "settings.units" => "Einheiten",
+        "settings.units_metric" => "Metrisch (kg, km, cm)",
+        "settings.units_imperial" => "Imperial (lbs, mi, in)",
+        "settings.height" => "Größe",
+        "settings.gender" => "Geschlecht",
+        "settings.gender_male" => "Männlich",
+        "settings.gender_female" => "Weiblich",
+        "settings.gender_other" => "Divers",
+        "settings.birth_date" => "Geburtsdatum",
+        "settings.rest_timer" => "Pausentimer (Sek.)",
+        "settings.rest_timer_hint" => "Countdown nach einem abgeschlossenen Satz",
+        "settings.bar_weight" => "Stangengewicht",
+        "settings.bar_weight_hint" => "Gewicht der leeren Stange, für Aufwärmsätze und den Scheibenrechner",
+        "settings.update_profile" => "Profil aktualisieren",
+        "settings.body_progress" => "Körperfortschritt",
+        "settings.log_weight" => "+ Gewicht eintragen",
+        "settings.weight" => "Gewicht",
+        "settings.body_fat" => "Körperfett %",
+        "settings.save_measurement" => "Messung speichern",
+        "settings.no_measurements" => "Noch keine Messungen.",
+        "settings.app_info" => "App-Info",
+        "settings.faq" => "Häufige Fragen",
+        "settings.version" => "Version: ",
+        "settings.release_notes" => "Versionshinweise ansehen →",
+        "settings.custom_exercises" => "Eigene Übungen",
+        "settings.no_custom_exercises" => "Noch keine eigenen Übungen.",
+        "settings.buy_coffee" => "Spendier mir einen Kaffee",
+        "settings.about" => "Über Treening",
+        "settings.language" => "Sprache",
+        "settings.language_hint" => "Sprache der Oberfläche und von Coach T",
+
+        "home.subtitle" => "Trainingstagebuch",
+        "home.start_workout" => "Neues Training starten",
+        "home.plate_calc" => "Scheibenrechner",
+        "home.your_progress" => "Dein Fortschritt",
+        "home.full_analytics" => "Alle Statistiken →",
+        "home.sessions" => "Einheiten",
+        "home.rel_volume" => "Rel. Volumen",
+        "home.total_volume" => "Gesamtvolumen",
+        "home.day_streak" => "Tage in Folge",
+        "home.best" => "Bestwert",
+        "home.community" => "Community",
+        "home.optional" => "Optional",
+        "home.community_pitch" => "Trainiere mit Freunden, teile Statistiken und sieh dein Ranking in der Community.",
+        "home.join_community" => "🚀 Community beitreten",
+        "home.friend_ranking" => "Freunde-Ranking",
+        "home.view_all" => "Alle ansehen →",
+        "home.no_friends" => "Noch keine Freunde hinzugefügt.",
+        "home.add_first_friend" => "Füge deinen ersten Freund hinzu →",
+        "home.offline" => "Offline",
+        "home.weekly_summary" => "Coach T Wochenrückblick",
+        "home.generating" => "Wird erstellt...",
+        "home.regenerate" => "Neu erstellen",
+        "home.generating_summary" => "Rückblick wird erstellt...",
+        "home.training_intelligence" => "Trainingsanalyse",
+        "home.all_balanced" => "Alle Muskeln ausgeglichen",
+        "home.undertrained" => "untertrainiert",
+        "home.overtrained" => "übertrainiert",
+        "home.routines" => "Trainingspläne",
+        "home.edit_all" => "Alle bearbeiten →",
+        "home.last_workout" => "Letztes Training",
+        "home.history" => "Verlauf →",
+        "home.summary" => "Übersicht",
+        "home.and" => "und ",
+        "home.more" => " weitere...",
+        "home.welcome" => "Willkommen bei Treening!",
+        "home.welcome_sub" => "Dein datenschutzfreundliches Offline-Trainingstagebuch.",
+        "home.quick_start" => "Schnellstart",
+        "home.step1_title" => "Übungen entdecken",
+        "home.step1_body" => "Über 80 eingebaute Übungen mit Muskelgruppen und Bildern.",
+        "home.step2_title" => "Plan erstellen",
+        "home.step2_body" => "Speichere deine Lieblingstrainings (z. B. 'Push-Tag') zum Starten mit einem Tipp.",
+        "home.step3_title" => "Training protokollieren",
+        "home.step3_body" => "Erfasse Sätze, Wiederholungen und Gewicht. Deine Daten bleiben zu 100 % auf deinem Gerät.",
+        "home.step4_title" => "Körperfortschritt verfolgen",
+        "home.step4_body" => "Trage dein Gewicht in den Einstellungen ein, um 'Relatives Volumen' und Diagramme freizuschalten.",
+        "home.step5_title" => "Lerne Coach T kennen",
+        "home.step5_body" => "Aktiviere Coach T in den Einstellungen für einen persönlichen KI-Coach. Hol dir Tipps, protokolliere Trainings per Chat und erhalte Wochenrückblicke – alles offline.",
+        "home.settings_sync" => "Einstellungen & Sync",
+        "home.settings_sync_sub" => "App-Einstellungen, Datensicherung und P2P-Sync",
+        "home.treen_toast" => "🪵 Treen-Modus aktiviert! Holzmaserung freigeschaltet.",
+        "home.todays_plan" => "Heutiger Plan",
+        "home.scheduled_today" => "Für heute geplant",
+        "home.suggested_today" => "Vorschlag: Die Übungen sind am besten erholt",
+        "home.done_today" => "Für heute erledigt",
+        "home.start" => "Starten",
+        _ => return None,
+    })
+}
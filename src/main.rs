@@ -1,6 +1,8 @@
 mod backup;
 mod components;
 mod data;
+mod dates;
+mod i18n;
 mod models;
 mod muscle_data;
 mod pages;
@@ -118,7 +120,12 @@ fn switch(routes: Route) -> Html {
         Route::Muscles => ("muscles", html! { <MusclesPage /> }),
         Route::NotFound => ("home", html! { <HomePage /> }),
     };
-    html! { <div key={key} class="page-enter">{page}</div> }
+    let motion = if storage::reduce_motion(&storage::load_user_config()) {
+        ""
+    } else {
+        "page-enter"
+    };
+    html! { <div key={key} class={motion}>{page}</div> }
 }
 
 #[function_component(FloatingLogButton)]
@@ -153,19 +160,33 @@ fn floating_log_button() -> Html {
 fn app() -> Html {
     let storage_warning = use_state(|| false);
     let warning_dismissed = use_state(|| false);
+    let backup_toast = use_state(|| None::<String>);
+    let backup_due = use_state(|| false);
+    let corrupt_keys = use_state(Vec::<&'static str>::new);
+    let restore_error = use_state(|| None::<String>);
 
     // Startup: request persistent storage, try restore from backup, check save errors
     {
         let storage_warning = storage_warning.clone();
+        let backup_due = backup_due.clone();
+        let corrupt_keys = corrupt_keys.clone();
         use_effect_with((), move |_| {
             backup::request_persistent_storage();
             storage::try_restore_from_backup();
 
+            // Scheduled backup file: remind rather than download, since
+            // browsers block downloads that don't come from a tap
+            backup_due.set(backup::file_backup_due(storage::load_user_config().auto_backup_days));
+
             // Check for save failures periodically
             let interval = gloo::timers::callback::Interval::new(2_000, move || {
                 if storage::has_save_failed() {
                     storage_warning.set(true);
                 }
+                let keys = storage::corrupt_keys();
+                if keys.len() != corrupt_keys.len() {
+                    corrupt_keys.set(keys);
+                }
             });
             move || drop(interval)
         });
@@ -179,6 +200,49 @@ fn app() -> Html {
         })
     };
 
+    let on_restore_backup = {
+        let restore_error = restore_error.clone();
+        Callback::from(move |_: MouseEvent| {
+            let restore_error = restore_error.clone();
+            backup::load_backup(move |data| match data.filter(|json| !json.is_empty()) {
+                Some(json) => match storage::import_all_data(&json) {
+                    Ok(()) => {
+                        storage::clear_corrupt_keys();
+                        let _ = gloo::utils::window().location().reload();
+                    }
+                    Err(e) => restore_error.set(Some(format!("Backup could not be restored: {}", e))),
+                },
+                None => restore_error.set(Some("No backup found. Import an exported file from Settings instead.".to_string())),
+            });
+        })
+    };
+
+    let on_download_backup = {
+        let backup_due = backup_due.clone();
+        let backup_toast = backup_toast.clone();
+        Callback::from(move |_: MouseEvent| {
+            backup_due.set(false);
+            if let Some(filename) = backup::download_backup_file() {
+                backup_toast.set(Some(format!("Backup saved to Downloads: {}", filename)));
+                let toast = backup_toast.clone();
+                gloo::timers::callback::Timeout::new(4_000, move || toast.set(None)).forget();
+            }
+        })
+    };
+
+    let on_dismiss_backup = {
+        let backup_due = backup_due.clone();
+        Callback::from(move |_: MouseEvent| backup_due.set(false))
+    };
+
+    let on_dismiss_corrupt = {
+        let corrupt_keys = corrupt_keys.clone();
+        Callback::from(move |_: MouseEvent| {
+            storage::clear_corrupt_keys();
+            corrupt_keys.set(Vec::new());
+        })
+    };
+
     html! {
         <HashRouter>
             <ThemeManager />
@@ -191,6 +255,45 @@ fn app() -> Html {
                         </button>
                     </div>
                 }
+                if !corrupt_keys.is_empty() {
+                    <div class="bg-red-600 text-white px-4 py-2 text-sm space-y-2">
+                        <div class="flex items-center justify-between">
+                            <span>
+                                {format!("Some saved data could not be read ({}). The original was kept under a \"_corrupt_backup\" key.",
+                                    corrupt_keys.iter().map(|k| k.trim_start_matches("treening_")).collect::<Vec<_>>().join(", "))}
+                            </span>
+                            <button onclick={on_dismiss_corrupt} class="ml-4 font-bold text-lg leading-none hover:opacity-70">
+                                {"\u{00d7}"}
+                            </button>
+                        </div>
+                        <div class="flex items-center gap-3">
+                            <button onclick={on_restore_backup} class="bg-white text-red-700 font-bold px-3 py-1 rounded-lg text-xs hover:bg-red-50 transition-colors">
+                                {"Restore Last Backup"}
+                            </button>
+                            if let Some(err) = &*restore_error {
+                                <span class="text-xs">{err}</span>
+                            }
+                        </div>
+                    </div>
+                }
+                if *backup_due {
+                    <div class="bg-blue-600 text-white px-4 py-2 text-sm flex items-center justify-between gap-3">
+                        <span>{"A scheduled backup is due."}</span>
+                        <div class="flex items-center gap-3">
+                            <button onclick={on_download_backup} class="bg-white text-blue-700 font-bold px-3 py-1 rounded-lg text-xs hover:bg-blue-50 transition-colors">
+                                {"Download Backup"}
+                            </button>
+                            <button onclick={on_dismiss_backup} class="font-bold text-lg leading-none hover:opacity-70">
+                                {"\u{00d7}"}
+                            </button>
+                        </div>
+                    </div>
+                }
+                if let Some(msg) = &*backup_toast {
+                    <div class="fixed top-4 left-4 right-4 z-50 bg-green-600 text-white px-4 py-3 rounded-xl shadow-lg text-center font-bold text-sm" style="animation: modalContentIn 200ms ease-out;">
+                        {format!("\u{1f4be} {}", msg)}
+                    </div>
+                }
                 <div class="flex-grow">
                     <Switch<Route> render={switch} />
                 </div>
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -39,6 +39,44 @@ impl Category {
             Category::Cardio,
         ]
     }
+
+    /// Default metabolic equivalent (MET) for training in this category, used
+    /// for rough calorie estimates. Values follow the Compendium of Physical
+    /// Activities ranges for resistance training and general cardio.
+    pub fn default_met(&self) -> f64 {
+        match self {
+            Category::Legs => 6.0,
+            Category::Back | Category::Chest => 5.0,
+            Category::Shoulders => 4.5,
+            Category::Core => 3.8,
+            Category::Arms => 3.5,
+            Category::Cardio => 7.0,
+        }
+    }
+
+    /// Default shortest rest (seconds) between strength sets of one exercise
+    /// before a "rest more" warning; big compound groups need the most.
+    pub fn default_min_rest(&self) -> u32 {
+        match self {
+            Category::Legs | Category::Back | Category::Chest | Category::Shoulders => 60,
+            Category::Arms => 45,
+            Category::Core => 30,
+            Category::Cardio => 0,
+        }
+    }
+
+    /// Default chart color (hex) for this category.
+    pub fn default_color(&self) -> &'static str {
+        match self {
+            Category::Chest => "#ef4444",
+            Category::Back => "#3b82f6",
+            Category::Legs => "#22c55e",
+            Category::Shoulders => "#f59e0b",
+            Category::Arms => "#a855f7",
+            Category::Core => "#ec4899",
+            Category::Cardio => "#06b6d4",
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -68,6 +106,21 @@ impl fmt::Display for Equipment {
     }
 }
 
+impl Equipment {
+    pub fn all() -> Vec<Equipment> {
+        vec![
+            Equipment::Barbell,
+            Equipment::Dumbbell,
+            Equipment::Machine,
+            Equipment::Cable,
+            Equipment::Bodyweight,
+            Equipment::Kettlebell,
+            Equipment::Band,
+            Equipment::Other,
+        ]
+    }
+}
+
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub enum ExerciseTrackingType {
     #[default]
@@ -77,6 +130,19 @@ pub enum ExerciseTrackingType {
     Bodyweight, // Reps only
 }
 
+impl ExerciseTrackingType {
+    /// Parse the variant name used as a `<select>` value.
+    pub fn from_key(key: &str) -> Option<Self> {
+        match key {
+            "Strength" => Some(ExerciseTrackingType::Strength),
+            "Cardio" => Some(ExerciseTrackingType::Cardio),
+            "Duration" => Some(ExerciseTrackingType::Duration),
+            "Bodyweight" => Some(ExerciseTrackingType::Bodyweight),
+            _ => None,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct Exercise {
     pub id: String,
@@ -89,6 +155,26 @@ pub struct Exercise {
     pub image: Option<String>,
     #[serde(default)]
     pub tracking_type: ExerciseTrackingType,
+    /// Bar weight in kg for the plate calculator, overriding the global default.
+    #[serde(default)]
+    pub bar_weight_override: Option<f64>,
+    /// Other names people use for it (e.g. "OHP", "RDL"), matched when parsing free text.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub aliases: Vec<String>,
+    /// Form cues shown alongside the description (e.g. "brace, then unrack").
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub instructions: Option<String>,
+    /// Logged weight is assistance (counterweight machines), so lower is better.
+    #[serde(default)]
+    pub assisted: bool,
+    /// Weight is entered per dumbbell; sets store the total for both hands.
+    #[serde(default)]
+    pub per_hand: bool,
+    /// Cardio with no meaningful distance (stair climber, air bike); only time is logged.
+    #[serde(default)]
+    pub time_only: bool,
+    #[serde(default)]
+    pub difficulty: Difficulty,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -104,6 +190,71 @@ pub struct WorkoutSet {
     pub completed: bool,
     #[serde(default)]
     pub note: Option<String>,
+    /// Seconds actually rested since the previous completed set in this session.
+    #[serde(default)]
+    pub rest_taken_secs: Option<u32>,
+    #[serde(default)]
+    pub failure: Option<SetFailure>,
+    /// Body weight (kg) moved in this set, recorded when a bodyweight exercise's
+    /// set is ticked. `weight` is then the load added on top (belt, vest).
+    #[serde(default)]
+    pub bodyweight: Option<f64>,
+    /// When the set was ticked done (ms since the epoch), for the short-rest warning.
+    #[serde(default)]
+    pub completed_at: Option<f64>,
+}
+
+/// How a set ended, for autoregulation: technical failure means form broke
+/// down before the muscle gave out, to failure means no further rep was possible.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SetFailure {
+    Technical,
+    Muscular,
+}
+
+impl SetFailure {
+    pub fn all() -> Vec<SetFailure> {
+        vec![SetFailure::Muscular, SetFailure::Technical]
+    }
+
+    pub fn display_name(&self) -> &str {
+        match self {
+            SetFailure::Technical => "Technical failure",
+            SetFailure::Muscular => "To failure",
+        }
+    }
+
+    /// Compact badge text for set rows.
+    pub fn short_label(&self) -> &str {
+        match self {
+            SetFailure::Technical => "TF",
+            SetFailure::Muscular => "F",
+        }
+    }
+}
+
+impl WorkoutSet {
+    /// Total load moved: added weight plus any recorded body weight.
+    pub fn load(&self) -> f64 {
+        self.weight + self.bodyweight.unwrap_or(0.0)
+    }
+
+    /// Cluster/rest-pause breakdown written at the start of the set note,
+    /// e.g. "8+2" or "6+3+2 rest-pause" -> [8, 2] / [6, 3, 2].
+    pub fn cluster_reps(&self) -> Option<Vec<u32>> {
+        let token = self.note.as_deref()?.split_whitespace().next()?;
+        if !token.contains('+') {
+            return None;
+        }
+        let parts: Option<Vec<u32>> = token.split('+').map(|p| p.parse().ok()).collect();
+        parts.filter(|p| p.len() >= 2)
+    }
+
+    /// 0.5 kg wide load bucket used to compare rep PRs at the same weight.
+    /// Bodyweight sets (weight 0) all share bracket 0.
+    pub fn weight_bracket(&self) -> i64 {
+        (self.weight * 2.0).round() as i64
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -115,9 +266,73 @@ pub struct WorkoutExercise {
     pub superset_group: Option<u32>,
     #[serde(default)]
     pub rest_seconds_override: Option<u32>,
+    /// Lifting tempo as "eccentric-pause-concentric-pause" seconds, e.g. "3-1-1-0".
+    #[serde(default)]
+    pub tempo: Option<String>,
+}
+
+/// Seconds per rep assumed when no tempo is recorded.
+pub const DEFAULT_SECS_PER_REP: f64 = 2.0;
+
+/// Parse a tempo string like "3-1-1-0" (or "3110") into total seconds per rep.
+/// Non-numeric markers such as "X" (explosive) count as zero seconds.
+pub fn tempo_secs_per_rep(tempo: &str) -> Option<f64> {
+    let tempo = tempo.trim();
+    if tempo.is_empty() {
+        return None;
+    }
+    let parts: Vec<String> = if tempo.contains(['-', '/', ':']) {
+        tempo.split(['-', '/', ':']).map(|p| p.to_string()).collect()
+    } else {
+        tempo.chars().map(|c| c.to_string()).collect()
+    };
+    let mut total = 0.0;
+    let mut any = false;
+    for p in &parts {
+        let p = p.trim();
+        if let Ok(v) = p.parse::<f64>() {
+            total += v;
+            any = true;
+        } else if !p.eq_ignore_ascii_case("x") {
+            return None;
+        }
+    }
+    if any && total > 0.0 {
+        Some(total)
+    } else {
+        None
+    }
 }
 
 impl WorkoutExercise {
+    /// Estimated time under tension in seconds across completed sets.
+    /// Duration sets count their recorded time; rep sets use the tempo if set,
+    /// otherwise `DEFAULT_SECS_PER_REP`. Cardio distance sets are ignored.
+    pub fn time_under_tension_secs(&self) -> f64 {
+        let per_rep = self
+            .tempo
+            .as_deref()
+            .and_then(tempo_secs_per_rep)
+            .unwrap_or(DEFAULT_SECS_PER_REP);
+        self.sets
+            .iter()
+            .filter(|s| s.completed)
+            .map(|s| {
+                if let Some(secs) = s.duration_secs {
+                    if s.distance.is_some() {
+                        0.0
+                    } else {
+                        secs as f64
+                    }
+                } else if s.distance.is_some() {
+                    0.0
+                } else {
+                    s.reps as f64 * per_rep
+                }
+            })
+            .sum()
+    }
+
     pub fn volume(&self) -> f64 {
         self.sets
             .iter()
@@ -128,11 +343,20 @@ impl WorkoutExercise {
                 } else if let Some(secs) = s.duration_secs {
                     secs as f64 / 6.0 // Arbitrary duration weight: 1min = 10kg volume
                 } else {
-                    s.weight * s.reps as f64
+                    s.load() * s.reps as f64
                 }
             })
             .sum()
     }
+
+    /// Volume measured as `metric`; `Tonnage` is the same as `volume()`.
+    pub fn metric_volume(&self, metric: VolumeMetric) -> f64 {
+        match metric {
+            VolumeMetric::Tonnage => self.volume(),
+            VolumeMetric::HardSets => self.sets.iter().filter(|s| s.completed).count() as f64,
+            VolumeMetric::Reps => self.sets.iter().filter(|s| s.completed).map(|s| s.reps as f64).sum(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -142,12 +366,33 @@ pub struct Workout {
     pub name: String,
     pub exercises: Vec<WorkoutExercise>,
     pub duration_mins: u32,
+    /// Name of the gym profile that was active when the workout was saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gym: Option<String>,
+    /// How the session as a whole went ("slept badly, low energy").
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub notes: String,
+    /// When the session started, RFC 3339 with the local UTC offset at the
+    /// time. `date` is the local calendar date of this instant.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<String>,
 }
 
 impl Workout {
     pub fn total_volume(&self) -> f64 {
         self.exercises.iter().map(|e| e.volume()).sum()
     }
+
+    pub fn metric_volume(&self, metric: VolumeMetric) -> f64 {
+        self.exercises.iter().map(|e| e.metric_volume(metric)).sum()
+    }
+
+    pub fn time_under_tension_secs(&self) -> f64 {
+        self.exercises
+            .iter()
+            .map(|e| e.time_under_tension_secs())
+            .sum()
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -155,6 +400,16 @@ pub struct Routine {
     pub id: String,
     pub name: String,
     pub exercise_ids: Vec<String>,
+    /// Optional per-exercise target sets, keyed by exercise id, prefilled on start.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub targets: HashMap<String, Vec<SetTarget>>,
+}
+
+/// A planned set stored on a routine. Weight is in kg.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SetTarget {
+    pub weight: f64,
+    pub reps: u32,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
@@ -182,6 +437,75 @@ pub struct AppData {
     pub user_config: Option<UserConfig>,
     #[serde(default)]
     pub trusted_devices: Vec<TrustedDevice>,
+    #[serde(default)]
+    pub annotations: Vec<Annotation>,
+}
+
+/// What an annotation explains; sets its marker color on charts.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum AnnotationKind {
+    #[default]
+    Note,
+    Deload,
+    Injury,
+    Vacation,
+}
+
+impl AnnotationKind {
+    pub fn all() -> Vec<AnnotationKind> {
+        vec![
+            AnnotationKind::Note,
+            AnnotationKind::Deload,
+            AnnotationKind::Injury,
+            AnnotationKind::Vacation,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AnnotationKind::Note => "Note",
+            AnnotationKind::Deload => "Deload",
+            AnnotationKind::Injury => "Injury",
+            AnnotationKind::Vacation => "Vacation",
+        }
+    }
+
+    pub fn color(&self) -> &'static str {
+        match self {
+            AnnotationKind::Note => "#6b7280",
+            AnnotationKind::Deload => "#3b82f6",
+            AnnotationKind::Injury => "#ef4444",
+            AnnotationKind::Vacation => "#10b981",
+        }
+    }
+
+    pub fn from_str(s: &str) -> AnnotationKind {
+        match s {
+            "deload" => AnnotationKind::Deload,
+            "injury" => AnnotationKind::Injury,
+            "vacation" => AnnotationKind::Vacation,
+            _ => AnnotationKind::Note,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            AnnotationKind::Note => "note",
+            AnnotationKind::Deload => "deload",
+            AnnotationKind::Injury => "injury",
+            AnnotationKind::Vacation => "vacation",
+        }
+    }
+}
+
+/// Dated note ("back tweak", "deload week") drawn as a marker on time-series charts.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Annotation {
+    pub id: String,
+    pub date: String,
+    pub label: String,
+    #[serde(default)]
+    pub kind: AnnotationKind,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -210,6 +534,305 @@ pub enum Theme {
     Treen,
 }
 
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum Language {
+    #[default]
+    En,
+    Es,
+    De,
+}
+
+impl Language {
+    pub fn all() -> Vec<Language> {
+        vec![Language::En, Language::Es, Language::De]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Es => "Español",
+            Language::De => "Deutsch",
+        }
+    }
+
+    /// English name of the language, used to instruct the AI coach.
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Language::En => "English",
+            Language::Es => "Spanish",
+            Language::De => "German",
+        }
+    }
+
+    /// Decimal separator used when displaying numbers.
+    pub fn decimal_separator(&self) -> char {
+        match self {
+            Language::En => '.',
+            Language::Es | Language::De => ',',
+        }
+    }
+
+    /// Thousands grouping separator; Spanish uses a non-breaking space.
+    pub fn thousands_separator(&self) -> char {
+        match self {
+            Language::En => ',',
+            Language::Es => '\u{a0}',
+            Language::De => '.',
+        }
+    }
+
+    pub fn from_str(s: &str) -> Language {
+        match s {
+            "es" => Language::Es,
+            "de" => Language::De,
+            _ => Language::En,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            Language::En => "en",
+            Language::Es => "es",
+            Language::De => "de",
+        }
+    }
+}
+
+/// Skill level an exercise demands, matched against the user's experience so
+/// recommendations stay within reach. Untagged exercises count as intermediate.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Difficulty {
+    Beginner,
+    #[default]
+    Intermediate,
+    Advanced,
+}
+
+impl Difficulty {
+    pub fn all() -> Vec<Difficulty> {
+        vec![
+            Difficulty::Beginner,
+            Difficulty::Intermediate,
+            Difficulty::Advanced,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "Beginner",
+            Difficulty::Intermediate => "Intermediate",
+            Difficulty::Advanced => "Advanced",
+        }
+    }
+
+    pub fn from_key(s: &str) -> Difficulty {
+        match s {
+            "beginner" => Difficulty::Beginner,
+            "advanced" => Difficulty::Advanced,
+            _ => Difficulty::Intermediate,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            Difficulty::Beginner => "beginner",
+            Difficulty::Intermediate => "intermediate",
+            Difficulty::Advanced => "advanced",
+        }
+    }
+}
+
+/// Step that displayed weights are rounded to, in the display unit.
+/// Stored values are always kept precise in kg.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum WeightRounding {
+    #[default]
+    Off,
+    Half,
+    One,
+    TwoPointFive,
+}
+
+impl WeightRounding {
+    pub fn all() -> Vec<WeightRounding> {
+        vec![
+            WeightRounding::Off,
+            WeightRounding::Half,
+            WeightRounding::One,
+            WeightRounding::TwoPointFive,
+        ]
+    }
+
+    pub fn step(&self) -> Option<f64> {
+        match self {
+            WeightRounding::Off => None,
+            WeightRounding::Half => Some(0.5),
+            WeightRounding::One => Some(1.0),
+            WeightRounding::TwoPointFive => Some(2.5),
+        }
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            WeightRounding::Off => "Off (0.1)",
+            WeightRounding::Half => "Nearest 0.5",
+            WeightRounding::One => "Nearest 1",
+            WeightRounding::TwoPointFive => "Nearest 2.5",
+        }
+    }
+
+    pub fn from_str(s: &str) -> WeightRounding {
+        match s {
+            "0.5" => WeightRounding::Half,
+            "1" => WeightRounding::One,
+            "2.5" => WeightRounding::TwoPointFive,
+            _ => WeightRounding::Off,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            WeightRounding::Off => "off",
+            WeightRounding::Half => "0.5",
+            WeightRounding::One => "1",
+            WeightRounding::TwoPointFive => "2.5",
+        }
+    }
+}
+
+/// What "volume" means in analytics charts and summaries.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum VolumeMetric {
+    /// Weight × reps.
+    #[default]
+    Tonnage,
+    /// Completed sets.
+    HardSets,
+    /// Completed reps.
+    Reps,
+}
+
+impl VolumeMetric {
+    pub fn all() -> Vec<VolumeMetric> {
+        vec![VolumeMetric::Tonnage, VolumeMetric::HardSets, VolumeMetric::Reps]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            VolumeMetric::Tonnage => "Tonnage (weight \u{d7} reps)",
+            VolumeMetric::HardSets => "Hard sets",
+            VolumeMetric::Reps => "Total reps",
+        }
+    }
+
+    pub fn from_str(s: &str) -> VolumeMetric {
+        match s {
+            "sets" => VolumeMetric::HardSets,
+            "reps" => VolumeMetric::Reps,
+            _ => VolumeMetric::Tonnage,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            VolumeMetric::Tonnage => "tonnage",
+            VolumeMetric::HardSets => "sets",
+            VolumeMetric::Reps => "reps",
+        }
+    }
+
+    /// Unit suffix for chart titles, e.g. "Volume Per Week (sets)".
+    pub fn unit_label(&self, units: &UnitSystem) -> &'static str {
+        match self {
+            VolumeMetric::Tonnage => units.weight_label(),
+            VolumeMetric::HardSets => "sets",
+            VolumeMetric::Reps => "reps",
+        }
+    }
+}
+
+/// Where a newly added exercise's first set takes its weight and reps from.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum PrefillMode {
+    /// First set of the most recent session with the exercise.
+    #[default]
+    Last,
+    /// Heaviest completed set across the last `PREFILL_RECENT_SESSIONS` sessions,
+    /// so a deload week doesn't drag the prefill down.
+    BestRecent,
+}
+
+/// Sessions searched by `PrefillMode::BestRecent`.
+pub const PREFILL_RECENT_SESSIONS: usize = 5;
+
+impl PrefillMode {
+    pub fn all() -> Vec<PrefillMode> {
+        vec![PrefillMode::Last, PrefillMode::BestRecent]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            PrefillMode::Last => "Last session",
+            PrefillMode::BestRecent => "Best recent set",
+        }
+    }
+
+    pub fn from_str(s: &str) -> PrefillMode {
+        match s {
+            "best" => PrefillMode::BestRecent,
+            _ => PrefillMode::Last,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            PrefillMode::Last => "last",
+            PrefillMode::BestRecent => "best",
+        }
+    }
+}
+
+/// What fills the quick-add row of exercise buttons on the Workout page.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum QuickAddSource {
+    /// Exercises logged in the most workouts.
+    #[default]
+    MostUsed,
+    /// Favorite exercises, most used first.
+    Favorites,
+    Off,
+}
+
+impl QuickAddSource {
+    pub fn all() -> Vec<QuickAddSource> {
+        vec![QuickAddSource::MostUsed, QuickAddSource::Favorites, QuickAddSource::Off]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            QuickAddSource::MostUsed => "Most used",
+            QuickAddSource::Favorites => "Favorites",
+            QuickAddSource::Off => "Off",
+        }
+    }
+
+    pub fn from_str(s: &str) -> QuickAddSource {
+        match s {
+            "favorites" => QuickAddSource::Favorites,
+            "off" => QuickAddSource::Off,
+            _ => QuickAddSource::MostUsed,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            QuickAddSource::MostUsed => "most_used",
+            QuickAddSource::Favorites => "favorites",
+            QuickAddSource::Off => "off",
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
 pub enum UnitSystem {
     #[default]
@@ -226,6 +849,23 @@ impl UnitSystem {
         }
     }
 
+    /// Convert a weight from kg (storage) to a display string, rounded to the
+    /// configured step. Whole numbers drop the trailing ".0".
+    pub fn format_weight(&self, kg: f64, rounding: WeightRounding) -> String {
+        let val = self.display_weight(kg);
+        match rounding.step() {
+            Some(step) => {
+                let rounded = (val / step).round() * step;
+                if rounded.fract() == 0.0 {
+                    crate::i18n::format_number(rounded, 0)
+                } else {
+                    crate::i18n::format_number(rounded, 1)
+                }
+            }
+            None => crate::i18n::format_number(val, 1),
+        }
+    }
+
     /// Convert a weight from display unit to kg (storage)
     pub fn to_kg(&self, val: f64) -> f64 {
         match self {
@@ -314,6 +954,228 @@ pub struct UserConfig {
     pub ai_model: AiModel,
     #[serde(default)]
     pub muscle_thresholds: Option<HashMap<String, (f64, f64)>>,
+    #[serde(default = "default_count_fractional_volume")]
+    pub count_fractional_volume: bool,
+    #[serde(default)]
+    pub language: Language,
+    #[serde(default)]
+    pub weight_rounding: WeightRounding,
+    /// Remind to download a backup file every N days; 0 disables the reminder.
+    #[serde(default)]
+    pub auto_backup_days: u32,
+    /// Rest timer defaults keyed by category name, used when an exercise has no override.
+    #[serde(default)]
+    pub category_rest_seconds: HashMap<String, u32>,
+    #[serde(default)]
+    pub custom_milestones: Vec<CustomMilestone>,
+    /// Exercise ids starred by the user; listed first in exercise lists.
+    #[serde(default)]
+    pub favorite_exercises: HashSet<String>,
+    /// Personal weekly set targets per muscle, independent of MEV/MRV.
+    #[serde(default)]
+    pub muscle_set_targets: HashMap<String, f64>,
+    /// Read Coach T replies aloud as soon as they finish generating.
+    #[serde(default)]
+    pub ai_auto_speak: bool,
+    /// Also show the closest lb-plate loading in the workout plate calculator.
+    #[serde(default)]
+    pub mixed_plates: bool,
+    /// Planned routine per weekday, keyed by `WEEKDAY_KEYS` ("mon".."sun") to routine id.
+    #[serde(default)]
+    pub routine_schedule: HashMap<String, String>,
+    /// Skip decorative animations; `None` follows the OS `prefers-reduced-motion` setting.
+    #[serde(default)]
+    pub reduce_motion: Option<bool>,
+    #[serde(default)]
+    pub gym_profiles: Vec<GymProfile>,
+    /// Id of the gym profile in use; `None` uses the global bar weight and plates.
+    #[serde(default)]
+    pub active_gym: Option<String>,
+    /// Tick strength sets as done once both weight and reps are entered.
+    #[serde(default)]
+    pub auto_complete_sets: bool,
+    /// Short vibration when a set is ticked done (where supported, unless motion is reduced).
+    #[serde(default)]
+    pub haptic_feedback: bool,
+    /// Drop unchecked sets, and exercises left without sets, when a workout is saved.
+    #[serde(default)]
+    pub discard_incomplete_sets: bool,
+    /// Body weight in kg for calorie estimates, used when no body metric is logged.
+    #[serde(default)]
+    pub body_weight: Option<f64>,
+    /// Units for body weight, independent of lifting; `None` follows `unit_system`.
+    /// Body metrics are still stored in kg.
+    #[serde(default)]
+    pub body_unit: Option<UnitSystem>,
+    /// MET overrides keyed by category name, replacing `Category::default_met`.
+    #[serde(default)]
+    pub category_mets: HashMap<String, f64>,
+    /// Tracking type preselected for new custom exercises; `None` reuses the last one picked.
+    #[serde(default)]
+    pub default_tracking_type: Option<ExerciseTrackingType>,
+    /// Tracking type of the most recently created custom exercise.
+    #[serde(default)]
+    pub last_tracking_type: Option<ExerciseTrackingType>,
+    /// Planned rest days per Monday–Sunday week; days off beyond this count as missed.
+    #[serde(default = "default_rest_days_per_week")]
+    pub rest_days_per_week: u32,
+    #[serde(default)]
+    pub ai_persona: AiPersona,
+    /// Persona text used when `ai_persona` is `Custom`.
+    #[serde(default)]
+    pub ai_custom_persona: String,
+    /// Coach chat threads to keep; starting one past this drops the oldest.
+    #[serde(default = "default_ai_max_threads")]
+    pub ai_max_threads: u32,
+    /// Delete coach chat threads older than this many days; 0 keeps them.
+    #[serde(default)]
+    pub ai_thread_max_age_days: u32,
+    #[serde(default)]
+    pub prefill_mode: PrefillMode,
+    #[serde(default)]
+    pub quick_add: QuickAddSource,
+    #[serde(default)]
+    pub volume_metric: VolumeMetric,
+    /// Chart color overrides (hex) keyed by category name, replacing `Category::default_color`.
+    #[serde(default)]
+    pub category_colors: HashMap<String, String>,
+    /// Per-hand dumbbell entry chosen per exercise id, replacing `Exercise::per_hand`.
+    #[serde(default)]
+    pub per_hand_overrides: HashMap<String, bool>,
+    /// Bar weight (kg) chosen per exercise id, replacing `Exercise::bar_weight_override`.
+    #[serde(default)]
+    pub bar_weight_overrides: HashMap<String, f64>,
+    /// Hardest exercise difficulty suggested by recommendations and Coach T.
+    #[serde(default)]
+    pub experience_level: Difficulty,
+    /// Warn when a strength set is ticked sooner than `min_rest_for` after the
+    /// previous set of the same exercise.
+    #[serde(default)]
+    pub min_rest_warning: bool,
+    /// Minimum rest overrides (seconds) keyed by category name, replacing `Category::default_min_rest`.
+    #[serde(default)]
+    pub min_rest_secs: HashMap<String, u32>,
+    /// Target est. 1RM (kg) keyed by exercise id.
+    #[serde(default)]
+    pub e1rm_goals: HashMap<String, f64>,
+}
+
+impl UserConfig {
+    pub fn met_for(&self, category: &Category) -> f64 {
+        self.category_mets
+            .get(&category.to_string())
+            .copied()
+            .unwrap_or_else(|| category.default_met())
+    }
+
+    pub fn min_rest_for(&self, category: &Category) -> u32 {
+        self.min_rest_secs
+            .get(&category.to_string())
+            .copied()
+            .unwrap_or_else(|| category.default_min_rest())
+    }
+
+    /// Whether weights for `exercise` are entered per hand.
+    pub fn is_per_hand(&self, exercise: &Exercise) -> bool {
+        self.per_hand_overrides
+            .get(&exercise.id)
+            .copied()
+            .unwrap_or(exercise.per_hand)
+    }
+
+    /// Dumbbells a logged weight for `exercise` is split across when shown:
+    /// 2 for a strength lift entered per hand, otherwise 1.
+    pub fn hands(&self, exercise: &Exercise) -> f64 {
+        if exercise.tracking_type == ExerciseTrackingType::Strength && self.is_per_hand(exercise) {
+            2.0
+        } else {
+            1.0
+        }
+    }
+
+    /// Bar weight (kg) set for `exercise`, if any; `None` uses the gym's bar.
+    pub fn bar_weight_override(&self, exercise: &Exercise) -> Option<f64> {
+        self.bar_weight_overrides
+            .get(&exercise.id)
+            .copied()
+            .or(exercise.bar_weight_override)
+    }
+
+    pub fn color_for(&self, category: &Category) -> String {
+        self.category_colors
+            .get(&category.to_string())
+            .cloned()
+            .unwrap_or_else(|| category.default_color().to_string())
+    }
+
+    /// Units to show and enter body weight in.
+    pub fn body_units(&self) -> &UnitSystem {
+        self.body_unit.as_ref().unwrap_or(&self.unit_system)
+    }
+
+    pub fn active_gym(&self) -> Option<&GymProfile> {
+        let id = self.active_gym.as_ref()?;
+        self.gym_profiles.iter().find(|g| &g.id == id)
+    }
+
+    /// Bar weight in kg from the active gym, falling back to the global setting.
+    pub fn effective_bar_weight(&self) -> f64 {
+        self.active_gym()
+            .map(|g| g.bar_weight)
+            .unwrap_or(self.bar_weight)
+    }
+
+    /// Plate sizes in kg (heaviest first) at the active gym, if it restricts them,
+    /// each with the pairs owned (`None` when there's no limit).
+    pub fn active_plates(&self) -> Option<Vec<(f64, Option<u32>)>> {
+        let gym = self.active_gym().filter(|g| !g.plates.is_empty())?;
+        Some(gym.plates.iter().map(|p| (*p, gym.pairs_owned(*p))).collect())
+    }
+}
+
+/// A place the user trains, with its own bar, plates and equipment.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct GymProfile {
+    pub id: String,
+    pub name: String,
+    /// Bar weight in kg.
+    pub bar_weight: f64,
+    /// Plate sizes in kg, heaviest first; empty means the standard set.
+    #[serde(default)]
+    pub plates: Vec<f64>,
+    /// Equipment available here; empty means everything.
+    #[serde(default)]
+    pub equipment: Vec<Equipment>,
+    /// Pairs owned per plate size, keyed by `plate_key`; sizes not listed are unlimited.
+    #[serde(default)]
+    pub plate_pairs: HashMap<String, u32>,
+}
+
+impl GymProfile {
+    /// Pairs of the given plate (kg) owned here, if limited.
+    pub fn pairs_owned(&self, plate: f64) -> Option<u32> {
+        self.plate_pairs.get(&plate_key(plate)).copied()
+    }
+}
+
+/// Map key for a plate size in kg, trimmed of float noise.
+pub fn plate_key(kg: f64) -> String {
+    format!("{}", (kg * 1000.0).round() / 1000.0)
+}
+
+/// Weekday keys for `UserConfig::routine_schedule`, Monday first.
+pub const WEEKDAY_KEYS: [&str; 7] = ["mon", "tue", "wed", "thu", "fri", "sat", "sun"];
+
+/// User-defined milestone badge shown next to the built-in workout-count ones.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CustomMilestone {
+    pub count: u32,
+    pub emoji: String,
+    #[serde(default)]
+    pub label: String,
+    /// Count sessions of this exercise instead of total workouts.
+    #[serde(default)]
+    pub exercise_id: Option<String>,
 }
 
 #[derive(Clone, Debug, Default, Serialize, Deserialize, PartialEq)]
@@ -376,6 +1238,75 @@ impl AiModel {
     }
 }
 
+/// Tone Coach T takes in the system prompt.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize, PartialEq)]
+pub enum AiPersona {
+    #[default]
+    Friendly,
+    DrillSergeant,
+    ScienceNerd,
+    ChillBuddy,
+    /// Free-text description from `UserConfig::ai_custom_persona`.
+    Custom,
+}
+
+impl AiPersona {
+    pub fn all() -> Vec<AiPersona> {
+        vec![
+            AiPersona::Friendly,
+            AiPersona::DrillSergeant,
+            AiPersona::ScienceNerd,
+            AiPersona::ChillBuddy,
+            AiPersona::Custom,
+        ]
+    }
+
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AiPersona::Friendly => "Friendly Coach",
+            AiPersona::DrillSergeant => "Drill Sergeant",
+            AiPersona::ScienceNerd => "Science Nerd",
+            AiPersona::ChillBuddy => "Chill Buddy",
+            AiPersona::Custom => "Custom",
+        }
+    }
+
+    pub fn from_str(s: &str) -> AiPersona {
+        match s {
+            "drill" => AiPersona::DrillSergeant,
+            "science" => AiPersona::ScienceNerd,
+            "chill" => AiPersona::ChillBuddy,
+            "custom" => AiPersona::Custom,
+            _ => AiPersona::Friendly,
+        }
+    }
+
+    pub fn to_key(self) -> &'static str {
+        match self {
+            AiPersona::Friendly => "friendly",
+            AiPersona::DrillSergeant => "drill",
+            AiPersona::ScienceNerd => "science",
+            AiPersona::ChillBuddy => "chill",
+            AiPersona::Custom => "custom",
+        }
+    }
+
+    /// Who Coach T is, completing "You are Coach T, ...". `Custom` has no
+    /// fixed description and falls back to the friendly one.
+    pub fn description(&self) -> &'static str {
+        match self {
+            AiPersona::DrillSergeant => {
+                "a tough, no-excuses drill-sergeant gym coach who speaks in short, punchy commands"
+            }
+            AiPersona::ScienceNerd => {
+                "an evidence-based strength coach who loves explaining the physiology and research behind advice"
+            }
+            AiPersona::ChillBuddy => "a laid-back, easygoing gym buddy who keeps things light and casual",
+            AiPersona::Friendly | AiPersona::Custom => "a friendly and knowledgeable personal gym coach",
+        }
+    }
+}
+
 fn default_rest_seconds() -> u32 {
     90
 }
@@ -388,6 +1319,18 @@ fn default_social_enabled() -> bool {
     true
 }
 
+fn default_count_fractional_volume() -> bool {
+    true
+}
+
+fn default_rest_days_per_week() -> u32 {
+    3
+}
+
+fn default_ai_max_threads() -> u32 {
+    20
+}
+
 /// Compute unique sorted workout dates.
 fn workout_dates(workouts: &[Workout]) -> Vec<chrono::NaiveDate> {
     let mut dates: Vec<chrono::NaiveDate> = workouts
@@ -405,7 +1348,7 @@ pub fn current_streak(workouts: &[Workout]) -> u32 {
     if dates.is_empty() {
         return 0;
     }
-    let today = chrono::Local::now().date_naive();
+    let today = crate::dates::today();
     let last = *dates.last().unwrap();
     if (today - last).num_days() > 1 {
         return 0;
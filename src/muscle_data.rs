@@ -1504,12 +1504,21 @@ pub fn parse_custom_muscles(muscle_groups: &[String]) -> Vec<MuscleContribution>
     result
 }
 
+/// Minimum contribution for a muscle to count as directly trained when
+/// fractional (indirect) volume counting is turned off.
+pub const DIRECT_SET_THRESHOLD: f64 = 1.0;
+
 /// Compute effective sets per muscle group from completed sets in a workout exercise.
 /// Returns a map of muscle name -> effective sets.
+///
+/// With `fractional` set, every contribution counts proportionally (0.5 secondary,
+/// 0.25 tertiary). Otherwise only direct contributions (>= `DIRECT_SET_THRESHOLD`)
+/// count, each as one full set.
 pub fn effective_sets_for_exercise(
     exercise_id: &str,
     completed_sets: usize,
     custom_muscle_groups: Option<&[String]>,
+    fractional: bool,
 ) -> HashMap<&'static str, f64> {
     let mut result = HashMap::new();
 
@@ -1526,7 +1535,14 @@ pub fn effective_sets_for_exercise(
     };
 
     for mc in &contributions {
-        *result.entry(mc.muscle).or_insert(0.0) += completed_sets as f64 * mc.contribution;
+        let weight = if fractional {
+            mc.contribution
+        } else if mc.contribution >= DIRECT_SET_THRESHOLD {
+            1.0
+        } else {
+            continue;
+        };
+        *result.entry(mc.muscle).or_insert(0.0) += completed_sets as f64 * weight;
     }
     result
 }
@@ -2,9 +2,16 @@ use chrono::{Datelike, NaiveDate};
 use std::collections::HashMap;
 use yew::prelude::*;
 
-use crate::components::charts::{BarChart, HorizontalBarChart, LineChart, StatCard};
+use crate::components::annotation_editor::AnnotationEditor;
+use crate::components::charts::{linear_regression, BarChart, HorizontalBarChart, LineChart, StatCard};
 use crate::data::default_exercises;
-use crate::models::{Category, Exercise, UnitSystem, Workout, WorkoutExercise};
+use crate::dates::{iso_week_key, iso_week_label};
+use crate::models::{
+    Annotation, Category, CustomMilestone, Exercise, ExerciseTrackingType, UnitSystem, UserConfig, VolumeMetric, WeightRounding,
+    Workout, WorkoutExercise,
+};
+use crate::muscle_data::effective_sets_for_exercise;
+use crate::pages::muscles::count_fractional;
 use crate::storage;
 
 // ── Helpers ─────────────────────────────────────────────────────────────────
@@ -23,6 +30,11 @@ fn find_exercise_name(exercises: &[Exercise], id: &str) -> String {
         .unwrap_or_else(|| id.to_string())
 }
 
+/// Whether the exercise logs assistance weight, where lower is better.
+fn is_assisted(exercises: &[Exercise], id: &str) -> bool {
+    find_exercise(exercises, id).is_some_and(|e| e.assisted)
+}
+
 fn find_exercise<'a>(exercises: &'a [Exercise], id: &str) -> Option<&'a Exercise> {
     exercises.iter().find(|e| e.id == id)
 }
@@ -31,12 +43,86 @@ fn parse_date(s: &str) -> Option<NaiveDate> {
     NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
 }
 
-fn workout_volume(w: &Workout) -> f64 {
-    w.total_volume()
+/// Chart markers for annotations, given the ascending date each point starts
+/// at and how many days a point covers (1 per session, 7 per week). An
+/// annotation lands on the point whose span contains it, or the next one;
+/// those outside the plotted span are dropped.
+fn annotation_markers(
+    point_dates: &[NaiveDate],
+    span_days: i64,
+    annotations: &[Annotation],
+) -> Vec<(usize, AttrValue, AttrValue)> {
+    let Some(first) = point_dates.first() else {
+        return Vec::new();
+    };
+    annotations
+        .iter()
+        .filter_map(|a| {
+            let d = parse_date(&a.date)?;
+            if d < *first {
+                return None;
+            }
+            let idx = point_dates
+                .iter()
+                .position(|p| *p + chrono::Duration::days(span_days - 1) >= d)?;
+            Some((idx, AttrValue::from(a.label.clone()), AttrValue::from(a.kind.color())))
+        })
+        .collect()
+}
+
+fn workout_volume(w: &Workout, metric: VolumeMetric) -> f64 {
+    w.metric_volume(metric)
 }
 
-fn exercise_volume(we: &WorkoutExercise) -> f64 {
-    we.volume()
+/// Rough calories burned in a session: MET x body weight (kg) x hours, with
+/// the session's MET averaged over its exercises' categories, weighted by sets.
+fn session_calories(w: &Workout, exercises: &[Exercise], config: &UserConfig, body_kg: f64) -> f64 {
+    let mut weighted_met = 0.0;
+    let mut total_sets = 0.0;
+    for we in &w.exercises {
+        let Some(cat) = exercises.iter().find(|e| e.id == we.exercise_id).map(|e| &e.category) else {
+            continue;
+        };
+        let completed = we.sets.iter().filter(|s| s.completed).count();
+        let sets = if completed > 0 { completed } else { we.sets.len() } as f64;
+        weighted_met += config.met_for(cat) * sets;
+        total_sets += sets;
+    }
+    if total_sets == 0.0 {
+        return 0.0;
+    }
+    weighted_met / total_sets * body_kg * w.duration_mins as f64 / 60.0
+}
+
+/// Lean body mass (kg) by date from body metrics that have both weight and
+/// body fat, oldest first.
+fn lean_mass_series() -> Vec<(NaiveDate, f64)> {
+    let mut series: Vec<(NaiveDate, f64)> = storage::load_body_metrics()
+        .iter()
+        .filter_map(|m| {
+            let d = parse_date(&m.date)?;
+            let (w, bf) = (m.weight?, m.body_fat?);
+            let lean = w * (1.0 - bf / 100.0);
+            (lean > 0.0).then_some((d, lean))
+        })
+        .collect();
+    series.sort_by_key(|(d, _)| *d);
+    series
+}
+
+/// Lean mass in effect on `date`: the latest reading on or before it, or the
+/// first reading for workouts that predate all of them.
+fn lean_mass_at(series: &[(NaiveDate, f64)], date: NaiveDate) -> Option<f64> {
+    series
+        .iter()
+        .rev()
+        .find(|(d, _)| *d <= date)
+        .or_else(|| series.first())
+        .map(|(_, lean)| *lean)
+}
+
+fn exercise_volume(we: &WorkoutExercise, metric: VolumeMetric) -> f64 {
+    we.metric_volume(metric)
 }
 
 fn exercise_max_weight(we: &WorkoutExercise) -> f64 {
@@ -47,31 +133,42 @@ fn exercise_max_weight(we: &WorkoutExercise) -> f64 {
         .fold(0.0_f64, f64::max)
 }
 
+/// Least weight in a session's completed sets; the best set of an assisted exercise.
+fn exercise_min_weight(we: &WorkoutExercise) -> Option<f64> {
+    we.sets
+        .iter()
+        .filter(|s| s.completed && s.reps > 0)
+        .map(|s| s.weight)
+        .min_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))
+}
+
 /// Epley formula for estimated 1RM
 fn estimate_1rm(weight: f64, reps: u32) -> f64 {
     weight * (1.0 + reps as f64 / 30.0)
 }
 
-fn iso_week_label(d: NaiveDate) -> String {
-    let iso = d.iso_week();
-    format!("W{}", iso.week())
-}
-
-fn iso_week_key(d: NaiveDate) -> (i32, u32) {
-    let iso = d.iso_week();
-    (iso.year(), iso.week())
+/// Format seconds of time under tension as "1h 05m", "12m 30s" or "45s".
+fn format_tut(secs: f64) -> String {
+    let secs = secs.round() as u64;
+    if secs >= 3600 {
+        format!("{}h {:02}m", secs / 3600, (secs % 3600) / 60)
+    } else if secs >= 60 {
+        format!("{}m {:02}s", secs / 60, secs % 60)
+    } else {
+        format!("{}s", secs)
+    }
 }
 
-fn category_color(cat: &Category) -> &'static str {
-    match cat {
-        Category::Chest => "#ef4444",
-        Category::Back => "#3b82f6",
-        Category::Legs => "#22c55e",
-        Category::Shoulders => "#f59e0b",
-        Category::Arms => "#a855f7",
-        Category::Core => "#ec4899",
-        Category::Cardio => "#06b6d4",
+/// Signed "+2.5"/"-5" label for a PR jump, or `None` when it rounds to zero at
+/// the display precision.
+fn format_jump(units: &UnitSystem, jump: f64, rounding: WeightRounding) -> Option<String> {
+    let step = rounding.step().unwrap_or(0.1);
+    let shown = (units.display_weight(jump.abs()) / step).round() * step;
+    if shown <= 0.0 {
+        return None;
     }
+    let sign = if jump < 0.0 { "-" } else { "+" };
+    Some(format!("{}{}", sign, units.format_weight(jump.abs(), rounding)))
 }
 
 /// Build ordered list of last N weeks as (year, week) keys + labels.
@@ -93,39 +190,120 @@ fn last_n_weeks(workouts: &[Workout], n: usize) -> Vec<((i32, u32), String)> {
     weeks
 }
 
+/// Selectable analytics windows in weeks; `None` covers all logged history.
+const RANGE_OPTIONS: [(Option<usize>, &str); 6] = [
+    (Some(4), "4w"),
+    (Some(8), "8w"),
+    (Some(12), "12w"),
+    (Some(26), "26w"),
+    (Some(52), "52w"),
+    (None, "All"),
+];
+
+/// Number of weeks covered by `range`, resolving "all time" to the span
+/// between the first and latest workout.
+fn range_week_count(workouts: &[Workout], range: Option<usize>) -> usize {
+    if let Some(n) = range {
+        return n;
+    }
+    let dates: Vec<NaiveDate> = workouts.iter().filter_map(|w| parse_date(&w.date)).collect();
+    match (dates.iter().min(), dates.iter().max()) {
+        (Some(first), Some(last)) => {
+            let first_monday =
+                *first - chrono::Duration::days(first.weekday().num_days_from_monday() as i64);
+            ((*last - first_monday).num_days() / 7 + 1) as usize
+        }
+        _ => 1,
+    }
+}
+
+/// First date inside `range`, counted back from the latest workout.
+fn range_start(workouts: &[Workout], range: Option<usize>) -> Option<NaiveDate> {
+    let n = range?;
+    let latest = workouts.iter().filter_map(|w| parse_date(&w.date)).max()?;
+    let monday = latest - chrono::Duration::days(latest.weekday().num_days_from_monday() as i64);
+    Some(monday - chrono::Duration::weeks(n as i64 - 1))
+}
+
 use crate::models::{best_streak, current_streak};
 
+/// Sessions per week at which the frequency part of the consistency score maxes out.
+const CONSISTENCY_TARGET_PER_WEEK: f64 = 3.0;
+
+/// Consistency score (0-100) from per-week session counts:
+/// `100 × F × S × G` where
+/// - F = min(mean / 3, 1) rewards training about three times a week,
+/// - S = 1 − min(cv, 1) / 2 penalises swings between weeks (cv = std dev / mean),
+/// - G = weeks with at least one session / weeks counted penalises gaps.
+fn consistency_score(week_counts: &[f64]) -> u32 {
+    if week_counts.is_empty() {
+        return 0;
+    }
+    let n = week_counts.len() as f64;
+    let mean = week_counts.iter().sum::<f64>() / n;
+    if mean <= 0.0 {
+        return 0;
+    }
+    let variance = week_counts.iter().map(|c| (c - mean).powi(2)).sum::<f64>() / n;
+    let cv = variance.sqrt() / mean;
+    let frequency = (mean / CONSISTENCY_TARGET_PER_WEEK).min(1.0);
+    let stability = 1.0 - cv.min(1.0) / 2.0;
+    let coverage = week_counts.iter().filter(|c| **c > 0.0).count() as f64 / n;
+    (100.0 * frequency * stability * coverage).round() as u32
+}
+
 // ── Personal Records ────────────────────────────────────────────────────────
 
 struct PersonalRecord {
+    exercise_id: String,
     exercise_name: String,
     max_weight: f64,
+    /// Most reps completed at `max_weight`.
+    reps: u32,
     date: String,
+    /// `max_weight` is the least assistance used rather than the most load.
+    assisted: bool,
+    /// Dumbbells `max_weight` is split across when shown; 2 for per-hand entry.
+    hands: f64,
 }
 
 fn personal_records(workouts: &[Workout], exercises: &[Exercise]) -> Vec<PersonalRecord> {
-    let mut best: HashMap<String, (f64, String)> = HashMap::new();
+    // (weight bracket, weight, reps, date) of the heaviest set, ties broken by reps
+    let mut best: HashMap<String, (i64, f64, u32, String)> = HashMap::new();
 
     for w in workouts {
         for we in &w.exercises {
-            let max_w = exercise_max_weight(we);
-            if max_w > 0.0 {
-                let entry = best
-                    .entry(we.exercise_id.clone())
-                    .or_insert((0.0, String::new()));
-                if max_w > entry.0 {
-                    *entry = (max_w, w.date.clone());
+            let assisted = is_assisted(exercises, &we.exercise_id);
+            for s in we.sets.iter().filter(|s| s.completed && s.reps > 0) {
+                let bracket = s.weight_bracket();
+                let better = match best.get(&we.exercise_id) {
+                    None => true,
+                    Some((b, _, reps, _)) => {
+                        let stronger = if assisted { bracket < *b } else { bracket > *b };
+                        stronger || (bracket == *b && s.reps > *reps)
+                    }
+                };
+                if better {
+                    best.insert(
+                        we.exercise_id.clone(),
+                        (bracket, s.weight, s.reps, w.date.clone()),
+                    );
                 }
             }
         }
     }
 
+    let config = storage::load_user_config();
     let mut records: Vec<PersonalRecord> = best
         .into_iter()
-        .map(|(id, (weight, date))| PersonalRecord {
+        .map(|(id, (_, weight, reps, date))| PersonalRecord {
             exercise_name: find_exercise_name(exercises, &id),
             max_weight: weight,
+            reps,
             date,
+            assisted: is_assisted(exercises, &id),
+            hands: find_exercise(exercises, &id).map_or(1.0, |e| config.hands(e)),
+            exercise_id: id,
         })
         .collect();
 
@@ -140,12 +318,235 @@ fn personal_records(workouts: &[Workout], exercises: &[Exercise]) -> Vec<Persona
     records
 }
 
+struct RepRecord {
+    weight: f64,
+    reps: u32,
+    date: String,
+}
+
+/// Most reps ever completed at each weight bracket for one exercise,
+/// heaviest first.
+fn rep_records(workouts: &[Workout], exercise_id: &str) -> Vec<RepRecord> {
+    let mut best: HashMap<i64, RepRecord> = HashMap::new();
+    for w in workouts {
+        for we in w.exercises.iter().filter(|we| we.exercise_id == exercise_id) {
+            for s in we.sets.iter().filter(|s| s.completed && s.reps > 0) {
+                let entry = best.entry(s.weight_bracket()).or_insert(RepRecord {
+                    weight: s.weight,
+                    reps: 0,
+                    date: String::new(),
+                });
+                if s.reps > entry.reps || (s.reps == entry.reps && w.date < entry.date) {
+                    *entry = RepRecord {
+                        weight: s.weight,
+                        reps: s.reps,
+                        date: w.date.clone(),
+                    };
+                }
+            }
+        }
+    }
+    let mut records: Vec<RepRecord> = best.into_values().collect();
+    records.sort_by(|a, b| b.weight.partial_cmp(&a.weight).unwrap_or(std::cmp::Ordering::Equal));
+    records
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum PrKind {
+    Weight,
+    E1rm,
+}
+
+struct PrEvent {
+    date: String,
+    kind: PrKind,
+    value: f64,
+    /// Improvement over the previous best; `None` for the first recorded session.
+    jump: Option<f64>,
+}
+
+/// Chronological list of every time a new max weight or new max est. 1RM was
+/// set for one exercise. Assisted exercises track the least assistance instead,
+/// with no est. 1RM.
+fn pr_timeline(workouts: &[Workout], exercise_id: &str, assisted: bool) -> Vec<PrEvent> {
+    let mut sorted: Vec<&Workout> = workouts.iter().collect();
+    sorted.sort_by(|a, b| a.date.cmp(&b.date));
+
+    let mut best_weight: Option<f64> = None;
+    let mut best_e1rm: Option<f64> = None;
+    let mut events = Vec::new();
+
+    for w in sorted {
+        for we in w.exercises.iter().filter(|we| we.exercise_id == exercise_id) {
+            if assisted {
+                // Any drop below the least assistance so far is a PR
+                let Some(value) = exercise_min_weight(we) else {
+                    continue;
+                };
+                if best_weight.is_none_or(|prev| value < prev) {
+                    events.push(PrEvent {
+                        date: w.date.clone(),
+                        kind: PrKind::Weight,
+                        value,
+                        jump: best_weight.map(|prev| value - prev),
+                    });
+                    best_weight = Some(value);
+                }
+                continue;
+            }
+
+            let max_w = exercise_max_weight(we);
+            let max_e1rm = we
+                .sets
+                .iter()
+                .filter(|s| s.completed && s.weight > 0.0 && s.reps > 0)
+                .map(|s| estimate_1rm(s.weight, s.reps))
+                .fold(0.0_f64, f64::max);
+
+            for (kind, value, best) in [
+                (PrKind::Weight, max_w, &mut best_weight),
+                (PrKind::E1rm, max_e1rm, &mut best_e1rm),
+            ] {
+                if value <= 0.0 || best.is_some_and(|prev| value <= prev) {
+                    continue;
+                }
+                events.push(PrEvent {
+                    date: w.date.clone(),
+                    kind,
+                    value,
+                    jump: best.map(|prev| value - prev),
+                });
+                *best = Some(value);
+            }
+        }
+    }
+
+    events
+}
+
+struct WeeklyPr {
+    exercise_name: String,
+    kind: PrKind,
+    value: f64,
+    /// Best before this week.
+    previous: f64,
+    date: String,
+    assisted: bool,
+}
+
+/// An exercise's best weight and est. 1RM within the week, and when each was set.
+#[derive(Default)]
+struct WeekBest {
+    weight: Option<f64>,
+    weight_date: String,
+    e1rm: f64,
+    e1rm_date: String,
+}
+
+/// Weight and est. 1RM records set since Monday of `today`'s week, each
+/// compared against the exercise's all-time best before the week. Exercises
+/// first logged this week have nothing to beat and are skipped.
+fn weekly_prs(workouts: &[Workout], exercises: &[Exercise], today: NaiveDate) -> Vec<WeeklyPr> {
+    let week_start = today - chrono::Duration::days(today.weekday().num_days_from_monday() as i64);
+
+    // exercise id -> (best weight, best e1RM) before the week
+    let mut before: HashMap<String, (Option<f64>, f64)> = HashMap::new();
+    let mut within: HashMap<String, WeekBest> = HashMap::new();
+
+    for w in workouts {
+        let Some(d) = parse_date(&w.date) else {
+            continue;
+        };
+        if d > today {
+            continue;
+        }
+        for we in &w.exercises {
+            let assisted = is_assisted(exercises, &we.exercise_id);
+            let weight = if assisted {
+                exercise_min_weight(we)
+            } else {
+                Some(exercise_max_weight(we)).filter(|v| *v > 0.0)
+            };
+            let e1rm = if assisted {
+                0.0
+            } else {
+                we.sets
+                    .iter()
+                    .filter(|s| s.completed && s.weight > 0.0 && s.reps > 0)
+                    .map(|s| estimate_1rm(s.weight, s.reps))
+                    .fold(0.0_f64, f64::max)
+            };
+            let better = |value: f64, best: Option<f64>| {
+                best.is_none_or(|b| if assisted { value < b } else { value > b })
+            };
+
+            if d < week_start {
+                let entry = before.entry(we.exercise_id.clone()).or_insert((None, 0.0));
+                if let Some(v) = weight.filter(|v| better(*v, entry.0)) {
+                    entry.0 = Some(v);
+                }
+                entry.1 = entry.1.max(e1rm);
+            } else {
+                let entry = within.entry(we.exercise_id.clone()).or_default();
+                if let Some(v) = weight.filter(|v| better(*v, entry.weight)) {
+                    entry.weight = Some(v);
+                    entry.weight_date = w.date.clone();
+                }
+                if e1rm > entry.e1rm {
+                    entry.e1rm = e1rm;
+                    entry.e1rm_date = w.date.clone();
+                }
+            }
+        }
+    }
+
+    let mut prs = Vec::new();
+    for (id, week) in within {
+        let Some((prev_weight, prev_e1rm)) = before.get(&id) else {
+            continue;
+        };
+        let assisted = is_assisted(exercises, &id);
+        let name = find_exercise_name(exercises, &id);
+        if let (Some(value), Some(previous)) = (week.weight, *prev_weight) {
+            let beaten = if assisted { value < previous } else { value > previous };
+            if beaten {
+                prs.push(WeeklyPr {
+                    exercise_name: name.clone(),
+                    kind: PrKind::Weight,
+                    value,
+                    previous,
+                    date: week.weight_date,
+                    assisted,
+                });
+            }
+        }
+        if *prev_e1rm > 0.0 && week.e1rm > *prev_e1rm {
+            prs.push(WeeklyPr {
+                exercise_name: name,
+                kind: PrKind::E1rm,
+                value: week.e1rm,
+                previous: *prev_e1rm,
+                date: week.e1rm_date,
+                assisted,
+            });
+        }
+    }
+
+    prs.sort_by(|a, b| {
+        b.date
+            .cmp(&a.date)
+            .then_with(|| a.exercise_name.cmp(&b.exercise_name))
+    });
+    prs
+}
+
 // ── Volume per category per week ─────────────────────────────────────────────
 
 fn volume_per_category_per_week(
     workouts: &[Workout],
     exercises: &[Exercise],
     weeks: &[((i32, u32), String)],
+    metric: VolumeMetric,
 ) -> Vec<(Category, Vec<(String, f64)>)> {
     let mut cat_week_vol: HashMap<String, HashMap<(i32, u32), f64>> = HashMap::new();
 
@@ -154,7 +555,7 @@ fn volume_per_category_per_week(
             let wk = iso_week_key(d);
             for we in &w.exercises {
                 if let Some(ex) = find_exercise(exercises, &we.exercise_id) {
-                    let vol = exercise_volume(we);
+                    let vol = exercise_volume(we, metric);
                     *cat_week_vol
                         .entry(ex.category.to_string())
                         .or_default()
@@ -192,7 +593,7 @@ fn volume_per_category_per_week(
 // ── Days since last training per category ────────────────────────────────────
 
 fn days_since_category(workouts: &[Workout], exercises: &[Exercise]) -> Vec<(Category, i64)> {
-    let today = chrono::Local::now().date_naive();
+    let today = crate::dates::today();
     let mut last_trained: HashMap<String, NaiveDate> = HashMap::new();
 
     for w in workouts {
@@ -232,6 +633,203 @@ const MILESTONES: &[(u32, &str)] = &[
     (500, "\u{1f680}"), // 🚀
 ];
 
+struct MilestoneBadge {
+    threshold: u32,
+    emoji: String,
+    label: String,
+    progress: u32,
+    /// Index into the user's custom milestones; `None` for built-ins.
+    custom_index: Option<usize>,
+}
+
+/// Built-in workout-count milestones followed by the user's custom ones.
+fn milestone_badges(
+    workouts: &[Workout],
+    exercises: &[Exercise],
+    custom: &[CustomMilestone],
+) -> Vec<MilestoneBadge> {
+    let total = workouts.len() as u32;
+    let mut badges: Vec<MilestoneBadge> = MILESTONES
+        .iter()
+        .map(|(threshold, emoji)| MilestoneBadge {
+            threshold: *threshold,
+            emoji: emoji.to_string(),
+            label: format!("{} workouts", threshold),
+            progress: total,
+            custom_index: None,
+        })
+        .collect();
+
+    for (i, m) in custom.iter().enumerate() {
+        let (progress, default_label) = match &m.exercise_id {
+            Some(eid) => (
+                workouts
+                    .iter()
+                    .filter(|w| w.exercises.iter().any(|we| we.exercise_id == *eid))
+                    .count() as u32,
+                format!("{} {} sessions", m.count, find_exercise_name(exercises, eid)),
+            ),
+            None => (total, format!("{} workouts", m.count)),
+        };
+        badges.push(MilestoneBadge {
+            threshold: m.count,
+            emoji: m.emoji.clone(),
+            label: if m.label.trim().is_empty() {
+                default_label
+            } else {
+                m.label.clone()
+            },
+            progress,
+            custom_index: Some(i),
+        });
+    }
+    badges
+}
+
+// ── JSON summary export ─────────────────────────────────────────────────────
+
+/// Computed analytics as pretty JSON for external dashboards. Weights are kg
+/// and dates `YYYY-MM-DD` regardless of the display settings. Shape:
+///
+/// ```text
+/// {
+///   "schema_version": 1,
+///   "generated_at": "2026-10-16",
+///   "weekly": [                      // every ISO week from the first workout, oldest first
+///     { "week": "2026-W41", "start": "2026-10-05", "workouts": 3,
+///       "tonnage_kg": 12345.0, "sets": 54,
+///       "sets_by_category": { "Chest": 12, "Legs": 18 },   // completed sets by exercise category
+///       "effective_sets_by_muscle": { "Chest": 10.5, "Triceps": 4.0 } }
+///       // per-muscle sets as on the Muscles page, fractional if that setting is on
+///   ],
+///   "volume_trend_kg_per_week": 210.5,   // regression slope of weekly tonnage; null under 2 weeks
+///   "personal_records": [            // heaviest set per exercise, latest 10
+///     { "exercise_id": "chest-01", "exercise": "Bench Press", "weight_kg": 100.0,
+///       "reps": 3, "date": "2026-10-01", "assisted": false }
+///   ],
+///   "e1rm_trends": [                 // exercises with at least 2 sessions, by name
+///     { "exercise_id": "chest-01", "exercise": "Bench Press", "sessions": 14,
+///       "latest_e1rm_kg": 112.0, "best_e1rm_kg": 115.0,
+///       "slope_kg_per_week": 0.8 }   // fitted to the last 8 sessions; null if undefined
+///   ]
+/// }
+/// ```
+pub fn export_summary_json() -> String {
+    let workouts = storage::load_workouts();
+    let exercises = all_exercises();
+    let round1 = |v: f64| (v * 10.0).round() / 10.0;
+
+    let weeks = last_n_weeks(&workouts, range_week_count(&workouts, None));
+    let mut weekly_workouts: HashMap<(i32, u32), u32> = HashMap::new();
+    let mut weekly_tonnage: HashMap<(i32, u32), f64> = HashMap::new();
+    let mut weekly_sets: HashMap<(i32, u32), HashMap<String, u32>> = HashMap::new();
+    let mut weekly_muscle_sets: HashMap<(i32, u32), HashMap<String, f64>> = HashMap::new();
+    let fractional = count_fractional();
+    for w in &workouts {
+        let Some(d) = parse_date(&w.date) else { continue };
+        let key = iso_week_key(d);
+        *weekly_workouts.entry(key).or_default() += 1;
+        *weekly_tonnage.entry(key).or_default() += workout_volume(w, VolumeMetric::Tonnage);
+        for we in &w.exercises {
+            let done = we.sets.iter().filter(|s| s.completed).count();
+            if done == 0 {
+                continue;
+            }
+            let ex = find_exercise(&exercises, &we.exercise_id);
+            if let Some(ex) = ex {
+                *weekly_sets.entry(key).or_default().entry(ex.category.to_string()).or_default() += done as u32;
+            }
+            // Same counting as the Muscles page
+            let custom_mg = ex.filter(|e| e.is_custom).map(|e| e.muscle_groups.as_slice());
+            let muscle_sets = weekly_muscle_sets.entry(key).or_default();
+            for (muscle, val) in effective_sets_for_exercise(&we.exercise_id, done, custom_mg, fractional) {
+                *muscle_sets.entry(muscle.to_string()).or_default() += val;
+            }
+        }
+    }
+    let weekly: Vec<serde_json::Value> = weeks
+        .iter()
+        .map(|(key, _)| {
+            let start = NaiveDate::from_isoywd_opt(key.0, key.1, chrono::Weekday::Mon)
+                .map(|d| d.format("%Y-%m-%d").to_string());
+            let by_category = weekly_sets.get(key).cloned().unwrap_or_default();
+            let by_muscle: HashMap<String, f64> = weekly_muscle_sets
+                .get(key)
+                .map(|m| m.iter().map(|(muscle, v)| (muscle.clone(), round1(*v))).collect())
+                .unwrap_or_default();
+            serde_json::json!({
+                "week": format!("{}-W{:02}", key.0, key.1),
+                "start": start,
+                "workouts": weekly_workouts.get(key).copied().unwrap_or(0),
+                "tonnage_kg": round1(weekly_tonnage.get(key).copied().unwrap_or(0.0)),
+                "sets": by_category.values().sum::<u32>(),
+                "sets_by_category": by_category,
+                "effective_sets_by_muscle": by_muscle,
+            })
+        })
+        .collect();
+    let volume_points: Vec<(f64, f64)> = weeks
+        .iter()
+        .enumerate()
+        .map(|(i, (key, _))| (i as f64, weekly_tonnage.get(key).copied().unwrap_or(0.0)))
+        .collect();
+    let volume_trend = linear_regression(&volume_points).map(|(slope, _)| round1(slope));
+
+    let personal_records: Vec<serde_json::Value> = personal_records(&workouts, &exercises)
+        .into_iter()
+        .map(|pr| {
+            serde_json::json!({
+                "exercise_id": pr.exercise_id,
+                "exercise": pr.exercise_name,
+                "weight_kg": round1(pr.max_weight),
+                "reps": pr.reps,
+                "date": pr.date,
+                "assisted": pr.assisted,
+            })
+        })
+        .collect();
+
+    let mut exercise_ids: Vec<&str> = workouts
+        .iter()
+        .flat_map(|w| w.exercises.iter().map(|we| we.exercise_id.as_str()))
+        .collect();
+    exercise_ids.sort_unstable();
+    exercise_ids.dedup();
+    let mut e1rm_trends: Vec<(String, serde_json::Value)> = exercise_ids
+        .into_iter()
+        .filter(|id| !is_assisted(&exercises, id))
+        .filter_map(|id| {
+            let sessions = e1rm_sessions(&workouts, id);
+            if sessions.len() < 2 {
+                return None;
+            }
+            let (_, points) = projection_points(&sessions)?;
+            let slope = linear_regression(&points).map(|(slope, _)| round1(slope * 7.0));
+            let name = find_exercise_name(&exercises, id);
+            let value = serde_json::json!({
+                "exercise_id": id,
+                "exercise": name,
+                "sessions": sessions.len(),
+                "latest_e1rm_kg": round1(sessions[sessions.len() - 1].1),
+                "best_e1rm_kg": round1(sessions.iter().map(|(_, v)| *v).fold(0.0_f64, f64::max)),
+                "slope_kg_per_week": slope,
+            });
+            Some((name, value))
+        })
+        .collect();
+    e1rm_trends.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let summary = serde_json::json!({
+        "schema_version": 1,
+        "generated_at": crate::dates::today_str(),
+        "weekly": weekly,
+        "volume_trend_kg_per_week": volume_trend,
+        "personal_records": personal_records,
+        "e1rm_trends": e1rm_trends.into_iter().map(|(_, v)| v).collect::<Vec<_>>(),
+    });
+    serde_json::to_string_pretty(&summary).unwrap_or_default()
+}
+
 // ── Analytics Page ──────────────────────────────────────────────────────────
 
 #[function_component(AnalyticsPage)]
@@ -240,7 +838,22 @@ pub fn analytics_page() -> Html {
     let routines = use_state(storage::load_routines);
     let exercises = use_memo((), |_| all_exercises());
     let units = use_memo((), |_| storage::load_user_config().unit_system);
+    let rounding = storage::load_user_config().weight_rounding;
+    let volume_metric = storage::load_user_config().volume_metric;
     let active_tab = use_state(|| 0u8);
+    let range = use_state(|| Some(8usize));
+
+    let range_chip = |value: Option<usize>, label: &'static str| {
+        let range = range.clone();
+        let class = if *range == value {
+            "px-3 py-1 rounded-full text-xs bg-blue-600 text-white font-medium neu-chip-active transition-colors"
+        } else {
+            "px-3 py-1 rounded-full text-xs bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-300 neu-chip hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+        };
+        html! {
+            <button class={class} onclick={Callback::from(move |_: MouseEvent| range.set(value))}>{label}</button>
+        }
+    };
 
     let tab_click = |tab: u8| {
         let active_tab = active_tab.clone();
@@ -259,15 +872,23 @@ pub fn analytics_page() -> Html {
         <div class="px-4 py-4 space-y-4">
             <h1 class="text-2xl font-bold text-gray-900 dark:text-gray-100">{"Analytics"}</h1>
 
+            if *active_tab != 3 {
+                <div class="flex flex-wrap gap-2">
+                    { for RANGE_OPTIONS.iter().map(|(value, label)| range_chip(*value, label)) }
+                </div>
+            }
+
             <div class="flex border-b border-gray-200 dark:border-gray-700">
                 <button class={tab_class(0)} onclick={tab_click(0)}>{"Overview"}</button>
                 <button class={tab_class(1)} onclick={tab_click(1)}>{"Progress"}</button>
-                <button class={tab_class(2)} onclick={tab_click(2)}>{"Body"}</button>
+                <button class={tab_class(2)} onclick={tab_click(2)}>{"Cardio"}</button>
+                <button class={tab_class(3)} onclick={tab_click(3)}>{"Body"}</button>
             </div>
 
             { match *active_tab {
-                0 => html! { <OverviewTab workouts={(*workouts).clone()} exercises={(*exercises).clone()} units={(*units).clone()} /> },
-                1 => html! { <ProgressTab workouts={(*workouts).clone()} exercises={(*exercises).clone()} routines={(*routines).clone()} units={(*units).clone()} /> },
+                0 => html! { <OverviewTab workouts={(*workouts).clone()} exercises={(*exercises).clone()} units={(*units).clone()} rounding={rounding} range={*range} volume_metric={volume_metric} /> },
+                1 => html! { <ProgressTab workouts={(*workouts).clone()} exercises={(*exercises).clone()} routines={(*routines).clone()} units={(*units).clone()} rounding={rounding} range={*range} volume_metric={volume_metric} /> },
+                2 => html! { <CardioTab workouts={(*workouts).clone()} exercises={(*exercises).clone()} units={(*units).clone()} range={*range} /> },
                 _ => html! { <BodyTab /> },
             }}
         </div>
@@ -282,6 +903,12 @@ struct OverviewProps {
     exercises: Vec<Exercise>,
     #[prop_or_default]
     units: UnitSystem,
+    #[prop_or_default]
+    rounding: WeightRounding,
+    #[prop_or(Some(8))]
+    range: Option<usize>,
+    #[prop_or_default]
+    volume_metric: VolumeMetric,
 }
 
 #[function_component(OverviewTab)]
@@ -289,6 +916,17 @@ fn overview_tab(props: &OverviewProps) -> Html {
     let workouts = &props.workouts;
     let exercises = &props.exercises;
     let show_volume_cats = use_state(|| false);
+    // Weekly volume as raw tonnage, or divided by lean body mass
+    let lean_volume = use_state(|| false);
+    let recap_copied = use_state(|| false);
+    let digest_copied = use_state(|| false);
+    let custom_milestones = use_state(|| storage::load_user_config().custom_milestones);
+    let show_milestone_form = use_state(|| false);
+    let ms_count = use_state(String::new);
+    let ms_emoji = use_state(|| "\u{1f3af}".to_string());
+    let ms_label = use_state(String::new);
+    let ms_exercise = use_state(String::new);
+    let annotations = use_state(storage::load_annotations);
 
     if workouts.is_empty() {
         return html! {
@@ -302,7 +940,8 @@ fn overview_tab(props: &OverviewProps) -> Html {
 
     // ── Stats
     let total_workouts = workouts.len();
-    let total_volume: f64 = workouts.iter().map(workout_volume).sum();
+    let metric = props.volume_metric;
+    let total_volume: f64 = workouts.iter().map(|w| workout_volume(w, metric)).sum();
     let streak = current_streak(workouts);
     let best = best_streak(workouts);
     let avg_duration: u32 = if total_workouts > 0 {
@@ -312,16 +951,39 @@ fn overview_tab(props: &OverviewProps) -> Html {
         0
     };
 
-    let volume_display = if total_volume >= 1_000_000.0 {
-        format!("{:.1}M", total_volume / 1_000_000.0)
-    } else if total_volume >= 1000.0 {
-        format!("{:.0}k", total_volume / 1000.0)
+    // ── Estimated calories (MET-based; needs a body weight)
+    let config = storage::load_user_config();
+    let body_kg = storage::latest_body_weight(&config);
+    let calories: Vec<(&Workout, f64)> = body_kg
+        .map(|kg| {
+            workouts
+                .iter()
+                .map(|w| (w, session_calories(w, exercises, &config, kg)))
+                .filter(|(_, c)| *c > 0.0)
+                .collect()
+        })
+        .unwrap_or_default();
+    let avg_calories = if calories.is_empty() {
+        None
     } else {
-        format!("{:.0}", total_volume)
+        Some(calories.iter().map(|(_, c)| c).sum::<f64>() / calories.len() as f64)
     };
+    let this_week = iso_week_key(crate::dates::today());
+    let week_calories: f64 = calories
+        .iter()
+        .filter(|(w, _)| parse_date(&w.date).map(iso_week_key) == Some(this_week))
+        .map(|(_, c)| c)
+        .sum();
+
+    // ── Time under tension
+    let total_tut: f64 = workouts.iter().map(|w| w.time_under_tension_secs()).sum();
+    let avg_tut = total_tut / total_workouts as f64;
+
+    let volume_display = crate::i18n::format_compact(total_volume);
 
     // ── Workouts per week (bar chart)
-    let weeks = last_n_weeks(workouts, 8);
+    let range_weeks = range_week_count(workouts, props.range);
+    let weeks = last_n_weeks(workouts, range_weeks);
     let mut week_counts: HashMap<(i32, u32), f64> = HashMap::new();
     for w in workouts {
         if let Some(d) = parse_date(&w.date) {
@@ -333,17 +995,60 @@ fn overview_tab(props: &OverviewProps) -> Html {
         .map(|(key, label)| (label.clone(), *week_counts.get(key).unwrap_or(&0.0)))
         .collect();
 
+    let mut week_kcal: HashMap<(i32, u32), f64> = HashMap::new();
+    for (w, c) in &calories {
+        if let Some(d) = parse_date(&w.date) {
+            *week_kcal.entry(iso_week_key(d)).or_default() += c;
+        }
+    }
+    let calories_per_week: Vec<(String, f64)> = weeks
+        .iter()
+        .map(|(key, label)| (label.clone(), week_kcal.get(key).copied().unwrap_or(0.0).round()))
+        .collect();
+
+    let consistency = consistency_score(
+        &workouts_per_week.iter().map(|(_, c)| *c).collect::<Vec<_>>(),
+    );
+
     // ── Volume per week (line chart)
     let mut week_volume: HashMap<(i32, u32), f64> = HashMap::new();
     for w in workouts {
         if let Some(d) = parse_date(&w.date) {
-            *week_volume.entry(iso_week_key(d)).or_default() += workout_volume(w);
+            *week_volume.entry(iso_week_key(d)).or_default() += workout_volume(w, metric);
         }
     }
     let volume_per_week: Vec<(String, f64)> = weeks
         .iter()
         .map(|(key, label)| (label.clone(), *week_volume.get(key).unwrap_or(&0.0)))
         .collect();
+    let week_starts: Vec<NaiveDate> = weeks
+        .iter()
+        .filter_map(|((year, week), _)| NaiveDate::from_isoywd_opt(*year, *week, chrono::Weekday::Mon))
+        .collect();
+    let week_markers = annotation_markers(&week_starts, 7, &annotations);
+    let on_annotations_change = {
+        let annotations = annotations.clone();
+        Callback::from(move |list: Vec<Annotation>| {
+            storage::save_annotations(&list);
+            annotations.set(list);
+        })
+    };
+
+    // Same, per kg of lean mass at the time of each workout (needs body fat readings)
+    let lean_series = lean_mass_series();
+    let mut week_lean_volume: HashMap<(i32, u32), f64> = HashMap::new();
+    for w in workouts {
+        if let Some(d) = parse_date(&w.date) {
+            if let Some(lean) = lean_mass_at(&lean_series, d) {
+                *week_lean_volume.entry(iso_week_key(d)).or_default() += workout_volume(w, metric) / lean;
+            }
+        }
+    }
+    let lean_volume_per_week: Vec<(String, f64)> = weeks
+        .iter()
+        .map(|(key, label)| (label.clone(), *week_lean_volume.get(key).unwrap_or(&0.0)))
+        .collect();
+    let show_lean = *lean_volume && !lean_series.is_empty();
 
     // ── Muscle group distribution
     let mut cat_counts: HashMap<String, f64> = HashMap::new();
@@ -359,7 +1064,7 @@ fn overview_tab(props: &OverviewProps) -> Html {
         .filter_map(|cat| {
             let count = cat_counts.get(&cat.to_string()).copied().unwrap_or(0.0);
             if count > 0.0 {
-                Some((cat.to_string(), count, category_color(cat).to_string()))
+                Some((cat.to_string(), count, config.color_for(cat)))
             } else {
                 None
             }
@@ -368,13 +1073,74 @@ fn overview_tab(props: &OverviewProps) -> Html {
 
     // ── Personal Records
     let prs = personal_records(workouts, exercises);
+    let week_prs = weekly_prs(workouts, exercises, crate::dates::today());
 
     // ── Milestone badges
-    let total = total_workouts as u32;
-    let next_milestone = MILESTONES.iter().find(|(threshold, _)| *threshold > total);
+    let badges = milestone_badges(workouts, exercises, &custom_milestones);
+    let next_milestone = badges
+        .iter()
+        .filter(|b| b.progress < b.threshold)
+        .min_by_key(|b| b.threshold - b.progress);
+
+    let save_custom_milestones = {
+        let custom_milestones = custom_milestones.clone();
+        move |list: Vec<CustomMilestone>| {
+            let mut config = storage::load_user_config();
+            config.custom_milestones = list.clone();
+            storage::save_user_config(&config);
+            custom_milestones.set(list);
+        }
+    };
+
+    let on_add_milestone = {
+        let custom_milestones = custom_milestones.clone();
+        let save = save_custom_milestones.clone();
+        let show = show_milestone_form.clone();
+        let ms_count = ms_count.clone();
+        let ms_emoji = ms_emoji.clone();
+        let ms_label = ms_label.clone();
+        let ms_exercise = ms_exercise.clone();
+        Callback::from(move |_: MouseEvent| {
+            let count = match ms_count.trim().parse::<u32>() {
+                Ok(c) if c > 0 => c,
+                _ => return,
+            };
+            let emoji = if ms_emoji.trim().is_empty() {
+                "\u{1f3af}".to_string()
+            } else {
+                ms_emoji.trim().to_string()
+            };
+            let mut list = (*custom_milestones).clone();
+            list.push(CustomMilestone {
+                count,
+                emoji,
+                label: ms_label.trim().to_string(),
+                exercise_id: if ms_exercise.is_empty() {
+                    None
+                } else {
+                    Some((*ms_exercise).clone())
+                },
+            });
+            save(list);
+            ms_count.set(String::new());
+            ms_label.set(String::new());
+            show.set(false);
+        })
+    };
+
+    // Exercises the user has logged, for exercise-specific milestones
+    let mut logged_ids: Vec<String> = Vec::new();
+    for w in workouts {
+        for we in &w.exercises {
+            if !logged_ids.contains(&we.exercise_id) {
+                logged_ids.push(we.exercise_id.clone());
+            }
+        }
+    }
+    logged_ids.sort_by_key(|id| find_exercise_name(exercises, id));
 
     // ── Volume per category per week
-    let vol_cat_data = volume_per_category_per_week(workouts, exercises, &weeks);
+    let vol_cat_data = volume_per_category_per_week(workouts, exercises, &weeks, metric);
 
     // ── Training frequency warnings
     let days_since = days_since_category(workouts, exercises);
@@ -386,38 +1152,188 @@ fn overview_tab(props: &OverviewProps) -> Html {
             // Stat cards
             <div class="grid grid-cols-2 gap-3">
                 <StatCard label="Total Workouts" value={format!("{}", total_workouts)} icon="\u{1f3cb}" />
-                <StatCard label={format!("Total Volume ({})", props.units.weight_label())} value={volume_display} icon="\u{1f4aa}" />
+                <StatCard label={format!("Total Volume ({})", metric.unit_label(&props.units))} value={volume_display} icon="\u{1f4aa}" />
                 <StatCard label="Day Streak" value={format!("{}", streak)} icon="\u{1f525}" />
                 <StatCard label="Best Streak" value={format!("{}d", best)} icon="\u{1f3c6}" />
                 <StatCard label="Avg Duration" value={format!("{}m", avg_duration)} icon="\u{23f1}" />
+                <StatCard label="Avg TUT / Session" value={format_tut(avg_tut)} icon="\u{231b}" />
+                <StatCard label="Total Time Under Tension" value={format_tut(total_tut)} icon="\u{23f3}" />
+                <StatCard
+                    label={format!("Consistency ({} wk)", range_weeks)}
+                    value={format!("{}/100", consistency)}
+                    icon="\u{1f4c8}"
+                    hint={AttrValue::from("Sessions/week vs 3, minus week-to-week swings and empty weeks")}
+                />
+                if body_kg.is_some() {
+                    <StatCard
+                        label="Est. kcal / Session"
+                        value={avg_calories.map(|c| format!("{:.0}", c)).unwrap_or_else(|| "--".to_string())}
+                        icon="\u{26a1}"
+                        hint={AttrValue::from("Rough MET estimate from duration and exercise mix")}
+                    />
+                    <StatCard
+                        label="Est. kcal This Week"
+                        value={format!("{:.0}", week_calories)}
+                        icon="\u{1f37d}"
+                        hint={AttrValue::from("Ballpark only; not a measurement")}
+                    />
+                } else {
+                    <StatCard
+                        label="Est. kcal / Session"
+                        value="--"
+                        icon="\u{26a1}"
+                        hint={AttrValue::from("Log a body weight or set one in Settings")}
+                    />
+                }
+            </div>
+
+            // Monthly recap
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors flex items-center justify-between gap-3">
+                <div>
+                    <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Monthly Recap"}</h3>
+                    <p class="text-xs text-gray-500 dark:text-gray-400 mt-0.5">{"Last 30 days as a markdown report for your coach."}</p>
+                </div>
+                <button
+                    class="px-3 py-2 bg-blue-600 text-white rounded-lg text-xs font-bold hover:bg-blue-700 neu-btn transition-colors flex-shrink-0"
+                    onclick={{
+                        let recap_copied = recap_copied.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let report = crate::components::ai_chat::build_training_report(30);
+                            let _ = gloo::utils::window().navigator().clipboard().write_text(&report);
+                            recap_copied.set(true);
+                            let rc = recap_copied.clone();
+                            gloo::timers::callback::Timeout::new(2_000, move || rc.set(false)).forget();
+                        })
+                    }}
+                >{ if *recap_copied { "Copied!" } else { "Copy Recap" } }</button>
+            </div>
+
+            // Weekly digest
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors flex items-center justify-between gap-3">
+                <div>
+                    <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Weekly Digest"}</h3>
+                    <p class="text-xs text-gray-500 dark:text-gray-400 mt-0.5">{"This week in a few lines, with one tip."}</p>
+                </div>
+                <button
+                    class="px-3 py-2 bg-blue-600 text-white rounded-lg text-xs font-bold hover:bg-blue-700 neu-btn transition-colors flex-shrink-0"
+                    onclick={{
+                        let digest_copied = digest_copied.clone();
+                        Callback::from(move |_: MouseEvent| {
+                            let digest = crate::components::ai_chat::build_weekly_digest();
+                            let _ = gloo::utils::window().navigator().clipboard().write_text(&digest);
+                            digest_copied.set(true);
+                            let dc = digest_copied.clone();
+                            gloo::timers::callback::Timeout::new(2_000, move || dc.set(false)).forget();
+                        })
+                    }}
+                >{ if *digest_copied { "Copied!" } else { "Copy Digest" } }</button>
+            </div>
+
+            // Computed analytics for external dashboards
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors flex items-center justify-between gap-3">
+                <div>
+                    <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Analytics Export"}</h3>
+                    <p class="text-xs text-gray-500 dark:text-gray-400 mt-0.5">{"Weekly volume, sets per muscle group, PRs and 1RM trends as JSON."}</p>
+                </div>
+                <button
+                    class="px-3 py-2 bg-blue-600 text-white rounded-lg text-xs font-bold hover:bg-blue-700 neu-btn transition-colors flex-shrink-0"
+                    onclick={Callback::from(|_: MouseEvent| {
+                        let filename = format!("treening-analytics-{}.json", crate::dates::today_str());
+                        let _ = crate::backup::save_json_file(&filename, &export_summary_json());
+                    })}
+                >{"Export JSON"}</button>
             </div>
 
             // Milestone badges
             <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
-                <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 mb-3 uppercase tracking-wider">{"Milestones"}</h3>
+                <div class="flex justify-between items-center mb-3">
+                    <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Milestones"}</h3>
+                    <button
+                        class="text-xs font-bold text-blue-600 dark:text-blue-400 hover:underline"
+                        onclick={let s = show_milestone_form.clone(); Callback::from(move |_| s.set(!*s))}
+                    >{ if *show_milestone_form { "Cancel" } else { "+ Custom" } }</button>
+                </div>
+                if *show_milestone_form {
+                    <div class="mb-3 p-3 bg-white dark:bg-gray-700/50 rounded-lg space-y-2">
+                        <div class="flex gap-2">
+                            <input
+                                type="text"
+                                class="w-14 bg-gray-100 dark:bg-gray-700 rounded-lg px-2 py-1.5 text-center text-lg outline-none neu-pressed"
+                                value={(*ms_emoji).clone()}
+                                oninput={let e = ms_emoji.clone(); Callback::from(move |ev: InputEvent| e.set(ev.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
+                            />
+                            <input
+                                type="number" min="1" autocomplete="off" placeholder="Count"
+                                class="w-24 bg-gray-100 dark:bg-gray-700 rounded-lg px-2 py-1.5 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                                value={(*ms_count).clone()}
+                                oninput={let c = ms_count.clone(); Callback::from(move |ev: InputEvent| c.set(ev.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
+                            />
+                            <input
+                                type="text" placeholder="Label (optional)"
+                                class="flex-1 min-w-0 bg-gray-100 dark:bg-gray-700 rounded-lg px-2 py-1.5 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                                value={(*ms_label).clone()}
+                                oninput={let l = ms_label.clone(); Callback::from(move |ev: InputEvent| l.set(ev.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
+                            />
+                        </div>
+                        <select
+                            class="w-full bg-gray-100 dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg px-2 py-1.5 text-sm outline-none neu-pressed"
+                            onchange={let x = ms_exercise.clone(); Callback::from(move |ev: Event| x.set(ev.target_unchecked_into::<web_sys::HtmlSelectElement>().value()))}
+                        >
+                            <option value="" selected={ms_exercise.is_empty()}>{"Total workouts"}</option>
+                            { for logged_ids.iter().map(|id| html! {
+                                <option value={id.clone()} selected={*ms_exercise == *id}>{format!("Sessions of {}", find_exercise_name(exercises, id))}</option>
+                            })}
+                        </select>
+                        <button
+                            class="w-full py-1.5 bg-blue-600 text-white rounded-lg text-sm font-bold hover:bg-blue-700 neu-btn transition-colors"
+                            onclick={on_add_milestone}
+                        >{"Add Milestone"}</button>
+                    </div>
+                }
                 <div class="flex gap-2 overflow-x-auto pb-1">
-                    { for MILESTONES.iter().map(|(threshold, emoji)| {
-                        let achieved = total >= *threshold;
+                    { for badges.iter().map(|b| {
+                        let achieved = b.progress >= b.threshold;
+                        let on_remove = b.custom_index.map(|i| {
+                            let custom_milestones = custom_milestones.clone();
+                            let save = save_custom_milestones.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let mut list = (*custom_milestones).clone();
+                                if i < list.len() {
+                                    list.remove(i);
+                                }
+                                save(list);
+                            })
+                        });
                         html! {
-                            <div class={classes!(
-                                "flex-shrink-0", "flex", "flex-col", "items-center", "gap-1", "px-3", "py-2", "rounded-lg", "border",
-                                if achieved {
-                                    "bg-yellow-500/10 border-yellow-500/30 text-yellow-500"
-                                } else {
-                                    "bg-gray-200 dark:bg-gray-700 border-transparent text-gray-400 dark:text-gray-600 opacity-50"
+                            <div
+                                title={b.label.clone()}
+                                class={classes!(
+                                    "relative", "flex-shrink-0", "flex", "flex-col", "items-center", "gap-1", "px-3", "py-2", "rounded-lg", "border",
+                                    if achieved {
+                                        "bg-yellow-500/10 border-yellow-500/30 text-yellow-500"
+                                    } else {
+                                        "bg-gray-200 dark:bg-gray-700 border-transparent text-gray-400 dark:text-gray-600 opacity-50"
+                                    },
+                                    b.custom_index.map(|_| "border-dashed")
+                                )}
+                            >
+                                <span class="text-lg">{&b.emoji}</span>
+                                <span class="text-[10px] font-bold">{b.threshold}</span>
+                                if let Some(on_remove) = on_remove {
+                                    <button
+                                        class="absolute -top-1 -right-1 w-4 h-4 rounded-full bg-gray-400 dark:bg-gray-600 text-white text-[9px] leading-none flex items-center justify-center hover:bg-red-500"
+                                        onclick={on_remove}
+                                    >{"\u{00d7}"}</button>
                                 }
-                            )}>
-                                <span class="text-lg">{emoji}</span>
-                                <span class="text-[10px] font-bold">{threshold}</span>
                             </div>
                         }
                     })}
                 </div>
-                { if let Some((next_threshold, _)) = next_milestone {
-                    let remaining = next_threshold - total;
+                { if let Some(next) = next_milestone {
+                    let remaining = next.threshold - next.progress;
                     html! {
                         <p class="text-xs text-gray-500 mt-2">
-                            {format!("Next: {} workouts ({} to go)", next_threshold, remaining)}
+                            {format!("Next: {} {} ({} to go)", next.emoji, next.label, remaining)}
                         </p>
                     }
                 } else {
@@ -453,11 +1369,45 @@ fn overview_tab(props: &OverviewProps) -> Html {
                 <BarChart data={workouts_per_week} title="Workouts Per Week" height={180} color="#3b82f6" />
             </div>
 
+            if !calories.is_empty() {
+                <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
+                    <BarChart data={calories_per_week} title="Est. Calories Per Week (kcal)" height={180} color="#f97316" />
+                    <p class="text-[10px] text-gray-400 dark:text-gray-500 mt-2">{"Estimated from session duration, exercise categories and body weight. Real burn varies a lot with effort and rest; use it for trends, not nutrition math."}</p>
+                </div>
+            }
+
             // Volume over time
             <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
-                <LineChart data={volume_per_week} title={format!("Volume Per Week ({})", props.units.weight_label())} height={180} color="#10b981" />
+                <div class="flex gap-2 mb-2">
+                    { for [(false, "Total"), (true, "Per kg lean mass")].into_iter().map(|(lean, label)| {
+                        let lean_volume = lean_volume.clone();
+                        let class = if show_lean == lean {
+                            "px-3 py-1 rounded-full text-xs bg-blue-600 text-white font-medium neu-chip-active transition-colors"
+                        } else {
+                            "px-3 py-1 rounded-full text-xs bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-300 neu-chip hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+                        };
+                        html! {
+                            <button
+                                {class}
+                                disabled={lean && lean_series.is_empty()}
+                                onclick={Callback::from(move |_| lean_volume.set(lean))}
+                            >{label}</button>
+                        }
+                    })}
+                </div>
+                if show_lean {
+                    <LineChart data={lean_volume_per_week} title={format!("Volume Per Week ({} per kg lean mass)", metric.unit_label(&props.units))} height={180} color="#14b8a6" markers={week_markers.clone()} trend_unit="/wk" />
+                    <p class="text-[10px] text-gray-400 dark:text-gray-500 mt-2">{"Each workout's volume divided by the lean mass (weight \u{00d7} (1 \u{2212} body fat %)) from your latest body metric at that date."}</p>
+                } else {
+                    <LineChart data={volume_per_week} title={format!("Volume Per Week ({})", metric.unit_label(&props.units))} height={180} color="#10b981" markers={week_markers} trend_unit="/wk" />
+                    if lean_series.is_empty() {
+                        <p class="text-[10px] text-gray-400 dark:text-gray-500 mt-2">{"Log body weight and body fat together in Body metrics to compare volume per kg of lean mass."}</p>
+                    }
+                }
             </div>
 
+            <AnnotationEditor annotations={(*annotations).clone()} on_change={on_annotations_change} />
+
             // Volume per muscle group over time (collapsible)
             { if !vol_cat_data.is_empty() {
                 let show = *show_volume_cats;
@@ -468,14 +1418,14 @@ fn overview_tab(props: &OverviewProps) -> Html {
                             class="w-full flex justify-between items-center text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider"
                             onclick={Callback::from(move |_| toggle.set(!show))}
                         >
-                            {"Volume Per Muscle Group"}
+                            {format!("Volume Per Muscle Group ({})", metric.unit_label(&props.units))}
                             <span class={classes!("text-gray-400", "transition-transform", if show { "rotate-180" } else { "" })}>{"\u{25be}"}</span>
                         </button>
                         { if show {
                             html! {
                                 <div class="mt-4 space-y-4">
                                     { for vol_cat_data.iter().map(|(cat, data)| {
-                                        let color = category_color(cat).to_string();
+                                        let color = config.color_for(cat);
                                         html! {
                                             <LineChart data={data.clone()} title={cat.to_string()} height={140} color={color} />
                                         }
@@ -492,6 +1442,41 @@ fn overview_tab(props: &OverviewProps) -> Html {
                 <HorizontalBarChart data={muscle_data} title="Muscle Group Distribution" />
             </div>
 
+            // PRs set this week
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
+                <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 mb-3 uppercase tracking-wider">{"PRs This Week"}</h3>
+                if week_prs.is_empty() {
+                    <p class="text-sm text-gray-500 dark:text-gray-400">{"No new records yet this week. Beat a previous best to see it here."}</p>
+                } else {
+                    <div class="space-y-2">
+                        { for week_prs.iter().map(|pr| {
+                            let (label, badge) = match pr.kind {
+                                PrKind::Weight if pr.assisted => ("Least Assist", "bg-amber-100 dark:bg-amber-900/30 text-amber-700 dark:text-amber-400"),
+                                PrKind::Weight => ("Max Weight", "bg-amber-100 dark:bg-amber-900/30 text-amber-700 dark:text-amber-400"),
+                                PrKind::E1rm => ("Est. 1RM", "bg-pink-100 dark:bg-pink-900/30 text-pink-700 dark:text-pink-400"),
+                            };
+                            let jump = format_jump(&props.units, pr.value - pr.previous, props.rounding);
+                            html! {
+                                <div class="flex justify-between items-center text-sm">
+                                    <div class="flex items-center gap-2 min-w-0 mr-2">
+                                        <span class={classes!("text-[10px]", "font-bold", "px-1.5", "py-0.5", "rounded", "flex-shrink-0", badge)}>{label}</span>
+                                        <span class="text-gray-700 dark:text-gray-300 truncate">{&pr.exercise_name}</span>
+                                    </div>
+                                    <div class="flex items-center gap-2 flex-shrink-0">
+                                        <span class="text-yellow-600 dark:text-yellow-400 font-bold">
+                                            {format!("{} {}", props.units.format_weight(pr.value, props.rounding), props.units.weight_label())}
+                                        </span>
+                                        if let Some(jump) = jump {
+                                            <span class="text-green-500 text-xs">{jump}</span>
+                                        }
+                                    </div>
+                                </div>
+                            }
+                        })}
+                    </div>
+                }
+            </div>
+
             // Personal Records
             if !prs.is_empty() {
                 <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
@@ -502,7 +1487,21 @@ fn overview_tab(props: &OverviewProps) -> Html {
                                 <div class="flex justify-between items-center text-sm">
                                     <span class="text-gray-700 dark:text-gray-300 truncate mr-2">{&pr.exercise_name}</span>
                                     <div class="flex items-center gap-2 flex-shrink-0">
-                                        <span class="text-yellow-600 dark:text-yellow-400 font-bold">{format!("{:.1} {}", props.units.display_weight(pr.max_weight), props.units.weight_label())}</span>
+                                        <span class="text-yellow-600 dark:text-yellow-400 font-bold">
+                                            { if pr.assisted {
+                                                format!("{} {} assist \u{d7} {}", props.units.format_weight(pr.max_weight, props.rounding), props.units.weight_label(), pr.reps)
+                                            } else if pr.max_weight > 0.0 {
+                                                format!(
+                                                    "{} {}{} \u{d7} {}",
+                                                    props.units.format_weight(pr.max_weight / pr.hands, props.rounding),
+                                                    props.units.weight_label(),
+                                                    if pr.hands > 1.0 { "/hand" } else { "" },
+                                                    pr.reps
+                                                )
+                                            } else {
+                                                format!("{} reps", pr.reps)
+                                            }}
+                                        </span>
                                         <span class="text-gray-500 text-xs">{&pr.date}</span>
                                     </div>
                                 </div>
@@ -517,6 +1516,56 @@ fn overview_tab(props: &OverviewProps) -> Html {
 
 // ── Progress Tab ────────────────────────────────────────────────────────────
 
+/// Recent sessions the est. 1RM goal projection is fitted to.
+const PROJECTION_SESSIONS: usize = 8;
+
+/// Best est. 1RM of each session of `exercise_id`, oldest first.
+fn e1rm_sessions(workouts: &[Workout], exercise_id: &str) -> Vec<(NaiveDate, f64)> {
+    let mut sessions: Vec<(NaiveDate, f64)> = workouts
+        .iter()
+        .filter_map(|w| {
+            let date = parse_date(&w.date)?;
+            let best = w
+                .exercises
+                .iter()
+                .filter(|we| we.exercise_id == exercise_id)
+                .flat_map(|we| we.sets.iter())
+                .filter(|s| s.completed && s.weight > 0.0 && s.reps > 0)
+                .map(|s| estimate_1rm(s.weight, s.reps))
+                .fold(0.0_f64, f64::max);
+            (best > 0.0).then_some((date, best))
+        })
+        .collect();
+    sessions.sort_by(|a, b| a.0.cmp(&b.0));
+    sessions
+}
+
+/// The last `PROJECTION_SESSIONS` sessions as (days since the first of them,
+/// est. 1RM) points for the trend line, with that first date.
+fn projection_points(sessions: &[(NaiveDate, f64)]) -> Option<(NaiveDate, Vec<(f64, f64)>)> {
+    let recent = &sessions[sessions.len().saturating_sub(PROJECTION_SESSIONS)..];
+    let (first, _) = *recent.first()?;
+    let points = recent
+        .iter()
+        .map(|(d, v)| ((*d - first).num_days() as f64, *v))
+        .collect();
+    Some((first, points))
+}
+
+/// Day the recent est. 1RM trend crosses `goal`, or `None` when the trend is
+/// flat or declining.
+fn project_goal_date(sessions: &[(NaiveDate, f64)], goal: f64) -> Option<NaiveDate> {
+    let (first, points) = projection_points(sessions)?;
+    let (slope, intercept) = linear_regression(&points)?;
+    if slope <= 0.0 {
+        return None;
+    }
+    // The fitted line can sit above the goal before any set actually hit it
+    let last_x = points.last()?.0;
+    let days = ((goal - intercept) / slope).ceil().max(last_x + 1.0);
+    first.checked_add_signed(chrono::Duration::try_days(days as i64)?)
+}
+
 #[derive(Properties, PartialEq)]
 struct ProgressProps {
     workouts: Vec<Workout>,
@@ -524,6 +1573,12 @@ struct ProgressProps {
     routines: Vec<crate::models::Routine>,
     #[prop_or_default]
     units: UnitSystem,
+    #[prop_or_default]
+    rounding: WeightRounding,
+    #[prop_or(Some(8))]
+    range: Option<usize>,
+    #[prop_or_default]
+    volume_metric: VolumeMetric,
 }
 
 #[function_component(ProgressTab)]
@@ -533,6 +1588,7 @@ fn progress_tab(props: &ProgressProps) -> Html {
     let routines = &props.routines;
 
     let selected_exercise = use_state(String::new);
+    let config = use_state(storage::load_user_config);
 
     if workouts.is_empty() {
         return html! {
@@ -566,12 +1622,17 @@ fn progress_tab(props: &ProgressProps) -> Html {
         })
     };
 
+    let assisted = is_assisted(exercises, &selected_exercise);
+
     // Build data for selected exercise
-    let (weight_data, volume_data, e1rm_data) = if !selected_exercise.is_empty() {
+    let (weight_data, volume_data, e1rm_data, session_dates, e1rm_dates) = if !selected_exercise.is_empty() {
         let mut weight_points: Vec<(String, f64)> = Vec::new();
         let mut volume_points: Vec<(String, f64)> = Vec::new();
         let mut e1rm_points: Vec<(String, f64)> = Vec::new();
+        let mut session_dates: Vec<NaiveDate> = Vec::new();
+        let mut e1rm_dates: Vec<NaiveDate> = Vec::new();
 
+        let start = range_start(workouts, props.range);
         let mut relevant: Vec<&Workout> = workouts
             .iter()
             .filter(|w| {
@@ -579,14 +1640,19 @@ fn progress_tab(props: &ProgressProps) -> Html {
                     .iter()
                     .any(|we| we.exercise_id == *selected_exercise)
             })
+            .filter(|w| start.is_none_or(|s| parse_date(&w.date).is_some_and(|d| d >= s)))
             .collect();
         relevant.sort_by(|a, b| a.date.cmp(&b.date));
 
         for w in &relevant {
             for we in &w.exercises {
                 if we.exercise_id == *selected_exercise {
-                    let max_w = exercise_max_weight(we);
-                    let vol = exercise_volume(we);
+                    let max_w = if assisted {
+                        exercise_min_weight(we).unwrap_or(0.0)
+                    } else {
+                        exercise_max_weight(we)
+                    };
+                    let vol = exercise_volume(we, props.volume_metric);
                     let label = if w.date.len() >= 10 {
                         format!("{}/{}", &w.date[5..7], &w.date[8..10])
                     } else {
@@ -601,29 +1667,120 @@ fn progress_tab(props: &ProgressProps) -> Html {
                         .map(|s| estimate_1rm(s.weight, s.reps))
                         .fold(0.0_f64, f64::max);
 
+                    let date = parse_date(&w.date).unwrap_or_default();
                     weight_points.push((label.clone(), max_w));
                     volume_points.push((label.clone(), vol));
-                    if max_e1rm > 0.0 {
+                    session_dates.push(date);
+                    // Est. 1RM from assistance weight would read backwards
+                    if max_e1rm > 0.0 && !assisted {
                         e1rm_points.push((label, max_e1rm));
+                        e1rm_dates.push(date);
                     }
                 }
             }
         }
 
-        // Limit to last 12 sessions
-        let n = weight_points.len();
-        if n > 12 {
-            weight_points = weight_points[n - 12..].to_vec();
-            volume_points = volume_points[n - 12..].to_vec();
-        }
-        let n2 = e1rm_points.len();
-        if n2 > 12 {
-            e1rm_points = e1rm_points[n2 - 12..].to_vec();
-        }
+        (weight_points, volume_points, e1rm_points, session_dates, e1rm_dates)
+    } else {
+        (vec![], vec![], vec![], vec![], vec![])
+    };
+    let annotations = storage::load_annotations();
+    let session_markers = annotation_markers(&session_dates, 1, &annotations);
+    let e1rm_markers = annotation_markers(&e1rm_dates, 1, &annotations);
+
+    let pr_events = if selected_exercise.is_empty() {
+        Vec::new()
+    } else {
+        pr_timeline(workouts, &selected_exercise, assisted)
+    };
 
-        (weight_points, volume_points, e1rm_points)
+    // Est. 1RM goal and projected date for the selected exercise
+    let goal_card = if selected_exercise.is_empty() || assisted {
+        html! {}
+    } else {
+        let sessions = e1rm_sessions(workouts, &selected_exercise);
+        let goal = config.e1rm_goals.get(&*selected_exercise).copied();
+        let units = &props.units;
+        let wl = units.weight_label();
+        let current = sessions[sessions.len().saturating_sub(PROJECTION_SESSIONS)..]
+            .iter()
+            .map(|(_, v)| *v)
+            .fold(0.0_f64, f64::max);
+        let on_goal_change = {
+            let config = config.clone();
+            let exercise_id = (*selected_exercise).clone();
+            let units = units.clone();
+            Callback::from(move |e: Event| {
+                let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                let mut new_config = (*config).clone();
+                match input.value().parse::<f64>() {
+                    Ok(v) if v > 0.0 => {
+                        new_config.e1rm_goals.insert(exercise_id.clone(), units.to_kg(v));
+                    }
+                    _ => {
+                        new_config.e1rm_goals.remove(&exercise_id);
+                    }
+                }
+                storage::save_user_config(&new_config);
+                config.set(new_config);
+            })
+        };
+        let status = goal.map(|goal| {
+            if let Some((date, _)) = sessions.iter().find(|(_, v)| *v >= goal) {
+                html! {
+                    <div class="p-3 rounded-lg bg-green-500/15 text-green-700 dark:text-green-300 text-sm font-bold text-center">
+                        {format!("\u{1f389} Goal reached on {}!", date.format("%b %-d, %Y"))}
+                    </div>
+                }
+            } else {
+                let today = crate::dates::today();
+                match project_goal_date(&sessions, goal) {
+                    Some(date) => {
+                        let weeks = ((date - today).num_days().max(0) as f64 / 7.0).ceil() as i64;
+                        html! {
+                            <p class="text-sm text-gray-700 dark:text-gray-300">
+                                {"Projected: "}
+                                <span class="font-bold">{date.format("%b %-d, %Y").to_string()}</span>
+                                <span class="text-gray-500">{format!(" (~{} wk{})", weeks, if weeks == 1 { "" } else { "s" })}</span>
+                            </p>
+                        }
+                    }
+                    None => html! {
+                        <p class="text-sm text-gray-500 dark:text-gray-400">{"No projection \u{2014} your recent est. 1RM trend is flat or declining."}</p>
+                    },
+                }
+            }
+        });
+        html! {
+            <div class="p-3 bg-white dark:bg-gray-700/50 rounded-lg space-y-2">
+                <div class="flex items-center justify-between gap-2">
+                    <span class="text-xs font-bold text-gray-500 dark:text-gray-400 uppercase tracking-wider">{"1RM Goal"}</span>
+                    <div class="flex items-center gap-1">
+                        <input
+                            type="number" min="0" step="any" placeholder="Goal"
+                            class="w-20 bg-gray-100 dark:bg-gray-700 rounded-lg px-2 py-1 text-sm text-right text-gray-900 dark:text-white outline-none neu-pressed"
+                            value={goal.map(|g| ((units.display_weight(g) * 10.0).round() / 10.0).to_string()).unwrap_or_default()}
+                            onchange={on_goal_change}
+                        />
+                        <span class="text-xs text-gray-500">{wl}</span>
+                    </div>
+                </div>
+                if current > 0.0 {
+                    <p class="text-xs text-gray-500 dark:text-gray-400">
+                        {format!("Current est. 1RM: {}{}", units.format_weight(current, props.rounding), wl)}
+                        if let Some(goal) = goal {
+                            {format!(" \u{00b7} Goal: {}{}", units.format_weight(goal, props.rounding), wl)}
+                        }
+                    </p>
+                }
+                { status.unwrap_or_default() }
+            </div>
+        }
+    };
+    let rep_prs = if selected_exercise.is_empty() {
+        Vec::new()
     } else {
-        (vec![], vec![], vec![])
+        rep_records(workouts, &selected_exercise)
     };
 
     // Routine tracking
@@ -682,13 +1839,12 @@ fn progress_tab(props: &ProgressProps) -> Html {
                     };
 
                     let wl = props.units.weight_label();
-                    let d_last = props.units.display_weight(last);
                     let d_diff = props.units.display_weight(diff);
                     Some(html! {
                         <div class="flex justify-between text-xs">
                             <span class="text-gray-400 truncate mr-2">{name}</span>
                             <span class={color}>
-                                {format!("{:.1}{} ", d_last, wl)}{arrow}
+                                {format!("{}{} ", props.units.format_weight(last, props.rounding), wl)}{arrow}
                                 if diff.abs() > 0.0 {
                                     <span class="text-gray-500">{format!(" ({:+.1})", d_diff)}</span>
                                 }
@@ -734,15 +1890,84 @@ fn progress_tab(props: &ProgressProps) -> Html {
                 </select>
 
                 if !weight_data.is_empty() {
-                    <LineChart data={weight_data} title={format!("Max Weight Per Session ({})", props.units.weight_label())} height={180} color="#f59e0b" />
+                    if assisted {
+                        <LineChart data={weight_data} title={format!("Least Assistance Per Session ({}, lower is better)", props.units.weight_label())} height={180} color="#f59e0b" markers={session_markers.clone()} />
+                    } else {
+                        <LineChart data={weight_data} title={format!("Max Weight Per Session ({})", props.units.weight_label())} height={180} color="#f59e0b" markers={session_markers.clone()} trend_unit="/session" />
+                    }
                 }
 
                 if !volume_data.is_empty() {
-                    <LineChart data={volume_data} title={format!("Volume Per Session ({})", props.units.weight_label())} height={180} color="#8b5cf6" />
+                    <LineChart data={volume_data} title={format!("Volume Per Session ({})", props.volume_metric.unit_label(&props.units))} height={180} color="#8b5cf6" markers={session_markers} trend_unit="/session" />
                 }
 
                 if !e1rm_data.is_empty() {
-                    <LineChart data={e1rm_data} title={format!("Est. 1RM Per Session ({})", props.units.weight_label())} height={180} color="#ec4899" />
+                    <LineChart data={e1rm_data} title={format!("Est. 1RM Per Session ({})", props.units.weight_label())} height={180} color="#ec4899" markers={e1rm_markers} trend_unit="/session" />
+                }
+                { goal_card }
+
+                if !pr_events.is_empty() {
+                    <div class="space-y-2">
+                        <h4 class="text-xs font-bold text-gray-500 dark:text-gray-400 uppercase tracking-wider">{"PR Timeline"}</h4>
+                        <div class="space-y-1.5 max-h-64 overflow-y-auto">
+                            { for pr_events.iter().rev().map(|ev| {
+                                let wl = props.units.weight_label();
+                                let (label, badge) = match ev.kind {
+                                    PrKind::Weight if assisted => ("Least Assist", "bg-amber-100 dark:bg-amber-900/30 text-amber-700 dark:text-amber-400"),
+                                    PrKind::Weight => ("Max Weight", "bg-amber-100 dark:bg-amber-900/30 text-amber-700 dark:text-amber-400"),
+                                    PrKind::E1rm => ("Est. 1RM", "bg-pink-100 dark:bg-pink-900/30 text-pink-700 dark:text-pink-400"),
+                                };
+                                html! {
+                                    <div class="flex justify-between items-center text-xs bg-white dark:bg-gray-700/50 rounded-lg px-3 py-2">
+                                        <div class="flex items-center gap-2">
+                                            <span class={classes!("px-1.5", "py-0.5", "rounded", "text-[10px]", "font-bold", badge)}>{label}</span>
+                                            <span class="font-mono text-gray-500">{&ev.date}</span>
+                                        </div>
+                                        <div class="text-right">
+                                            <span class="font-bold text-gray-900 dark:text-gray-100">
+                                                {format!("{}{}", props.units.format_weight(ev.value, props.rounding), wl)}
+                                            </span>
+                                            { match ev.jump {
+                                                Some(j) => match format_jump(&props.units, j, props.rounding) {
+                                                    Some(label) => html! {
+                                                        <span class="ml-1.5 text-green-500">{label}</span>
+                                                    },
+                                                    None => html! {},
+                                                },
+                                                None => html! {
+                                                    <span class="ml-1.5 text-gray-400">{"first"}</span>
+                                                },
+                                            }}
+                                        </div>
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    </div>
+                }
+
+                if !rep_prs.is_empty() {
+                    <div class="space-y-2">
+                        <h4 class="text-xs font-bold text-gray-500 dark:text-gray-400 uppercase tracking-wider">{"Rep PRs by Weight"}</h4>
+                        <div class="space-y-1.5 max-h-64 overflow-y-auto">
+                            { for rep_prs.iter().map(|rec| {
+                                let load = if rec.weight > 0.0 {
+                                    format!("{}{}", props.units.format_weight(rec.weight, props.rounding), props.units.weight_label())
+                                } else {
+                                    "Bodyweight".to_string()
+                                };
+                                html! {
+                                    <div class="flex justify-between items-center text-xs bg-white dark:bg-gray-700/50 rounded-lg px-3 py-2">
+                                        <div class="flex items-center gap-2">
+                                            <span class="font-bold text-gray-900 dark:text-gray-100">{load}</span>
+                                            <span class="font-mono text-gray-500">{&rec.date}</span>
+                                        </div>
+                                        <span class="font-bold text-emerald-600 dark:text-emerald-400">{format!("{} reps", rec.reps)}</span>
+                                    </div>
+                                }
+                            })}
+                        </div>
+                    </div>
                 }
             </div>
 
@@ -757,13 +1982,228 @@ fn progress_tab(props: &ProgressProps) -> Html {
     }
 }
 
+// ── Cardio Tab ──────────────────────────────────────────────────────────────
+
+#[derive(Properties, PartialEq)]
+struct CardioProps {
+    workouts: Vec<Workout>,
+    exercises: Vec<Exercise>,
+    #[prop_or_default]
+    units: UnitSystem,
+    #[prop_or(Some(8))]
+    range: Option<usize>,
+}
+
+/// Completed distance (km) and duration (secs) for one cardio exercise entry.
+fn cardio_totals(we: &WorkoutExercise) -> (f64, u32) {
+    we.sets
+        .iter()
+        .filter(|s| s.completed)
+        .fold((0.0, 0), |(dist, secs), s| {
+            (dist + s.distance.unwrap_or(0.0), secs + s.duration_secs.unwrap_or(0))
+        })
+}
+
+/// Format a pace in minutes per distance unit as "5:32".
+fn format_pace(mins: f64) -> String {
+    let total_secs = (mins * 60.0).round() as u32;
+    format!("{}:{:02}", total_secs / 60, total_secs % 60)
+}
+
+#[function_component(CardioTab)]
+fn cardio_tab(props: &CardioProps) -> Html {
+    let workouts = &props.workouts;
+    let exercises = &props.exercises;
+    let units = &props.units;
+    let selected_exercise = use_state(String::new);
+
+    let is_cardio = |id: &str| {
+        find_exercise(exercises, id)
+            .map(|e| e.tracking_type == ExerciseTrackingType::Cardio)
+            .unwrap_or(false)
+    };
+
+    // Cardio exercises that appear in workouts, in first-seen order
+    let mut cardio_ids: Vec<String> = Vec::new();
+    for w in workouts {
+        for we in &w.exercises {
+            if is_cardio(&we.exercise_id) && !cardio_ids.contains(&we.exercise_id) {
+                cardio_ids.push(we.exercise_id.clone());
+            }
+        }
+    }
+
+    if cardio_ids.is_empty() {
+        return html! {
+            <div class="text-center py-12 bg-gray-50 dark:bg-gray-800/20 rounded-2xl border border-dashed border-gray-200 dark:border-gray-700 transition-colors">
+                <p class="text-4xl mb-4">{"\u{1f3c3}"}</p>
+                <p class="text-lg font-bold text-gray-900 dark:text-gray-100">{"No cardio yet"}</p>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mt-1 px-4">{"Log runs, rides or rows with distance and time to see cardio trends."}</p>
+            </div>
+        };
+    }
+
+    if selected_exercise.is_empty() {
+        selected_exercise.set(cardio_ids[0].clone());
+    }
+
+    let on_select = {
+        let selected_exercise = selected_exercise.clone();
+        Callback::from(move |e: Event| {
+            let input: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected_exercise.set(input.value());
+        })
+    };
+
+    // ── Weekly totals
+    let range_weeks = range_week_count(workouts, props.range);
+    let weeks = last_n_weeks(workouts, range_weeks);
+    let mut week_distance: HashMap<(i32, u32), f64> = HashMap::new();
+    let mut week_secs: HashMap<(i32, u32), u32> = HashMap::new();
+    let mut total_distance = 0.0;
+    let mut total_secs = 0u32;
+    let mut sessions = 0usize;
+    for w in workouts {
+        let mut had_cardio = false;
+        for we in w.exercises.iter().filter(|we| is_cardio(&we.exercise_id)) {
+            let (dist, secs) = cardio_totals(we);
+            if dist <= 0.0 && secs == 0 {
+                continue;
+            }
+            had_cardio = true;
+            total_distance += dist;
+            total_secs += secs;
+            if let Some(d) = parse_date(&w.date) {
+                *week_distance.entry(iso_week_key(d)).or_default() += dist;
+                *week_secs.entry(iso_week_key(d)).or_default() += secs;
+            }
+        }
+        if had_cardio {
+            sessions += 1;
+        }
+    }
+
+    let distance_per_week: Vec<(String, f64)> = weeks
+        .iter()
+        .map(|(key, label)| {
+            let km = week_distance.get(key).copied().unwrap_or(0.0);
+            (label.clone(), (units.display_distance(km) * 10.0).round() / 10.0)
+        })
+        .collect();
+    let minutes_per_week: Vec<(String, f64)> = weeks
+        .iter()
+        .map(|(key, label)| {
+            let secs = week_secs.get(key).copied().unwrap_or(0);
+            (label.clone(), (secs as f64 / 60.0).round())
+        })
+        .collect();
+
+    // ── Pace trend for the selected exercise (minutes per distance unit)
+    let mut relevant: Vec<&Workout> = workouts.iter().collect();
+    relevant.sort_by(|a, b| a.date.cmp(&b.date));
+    let mut pace_data: Vec<(String, f64)> = Vec::new();
+    for w in relevant {
+        for we in w.exercises.iter().filter(|we| we.exercise_id == *selected_exercise) {
+            let (dist, secs) = cardio_totals(we);
+            let display_dist = units.display_distance(dist);
+            if display_dist > 0.0 && secs > 0 {
+                let label = if w.date.len() >= 10 {
+                    format!("{}/{}", &w.date[5..7], &w.date[8..10])
+                } else {
+                    w.date.clone()
+                };
+                let pace = secs as f64 / 60.0 / display_dist;
+                pace_data.push((label, (pace * 100.0).round() / 100.0));
+            }
+        }
+    }
+    let n = pace_data.len();
+    if n > 12 {
+        pace_data = pace_data[n - 12..].to_vec();
+    }
+    let best_pace = pace_data.iter().map(|(_, p)| *p).fold(f64::INFINITY, f64::min);
+    let dl = units.distance_label();
+
+    html! {
+        <div class="space-y-6">
+            <div class="grid grid-cols-2 gap-3">
+                <StatCard label="Cardio Sessions" value={sessions.to_string()} icon="\u{1f3c3}" />
+                <StatCard label={format!("Total Distance ({})", dl)} value={format!("{:.1}", units.display_distance(total_distance))} icon="\u{1f4cd}" />
+                <StatCard label="Total Cardio Time" value={format_tut(total_secs as f64)} icon="\u{23f1}" />
+                <StatCard
+                    label={format!("Best Pace (min/{})", dl)}
+                    value={if best_pace.is_finite() { format_pace(best_pace) } else { "--".to_string() }}
+                    icon="\u{26a1}"
+                />
+            </div>
+
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
+                <BarChart data={distance_per_week} title={format!("Distance Per Week ({})", dl)} color="#06b6d4" />
+            </div>
+
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
+                <BarChart data={minutes_per_week} title="Cardio Minutes Per Week" color="#22c55e" />
+            </div>
+
+            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 space-y-4 neu-flat transition-colors">
+                <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Pace Trend"}</h3>
+                <select class="w-full bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg px-3 py-2 text-sm outline-none neu-pressed transition-colors"
+                        onchange={on_select}>
+                    { for cardio_ids.iter().map(|id| {
+                        let name = find_exercise_name(exercises, id);
+                        let selected = *selected_exercise == *id;
+                        html! {
+                            <option value={id.clone()} selected={selected}>{name}</option>
+                        }
+                    })}
+                </select>
+                if pace_data.is_empty() {
+                    <p class="text-sm text-gray-500 dark:text-gray-400 text-center py-4">{"Log both distance and time for this exercise to see pace."}</p>
+                } else {
+                    <LineChart data={pace_data} title={format!("Pace (min/{}) — lower is faster", dl)} height={180} color="#f97316" />
+                }
+            </div>
+        </div>
+    }
+}
+
 // ── Body Tab ────────────────────────────────────────────────────────────────
 
+/// Days covered by the body weight moving average.
+const WEIGHT_AVG_DAYS: i64 = 7;
+
+/// Trailing moving average over `days` calendar days for date-sorted
+/// (date, value) points. Each point averages every weigh-in in the window
+/// ending on its date, so gaps just mean fewer samples rather than skewing
+/// the window the way a fixed "last N entries" average would.
+fn trailing_average(points: &[(NaiveDate, f64)], days: i64) -> Vec<f64> {
+    points
+        .iter()
+        .map(|(d, _)| {
+            let from = *d - chrono::Duration::days(days - 1);
+            let window: Vec<f64> = points
+                .iter()
+                .filter(|(pd, _)| *pd >= from && pd <= d)
+                .map(|(_, v)| *v)
+                .collect();
+            window.iter().sum::<f64>() / window.len() as f64
+        })
+        .collect()
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum WeightView {
+    Raw,
+    Average,
+    Both,
+}
+
 #[function_component(BodyTab)]
 fn body_tab() -> Html {
     let metrics = storage::load_body_metrics();
     let config = storage::load_user_config();
-    let units = &config.unit_system;
+    let units = config.body_units();
+    let weight_view = use_state(|| WeightView::Both);
 
     if metrics.is_empty() {
         return html! {
@@ -775,11 +2215,48 @@ fn body_tab() -> Html {
         };
     }
 
-    let mut weight_data: Vec<(String, f64)> = metrics
+    let mut weight_points: Vec<(NaiveDate, f64)> = metrics
         .iter()
-        .filter_map(|m| m.weight.map(|w| (m.date[5..].to_string(), w)))
+        .filter_map(|m| Some((parse_date(&m.date)?, m.weight?)))
+        .collect();
+    weight_points.sort_by(|a, b| a.0.cmp(&b.0));
+    // Points stay in kg; the chart shows them in the body-weight unit
+    let weight_avg: Vec<f64> = trailing_average(&weight_points, WEIGHT_AVG_DAYS)
+        .into_iter()
+        .map(|w| units.display_weight(w))
         .collect();
-    weight_data.sort_by(|a, b| a.0.cmp(&b.0));
+    let weight_data: Vec<(String, f64)> = weight_points
+        .iter()
+        .map(|(d, w)| (d.format("%m-%d").to_string(), units.display_weight(*w)))
+        .collect();
+    let weight_dates: Vec<NaiveDate> = weight_points.iter().map(|(d, _)| *d).collect();
+    let weight_markers = annotation_markers(&weight_dates, 1, &storage::load_annotations());
+    let (weight_chart, weight_overlay) = match *weight_view {
+        WeightView::Raw => (weight_data.clone(), None),
+        WeightView::Average => (
+            weight_data
+                .iter()
+                .zip(&weight_avg)
+                .map(|((label, _), avg)| (label.clone(), *avg))
+                .collect(),
+            None,
+        ),
+        WeightView::Both => (
+            weight_data.clone(),
+            Some((weight_avg, AttrValue::from("#1d4ed8"))),
+        ),
+    };
+    let view_chip = |view: WeightView, label: &'static str| {
+        let weight_view = weight_view.clone();
+        let class = if *weight_view == view {
+            "px-3 py-1 rounded-full text-xs bg-blue-600 text-white font-medium neu-chip-active transition-colors"
+        } else {
+            "px-3 py-1 rounded-full text-xs bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-300 neu-chip hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+        };
+        html! {
+            <button {class} onclick={Callback::from(move |_| weight_view.set(view))}>{label}</button>
+        }
+    };
 
     let mut fat_data: Vec<(String, f64)> = metrics
         .iter()
@@ -787,7 +2264,7 @@ fn body_tab() -> Html {
         .collect();
     fat_data.sort_by(|a, b| a.0.cmp(&b.0));
 
-    let latest_weight = weight_data.last().map(|d| d.1);
+    let latest_weight = weight_points.last().map(|d| d.1);
     let bmi = if let (Some(w), Some(h)) = (latest_weight, config.height) {
         let h_m = h / 100.0;
         Some(w / (h_m * h_m))
@@ -798,13 +2275,18 @@ fn body_tab() -> Html {
     html! {
         <div class="space-y-6">
             <div class="grid grid-cols-2 gap-3">
-                <StatCard label="Latest Weight" value={latest_weight.map(|w| format!("{:.1}{}", units.display_weight(w), units.weight_label())).unwrap_or_else(|| "--".to_string())} icon="\u{2696}" />
+                <StatCard label="Latest Weight" value={latest_weight.map(|w| format!("{}{}", units.format_weight(w, config.weight_rounding), units.weight_label())).unwrap_or_else(|| "--".to_string())} icon="\u{2696}" />
                 <StatCard label="BMI" value={bmi.map(|b| format!("{:.1}", b)).unwrap_or_else(|| "--".to_string())} icon="\u{1f4cf}" />
             </div>
 
             if !weight_data.is_empty() {
-                <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
-                    <LineChart data={weight_data} title={format!("Weight Progress ({})", units.weight_label())} height={180} color="#3b82f6" />
+                <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-2">
+                    <LineChart data={weight_chart} overlay={weight_overlay} title={format!("Weight Progress ({})", units.weight_label())} height={180} color="#3b82f6" markers={weight_markers} />
+                    <div class="flex items-center gap-2">
+                        {view_chip(WeightView::Raw, "Raw")}
+                        {view_chip(WeightView::Average, "7-day avg")}
+                        {view_chip(WeightView::Both, "Both")}
+                    </div>
                 </div>
             }
 
@@ -54,7 +54,21 @@ pub fn exercises_page() -> Html {
                     let data = ShareableData::Exercise { exercise: ex_clean };
                     share_target_c.set(Some((data, text)));
                 });
-                html! { <ExerciseDetail exercise={ex.clone()} on_back={on_back} on_share={on_share} /> }
+                let on_save_instructions = {
+                    let custom = custom_exercises.clone();
+                    let selected = selected.clone();
+                    let id = ex.id.clone();
+                    Callback::from(move |instructions: Option<String>| {
+                        let mut exs = (*custom).clone();
+                        if let Some(e) = exs.iter_mut().find(|e| e.id == id) {
+                            e.instructions = instructions;
+                            selected.set(Some(e.clone()));
+                        }
+                        storage::save_custom_exercises(&exs);
+                        custom.set(exs);
+                    })
+                };
+                html! { <ExerciseDetail exercise={ex.clone()} on_back={on_back} on_share={on_share} on_save_instructions={on_save_instructions} /> }
             } else if *show_custom_form {
                 html! {
                     <div class="px-4 py-4">
@@ -42,6 +42,10 @@ const FAQS: &[FaqItem] = &[
         question: "How do I back up my data?",
         answer: "Treening automatically mirrors every save to IndexedDB as a safety net. If localStorage is ever cleared, the app will auto-restore from the IndexedDB backup on next load. For extra safety, go to Settings and tap \"Export Data\" to download a JSON file you can keep offline.",
     },
+    FaqItem {
+        question: "How do I move my data to another device?",
+        answer: "In Settings, enter a passphrase under \"Encrypted Backup\" and tap \"Export Encrypted\". The file is encrypted in your browser (AES-GCM) so it is safe to keep in your own cloud storage. On the other device, enter the same passphrase and use Merge or Replace under \"Import Data\". A wrong passphrase is rejected without touching your data.",
+    },
     FaqItem {
         question: "What does the 'Storage full' warning mean?",
         answer: "It means your browser's localStorage quota has been exceeded and new data could not be saved. Go to Settings and export your data immediately, then free up space by clearing old browser data for other sites. The IndexedDB backup should still have your most recent data.",
@@ -66,6 +70,14 @@ const FAQS: &[FaqItem] = &[
         question: "What is the 1RM estimate?",
         answer: "For every completed strength set with more than 1 rep, Treening shows your estimated one-rep max (1RM) using the Epley formula: weight \u{00d7} (1 + reps/30). This helps you gauge your true maximal strength without actually testing it.",
     },
+    FaqItem {
+        question: "How are calories estimated?",
+        answer: "Analytics multiplies a MET value by your body weight and session duration. Each session's MET is the average of its exercises' categories (e.g. Legs 6.0, Arms 3.5, Cardio 7.0), weighted by sets. Body weight comes from your latest body metric, or the fallback in Settings, where you can also change the MET per category. It is a rough estimate for spotting trends, not a measurement.",
+    },
+    FaqItem {
+        question: "How do I load a percentage of my 1RM?",
+        answer: "Tap the % button next to a strength set's weight. It shows your best estimated 1RM for that exercise from past workouts, lets you pick or type a percentage, and previews the weight rounded to a loadable plate jump. Tap Apply to fill the set.",
+    },
     FaqItem {
         question: "How does 'Previous Performance' work?",
         answer: "When you add an exercise to your workout, Treening looks up your most recent workout that included the same exercise and shows a summary of the sets (e.g., 'Last: S1: 80kg x8, S2: 85kg x6') in gray text above your current sets. This helps you decide what weights to use.",
@@ -76,7 +88,7 @@ const FAQS: &[FaqItem] = &[
     },
     FaqItem {
         question: "How does the Plate Calculator work?",
-        answer: "Tap the barbell icon next to any weight input to see the exact plates needed per side (25, 20, 15, 10, 5, 2.5, 1.25 kg). The calculation uses your configured bar weight (default 20 kg), which you can change in Settings under 'Bar Weight'.",
+        answer: "Tap the barbell icon next to any weight input to see the exact plates needed per side (25, 20, 15, 10, 5, 2.5, 1.25 kg). The calculation uses your configured bar weight (default 20 kg), which you can change in Settings under 'Bar Weight'. If you set up gym profiles in Settings, the active gym's bar and plates are used instead. For a specialty bar on one lift (say a 25 kg safety squat bar), set its bar weight on that exercise's detail page.",
     },
     FaqItem {
         question: "What are Supersets?",
@@ -124,7 +136,7 @@ const FAQS: &[FaqItem] = &[
     },
     FaqItem {
         question: "What are warm-up sets?",
-        answer: "For strength exercises where your first set weight is above the bar weight, a 'Warm-up Sets' button appears. Tapping it prepends four progressive warm-up sets at 40%, 60%, 75%, and 90% of your working weight (with 10, 6, 4, and 2 reps respectively), rounded to the nearest 2.5 kg.",
+        answer: "For strength exercises where your first set weight is above the bar weight, a 'Warm-up Sets' button appears. Tapping it prepends four progressive warm-up sets at 40%, 60%, 75%, and 90% of your working weight (with 10, 6, 4, and 2 reps respectively), rounded to the nearest 2.5 kg (or the smallest jump your active gym's plates allow) and never below the empty bar. On a heavy day, 'Add warm-ups to all' on the Workout page does the same for every such exercise at once, skipping ones that already have their warm-ups.",
     },
     FaqItem {
         question: "Is there an undo feature?",
@@ -140,7 +152,7 @@ const FAQS: &[FaqItem] = &[
     },
     FaqItem {
         question: "What are the milestone badges?",
-        answer: "The milestones row in Analytics Overview shows achievement badges at 1, 5, 10, 25, 50, 100, 250, and 500 total workouts. Achieved badges are highlighted in gold; unachieved ones are greyed out. Below the row, you can see how many workouts remain until your next milestone.",
+        answer: "The milestones row in Analytics Overview shows achievement badges at 1, 5, 10, 25, 50, 100, 250, and 500 total workouts. Achieved badges are highlighted in gold; unachieved ones are greyed out. Tap \"+ Custom\" to add your own milestones, such as 1000 workouts or 100 sessions of a specific exercise. Below the row, you can see how far you are from your closest next milestone.",
     },
     FaqItem {
         question: "What do the training frequency chips mean?",
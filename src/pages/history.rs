@@ -4,9 +4,40 @@ use crate::models::{Exercise, Workout};
 use crate::storage;
 use yew::prelude::*;
 
+/// Longest note shown in full in search results; longer ones are cut around the match.
+const SNIPPET_CHARS: usize = 80;
+
+/// The part of `note` around the first match of `query_lower`, with ellipses where cut.
+fn note_snippet(note: &str, query_lower: &str) -> String {
+    let chars: Vec<char> = note.chars().collect();
+    if chars.len() <= SNIPPET_CHARS {
+        return note.to_string();
+    }
+    let lower = note.to_lowercase();
+    // Lowercasing can change byte lengths for some scripts; fall back to the start then
+    let match_idx = if lower.len() == note.len() {
+        lower.find(query_lower).map(|b| lower[..b].chars().count()).unwrap_or(0)
+    } else {
+        0
+    };
+    let start = match_idx.saturating_sub(SNIPPET_CHARS / 3);
+    let end = (start + SNIPPET_CHARS).min(chars.len());
+    let start = end.saturating_sub(SNIPPET_CHARS);
+    let mut out: String = chars[start..end].iter().collect();
+    if start > 0 {
+        out.insert(0, '…');
+    }
+    if end < chars.len() {
+        out.push('…');
+    }
+    out
+}
+
 #[function_component(HistoryPage)]
 pub fn history_page() -> Html {
     let workouts = use_state(storage::load_workouts);
+    let notes_query = use_state(String::new);
+    let focus = use_state(|| None::<(String, u32)>);
 
     let custom_exercises = storage::load_custom_exercises();
     let all_exercises: Vec<Exercise> = {
@@ -37,30 +68,118 @@ pub fn history_page() -> Html {
         })
     };
 
+    // Workouts whose session, exercise or set notes contain the query, newest first
+    let query_lower = notes_query.trim().to_lowercase();
+    let note_matches: Vec<(&Workout, Vec<(String, String)>)> = if query_lower.is_empty() {
+        Vec::new()
+    } else {
+        let exercise_name = |id: &str| {
+            all_exercises
+                .iter()
+                .find(|e| e.id == id)
+                .map(|e| e.name.clone())
+                .unwrap_or_else(|| id.to_string())
+        };
+        let mut found: Vec<(&Workout, Vec<(String, String)>)> = workouts
+            .iter()
+            .filter_map(|w| {
+                let mut hits = Vec::new();
+                if w.notes.to_lowercase().contains(&query_lower) {
+                    hits.push(("Session".to_string(), note_snippet(&w.notes, &query_lower)));
+                }
+                for we in &w.exercises {
+                    if we.notes.to_lowercase().contains(&query_lower) {
+                        hits.push((exercise_name(&we.exercise_id), note_snippet(&we.notes, &query_lower)));
+                    }
+                    for (i, s) in we.sets.iter().enumerate() {
+                        if let Some(note) = s.note.as_deref() {
+                            if note.to_lowercase().contains(&query_lower) {
+                                hits.push((
+                                    format!("{} · Set {}", exercise_name(&we.exercise_id), i + 1),
+                                    note_snippet(note, &query_lower),
+                                ));
+                            }
+                        }
+                    }
+                }
+                (!hits.is_empty()).then_some((w, hits))
+            })
+            .collect();
+        found.sort_by(|a, b| b.0.date.cmp(&a.0.date));
+        found
+    };
+
     html! {
         <div class="pb-20 transition-colors duration-200">
             <div class="px-4 pt-4 pb-2">
                 <h1 class="text-2xl font-bold mb-1 text-gray-900 dark:text-gray-100">{"Workout History"}</h1>
                 <p class="text-gray-500 dark:text-gray-400 text-sm mb-3">{"Your past workouts."}</p>
-            </div>
-            { if workouts.is_empty() {
-                html! {
-                    <div class="mx-4 text-center py-12 bg-gray-50 dark:bg-gray-800/20 rounded-2xl border border-dashed border-gray-200 dark:border-gray-700 transition-colors">
-                        <p class="text-4xl mb-4">{"📋"}</p>
-                        <p class="text-lg font-bold text-gray-900 dark:text-gray-100">{"No workouts yet!"}</p>
-                        <p class="text-sm text-gray-500 dark:text-gray-400 mt-1 px-4">{"Tap \"Start New Workout\" on the home page to log your first session."}</p>
-                    </div>
-                }
-            } else {
-                html! {
-                    <HistoryList
-                        workouts={(*workouts).clone()}
-                        all_exercises={all_exercises}
-                        on_delete={on_delete_workout}
-                        on_update={on_update_workout}
+                if !workouts.is_empty() {
+                    <input
+                        type="search"
+                        placeholder="Search notes..."
+                        class="w-full px-4 py-2 bg-gray-100 dark:bg-gray-800 rounded-lg text-gray-900 dark:text-gray-100 placeholder-gray-400 dark:placeholder-gray-500 outline-none neu-pressed transition-colors"
+                        value={(*notes_query).clone()}
+                        oninput={let q = notes_query.clone(); Callback::from(move |e: InputEvent| q.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
                     />
                 }
-            }}
+            </div>
+            if !query_lower.is_empty() {
+                <div class="px-4 pb-4 space-y-2">
+                    <p class="text-xs text-gray-500 dark:text-gray-400">
+                        {format!("{} workout{} with matching notes", note_matches.len(), if note_matches.len() == 1 { "" } else { "s" })}
+                    </p>
+                    { for note_matches.iter().map(|(w, hits)| {
+                        let on_jump = {
+                            let notes_query = notes_query.clone();
+                            let focus = focus.clone();
+                            let id = w.id.clone();
+                            Callback::from(move |_: MouseEvent| {
+                                let n = (*focus).as_ref().map(|(_, n)| n + 1).unwrap_or(0);
+                                focus.set(Some((id.clone(), n)));
+                                notes_query.set(String::new());
+                            })
+                        };
+                        html! {
+                            <button
+                                class="w-full text-left bg-gray-100 dark:bg-gray-800 rounded-xl p-3 neu-flat hover:bg-gray-200 dark:hover:bg-gray-700 transition-colors"
+                                onclick={on_jump}
+                            >
+                                <div class="flex justify-between items-baseline gap-2 mb-1">
+                                    <span class="text-sm font-medium text-gray-900 dark:text-gray-100 truncate">{w.name.clone()}</span>
+                                    <span class="text-xs text-gray-500 dark:text-gray-400 flex-shrink-0">{w.date.clone()}{" →"}</span>
+                                </div>
+                                { for hits.iter().map(|(label, snippet)| html! {
+                                    <div class="text-xs mt-1">
+                                        <span class="font-semibold text-gray-600 dark:text-gray-300">{label.clone()}{": "}</span>
+                                        <span class="italic text-gray-500 dark:text-gray-400">{snippet.clone()}</span>
+                                    </div>
+                                })}
+                            </button>
+                        }
+                    })}
+                </div>
+            } else {
+                { if workouts.is_empty() {
+                    html! {
+                        <div class="mx-4 text-center py-12 bg-gray-50 dark:bg-gray-800/20 rounded-2xl border border-dashed border-gray-200 dark:border-gray-700 transition-colors">
+                            <p class="text-4xl mb-4">{"📋"}</p>
+                            <p class="text-lg font-bold text-gray-900 dark:text-gray-100">{"No workouts yet!"}</p>
+                            <p class="text-sm text-gray-500 dark:text-gray-400 mt-1 px-4">{"Tap \"Start New Workout\" on the home page to log your first session."}</p>
+                        </div>
+                    }
+                } else {
+                    html! {
+                        <HistoryList
+                            workouts={(*workouts).clone()}
+                            all_exercises={all_exercises}
+                            on_delete={on_delete_workout}
+                            on_update={on_update_workout}
+                            focus={(*focus).clone()}
+                        />
+                    }
+                }}
+            }
         </div>
     }
 }
@@ -1,5 +1,7 @@
 use crate::components::achievements::AchievementBadges;
 use crate::components::ai_chat;
+use crate::components::streak_calendar::StreakCalendar;
+use crate::i18n::t;
 use crate::models::{self, Exercise, Workout};
 use crate::pages::muscles::muscle_balance_summary;
 use crate::storage;
@@ -24,19 +26,15 @@ fn summary_stats() -> Html {
     let weight = storage::load_body_metrics().first().and_then(|m| m.weight);
 
     let volume_display = if let Some(w) = weight {
-        format!("{:.1}x", total_volume / w)
-    } else if total_volume >= 1_000_000.0 {
-        format!("{:.1}M", total_volume / 1_000_000.0)
-    } else if total_volume >= 1000.0 {
-        format!("{:.0}k", total_volume / 1000.0)
+        format!("{}x", crate::i18n::format_number(total_volume / w, 1))
     } else {
-        format!("{:.0}", total_volume)
+        crate::i18n::format_compact(total_volume)
     };
 
     let volume_label = if weight.is_some() {
-        "Rel. Volume"
+        t("home.rel_volume")
     } else {
-        "Total Volume"
+        t("home.total_volume")
     };
 
     let streak = models::current_streak(&workouts);
@@ -45,16 +43,16 @@ fn summary_stats() -> Html {
     html! {
         <div class="space-y-3">
             <div class="flex justify-between items-center px-1">
-                <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Your Progress"}</h2>
+                <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("home.your_progress")}</h2>
                 <Link<Route> to={Route::Analytics} classes="text-xs text-blue-600 dark:text-blue-400 hover:underline">
-                    {"Full Analytics →"}
+                    {t("home.full_analytics")}
                 </Link<Route>>
             </div>
             <div class="grid grid-cols-3 gap-3">
                 <div class="bg-gray-100 dark:bg-gray-800/50 rounded-xl p-3 neu-flat text-center transition-colors">
                     <div class="text-xl mb-1">{"🏋️"}</div>
                     <div class="text-lg font-bold text-gray-800 dark:text-gray-200">{total_workouts}</div>
-                    <div class="text-[10px] text-gray-500 dark:text-gray-500 uppercase font-bold">{"Sessions"}</div>
+                    <div class="text-[10px] text-gray-500 dark:text-gray-500 uppercase font-bold">{t("home.sessions")}</div>
                 </div>
                 <div class="bg-gray-100 dark:bg-gray-800/50 rounded-xl p-3 neu-flat text-center transition-colors">
                     <div class="text-xl mb-1">{"💪"}</div>
@@ -64,8 +62,8 @@ fn summary_stats() -> Html {
                 <div class="bg-gray-100 dark:bg-gray-800/50 rounded-xl p-3 neu-flat text-center transition-colors">
                     <div class="text-xl mb-1">{"🔥"}</div>
                     <div class="text-lg font-bold text-gray-800 dark:text-gray-200">{streak}</div>
-                    <div class="text-[10px] text-gray-500 dark:text-gray-500 uppercase font-bold">{"Day Streak"}</div>
-                    <div class="text-[9px] text-gray-400 dark:text-gray-600 mt-0.5">{format!("Best: {}d", best)}</div>
+                    <div class="text-[10px] text-gray-500 dark:text-gray-500 uppercase font-bold">{t("home.day_streak")}</div>
+                    <div class="text-[9px] text-gray-400 dark:text-gray-600 mt-0.5">{format!("{}: {}d", t("home.best"), best)}</div>
                 </div>
             </div>
         </div>
@@ -81,12 +79,12 @@ fn community_summary() -> Html {
         return html! {
             <div class="bg-gradient-to-br from-gray-100 to-gray-200 dark:from-gray-800 dark:to-gray-900 rounded-2xl p-4 neu-flat transition-colors">
                 <div class="flex justify-between items-center mb-3">
-                    <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Community"}</h2>
-                    <span class="px-2 py-0.5 bg-blue-100 dark:bg-blue-900/40 text-blue-600 dark:text-blue-400 text-[10px] font-bold rounded-full uppercase tracking-wider">{"Optional"}</span>
+                    <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("home.community")}</h2>
+                    <span class="px-2 py-0.5 bg-blue-100 dark:bg-blue-900/40 text-blue-600 dark:text-blue-400 text-[10px] font-bold rounded-full uppercase tracking-wider">{t("home.optional")}</span>
                 </div>
-                <p class="text-sm text-gray-600 dark:text-gray-400 mb-4">{"Train with friends, share stats, and see your rankings in the community."}</p>
+                <p class="text-sm text-gray-600 dark:text-gray-400 mb-4">{t("home.community_pitch")}</p>
                 <Link<Route> to={Route::Social} classes="flex items-center justify-center w-full py-2 bg-gray-100 dark:bg-gray-700 hover:bg-gray-200 dark:hover:bg-gray-600 text-gray-900 dark:text-white rounded-lg text-sm font-bold transition neu-btn">
-                    {"🚀 Join Community"}
+                    {t("home.join_community")}
                 </Link<Route>>
             </div>
         };
@@ -95,9 +93,9 @@ fn community_summary() -> Html {
     html! {
         <div class="space-y-3">
             <div class="flex justify-between items-center px-1">
-                <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Friend Ranking"}</h2>
+                <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("home.friend_ranking")}</h2>
                 <Link<Route> to={Route::Social} classes="text-xs text-blue-600 dark:text-blue-400 hover:underline">
-                    {"View All →"}
+                    {t("home.view_all")}
                 </Link<Route>>
             </div>
 
@@ -105,9 +103,9 @@ fn community_summary() -> Html {
                 { if friends.is_empty() {
                     html! {
                         <div class="text-center py-4">
-                            <p class="text-sm text-gray-500">{"No friends added yet."}</p>
+                            <p class="text-sm text-gray-500">{t("home.no_friends")}</p>
                             <Link<Route> to={Route::Social} classes="text-xs text-blue-600 dark:text-blue-400 hover:underline mt-1 block">
-                                {"Add your first friend →"}
+                                {t("home.add_first_friend")}
                             </Link<Route>>
                         </div>
                     }
@@ -123,7 +121,7 @@ fn community_summary() -> Html {
                                             </div>
                                             <div class="text-sm font-medium text-gray-800 dark:text-gray-200">{&f.name}</div>
                                         </div>
-                                        <div class="text-xs text-gray-500">{"Offline"}</div>
+                                        <div class="text-xs text-gray-500">{t("home.offline")}</div>
                                     </div>
                                 }
                             })}
@@ -145,7 +143,7 @@ struct WeeklySummaryData {
 }
 
 fn current_iso_week() -> String {
-    let now = chrono::Local::now().date_naive();
+    let now = crate::dates::today();
     format!("{}-W{:02}", now.iso_week().year(), now.iso_week().week())
 }
 
@@ -291,27 +289,28 @@ fn weekly_summary() -> Html {
     if summary_text.is_none() && !*is_generating {
         return html! {};
     }
+    let dot_motion = if storage::reduce_motion(&config) { "" } else { "animate-bounce" };
 
     html! {
         <div class="space-y-3">
             <div class="flex justify-between items-center px-1">
-                <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Coach T Weekly Summary"}</h2>
+                <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("home.weekly_summary")}</h2>
                 <button
                     onclick={on_regenerate}
                     disabled={*is_generating}
                     class="text-xs text-blue-600 dark:text-blue-400 hover:underline disabled:opacity-50"
                 >
-                    { if *is_generating { "Generating..." } else { "Regenerate" } }
+                    { if *is_generating { t("home.generating") } else { t("home.regenerate") } }
                 </button>
             </div>
             <div class="bg-gray-100 dark:bg-gray-800/50 rounded-2xl p-4 neu-flat transition-colors">
                 { if *is_generating && summary_text.is_none() {
                     html! {
                         <div class="flex items-center gap-2 text-sm text-gray-500">
-                            <div class="w-2 h-2 bg-gray-400 rounded-full animate-bounce" style="animation-delay: 0ms;" />
-                            <div class="w-2 h-2 bg-gray-400 rounded-full animate-bounce" style="animation-delay: 150ms;" />
-                            <div class="w-2 h-2 bg-gray-400 rounded-full animate-bounce" style="animation-delay: 300ms;" />
-                            <span class="ml-1">{"Generating summary..."}</span>
+                            <div class={classes!("w-2", "h-2", "bg-gray-400", "rounded-full", dot_motion)} style="animation-delay: 0ms;" />
+                            <div class={classes!("w-2", "h-2", "bg-gray-400", "rounded-full", dot_motion)} style="animation-delay: 150ms;" />
+                            <div class={classes!("w-2", "h-2", "bg-gray-400", "rounded-full", dot_motion)} style="animation-delay: 300ms;" />
+                            <span class="ml-1">{t("home.generating_summary")}</span>
                         </div>
                     }
                 } else if let Some(text) = &*summary_text {
@@ -342,14 +341,14 @@ fn muscle_balance_card() -> Html {
     let (undertrained, overtrained) = muscle_balance_summary(&workouts, &all_exercises);
 
     let (summary, color) = if undertrained == 0 && overtrained == 0 {
-        ("All muscles balanced".to_string(), "text-green-500")
+        (t("home.all_balanced").to_string(), "text-green-500")
     } else {
         let mut parts = Vec::new();
         if undertrained > 0 {
-            parts.push(format!("{} undertrained", undertrained));
+            parts.push(format!("{} {}", undertrained, t("home.undertrained")));
         }
         if overtrained > 0 {
-            parts.push(format!("{} overtrained", overtrained));
+            parts.push(format!("{} {}", overtrained, t("home.overtrained")));
         }
         (
             parts.join(", "),
@@ -367,7 +366,7 @@ fn muscle_balance_card() -> Html {
                 <div class="flex items-center gap-3">
                     <span class="text-2xl group-hover:scale-110 transition-transform">{"🧠"}</span>
                     <div>
-                        <div class="font-medium text-gray-800 dark:text-gray-200">{"Training Intelligence"}</div>
+                        <div class="font-medium text-gray-800 dark:text-gray-200">{t("home.training_intelligence")}</div>
                         <div class={classes!("text-xs", "font-medium", color)}>{summary}</div>
                     </div>
                 </div>
@@ -431,6 +430,42 @@ pub fn home_page() -> Html {
             .unwrap_or_else(|| id.to_string())
     };
 
+    // Today's plan: the routine scheduled for this weekday, otherwise the one
+    // whose exercises have gone longest without being trained.
+    let today = crate::dates::today();
+    let trained_today = workouts.iter().any(|w| w.date == today.format("%Y-%m-%d").to_string());
+    let todays_plan: Option<(models::Routine, bool)> = {
+        let schedule = storage::load_user_config().routine_schedule;
+        let day_key = models::WEEKDAY_KEYS[today.weekday().num_days_from_monday() as usize];
+        let scheduled = schedule
+            .get(day_key)
+            .and_then(|rid| routines.iter().find(|r| r.id == *rid));
+        match scheduled {
+            Some(r) => Some((r.clone(), true)),
+            None => {
+                let days_since = |eid: &str| -> i64 {
+                    workouts
+                        .iter()
+                        .filter(|w| w.exercises.iter().any(|we| we.exercise_id == eid && we.sets.iter().any(|s| s.completed)))
+                        .filter_map(|w| chrono::NaiveDate::parse_from_str(&w.date, "%Y-%m-%d").ok())
+                        .max()
+                        .map(|d| (today - d).num_days())
+                        .unwrap_or(365)
+                };
+                routines
+                    .iter()
+                    .filter(|r| !r.exercise_ids.is_empty())
+                    .map(|r| {
+                        let avg = r.exercise_ids.iter().map(|e| days_since(e.as_str())).sum::<i64>() as f64
+                            / r.exercise_ids.len() as f64;
+                        (r, avg)
+                    })
+                    .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+                    .map(|(r, _)| (r.clone(), false))
+            }
+        }
+    };
+
     let start_empty = {
         let nav = navigator.clone();
         Callback::from(move |_| {
@@ -443,19 +478,48 @@ pub fn home_page() -> Html {
             <div class="flex justify-between items-start">
                 <div>
                     <h1 class="text-3xl font-bold cursor-pointer select-none" onclick={on_title_click}>{"Treening"}</h1>
-                    <p class="text-gray-400 mt-1">{"Workout Tracker"}</p>
+                    <p class="text-gray-400 mt-1">{t("home.subtitle")}</p>
                 </div>
             </div>
 
             <button
                 class="w-full py-4 bg-blue-600 rounded-xl text-lg font-bold hover:bg-blue-700 active:bg-blue-800 transition neu-btn btn-press"
                 onclick={start_empty}
-            >{"Start New Workout"}</button>
+            >{t("home.start_workout")}</button>
+
+            { if let Some((routine, scheduled)) = todays_plan {
+                let nav = navigator.clone();
+                let rid = routine.id.clone();
+                html! {
+                    <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors flex justify-between items-center gap-3">
+                        <div class="min-w-0">
+                            <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{t("home.todays_plan")}</h3>
+                            <div class="font-bold text-gray-800 dark:text-gray-200 mt-1 truncate">{&routine.name}</div>
+                            <div class="text-xs text-gray-500 mt-0.5">
+                                { if trained_today {
+                                    format!("\u{2713} {}", t("home.done_today"))
+                                } else if scheduled {
+                                    t("home.scheduled_today").to_string()
+                                } else {
+                                    t("home.suggested_today").to_string()
+                                }}
+                            </div>
+                        </div>
+                        <button
+                            class="px-4 py-2 bg-blue-600 text-white rounded-lg text-sm font-bold hover:bg-blue-700 neu-btn btn-press transition-colors flex-shrink-0"
+                            onclick={Callback::from(move |_| {
+                                let _ = LocalStorage::set("treening_active_routine", rid.clone());
+                                nav.push(&Route::Workout);
+                            })}
+                        >{t("home.start")}</button>
+                    </div>
+                }
+            } else { html! {} }}
 
             <div class="flex gap-3">
                 <Link<Route> to={Route::PlateCalc} classes="flex-1 py-3 bg-gray-100 dark:bg-gray-800/50 rounded-xl text-center hover:bg-gray-200 dark:hover:bg-gray-800 transition neu-flat">
                     <div class="text-lg">{"🏋️"}</div>
-                    <div class="text-xs font-bold text-gray-600 dark:text-gray-400">{"Plate Calc"}</div>
+                    <div class="text-xs font-bold text-gray-600 dark:text-gray-400">{t("home.plate_calc")}</div>
                 </Link<Route>>
                 { if storage::load_user_config().ai_enabled {
                     html! {
@@ -473,6 +537,8 @@ pub fn home_page() -> Html {
 
             <SummaryStats />
 
+            <StreakCalendar />
+
             <WeeklySummary />
 
             <AchievementBadges />
@@ -483,9 +549,9 @@ pub fn home_page() -> Html {
                 html! {
                     <div class="space-y-3">
                         <div class="flex justify-between items-center px-1">
-                            <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Routines"}</h2>
+                            <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("home.routines")}</h2>
                             <Link<Route> to={Route::Routines} classes="text-xs text-blue-600 dark:text-blue-400 hover:underline">
-                                {"Edit All →"}
+                                {t("home.edit_all")}
                             </Link<Route>>
                         </div>
                         <div class="space-y-2">
@@ -503,7 +569,7 @@ pub fn home_page() -> Html {
                                     >
                                         <div>
                                             <div class="font-bold text-gray-800 dark:text-gray-200">{&r.name}</div>
-                                            <div class="text-xs text-gray-500 mt-0.5">{r.exercise_ids.len()}{" exercises"}</div>
+                                            <div class="text-xs text-gray-500 mt-0.5">{r.exercise_ids.len()}{" "}{t("common.exercises")}</div>
                                         </div>
                                         <span class="text-gray-400 dark:text-gray-600 group-hover:text-blue-600 dark:group-hover:text-blue-400 group-hover:translate-x-1 transition-all">{"→"}</span>
                                     </button>
@@ -519,9 +585,9 @@ pub fn home_page() -> Html {
                 html! {
                     <div class="space-y-3">
                         <div class="flex justify-between items-center px-1">
-                            <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Last Workout"}</h2>
+                            <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("home.last_workout")}</h2>
                             <Link<Route> to={Route::History} classes="text-xs text-blue-600 dark:text-blue-400 hover:underline">
-                                {"History →"}
+                                {t("home.history")}
                             </Link<Route>>
                         </div>
                         <div class="bg-gray-100 dark:bg-gray-800/50 rounded-2xl p-4 neu-flat transition-colors">
@@ -530,11 +596,11 @@ pub fn home_page() -> Html {
                                     <span class="font-bold text-gray-800 dark:text-gray-200 block">{&w.name}</span>
                                     <span class="text-xs text-gray-500 font-medium font-mono uppercase tracking-wider">{&w.date}</span>
                                 </div>
-                                <span class="bg-blue-100 dark:bg-blue-600/20 text-blue-600 dark:text-blue-400 text-[10px] font-bold px-2 py-0.5 rounded-full uppercase">{"Summary"}</span>
+                                <span class="bg-blue-100 dark:bg-blue-600/20 text-blue-600 dark:text-blue-400 text-[10px] font-bold px-2 py-0.5 rounded-full uppercase">{t("home.summary")}</span>
                             </div>
                             <div class="text-xs text-gray-600 dark:text-gray-400 mb-4 flex gap-3">
-                                <span class="flex items-center gap-1.5"><span class="opacity-70">{"💪"}</span> {w.exercises.len()}{" exercises"}</span>
-                                <span class="flex items-center gap-1.5"><span class="opacity-70">{"⚡"}</span> {total_sets}{" sets"}</span>
+                                <span class="flex items-center gap-1.5"><span class="opacity-70">{"💪"}</span> {w.exercises.len()}{" "}{t("common.exercises")}</span>
+                                <span class="flex items-center gap-1.5"><span class="opacity-70">{"⚡"}</span> {total_sets}{" "}{t("common.sets")}</span>
                                 { if w.duration_mins > 0 {
                                     html! { <span class="flex items-center gap-1.5"><span class="opacity-70">{"⏱️"}</span> {w.duration_mins}{"min"}</span> }
                                 } else { html! {} }}
@@ -542,10 +608,10 @@ pub fn home_page() -> Html {
                             <div class="text-[11px] text-gray-500 space-y-1.5 border-t border-gray-200 dark:border-gray-700/50 pt-3">
                                 { for w.exercises.iter().take(3).map(|we| {
                                     let name = find_exercise(&we.exercise_id);
-                                    html! { <div class="flex justify-between"><span>{name}</span> <span class="text-gray-400 dark:text-gray-600">{we.sets.len()}{" sets"}</span></div> }
+                                    html! { <div class="flex justify-between"><span>{name}</span> <span class="text-gray-400 dark:text-gray-600">{we.sets.len()}{" "}{t("common.sets")}</span></div> }
                                 })}
                                 { if w.exercises.len() > 3 {
-                                    html! { <div class="text-gray-400 dark:text-gray-600 italic">{"and "}{w.exercises.len() - 3}{" more..."}</div> }
+                                    html! { <div class="text-gray-400 dark:text-gray-600 italic">{t("home.and")}{w.exercises.len() - 3}{t("home.more")}</div> }
                                 } else { html! {} }}
                             </div>
                         </div>
@@ -555,46 +621,46 @@ pub fn home_page() -> Html {
                 html! {
                     <div class="space-y-6 py-4">
                         <div class="text-center">
-                            <p class="text-2xl font-bold text-gray-900 dark:text-gray-100">{"Welcome to Treening!"}</p>
-                            <p class="mt-2 text-gray-600 dark:text-gray-400">{"Your privacy-first, offline workout tracker."}</p>
+                            <p class="text-2xl font-bold text-gray-900 dark:text-gray-100">{t("home.welcome")}</p>
+                            <p class="mt-2 text-gray-600 dark:text-gray-400">{t("home.welcome_sub")}</p>
                         </div>
 
                         <div class="bg-gray-100 dark:bg-gray-800/30 rounded-2xl p-6 space-y-6 neu-flat">
-                            <h3 class="font-bold text-gray-800 dark:text-gray-200">{"Quick Start Guide"}</h3>
+                            <h3 class="font-bold text-gray-800 dark:text-gray-200">{t("home.quick_start")}</h3>
                             <div class="space-y-4">
                                 <div class="flex gap-4">
                                     <div class="flex-shrink-0 w-8 h-8 bg-blue-600 text-white rounded-full flex items-center justify-center font-bold">{"1"}</div>
                                     <div>
-                                        <div class="font-bold text-gray-800 dark:text-gray-200">{"Explore Exercises"}</div>
-                                        <p class="text-sm text-gray-600 dark:text-gray-400">{"Browse over 80 built-in exercises with muscle group info and images."}</p>
+                                        <div class="font-bold text-gray-800 dark:text-gray-200">{t("home.step1_title")}</div>
+                                        <p class="text-sm text-gray-600 dark:text-gray-400">{t("home.step1_body")}</p>
                                     </div>
                                 </div>
                                 <div class="flex gap-4">
                                     <div class="flex-shrink-0 w-8 h-8 bg-blue-600 text-white rounded-full flex items-center justify-center font-bold">{"2"}</div>
                                     <div>
-                                        <div class="font-bold text-gray-800 dark:text-gray-200">{"Create a Routine"}</div>
-                                        <p class="text-sm text-gray-600 dark:text-gray-400">{"Save your favorite workouts (e.g., 'Push Day') for one-tap starting."}</p>
+                                        <div class="font-bold text-gray-800 dark:text-gray-200">{t("home.step2_title")}</div>
+                                        <p class="text-sm text-gray-600 dark:text-gray-400">{t("home.step2_body")}</p>
                                     </div>
                                 </div>
                                 <div class="flex gap-4">
                                     <div class="flex-shrink-0 w-8 h-8 bg-blue-600 text-white rounded-full flex items-center justify-center font-bold">{"3"}</div>
                                     <div>
-                                        <div class="font-bold text-gray-800 dark:text-gray-200">{"Log Your Session"}</div>
-                                        <p class="text-sm text-gray-600 dark:text-gray-400">{"Track sets, reps, and weight. Your data stays 100% on your device."}</p>
+                                        <div class="font-bold text-gray-800 dark:text-gray-200">{t("home.step3_title")}</div>
+                                        <p class="text-sm text-gray-600 dark:text-gray-400">{t("home.step3_body")}</p>
                                     </div>
                                 </div>
                                 <div class="flex gap-4">
                                     <div class="flex-shrink-0 w-8 h-8 bg-blue-600 text-white rounded-full flex items-center justify-center font-bold">{"4"}</div>
                                     <div>
-                                        <div class="font-bold text-gray-800 dark:text-gray-200">{"Track Body Progress"}</div>
-                                        <p class="text-sm text-gray-600 dark:text-gray-400">{"Log your weight in Settings to unlock 'Relative Volume' stats and charts."}</p>
+                                        <div class="font-bold text-gray-800 dark:text-gray-200">{t("home.step4_title")}</div>
+                                        <p class="text-sm text-gray-600 dark:text-gray-400">{t("home.step4_body")}</p>
                                     </div>
                                 </div>
                                 <div class="flex gap-4">
                                     <div class="flex-shrink-0 w-8 h-8 bg-blue-600 text-white rounded-full flex items-center justify-center font-bold">{"5"}</div>
                                     <div>
-                                        <div class="font-bold text-gray-800 dark:text-gray-200">{"Meet Coach T"}</div>
-                                        <p class="text-sm text-gray-600 dark:text-gray-400">{"Enable Coach T in Settings for a personal AI coach. Ask for advice, log workouts via chat, and get weekly summaries — all offline."}</p>
+                                        <div class="font-bold text-gray-800 dark:text-gray-200">{t("home.step5_title")}</div>
+                                        <p class="text-sm text-gray-600 dark:text-gray-400">{t("home.step5_body")}</p>
                                     </div>
                                 </div>
                             </div>
@@ -608,8 +674,8 @@ pub fn home_page() -> Html {
                     <div class="flex items-center gap-3">
                         <span class="text-2xl group-hover:scale-110 transition-transform">{"⚙️"}</span>
                         <div>
-                            <div class="font-medium text-gray-800 dark:text-gray-200">{"Settings & Sync"}</div>
-                            <div class="text-xs text-gray-500">{"App preferences, data backup, and P2P sync"}</div>
+                            <div class="font-medium text-gray-800 dark:text-gray-200">{t("home.settings_sync")}</div>
+                            <div class="text-xs text-gray-500">{t("home.settings_sync_sub")}</div>
                         </div>
                     </div>
                     <span class="text-gray-400 dark:text-gray-600 group-hover:text-blue-600 dark:group-hover:text-blue-400 group-hover:translate-x-1 transition-all">{"→"}</span>
@@ -619,7 +685,7 @@ pub fn home_page() -> Html {
             { if *show_treen_toast {
                 html! {
                     <div class="fixed top-4 left-4 right-4 z-50 bg-amber-800 text-amber-100 px-4 py-3 rounded-xl shadow-lg text-center font-bold text-sm" style="animation: modalContentIn 200ms ease-out;">
-                        {"🪵 Treen Mode activated! Wood grain unlocked."}
+                        {t("home.treen_toast")}
                     </div>
                 }
             } else {
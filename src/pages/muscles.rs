@@ -1,15 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
-use chrono::NaiveDate;
+use chrono::{Datelike, NaiveDate};
 use yew::prelude::*;
+use yew_router::prelude::*;
 
+use crate::components::charts::LineChart;
 use crate::data::default_exercises;
-use crate::models::{Exercise, Workout};
+use crate::dates::today;
+use crate::models::{Equipment, Exercise, UserConfig, Workout};
 use crate::muscle_data::{
     self, effective_sets_for_exercise, exercise_muscles, CORE_MUSCLES, LEG_MUSCLES, PULL_MUSCLES,
     PUSH_MUSCLES, TRACKED_MUSCLES,
 };
+use crate::pages::workout::queue_exercise;
 use crate::storage;
+use crate::Route;
 
 // ── Helpers ──────────────────────────────────────────────────────────────────
 
@@ -27,10 +32,6 @@ fn parse_date(s: &str) -> Option<NaiveDate> {
     NaiveDate::parse_from_str(s, "%Y-%m-%d").ok()
 }
 
-fn today() -> NaiveDate {
-    chrono::Local::now().date_naive()
-}
-
 /// Epley formula for estimated 1RM.
 fn estimate_1rm(weight: f64, reps: u32) -> f64 {
     if reps <= 1 {
@@ -41,7 +42,7 @@ fn estimate_1rm(weight: f64, reps: u32) -> f64 {
 }
 
 /// Get thresholds: user overrides merged with defaults.
-fn get_thresholds() -> HashMap<String, (f64, f64)> {
+pub fn get_thresholds() -> HashMap<String, (f64, f64)> {
     let defaults = muscle_data::default_thresholds();
     let config = storage::load_user_config();
     let mut result: HashMap<String, (f64, f64)> =
@@ -54,14 +55,20 @@ fn get_thresholds() -> HashMap<String, (f64, f64)> {
     result
 }
 
+/// Whether secondary/tertiary contributions count as fractional sets.
+pub fn count_fractional() -> bool {
+    storage::load_user_config().count_fractional_volume
+}
+
 /// Compute effective sets per muscle over a date range from workouts.
-fn compute_muscle_sets(
+pub fn compute_muscle_sets(
     workouts: &[Workout],
     exercises: &[Exercise],
     from: NaiveDate,
     to: NaiveDate,
 ) -> HashMap<String, f64> {
     let mut sets: HashMap<String, f64> = HashMap::new();
+    let fractional = count_fractional();
 
     for w in workouts {
         if let Some(d) = parse_date(&w.date) {
@@ -76,7 +83,7 @@ fn compute_muscle_sets(
                         .filter(|e| e.is_custom)
                         .map(|e| e.muscle_groups.as_slice());
 
-                    let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg);
+                    let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg, fractional);
                     for (muscle, val) in eff {
                         *sets.entry(muscle.to_string()).or_default() += val;
                     }
@@ -87,10 +94,16 @@ fn compute_muscle_sets(
     sets
 }
 
-/// Compute per-session muscle sets for session volume warnings.
-fn session_muscle_sets(workout: &Workout, exercises: &[Exercise]) -> HashMap<String, f64> {
+/// Compute per-session muscle sets for session volume warnings. With
+/// `superset` set, only exercises in that superset group are counted, giving
+/// the combined load the group put on each muscle.
+fn session_muscle_sets(workout: &Workout, exercises: &[Exercise], superset: Option<u32>) -> HashMap<String, f64> {
     let mut sets: HashMap<String, f64> = HashMap::new();
+    let fractional = count_fractional();
     for we in &workout.exercises {
+        if superset.is_some() && we.superset_group != superset {
+            continue;
+        }
         let completed = we.sets.iter().filter(|s| s.completed).count();
         if completed == 0 {
             continue;
@@ -98,7 +111,7 @@ fn session_muscle_sets(workout: &Workout, exercises: &[Exercise]) -> HashMap<Str
         let custom_mg = find_exercise(exercises, &we.exercise_id)
             .filter(|e| e.is_custom)
             .map(|e| e.muscle_groups.as_slice());
-        let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg);
+        let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg, fractional);
         for (muscle, val) in eff {
             *sets.entry(muscle.to_string()).or_default() += val;
         }
@@ -142,16 +155,109 @@ fn frequency_color(freq: f64) -> &'static str {
     }
 }
 
-fn recovery_color(hours: f64) -> &'static str {
-    if hours < 24.0 {
+/// Recovery needed after a session with zero effective sets for a muscle.
+const RECOVERY_BASE_HOURS: f64 = 24.0;
+/// Extra recovery per effective set in the last session.
+const RECOVERY_HOURS_PER_SET: f64 = 6.0;
+/// Even very high-volume sessions are treated as recovered after four days.
+const RECOVERY_MAX_HOURS: f64 = 96.0;
+
+/// Estimated hours a muscle needs after `sets` effective sets in one session:
+/// 2 sets -> 36h, 6 sets -> 60h, 12+ sets -> 96h.
+fn recovery_hours_needed(sets: f64) -> f64 {
+    (RECOVERY_BASE_HOURS + sets * RECOVERY_HOURS_PER_SET).min(RECOVERY_MAX_HOURS)
+}
+
+/// Color by how far through its estimated recovery a muscle is.
+fn recovery_color(hours_since: f64, hours_needed: f64) -> &'static str {
+    let progress = hours_since / hours_needed;
+    if progress < 0.5 {
         "text-red-500"
-    } else if hours < 48.0 {
+    } else if progress < 1.0 {
         "text-yellow-500"
     } else {
         "text-green-500"
     }
 }
 
+// ── Section explanations ─────────────────────────────────────────────────────
+
+#[derive(Properties, PartialEq)]
+struct SectionInfoProps {
+    #[prop_or_default]
+    children: Children,
+}
+
+/// Collapsible "i" panel explaining what a section measures and what to do about it.
+#[function_component(SectionInfo)]
+fn section_info(props: &SectionInfoProps) -> Html {
+    html! {
+        <details class="group text-xs">
+            <summary class="list-none cursor-pointer inline-flex items-center gap-1 text-blue-600 dark:text-blue-400 hover:underline select-none">
+                <span class="w-4 h-4 rounded-full border border-current flex items-center justify-center text-[10px] font-bold">{"i"}</span>
+                <span class="group-open:hidden">{"Why?"}</span>
+                <span class="hidden group-open:inline">{"Hide"}</span>
+            </summary>
+            <div class="mt-2 p-3 bg-blue-50 dark:bg-blue-900/20 rounded-lg text-gray-700 dark:text-gray-300 space-y-1.5 leading-relaxed">
+                { for props.children.iter() }
+            </div>
+        </details>
+    }
+}
+
+/// What to do for a muscle given its weekly sets and the thresholds in effect.
+fn volume_advice(sets: f64, mev: f64, mrv: f64) -> String {
+    if sets <= 0.0 {
+        if mev > 0.0 {
+            format!("not trained — aim for at least {:.0} sets", mev)
+        } else {
+            "not trained".to_string()
+        }
+    } else if sets < mev {
+        format!("add ~{:.0} sets to reach MEV", (mev - sets).ceil())
+    } else if sets <= mrv {
+        "in the productive range — keep it up".to_string()
+    } else {
+        format!("~{:.0} sets over MRV — cut back to recover", (sets - mrv).ceil())
+    }
+}
+
+/// Per-muscle MEV/MRV table using the thresholds currently in effect.
+fn threshold_table(sets: &HashMap<String, f64>, thresholds: &HashMap<String, (f64, f64)>) -> Html {
+    html! {
+        <div class="grid grid-cols-1 gap-0.5 pt-1 font-mono text-[10px]">
+            { for TRACKED_MUSCLES.iter().map(|&muscle| {
+                let s = sets.get(muscle).copied().unwrap_or(0.0);
+                let (mev, mrv) = thresholds.get(muscle).copied().unwrap_or((0.0, 20.0));
+                html! {
+                    <div class="flex justify-between gap-2">
+                        <span class={classes!("truncate", volume_text_color(s, mev, mrv))}>
+                            {format!("{}: {:.1} / {:.0}–{:.0}", muscle, s, mev, mrv)}
+                        </span>
+                        <span class="text-gray-500 dark:text-gray-400 text-right">{volume_advice(s, mev, mrv)}</span>
+                    </div>
+                }
+            })}
+        </div>
+    }
+}
+
+fn thresholds_source_note() -> &'static str {
+    if storage::load_user_config().muscle_thresholds.is_some() {
+        "These are your customized thresholds (gear icon on Muscle Balance)."
+    } else {
+        "These are the default research-based thresholds; adjust them with the gear icon on Muscle Balance."
+    }
+}
+
+fn set_counting_note() -> &'static str {
+    if count_fractional() {
+        "Sets count fully for the primary muscle and fractionally for secondary muscles (e.g. bench press adds 0.5 to triceps)."
+    } else {
+        "Only direct sets are counted: secondary muscles worked by an exercise are ignored."
+    }
+}
+
 // ── Main Page ────────────────────────────────────────────────────────────────
 
 #[function_component(MusclesPage)]
@@ -164,12 +270,16 @@ pub fn muscles_page() -> Html {
         <div class="px-4 py-4 space-y-6">
             <h1 class="text-2xl font-bold text-gray-900 dark:text-gray-100">{"Training Intelligence"}</h1>
 
+            <SectionVolumeRings workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
             <SectionMuscleBalance workouts={(*workouts).clone()} exercises={(*exercises).clone()} show_modal={show_thresholds.clone()} />
+            <SectionVolumeTrend workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
+            <SectionWeeklyTargets workouts={(*workouts).clone()} exercises={(*exercises).clone()} show_modal={show_thresholds.clone()} />
             <SectionRecommendations workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
             <SectionFrequency workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
             <SectionOverload workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
             <SectionRepRange workouts={(*workouts).clone()} />
             <SectionDeload workouts={(*workouts).clone()} />
+            <SectionWorkloadRatio workouts={(*workouts).clone()} />
             <SectionPushPull workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
             <SectionSessionVolume workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
             <SectionRecovery workouts={(*workouts).clone()} exercises={(*exercises).clone()} />
@@ -179,6 +289,100 @@ pub fn muscles_page() -> Html {
     }
 }
 
+// ── Volume Target Rings ──────────────────────────────────────────────────────
+
+#[derive(Properties, PartialEq)]
+struct MuscleRingProps {
+    muscle: AttrValue,
+    sets: f64,
+    mev: f64,
+    mrv: f64,
+}
+
+/// Compact dial showing how much of the MEV→MRV band is filled this week.
+#[function_component(MuscleRing)]
+fn muscle_ring(props: &MuscleRingProps) -> Html {
+    let (s, mev, mrv) = (props.sets, props.mev, props.mrv);
+    let band = (mrv - mev).max(1.0);
+    let band_pct = ((s - mev) / band).clamp(0.0, 1.0);
+    // Below MEV the ring shows progress towards MEV instead
+    let fill = if s < mev && mev > 0.0 {
+        (s / mev).clamp(0.0, 1.0)
+    } else if s > mrv {
+        1.0
+    } else {
+        band_pct
+    };
+    let label = if s <= 0.0 {
+        "--".to_string()
+    } else if s < mev {
+        "<MEV".to_string()
+    } else if s > mrv {
+        ">MRV".to_string()
+    } else {
+        format!("{:.0}%", band_pct * 100.0)
+    };
+
+    let radius = 16.0_f64;
+    let circumference = 2.0 * std::f64::consts::PI * radius;
+    let dash = format!("{:.2} {:.2}", circumference * fill, circumference);
+    let color = volume_text_color(s, mev, mrv);
+
+    html! {
+        <div class="flex flex-col items-center gap-1" title={format!("{}: {:.1} sets (MEV {:.0}, MRV {:.0})", props.muscle, s, mev, mrv)}>
+            <div class="relative w-12 h-12">
+                <svg viewBox="0 0 40 40" class="w-12 h-12 -rotate-90">
+                    <circle cx="20" cy="20" r={radius.to_string()} fill="none" stroke="currentColor" stroke-width="4"
+                            class="text-gray-200 dark:text-gray-700" />
+                    <circle cx="20" cy="20" r={radius.to_string()} fill="none" stroke="currentColor" stroke-width="4"
+                            stroke-linecap="round" stroke-dasharray={dash} class={classes!(color)} />
+                </svg>
+                <span class={classes!("absolute", "inset-0", "flex", "items-center", "justify-center", "text-[9px]", "font-bold", color)}>
+                    {label}
+                </span>
+            </div>
+            <span class="text-[10px] text-gray-600 dark:text-gray-400 truncate w-14 text-center">{&props.muscle}</span>
+        </div>
+    }
+}
+
+#[derive(Properties, PartialEq)]
+struct VolumeRingsProps {
+    workouts: Vec<Workout>,
+    exercises: Vec<Exercise>,
+}
+
+#[function_component(SectionVolumeRings)]
+fn section_volume_rings(props: &VolumeRingsProps) -> Html {
+    if props.workouts.is_empty() {
+        return html! {};
+    }
+
+    let t = today();
+    let from = t - chrono::Duration::days(7);
+    let sets = compute_muscle_sets(&props.workouts, &props.exercises, from, t);
+    let thresholds = get_thresholds();
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
+            <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Weekly Volume Targets"}</h3>
+            <SectionInfo>
+                <p>{"Each ring tracks one muscle's sets over the last 7 days. Below MEV (minimum effective volume) the ring fills towards MEV; once you pass MEV it shows how far into the MEV–MRV band you are. Past MRV (maximum recoverable volume) it turns red."}</p>
+                <p>{set_counting_note()}</p>
+                <p>{thresholds_source_note()}</p>
+                {threshold_table(&sets, &thresholds)}
+            </SectionInfo>
+            <div class="grid grid-cols-4 sm:grid-cols-7 gap-3">
+                { for TRACKED_MUSCLES.iter().map(|&muscle| {
+                    let s = sets.get(muscle).copied().unwrap_or(0.0);
+                    let (mev, mrv) = thresholds.get(muscle).copied().unwrap_or((0.0, 20.0));
+                    html! { <MuscleRing muscle={muscle} sets={s} mev={mev} mrv={mrv} /> }
+                })}
+            </div>
+        </div>
+    }
+}
+
 // ── Section A: Muscle Balance ────────────────────────────────────────────────
 
 #[derive(Properties, PartialEq)]
@@ -261,6 +465,13 @@ fn section_muscle_balance(props: &MuscleBalanceProps) -> Html {
                     </svg>
                 </button>
             </div>
+            <SectionInfo>
+                <p>{"Bars show effective sets per muscle over the last 7 days. The thin markers are MEV (the least volume that still drives growth) and MRV (the most you can recover from)."}</p>
+                <p>{"Yellow = below MEV, so add sets. Green = between MEV and MRV, the productive range. Red = above MRV, so reduce sets or take extra rest."}</p>
+                <p>{set_counting_note()}</p>
+                <p>{thresholds_source_note()}</p>
+                {threshold_table(&sets, &thresholds)}
+            </SectionInfo>
             <div class="flex gap-3 text-[10px] text-gray-500">
                 <span class="flex items-center gap-1"><span class="w-3 h-2 rounded bg-yellow-400 inline-block" /> {"< MEV"}</span>
                 <span class="flex items-center gap-1"><span class="w-3 h-2 rounded bg-green-500 inline-block" /> {"MEV-MRV"}</span>
@@ -274,6 +485,166 @@ fn section_muscle_balance(props: &MuscleBalanceProps) -> Html {
     }
 }
 
+// ── Section: Muscle Volume Trend ─────────────────────────────────────────────
+
+/// Number of calendar weeks shown in the volume trend chart.
+const TREND_WEEKS: i64 = 8;
+
+#[derive(Properties, PartialEq)]
+struct VolumeTrendProps {
+    workouts: Vec<Workout>,
+    exercises: Vec<Exercise>,
+}
+
+#[function_component(SectionVolumeTrend)]
+fn section_volume_trend(props: &VolumeTrendProps) -> Html {
+    let selected = use_state(|| TRACKED_MUSCLES[0].to_string());
+
+    if props.workouts.is_empty() {
+        return html! {};
+    }
+
+    let t = today();
+    let week_start = t - chrono::Duration::days(t.weekday().num_days_from_monday() as i64);
+    let data: Vec<(String, f64)> = (0..TREND_WEEKS)
+        .rev()
+        .map(|i| {
+            let from = week_start - chrono::Duration::weeks(i);
+            let to = (from + chrono::Duration::days(6)).min(t);
+            let sets = compute_muscle_sets(&props.workouts, &props.exercises, from, to);
+            (
+                format!("W{}", from.iso_week().week()),
+                sets.get(selected.as_str()).copied().unwrap_or(0.0),
+            )
+        })
+        .collect();
+    let (mev, mrv) = get_thresholds()
+        .get(selected.as_str())
+        .copied()
+        .unwrap_or((0.0, 20.0));
+    let mut reference_lines = vec![(mrv, AttrValue::from("MRV"))];
+    if mev > 0.0 {
+        reference_lines.push((mev, AttrValue::from("MEV")));
+    }
+
+    let on_select = {
+        let selected = selected.clone();
+        Callback::from(move |e: Event| {
+            let select: web_sys::HtmlSelectElement = e.target_unchecked_into();
+            selected.set(select.value());
+        })
+    };
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
+            <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Volume Trend (8 weeks)"}</h3>
+            <SectionInfo>
+                <p>{"Effective sets per calendar week (Monday to Sunday) for one muscle, so you can see whether its volume is ramping up, holding or sliding. The current week is still in progress."}</p>
+                <p>{"The dashed lines mark that muscle's MEV and MRV. Spending most weeks between them is the goal; a run of weeks above MRV is a cue to deload."}</p>
+                <p>{set_counting_note()}</p>
+            </SectionInfo>
+            <select class="w-full bg-white dark:bg-gray-700 text-gray-900 dark:text-white rounded-lg px-3 py-2 text-sm outline-none neu-pressed transition-colors"
+                    onchange={on_select}>
+                { for TRACKED_MUSCLES.iter().map(|&muscle| html! {
+                    <option value={muscle} selected={*selected == muscle}>{muscle}</option>
+                })}
+            </select>
+            <LineChart data={data} height={160} color="#3b82f6" reference_lines={reference_lines} />
+        </div>
+    }
+}
+
+// ── Section: Weekly Set Targets ──────────────────────────────────────────────
+
+#[derive(Properties, PartialEq)]
+struct WeeklyTargetsProps {
+    workouts: Vec<Workout>,
+    exercises: Vec<Exercise>,
+    show_modal: UseStateHandle<bool>,
+}
+
+#[function_component(SectionWeeklyTargets)]
+fn section_weekly_targets(props: &WeeklyTargetsProps) -> Html {
+    let targets = storage::load_user_config().muscle_set_targets;
+    if targets.is_empty() {
+        return html! {};
+    }
+
+    // Current calendar week, Monday through today
+    let t = today();
+    let week_start = t - chrono::Duration::days(t.weekday().num_days_from_monday() as i64);
+    let days_left = 6 - t.weekday().num_days_from_monday();
+    let sets = compute_muscle_sets(&props.workouts, &props.exercises, week_start, t);
+    let trained_today = props
+        .workouts
+        .iter()
+        .any(|w| parse_date(&w.date) == Some(t));
+
+    let rows: Vec<(&str, f64, f64)> = TRACKED_MUSCLES
+        .iter()
+        .copied()
+        .filter_map(|muscle| {
+            let target = targets.get(muscle).copied().filter(|v| *v > 0.0)?;
+            Some((muscle, sets.get(muscle).copied().unwrap_or(0.0), target))
+        })
+        .collect();
+    let short: Vec<String> = rows
+        .iter()
+        .filter(|(_, done, target)| done < target)
+        .map(|(muscle, done, target)| format!("{:.0} {}", (target - done).ceil(), muscle.to_lowercase()))
+        .collect();
+
+    let toggle_modal = {
+        let show = props.show_modal.clone();
+        Callback::from(move |_: MouseEvent| show.set(true))
+    };
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
+            <div class="flex justify-between items-center">
+                <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Weekly Targets"}</h3>
+                <button onclick={toggle_modal} class="text-xs text-blue-600 dark:text-blue-400 hover:underline">{"Edit"}</button>
+            </div>
+            <SectionInfo>
+                <p>{"Your own weekly set goals, counted from Monday. Unlike MEV/MRV these are not research landmarks — set them to whatever you're aiming for this block."}</p>
+                <p>{set_counting_note()}</p>
+            </SectionInfo>
+            // Nudge on a rest day late in the week when targets are still open
+            if !trained_today && days_left <= 2 && !short.is_empty() {
+                <div class="text-xs bg-yellow-100 dark:bg-yellow-900/30 text-yellow-800 dark:text-yellow-300 rounded-lg px-3 py-2">
+                    {format!(
+                        "Week ends in {} — still {} sets to go. Fit in a session to close the gap.",
+                        if days_left == 0 { "today".to_string() } else if days_left == 1 { "1 day".to_string() } else { format!("{} days", days_left) },
+                        short.join(", ")
+                    )}
+                </div>
+            }
+            <div class="space-y-2">
+                { for rows.iter().map(|(muscle, done, target)| {
+                    let pct = (done / target * 100.0).min(100.0);
+                    let remaining = (target - done).ceil();
+                    html! {
+                        <div class="flex items-center gap-2">
+                            <span class="w-24 text-xs text-gray-700 dark:text-gray-300 truncate">{*muscle}</span>
+                            <div class="flex-1 h-3 bg-gray-200 dark:bg-gray-700 rounded-full overflow-hidden">
+                                <div class={classes!("h-full", "rounded-full", "transition-all", if remaining <= 0.0 { "bg-green-500" } else { "bg-blue-500" })}
+                                     style={format!("width: {}%", pct)} />
+                            </div>
+                            <span class="w-28 text-right text-xs text-gray-500 dark:text-gray-400">
+                                { if remaining <= 0.0 {
+                                    format!("\u{2713} {:.0}/{:.0}", done, target)
+                                } else {
+                                    format!("{:.0} {} sets to go", remaining, muscle.to_lowercase())
+                                }}
+                            </span>
+                        </div>
+                    }
+                })}
+            </div>
+        </div>
+    }
+}
+
 // ── Section: Exercise Recommendations ────────────────────────────────────────
 
 #[derive(Properties, PartialEq)]
@@ -282,6 +653,61 @@ struct RecommendationsProps {
     exercises: Vec<Exercise>,
 }
 
+/// Exercises suggested for each undertrained muscle.
+const RECOMMENDATIONS_PER_MUSCLE: usize = 4;
+
+/// Ids of every exercise the user has completed a set of.
+fn completed_exercise_ids(workouts: &[Workout]) -> HashSet<String> {
+    workouts
+        .iter()
+        .flat_map(|w| w.exercises.iter())
+        .filter(|we| we.sets.iter().any(|s| s.completed))
+        .map(|we| we.exercise_id.clone())
+        .collect()
+}
+
+/// Every available exercise where `muscle` contributes at least half a set,
+/// limited to the active gym's equipment and the user's experience level.
+/// Ranked favorites first, then contribution, then exercises already in the
+/// user's history.
+fn rank_exercises_for<'a>(
+    muscle: &str,
+    exercises: &'a [Exercise],
+    config: &UserConfig,
+    user_exercise_ids: &HashSet<String>,
+) -> Vec<(&'a Exercise, f64)> {
+    let favorites = &config.favorite_exercises;
+    // Equipment at the active gym; empty means everything is available
+    let available_equipment = config
+        .active_gym()
+        .map(|g| g.equipment.clone())
+        .unwrap_or_default();
+    let mut ranked: Vec<(&Exercise, f64)> = exercises
+        .iter()
+        .filter(|e| {
+            available_equipment.is_empty()
+                || e.equipment == Equipment::Bodyweight
+                || available_equipment.contains(&e.equipment)
+        })
+        .filter(|e| e.difficulty <= config.experience_level)
+        .filter_map(|e| {
+            exercise_muscles(&e.id)
+                .iter()
+                .find(|mc| mc.muscle == muscle && mc.contribution >= 0.5)
+                .map(|mc| (e, mc.contribution))
+        })
+        .collect();
+    ranked.sort_by(|(a, ca), (b, cb)| {
+        favorites
+            .contains(&b.id)
+            .cmp(&favorites.contains(&a.id))
+            .then(cb.total_cmp(ca))
+            .then(user_exercise_ids.contains(&b.id).cmp(&user_exercise_ids.contains(&a.id)))
+            .then(a.name.cmp(&b.name))
+    });
+    ranked
+}
+
 #[function_component(SectionRecommendations)]
 fn section_recommendations(props: &RecommendationsProps) -> Html {
     let t = today();
@@ -304,33 +730,15 @@ fn section_recommendations(props: &RecommendationsProps) -> Html {
         return html! {};
     }
 
-    // Collect all exercise IDs the user has ever completed
-    let mut user_exercise_ids: std::collections::HashSet<String> = std::collections::HashSet::new();
-    for w in &props.workouts {
-        for we in &w.exercises {
-            if we.sets.iter().any(|s| s.completed) {
-                user_exercise_ids.insert(we.exercise_id.clone());
-            }
-        }
-    }
+    let user_exercise_ids = completed_exercise_ids(&props.workouts);
+    let config = storage::load_user_config();
+    let favorites = &config.favorite_exercises;
 
-    // For each undertrained muscle, find exercises from user's history with contribution >= 0.5
-    let mut recommendations: Vec<(&str, Vec<&Exercise>)> = Vec::new();
+    let mut recommendations: Vec<(&str, Vec<(&Exercise, f64)>)> = Vec::new();
     for &muscle in &undertrained {
-        let mut muscle_exercises: Vec<&Exercise> = Vec::new();
-        for eid in &user_exercise_ids {
-            let contributions = exercise_muscles(eid);
-            let has_significant = contributions
-                .iter()
-                .any(|mc| mc.muscle == muscle && mc.contribution >= 0.5);
-            if has_significant {
-                if let Some(ex) = props.exercises.iter().find(|e| e.id == *eid) {
-                    muscle_exercises.push(ex);
-                }
-            }
-        }
-        muscle_exercises.sort_by(|a, b| a.name.cmp(&b.name));
-        muscle_exercises.truncate(3);
+        let mut muscle_exercises =
+            rank_exercises_for(muscle, &props.exercises, &config, &user_exercise_ids);
+        muscle_exercises.truncate(RECOMMENDATIONS_PER_MUSCLE);
         if !muscle_exercises.is_empty() {
             recommendations.push((muscle, muscle_exercises));
         }
@@ -343,18 +751,39 @@ fn section_recommendations(props: &RecommendationsProps) -> Html {
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Recommended Exercises"}</h3>
-            <p class="text-xs text-gray-500">{"Based on your history — exercises you've done that target undertrained muscles."}</p>
+            <p class="text-xs text-gray-500">{"Exercises that efficiently target muscles below MEV this week."}</p>
+            <SectionInfo>
+                <p>{"A muscle is listed when its sets over the last 7 days are below its MEV. Suggested exercises are any in the database where that muscle contributes at least half a set per set, limited to your active gym's equipment and your experience level (Settings). Favorites come first, then the highest contribution, then exercises you've done before."}</p>
+                <p>{"Add 2–4 sets of one of these to your next sessions until the muscle reaches MEV."}</p>
+                <div class="font-mono text-[10px] pt-1">
+                    { for recommendations.iter().map(|(muscle, _)| {
+                        let s = sets.get(*muscle).copied().unwrap_or(0.0);
+                        let (mev, mrv) = thresholds.get(*muscle).copied().unwrap_or((0.0, 20.0));
+                        html! { <div>{format!("{}: {:.1} sets, MEV {:.0} — {}", muscle, s, mev, volume_advice(s, mev, mrv))}</div> }
+                    })}
+                </div>
+                <p>{thresholds_source_note()}</p>
+            </SectionInfo>
             <div class="space-y-3">
                 { for recommendations.iter().map(|(muscle, exercises)| {
+                    let names: Vec<&str> = exercises.iter().map(|(ex, _)| ex.name.as_str()).collect();
                     html! {
                         <div>
                             <h4 class="text-xs font-bold text-yellow-500 mb-1">{muscle}</h4>
+                            <p class="text-xs text-gray-600 dark:text-gray-400 mb-1">{format!("To hit {}, add: {}", muscle, names.join(", "))}</p>
                             <div class="space-y-1">
-                                { for exercises.iter().map(|ex| {
+                                { for exercises.iter().map(|(ex, contribution)| {
                                     html! {
-                                        <div class="text-xs text-gray-700 dark:text-gray-300 bg-gray-200 dark:bg-gray-700 rounded-lg px-3 py-1.5">
-                                            {&ex.name}
-                                            <span class="text-gray-400 ml-1">{format!("· {}", ex.equipment)}</span>
+                                        <div class="text-xs text-gray-700 dark:text-gray-300 bg-gray-200 dark:bg-gray-700 rounded-lg px-3 py-1.5 flex items-center gap-1">
+                                            if favorites.contains(&ex.id) {
+                                                <span class="text-yellow-500">{"\u{2605}"}</span>
+                                            }
+                                            <span class="truncate">{&ex.name}</span>
+                                            <span class="text-gray-400">{format!("· {}", ex.equipment)}</span>
+                                            if !user_exercise_ids.contains(&ex.id) {
+                                                <span class="text-[10px] text-blue-500">{"new"}</span>
+                                            }
+                                            <span class="ml-auto font-mono text-gray-400">{format!("{:.1}/set", contribution)}</span>
                                         </div>
                                     }
                                 })}
@@ -377,11 +806,13 @@ struct FrequencyProps {
 
 #[function_component(SectionFrequency)]
 fn section_frequency(props: &FrequencyProps) -> Html {
+    let navigator = use_navigator();
     let t = today();
     let from = t - chrono::Duration::days(14);
 
     // Track which days each muscle was trained
     let mut muscle_days: HashMap<String, Vec<NaiveDate>> = HashMap::new();
+    let fractional = count_fractional();
 
     for w in &props.workouts {
         if let Some(d) = parse_date(&w.date) {
@@ -394,7 +825,7 @@ fn section_frequency(props: &FrequencyProps) -> Html {
                     let custom_mg = find_exercise(&props.exercises, &we.exercise_id)
                         .filter(|e| e.is_custom)
                         .map(|e| e.muscle_groups.as_slice());
-                    let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg);
+                    let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg, fractional);
                     for (muscle, _) in eff {
                         let days = muscle_days.entry(muscle.to_string()).or_default();
                         if !days.contains(&d) {
@@ -410,10 +841,39 @@ fn section_frequency(props: &FrequencyProps) -> Html {
         return html! {};
     }
 
+    // Muscles under 2x/week, least trained first, each with the top-ranked
+    // exercise to add for it
+    let config = storage::load_user_config();
+    let user_exercise_ids = completed_exercise_ids(&props.workouts);
+    let mut below_target: Vec<(&str, f64)> = TRACKED_MUSCLES
+        .iter()
+        .map(|&muscle| {
+            let days = muscle_days.get(muscle).map(|d| d.len()).unwrap_or(0);
+            (muscle, days as f64 / 2.0)
+        })
+        .filter(|(_, freq)| *freq < 2.0)
+        .collect();
+    below_target.sort_by(|a, b| a.1.total_cmp(&b.1).then(a.0.cmp(b.0)));
+    let actions: Vec<(&str, f64, Option<&Exercise>)> = below_target
+        .into_iter()
+        .map(|(muscle, freq)| {
+            let pick = rank_exercises_for(muscle, &props.exercises, &config, &user_exercise_ids)
+                .first()
+                .map(|(e, _)| *e);
+            (muscle, freq, pick)
+        })
+        .collect();
+
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Training Frequency (14 days)"}</h3>
             <p class="text-xs text-gray-500">{"Times per week — 2x/week per muscle is optimal for hypertrophy."}</p>
+            <SectionInfo>
+                <p>{"Counts the distinct days each muscle got at least one completed set in the last 14 days, divided by two."}</p>
+                <p>{"Green = 2x/week or more. Yellow = 1–2x/week. Red = less than once a week."}</p>
+                <p>{"If a muscle is yellow or red, split its weekly sets across two sessions instead of one big day."}</p>
+                <p>{"Each muscle below 2x/week gets a suggested exercise, picked the same way as Recommended Exercises. Tap it to add it to your current workout, or start one with it."}</p>
+            </SectionInfo>
             <div class="grid grid-cols-2 gap-x-4 gap-y-1.5">
                 { for TRACKED_MUSCLES.iter().map(|&muscle| {
                     let days = muscle_days.get(muscle).map(|d| d.len()).unwrap_or(0);
@@ -429,6 +889,38 @@ fn section_frequency(props: &FrequencyProps) -> Html {
                     }
                 })}
             </div>
+            if !actions.is_empty() {
+                <div class="border-t border-gray-200 dark:border-gray-700 pt-3 space-y-1.5">
+                    { for actions.iter().map(|(muscle, freq, pick)| {
+                        let prompt = if *freq == 0.0 {
+                            format!("Train {} this week", muscle)
+                        } else {
+                            format!("Train {} again this week", muscle)
+                        };
+                        html! {
+                            <div class="flex items-center gap-2 text-xs">
+                                <span class={classes!("flex-1", "truncate", frequency_color(*freq))}>{prompt}</span>
+                                if let Some(ex) = pick {
+                                    <button
+                                        class="max-w-[50%] truncate px-2 py-1 rounded-lg bg-blue-600 text-white font-bold hover:bg-blue-700 neu-btn transition-colors"
+                                        title={format!("Add {} to a workout", ex.name)}
+                                        onclick={{
+                                            let id = ex.id.clone();
+                                            let navigator = navigator.clone();
+                                            Callback::from(move |_: MouseEvent| {
+                                                queue_exercise(&id);
+                                                if let Some(nav) = &navigator {
+                                                    nav.push(&Route::Workout);
+                                                }
+                                            })
+                                        }}
+                                    >{format!("+ {}", ex.name)}</button>
+                                }
+                            </div>
+                        }
+                    })}
+                </div>
+            }
         </div>
     }
 }
@@ -444,7 +936,9 @@ struct OverloadProps {
 struct OverloadEntry {
     name: String,
     trend: OverloadTrend,
+    /// Latest session's best e1RM, or least assistance for assisted exercises.
     recent_1rm: f64,
+    assisted: bool,
 }
 
 enum OverloadTrend {
@@ -453,18 +947,34 @@ enum OverloadTrend {
     Regressing,
 }
 
-#[function_component(SectionOverload)]
-fn section_overload(props: &OverloadProps) -> Html {
+/// e1RM trend per exercise over the last 4 weeks, sorted by exercise name.
+/// Assisted exercises trend on the least assistance per session, where a drop is progress.
+fn overload_entries(workouts: &[Workout], exercises: &[Exercise]) -> Vec<OverloadEntry> {
     let t = today();
     let from = t - chrono::Duration::days(28);
 
     // Group workout exercises by exercise_id within the last 4 weeks
     let mut exercise_sessions: HashMap<String, Vec<(NaiveDate, f64)>> = HashMap::new();
 
-    for w in &props.workouts {
+    for w in workouts {
         if let Some(d) = parse_date(&w.date) {
             if d >= from && d <= t {
                 for we in &w.exercises {
+                    if find_exercise(exercises, &we.exercise_id).is_some_and(|e| e.assisted) {
+                        let least = we
+                            .sets
+                            .iter()
+                            .filter(|s| s.completed && s.reps > 0)
+                            .map(|s| s.weight)
+                            .fold(f64::MAX, f64::min);
+                        if least < f64::MAX {
+                            exercise_sessions
+                                .entry(we.exercise_id.clone())
+                                .or_default()
+                                .push((d, least));
+                        }
+                        continue;
+                    }
                     let max_e1rm = we
                         .sets
                         .iter()
@@ -495,8 +1005,16 @@ fn section_overload(props: &OverloadProps) -> Html {
         let late_avg: f64 =
             sessions[midpoint..].iter().map(|(_, v)| v).sum::<f64>() / (n - midpoint) as f64;
         let recent_1rm = sessions.last().map(|(_, v)| *v).unwrap_or(0.0);
+        let assisted = find_exercise(exercises, &eid).is_some_and(|e| e.assisted);
 
-        let diff_pct = (late_avg - early_avg) / early_avg * 100.0;
+        let diff_pct = if early_avg <= 0.0 {
+            // Already unassisted; nothing left to drop
+            0.0
+        } else if assisted {
+            (early_avg - late_avg) / early_avg * 100.0
+        } else {
+            (late_avg - early_avg) / early_avg * 100.0
+        };
         let trend = if diff_pct > 2.0 {
             OverloadTrend::Progressing
         } else if diff_pct < -2.0 {
@@ -505,17 +1023,24 @@ fn section_overload(props: &OverloadProps) -> Html {
             OverloadTrend::Stagnant
         };
 
-        let name = find_exercise(&props.exercises, &eid)
+        let name = find_exercise(exercises, &eid)
             .map(|e| e.name.clone())
             .unwrap_or_else(|| eid.clone());
         entries.push(OverloadEntry {
             name,
             trend,
             recent_1rm,
+            assisted,
         });
     }
 
     entries.sort_by(|a, b| a.name.cmp(&b.name));
+    entries
+}
+
+#[function_component(SectionOverload)]
+fn section_overload(props: &OverloadProps) -> Html {
+    let entries = overload_entries(&props.workouts, &props.exercises);
 
     if entries.is_empty() {
         return html! {};
@@ -526,6 +1051,11 @@ fn section_overload(props: &OverloadProps) -> Html {
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Progressive Overload (4 weeks)"}</h3>
+            <SectionInfo>
+                <p>{"For each exercise done at least twice in the last 28 days, the best estimated 1RM (Epley formula) of each session is averaged for the earlier half and the later half of those sessions."}</p>
+                <p>{"\u{2191} Progressing = later half is more than 2% higher. \u{2192} Stagnant = within ±2%. \u{2193} Regressing = more than 2% lower."}</p>
+                <p>{"For stagnant lifts add a rep or a small weight jump each session; for regressing lifts check sleep, recovery and total volume."}</p>
+            </SectionInfo>
             <div class="space-y-2">
                 { for entries.iter().map(|e| {
                     let (arrow, color, label) = match e.trend {
@@ -538,7 +1068,7 @@ fn section_overload(props: &OverloadProps) -> Html {
                             <span class="text-xs text-gray-700 dark:text-gray-300 truncate mr-2">{&e.name}</span>
                             <div class="flex items-center gap-2 flex-shrink-0">
                                 <span class="text-xs text-gray-500">
-                                    {format!("e1RM {:.0}{}", units.display_weight(e.recent_1rm), units.weight_label())}
+                                    {format!("{} {:.0}{}", if e.assisted { "assist" } else { "e1RM" }, units.display_weight(e.recent_1rm), units.weight_label())}
                                 </span>
                                 <span class={classes!("text-sm", "font-bold", color)} title={label}>
                                     {arrow}
@@ -610,6 +1140,10 @@ fn section_rep_range(props: &RepRangeProps) -> Html {
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Rep Range Distribution (4 weeks)"}</h3>
+            <SectionInfo>
+                <p>{"Every completed set from the last 28 days is bucketed by reps: 1–5 builds strength, 6–12 is the classic hypertrophy range, 13+ trains muscular endurance."}</p>
+                <p>{"Most of your sets landing in 6–12 is normal for muscle growth. Mixing in some heavy and some high-rep work keeps progress well-rounded."}</p>
+            </SectionInfo>
             // Stacked bar
             <div class="h-6 rounded-full overflow-hidden flex bg-gray-200 dark:bg-gray-700">
                 if s_pct > 0 {
@@ -707,6 +1241,11 @@ fn section_deload(props: &DeloadProps) -> Html {
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Deload Check (6 weeks)"}</h3>
+            <SectionInfo>
+                <p>{"Bars show completed sets per week for the last 6 weeks. A deload is suggested when weekly sets have risen for 4 weeks in a row, since fatigue builds up faster than fitness."}</p>
+                <p>{format!("Current run of increasing weeks: {}.", increasing_streak)}</p>
+                <p>{"When a deload is suggested, keep your exercises but cut sets by 40–50% for a week, then ramp back up."}</p>
+            </SectionInfo>
             <div class="flex items-end gap-1 h-16">
                 { for weekly_volume.iter().map(|(label, vol)| {
                     let max_vol = volumes.iter().cloned().fold(0.0_f64, f64::max);
@@ -738,6 +1277,102 @@ fn section_deload(props: &DeloadProps) -> Html {
     }
 }
 
+// ── Section: Acute:Chronic Workload Ratio ────────────────────────────────────
+
+/// ACWR sweet spot and spike threshold, per the sports-science literature.
+const ACWR_SWEET_LOW: f64 = 0.8;
+const ACWR_SWEET_HIGH: f64 = 1.3;
+const ACWR_SPIKE: f64 = 1.5;
+/// Gauge scale maximum.
+const ACWR_GAUGE_MAX: f64 = 2.0;
+
+/// Tonnage over the `days` days ending on `to` (inclusive).
+fn tonnage_in_window(workouts: &[Workout], to: NaiveDate, days: i64) -> f64 {
+    let from = to - chrono::Duration::days(days - 1);
+    workouts
+        .iter()
+        .filter(|w| parse_date(&w.date).is_some_and(|d| d >= from && d <= to))
+        .map(|w| w.total_volume())
+        .sum()
+}
+
+/// (acute 7-day load, chronic weekly average over 28 days, ratio). The ratio is
+/// `None` until there is chronic load to compare against.
+fn acute_chronic_ratio(workouts: &[Workout], to: NaiveDate) -> (f64, f64, Option<f64>) {
+    let acute = tonnage_in_window(workouts, to, 7);
+    let chronic = tonnage_in_window(workouts, to, 28) / 4.0;
+    let ratio = if chronic > 0.0 { Some(acute / chronic) } else { None };
+    (acute, chronic, ratio)
+}
+
+#[derive(Properties, PartialEq)]
+struct WorkloadRatioProps {
+    workouts: Vec<Workout>,
+}
+
+#[function_component(SectionWorkloadRatio)]
+fn section_workload_ratio(props: &WorkloadRatioProps) -> Html {
+    let (acute, chronic, ratio) = acute_chronic_ratio(&props.workouts, today());
+    let ratio = match ratio {
+        Some(r) => r,
+        None => return html! {},
+    };
+    let units = storage::load_user_config().unit_system;
+
+    let pos = |v: f64| (v / ACWR_GAUGE_MAX * 100.0).clamp(0.0, 100.0);
+    let (status, status_color) = if ratio > ACWR_SPIKE {
+        ("Load spike", "text-red-500")
+    } else if ratio > ACWR_SWEET_HIGH {
+        ("Above sweet spot", "text-yellow-500")
+    } else if ratio >= ACWR_SWEET_LOW {
+        ("Sweet spot", "text-green-500")
+    } else {
+        ("Below sweet spot", "text-blue-400")
+    };
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
+            <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Workload Ratio (ACWR)"}</h3>
+            <SectionInfo>
+                <p>{"Acute:chronic workload ratio compares the tonnage of the last 7 days with your average week over the last 28 days."}</p>
+                <p>{format!(
+                    "Acute: {:.0} {unit}. Chronic weekly average: {:.0} {unit}. Ratio: {:.2}.",
+                    units.display_weight(acute),
+                    units.display_weight(chronic),
+                    ratio,
+                    unit = units.weight_label(),
+                )}</p>
+                <p>{"0.8–1.3 is the sweet spot. Above 1.5 means load is climbing faster than your body has adapted to, which is linked to higher injury risk. Below 0.8 you're doing less than usual — fine for a deload."}</p>
+            </SectionInfo>
+            <div class="flex items-baseline justify-between">
+                <span class={classes!("text-3xl", "font-bold", status_color)}>{format!("{:.2}", ratio)}</span>
+                <span class={classes!("text-xs", "font-bold", status_color)}>{status}</span>
+            </div>
+            // Gauge: sweet spot band, spike zone and current marker
+            <div class="relative h-4 bg-gray-200 dark:bg-gray-700 rounded-full overflow-hidden">
+                <div class="absolute top-0 bottom-0 bg-green-500/40"
+                     style={format!("left: {}%; width: {}%", pos(ACWR_SWEET_LOW), pos(ACWR_SWEET_HIGH) - pos(ACWR_SWEET_LOW))} />
+                <div class="absolute top-0 bottom-0 right-0 bg-red-500/30"
+                     style={format!("left: {}%", pos(ACWR_SPIKE))} />
+                <div class="absolute top-0 bottom-0 w-1 bg-gray-900 dark:bg-white rounded"
+                     style={format!("left: calc({}% - 2px)", pos(ratio))} />
+            </div>
+            <div class="relative h-3 text-[9px] text-gray-500">
+                { for [0.0, ACWR_SWEET_LOW, ACWR_SWEET_HIGH, ACWR_SPIKE, ACWR_GAUGE_MAX].iter().map(|v| html! {
+                    <span class="absolute -translate-x-1/2" style={format!("left: {}%", pos(*v))}>{format!("{}", v)}</span>
+                })}
+            </div>
+            { if ratio > ACWR_SPIKE {
+                html! {
+                    <p class="text-xs text-red-500 bg-red-500/10 rounded-lg p-2 font-medium">
+                        {"Your load this week is well above your recent average. Hold volume steady or back off for a few days before pushing further."}
+                    </p>
+                }
+            } else { html! {} }}
+        </div>
+    }
+}
+
 // ── Section F: Push/Pull Balance ─────────────────────────────────────────────
 
 #[derive(Properties, PartialEq)]
@@ -792,6 +1427,11 @@ fn section_push_pull(props: &PushPullProps) -> Html {
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Push/Pull Balance (7 days)"}</h3>
+            <SectionInfo>
+                <p>{format!("Push sets ({}) are divided by pull sets ({}) over the last 7 days.", PUSH_MUSCLES.join(", "), PULL_MUSCLES.join(", "))}</p>
+                <p>{"Green = 0.8–1.2:1, balanced. Yellow = 0.6–0.8 or 1.2–1.5. Red = outside 0.6–1.5."}</p>
+                <p>{"Too much pushing relative to pulling is linked to rounded shoulders and shoulder pain — add rows or face pulls to even it out."}</p>
+            </SectionInfo>
             <div class="flex justify-between items-center">
                 <span class="text-xs text-gray-500">{format!("Push: {:.0} sets", push_total)}</span>
                 <span class={classes!("text-lg", "font-bold", ratio_color)}>{ratio_text}</span>
@@ -832,20 +1472,59 @@ struct SessionVolumeProps {
     exercises: Vec<Exercise>,
 }
 
+/// A muscle that got too many sets in one session.
+struct SessionVolumeWarning {
+    muscle: String,
+    date: String,
+    sets: f64,
+    /// Supersets that hit the muscle, as (exercise names, combined sets).
+    supersets: Vec<(String, f64)>,
+}
+
 #[function_component(SectionSessionVolume)]
 fn section_session_volume(props: &SessionVolumeProps) -> Html {
     let t = today();
     let from = t - chrono::Duration::days(14);
 
-    let mut warnings: Vec<(String, String, f64)> = Vec::new(); // (muscle, date, sets)
+    let mut warnings: Vec<SessionVolumeWarning> = Vec::new();
 
     for w in &props.workouts {
         if let Some(d) = parse_date(&w.date) {
             if d >= from && d <= t {
-                let session_sets = session_muscle_sets(w, &props.exercises);
+                let session_sets = session_muscle_sets(w, &props.exercises, None);
+                let mut groups: Vec<u32> = w.exercises.iter().filter_map(|we| we.superset_group).collect();
+                groups.sort_unstable();
+                groups.dedup();
+                let group_sets: Vec<(String, HashMap<String, f64>)> = groups
+                    .into_iter()
+                    .map(|g| {
+                        let names: Vec<String> = w
+                            .exercises
+                            .iter()
+                            .filter(|we| we.superset_group == Some(g))
+                            .map(|we| {
+                                find_exercise(&props.exercises, &we.exercise_id)
+                                    .map(|e| e.name.clone())
+                                    .unwrap_or_else(|| we.exercise_id.clone())
+                            })
+                            .collect();
+                        (names.join(" + "), session_muscle_sets(w, &props.exercises, Some(g)))
+                    })
+                    .collect();
                 for (muscle, sets) in session_sets {
                     if sets > 10.0 {
-                        warnings.push((muscle, w.date.clone(), sets));
+                        let supersets = group_sets
+                            .iter()
+                            .filter_map(|(names, gs)| {
+                                gs.get(&muscle).filter(|v| **v > 0.0).map(|v| (names.clone(), *v))
+                            })
+                            .collect();
+                        warnings.push(SessionVolumeWarning {
+                            muscle,
+                            date: w.date.clone(),
+                            sets,
+                            supersets,
+                        });
                     }
                 }
             }
@@ -856,18 +1535,28 @@ fn section_session_volume(props: &SessionVolumeProps) -> Html {
         return html! {};
     }
 
-    warnings.sort_by(|a, b| b.1.cmp(&a.1));
+    warnings.sort_by(|a, b| b.date.cmp(&a.date));
     warnings.truncate(5);
 
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Session Volume Warnings"}</h3>
             <p class="text-xs text-gray-500">{"Research suggests >10 direct sets per muscle per session has diminishing returns."}</p>
+            <SectionInfo>
+                <p>{"Flags any muscle that got more than 10 effective sets in a single session during the last 14 days."}</p>
+                <p>{"Beyond that point extra sets mostly add fatigue. Move the surplus to another day of the week instead of dropping it."}</p>
+                <p>{"Supersets that hit the muscle are listed with their combined sets, so you can see how much of the total came from back-to-back work."}</p>
+            </SectionInfo>
             <div class="space-y-2">
-                { for warnings.iter().map(|(muscle, date, sets)| {
+                { for warnings.iter().map(|warning| {
                     html! {
                         <div class="text-xs bg-yellow-500/10 text-yellow-600 dark:text-yellow-400 rounded-lg p-2">
-                            {format!("{:.0} sets of {} on {}", sets, muscle, date)}
+                            {format!("{:.0} sets of {} on {}", warning.sets, warning.muscle, warning.date)}
+                            { for warning.supersets.iter().map(|(names, group_sets)| html! {
+                                <div class="mt-1 text-[10px] text-yellow-700/80 dark:text-yellow-300/70">
+                                    {format!("Superset {}: {:.1} combined sets", names, group_sets)}
+                                </div>
+                            })}
                         </div>
                     }
                 })}
@@ -887,8 +1576,9 @@ struct RecoveryProps {
 #[function_component(SectionRecovery)]
 fn section_recovery(props: &RecoveryProps) -> Html {
     let t = today();
-    // Find last trained date per muscle
-    let mut last_trained: HashMap<String, NaiveDate> = HashMap::new();
+    // Last trained date per muscle and the effective sets it got that day
+    let mut last_trained: HashMap<String, (NaiveDate, f64)> = HashMap::new();
+    let fractional = count_fractional();
 
     for w in &props.workouts {
         if let Some(d) = parse_date(&w.date) {
@@ -900,13 +1590,15 @@ fn section_recovery(props: &RecoveryProps) -> Html {
                 let custom_mg = find_exercise(&props.exercises, &we.exercise_id)
                     .filter(|e| e.is_custom)
                     .map(|e| e.muscle_groups.as_slice());
-                let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg);
-                for (muscle, _) in eff {
+                let eff = effective_sets_for_exercise(&we.exercise_id, completed, custom_mg, fractional);
+                for (muscle, sets) in eff {
                     let entry = last_trained
                         .entry(muscle.to_string())
-                        .or_insert(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap());
-                    if d > *entry {
-                        *entry = d;
+                        .or_insert((NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(), 0.0));
+                    if d > entry.0 {
+                        *entry = (d, sets);
+                    } else if d == entry.0 {
+                        entry.1 += sets;
                     }
                 }
             }
@@ -917,25 +1609,34 @@ fn section_recovery(props: &RecoveryProps) -> Html {
         return html! {};
     }
 
-    // Sort by most recently trained
-    let mut entries: Vec<(&str, f64)> = TRACKED_MUSCLES
+    // (muscle, hours since, hours needed, sets), least recovered first
+    let mut entries: Vec<(&str, f64, f64, f64)> = TRACKED_MUSCLES
         .iter()
         .filter_map(|&muscle| {
-            let d = last_trained.get(muscle)?;
+            let (d, sets) = last_trained.get(muscle)?;
             let days_ago = (t - *d).num_days() as f64;
             let hours_approx = days_ago * 24.0;
-            Some((muscle, hours_approx))
+            Some((muscle, hours_approx, recovery_hours_needed(*sets), *sets))
         })
         .collect();
-    entries.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal));
+    entries.sort_by(|a, b| {
+        (a.1 / a.2)
+            .partial_cmp(&(b.1 / b.2))
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
 
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors space-y-3">
             <h3 class="text-sm font-bold text-gray-900 dark:text-gray-100 uppercase tracking-wider">{"Recovery Status"}</h3>
-            <p class="text-xs text-gray-500">{"Optimal: 48-72h between sessions for the same muscle."}</p>
+            <p class="text-xs text-gray-500">{"Bigger sessions need longer: about 1 day plus 6h per set, up to 4 days."}</p>
+            <SectionInfo>
+                <p>{"Time since each muscle last got a completed set, rounded to whole days. The estimated recovery time scales with the effective sets that muscle got in its last session."}</p>
+                <p>{"Red = less than halfway recovered; train something else. Yellow = past halfway, fine for light work. Green = fully recovered, ready for a hard session."}</p>
+            </SectionInfo>
             <div class="grid grid-cols-2 gap-x-4 gap-y-1.5">
-                { for entries.iter().map(|(muscle, hours)| {
-                    let color = recovery_color(*hours);
+                { for entries.iter().map(|(muscle, hours, needed, sets)| {
+                    let color = recovery_color(*hours, *needed);
+                    let title = format!("{:.1} sets last session, ~{:.0}h to recover", sets, needed);
                     let display = if *hours < 24.0 {
                         format!("{:.0}h ago", hours)
                     } else {
@@ -943,7 +1644,7 @@ fn section_recovery(props: &RecoveryProps) -> Html {
                         format!("{:.0}d ago", days)
                     };
                     html! {
-                        <div class="flex justify-between items-center">
+                        <div class="flex justify-between items-center" title={title}>
                             <span class="text-xs text-gray-700 dark:text-gray-300 truncate">{muscle}</span>
                             <span class={classes!("text-xs", "font-bold", color)}>{display}</span>
                         </div>
@@ -964,6 +1665,8 @@ struct ThresholdModalProps {
 #[function_component(ThresholdModal)]
 fn threshold_modal(props: &ThresholdModalProps) -> Html {
     let thresholds = use_state(get_thresholds);
+    let fractional = use_state(count_fractional);
+    let targets = use_state(|| storage::load_user_config().muscle_set_targets);
 
     if !*props.visible {
         return html! {};
@@ -977,9 +1680,13 @@ fn threshold_modal(props: &ThresholdModalProps) -> Html {
     let on_save = {
         let visible = props.visible.clone();
         let thresholds = thresholds.clone();
+        let fractional = fractional.clone();
+        let targets = targets.clone();
         Callback::from(move |_: MouseEvent| {
             let mut config = storage::load_user_config();
             config.muscle_thresholds = Some((*thresholds).clone());
+            config.count_fractional_volume = *fractional;
+            config.muscle_set_targets = (*targets).clone();
             storage::save_user_config(&config);
             visible.set(false);
         })
@@ -987,7 +1694,9 @@ fn threshold_modal(props: &ThresholdModalProps) -> Html {
 
     let on_reset = {
         let thresholds = thresholds.clone();
+        let fractional = fractional.clone();
         Callback::from(move |_: MouseEvent| {
+            fractional.set(true);
             let defaults: HashMap<String, (f64, f64)> = muscle_data::default_thresholds()
                 .iter()
                 .map(|(k, v)| (k.to_string(), *v))
@@ -1004,14 +1713,51 @@ fn threshold_modal(props: &ThresholdModalProps) -> Html {
                     <h3 class="text-lg font-bold text-gray-900 dark:text-gray-100">{"Volume Thresholds"}</h3>
                     <button onclick={on_close} class="text-gray-400 hover:text-gray-600 dark:hover:text-gray-300 text-xl">{"\u{00d7}"}</button>
                 </div>
-                <p class="text-xs text-gray-500 mb-4">{"MEV = Minimum Effective Volume, MRV = Maximum Recoverable Volume (sets/week)"}</p>
+                <p class="text-xs text-gray-500 mb-4">{"MEV = Minimum Effective Volume, MRV = Maximum Recoverable Volume (sets/week). Target is your own weekly set goal; leave blank for none. Reset leaves targets unchanged."}</p>
+                <div class="flex items-center justify-between mb-4 pb-4 border-b border-gray-200 dark:border-gray-700/50">
+                    <div>
+                        <div class="text-sm font-medium text-gray-800 dark:text-gray-200">{"Count indirect volume"}</div>
+                        <div class="text-[10px] text-gray-500">
+                            { if *fractional {
+                                "Secondary muscles count 0.5 sets, tertiary 0.25."
+                            } else {
+                                "Only direct (primary) sets count, as 1 set each."
+                            }}
+                        </div>
+                    </div>
+                    <button
+                        onclick={{
+                            let fractional = fractional.clone();
+                            Callback::from(move |_: MouseEvent| fractional.set(!*fractional))
+                        }}
+                        class={classes!(
+                            "relative", "inline-flex", "h-6", "w-11", "items-center", "rounded-full", "transition-colors", "focus:outline-none", "flex-shrink-0",
+                            if *fractional { "bg-blue-600" } else { "bg-gray-300 dark:bg-gray-700" }
+                        )}
+                    >
+                        <span
+                            class={classes!(
+                                "inline-block", "h-4", "w-4", "transform", "rounded-full", "bg-white", "transition-transform",
+                                if *fractional { "translate-x-6" } else { "translate-x-1" }
+                            )}
+                        />
+                    </button>
+                </div>
+                <div class="flex items-center gap-2 mb-2 text-[10px] text-gray-500 uppercase tracking-wider">
+                    <span class="w-24">{"Muscle"}</span>
+                    <span class="flex-1">{"MEV - MRV"}</span>
+                    <span class="w-14 text-center">{"Target"}</span>
+                </div>
                 <div class="space-y-3">
                     { for TRACKED_MUSCLES.iter().map(|&muscle| {
                         let (mev, mrv) = thresholds.get(muscle).copied().unwrap_or((0.0, 20.0));
                         let thresholds_mev = thresholds.clone();
                         let thresholds_mrv = thresholds.clone();
+                        let targets_edit = targets.clone();
                         let muscle_str = muscle.to_string();
                         let muscle_str2 = muscle.to_string();
+                        let muscle_str3 = muscle.to_string();
+                        let target_val = targets.get(muscle).map(|v| format!("{:.0}", v)).unwrap_or_default();
                         html! {
                             <div class="flex items-center gap-2">
                                 <span class="w-24 text-xs text-gray-700 dark:text-gray-300 truncate">{muscle}</span>
@@ -1040,6 +1786,18 @@ fn threshold_modal(props: &ThresholdModalProps) -> Html {
                                         })}
                                     />
                                 </div>
+                                <input type="number" value={target_val} placeholder="-"
+                                    class="w-14 px-1.5 py-1 text-xs bg-gray-100 dark:bg-gray-700 rounded text-center text-gray-900 dark:text-gray-100 outline-none"
+                                    onchange={Callback::from(move |e: Event| {
+                                        let input: web_sys::HtmlInputElement = e.target_unchecked_into();
+                                        let mut t = (*targets_edit).clone();
+                                        match input.value().parse::<f64>() {
+                                            Ok(val) if val > 0.0 => { t.insert(muscle_str3.clone(), val); }
+                                            _ => { t.remove(&muscle_str3); }
+                                        }
+                                        targets_edit.set(t);
+                                    })}
+                                />
                             </div>
                         }
                     })}
@@ -1061,6 +1819,26 @@ fn threshold_modal(props: &ThresholdModalProps) -> Html {
 
 // ── Quick Summary (for Home page card) ───────────────────────────────────────
 
+/// Progressive overload trends as (exercise name, trend label, latest e1RM in kg).
+pub fn overload_summary(workouts: &[Workout], exercises: &[Exercise]) -> Vec<(String, &'static str, f64)> {
+    overload_entries(workouts, exercises)
+        .into_iter()
+        .map(|e| {
+            let label = match e.trend {
+                OverloadTrend::Progressing => "Progressing",
+                OverloadTrend::Stagnant => "Stagnant",
+                OverloadTrend::Regressing => "Regressing",
+            };
+            let name = if e.assisted {
+                format!("{} (assistance, lower is better)", e.name)
+            } else {
+                e.name
+            };
+            (name, label, e.recent_1rm)
+        })
+        .collect()
+}
+
 pub fn muscle_balance_summary(workouts: &[Workout], exercises: &[Exercise]) -> (u32, u32) {
     let t = today();
     let from = t - chrono::Duration::days(7);
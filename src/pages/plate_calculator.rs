@@ -1,4 +1,4 @@
-use crate::components::workout_log::compute_plates;
+use crate::components::workout_log::{compute_plates, standard_plates};
 use crate::storage;
 use yew::prelude::*;
 
@@ -7,11 +7,16 @@ pub fn plate_calculator_page() -> Html {
     let config = storage::load_user_config();
     let unit_system = config.unit_system.clone();
 
+    let bar_options: Vec<f64> = vec![20.0, 15.0, 10.0];
+    let gym_name = config.active_gym().map(|g| g.name.clone());
+    let plate_set = config.active_plates().unwrap_or_else(standard_plates);
+
     let target_weight = use_state(|| 100.0_f64);
-    let bar_weight = use_state(|| config.bar_weight);
-    let custom_bar = use_state(|| false);
+    let bar_weight = use_state(|| config.effective_bar_weight());
+    let custom_bar = use_state(|| !bar_options.contains(&config.effective_bar_weight()));
 
-    let plates = compute_plates(*target_weight, *bar_weight);
+    let (plates, loaded) = compute_plates(*target_weight, *bar_weight, &plate_set);
+    let limited = plate_set.iter().any(|(_, pairs)| pairs.is_some());
     let wl = unit_system.weight_label();
 
     let on_target_change = {
@@ -25,8 +30,6 @@ pub fn plate_calculator_page() -> Html {
         })
     };
 
-    let bar_options: Vec<f64> = vec![20.0, 15.0, 10.0];
-
     html! {
         <div class="px-4 py-4 space-y-6">
             <h1 class="text-2xl font-bold text-gray-900 dark:text-gray-100">{"Plate Calculator"}</h1>
@@ -116,6 +119,9 @@ pub fn plate_calculator_page() -> Html {
             // Plate breakdown
             <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-5 neu-flat transition-colors space-y-4">
                 <h2 class="text-sm font-bold text-gray-500 uppercase tracking-wider">{"Plates Per Side"}</h2>
+                if let Some(name) = &gym_name {
+                    <p class="text-xs text-gray-500 dark:text-gray-400">{format!("Using the plates at {}", name)}</p>
+                }
 
                 { if *target_weight <= *bar_weight {
                     html! {
@@ -161,7 +167,7 @@ pub fn plate_calculator_page() -> Html {
 
                 // Summary
                 { if *target_weight > *bar_weight {
-                    let remainder = (*target_weight - *bar_weight) - plates.iter().map(|(w, c)| w * *c as f64 * 2.0).sum::<f64>();
+                    let remainder = *target_weight - loaded;
                     html! {
                         <div class="border-t border-gray-200 dark:border-gray-700 pt-3 mt-3 text-xs text-gray-500 dark:text-gray-400 space-y-1">
                             <div class="flex justify-between">
@@ -170,16 +176,20 @@ pub fn plate_calculator_page() -> Html {
                             </div>
                             <div class="flex justify-between">
                                 <span>{"Plates (both sides)"}</span>
-                                <span class="font-mono">{format!("{:.1}{}", unit_system.display_weight(plates.iter().map(|(w, c)| w * *c as f64 * 2.0).sum::<f64>()), wl)}</span>
+                                <span class="font-mono">{format!("{:.1}{}", unit_system.display_weight(loaded - *bar_weight), wl)}</span>
                             </div>
                             <div class="flex justify-between font-bold text-gray-700 dark:text-gray-300">
                                 <span>{"Total"}</span>
-                                <span class="font-mono">{format!("{:.1}{}", unit_system.display_weight(*bar_weight + plates.iter().map(|(w, c)| w * *c as f64 * 2.0).sum::<f64>()), wl)}</span>
+                                <span class="font-mono">{format!("{:.1}{}", unit_system.display_weight(loaded), wl)}</span>
                             </div>
                             { if remainder.abs() > 0.01 {
                                 html! {
                                     <div class="text-yellow-500 font-bold mt-1">
-                                        {format!("Note: {:.1}{} cannot be made with standard plates", unit_system.display_weight(remainder), wl)}
+                                        { if limited {
+                                            format!("Not enough plates for the exact target: closest is {:.1}{} ({:.1}{} short)", unit_system.display_weight(loaded), wl, unit_system.display_weight(remainder), wl)
+                                        } else {
+                                            format!("Note: {:.1}{} cannot be made with the available plates", unit_system.display_weight(remainder), wl)
+                                        }}
                                     </div>
                                 }
                             } else {
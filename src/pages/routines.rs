@@ -43,6 +43,28 @@ pub fn routines_page() -> Html {
         })
     };
 
+    let on_reorder_routines = {
+        let routines = routines.clone();
+        Callback::from(move |rs: Vec<Routine>| {
+            storage::save_routines(&rs);
+            routines.set(rs);
+        })
+    };
+
+    // Copy every routine of a prebuilt program into the user's routines
+    let on_add_program = {
+        let routines = routines.clone();
+        Callback::from(move |idx: usize| {
+            let Some(program) = data::PROGRAM_TEMPLATES.get(idx) else {
+                return;
+            };
+            let mut rs = (*routines).clone();
+            rs.extend(program.routines.iter().map(|r| r.to_routine(program.name)));
+            storage::save_routines(&rs);
+            routines.set(rs);
+        })
+    };
+
     let on_start_from_routine = {
         let nav = navigator.clone();
         Callback::from(move |routine: Routine| {
@@ -57,12 +79,41 @@ pub fn routines_page() -> Html {
                 <h1 class="text-2xl font-bold mb-1 text-gray-900 dark:text-gray-100">{"Routines"}</h1>
                 <p class="text-gray-500 dark:text-gray-400 text-sm mb-3">{"Plan your workout routines and start sessions from them."}</p>
             </div>
+            <div class="px-4 pb-3">
+                <details open={routines.is_empty()} class="group bg-gray-100 dark:bg-gray-800 rounded-xl neu-flat transition-colors">
+                    <summary class="px-4 py-3 cursor-pointer font-semibold text-gray-900 dark:text-gray-100 list-none flex justify-between items-center">
+                        {"Program Templates"}
+                        <span class="text-gray-400 group-open:rotate-180 transition-transform">{"\u{25be}"}</span>
+                    </summary>
+                    <div class="px-4 pb-4 space-y-3">
+                        <p class="text-xs text-gray-500 dark:text-gray-400">{"Add a proven program as editable routines, then set your own weights."}</p>
+                        { for data::PROGRAM_TEMPLATES.iter().enumerate().map(|(idx, program)| {
+                            let on_add = on_add_program.clone();
+                            let names: Vec<&str> = program.routines.iter().map(|r| r.name).collect();
+                            html! {
+                                <div class="flex items-start justify-between gap-3">
+                                    <div class="min-w-0">
+                                        <div class="text-sm font-bold text-gray-800 dark:text-gray-200">{program.name}</div>
+                                        <div class="text-xs text-gray-500 dark:text-gray-400">{program.description}</div>
+                                        <div class="text-[10px] text-gray-400 mt-0.5">{names.join(" \u{b7} ")}</div>
+                                    </div>
+                                    <button
+                                        class="flex-shrink-0 px-3 py-1.5 bg-blue-600 text-white rounded-lg text-xs font-bold hover:bg-blue-700 neu-btn transition-colors"
+                                        onclick={Callback::from(move |_| on_add.emit(idx))}
+                                    >{"Add"}</button>
+                                </div>
+                            }
+                        })}
+                    </div>
+                </details>
+            </div>
             <RoutineEditor
                 routines={(*routines).clone()}
                 all_exercises={all_exercises}
                 on_save={on_save_routine}
                 on_delete={on_delete_routine}
                 on_start_workout={on_start_from_routine}
+                on_reorder={on_reorder_routines}
             />
         </div>
     }
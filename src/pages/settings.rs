@@ -1,7 +1,8 @@
 use crate::components::custom_exercise::CustomExerciseForm;
 use crate::components::settings::SettingsPanel;
 use crate::components::sync::SyncPanel;
-use crate::models::{BodyMetric, Exercise, UnitSystem};
+use crate::i18n::t;
+use crate::models::{plate_key, BodyMetric, Equipment, Exercise, GymProfile, UnitSystem, UserConfig};
 use crate::storage;
 use crate::Route;
 use wasm_bindgen::prelude::*;
@@ -114,6 +115,7 @@ fn profile_section() -> Html {
     let gender = use_state(|| config.gender.clone().unwrap_or_default());
     let rest_seconds = use_state(|| config.rest_seconds.to_string());
     let unit_system = use_state(|| config.unit_system.clone());
+    let body_unit = use_state(|| config.body_unit.clone());
     let bar_weight = use_state(|| {
         let bw = config.unit_system.display_weight(config.bar_weight);
         format!("{:.1}", bw)
@@ -130,6 +132,7 @@ fn profile_section() -> Html {
             || *gender != c.gender.clone().unwrap_or_default()
             || *rest_seconds != c.rest_seconds.to_string()
             || *unit_system != c.unit_system
+            || *body_unit != c.body_unit
             || *bar_weight != format!("{:.1}", c.unit_system.display_weight(c.bar_weight))
     };
 
@@ -142,10 +145,12 @@ fn profile_section() -> Html {
         let rest_seconds = rest_seconds.clone();
         let bar_weight = bar_weight.clone();
         let unit_system = unit_system.clone();
+        let body_unit = body_unit.clone();
         Callback::from(move |_| {
             let mut new_config = (*config_state).clone();
             new_config.nickname = (*nickname).clone();
             new_config.unit_system = (*unit_system).clone();
+            new_config.body_unit = (*body_unit).clone();
             new_config.height = height.parse::<f64>().ok().map(|h| unit_system.to_cm(h));
             new_config.birth_date = Some((*birth_date).clone()).filter(|s| !s.is_empty());
             new_config.gender = Some((*gender).clone()).filter(|s| !s.is_empty());
@@ -158,10 +163,10 @@ fn profile_section() -> Html {
 
     html! {
         <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 space-y-4 neu-flat transition-colors">
-            <h2 class="text-lg font-bold text-gray-900 dark:text-gray-100">{"Personal Profile"}</h2>
+            <h2 class="text-lg font-bold text-gray-900 dark:text-gray-100">{t("settings.profile")}</h2>
             <div class="grid grid-cols-2 gap-4">
                 <div class="col-span-2">
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Nickname"}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{t("settings.nickname")}</label>
                     <input
                         type="text"
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
@@ -170,7 +175,7 @@ fn profile_section() -> Html {
                     />
                 </div>
                 <div class="col-span-2">
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Units"}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{t("settings.units")}</label>
                     <select
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
                         onchange={let u = unit_system.clone(); Callback::from(move |e: Event| {
@@ -178,12 +183,30 @@ fn profile_section() -> Html {
                             u.set(if val == "Imperial" { UnitSystem::Imperial } else { UnitSystem::Metric });
                         })}
                     >
-                        <option value="Metric" selected={*unit_system == UnitSystem::Metric}>{"Metric (kg, km, cm)"}</option>
-                        <option value="Imperial" selected={*unit_system == UnitSystem::Imperial}>{"Imperial (lbs, mi, in)"}</option>
+                        <option value="Metric" selected={*unit_system == UnitSystem::Metric}>{t("settings.units_metric")}</option>
+                        <option value="Imperial" selected={*unit_system == UnitSystem::Imperial}>{t("settings.units_imperial")}</option>
+                    </select>
+                </div>
+                <div class="col-span-2">
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Body weight units"}</label>
+                    <select
+                        class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
+                        onchange={let b = body_unit.clone(); Callback::from(move |e: Event| {
+                            let val = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+                            b.set(match val.as_str() {
+                                "Metric" => Some(UnitSystem::Metric),
+                                "Imperial" => Some(UnitSystem::Imperial),
+                                _ => None,
+                            });
+                        })}
+                    >
+                        <option value="" selected={body_unit.is_none()}>{"Same as lifting"}</option>
+                        <option value="Metric" selected={*body_unit == Some(UnitSystem::Metric)}>{"kg"}</option>
+                        <option value="Imperial" selected={*body_unit == Some(UnitSystem::Imperial)}>{"lbs"}</option>
                     </select>
                 </div>
                 <div>
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("Height ({})", unit_system.height_label())}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("{} ({})", t("settings.height"), unit_system.height_label())}</label>
                     <input
                         type="number" autocomplete="off"
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
@@ -192,19 +215,19 @@ fn profile_section() -> Html {
                     />
                 </div>
                 <div>
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Gender"}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{t("settings.gender")}</label>
                     <select
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
                         onchange={let g = gender.clone(); Callback::from(move |e: Event| g.set(e.target_unchecked_into::<web_sys::HtmlSelectElement>().value()))}
                     >
-                        <option value="" selected={gender.is_empty()}>{"Select..."}</option>
-                        <option value="Male" selected={*gender == "Male"}>{"Male"}</option>
-                        <option value="Female" selected={*gender == "Female"}>{"Female"}</option>
-                        <option value="Other" selected={*gender == "Other"}>{"Other"}</option>
+                        <option value="" selected={gender.is_empty()}>{t("common.select")}</option>
+                        <option value="Male" selected={*gender == "Male"}>{t("settings.gender_male")}</option>
+                        <option value="Female" selected={*gender == "Female"}>{t("settings.gender_female")}</option>
+                        <option value="Other" selected={*gender == "Other"}>{t("settings.gender_other")}</option>
                     </select>
                 </div>
                 <div class="col-span-2">
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Birth Date"}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{t("settings.birth_date")}</label>
                     <input
                         type="date"
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
@@ -213,24 +236,24 @@ fn profile_section() -> Html {
                     />
                 </div>
                 <div>
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Rest Timer (sec)"}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{t("settings.rest_timer")}</label>
                     <input
                         type="number" autocomplete="off"
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
                         value={(*rest_seconds).clone()}
                         onchange={let r = rest_seconds.clone(); Callback::from(move |e: Event| r.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
                     />
-                    <p class="text-[10px] text-gray-400 mt-0.5">{"Countdown after completing a set"}</p>
+                    <p class="text-[10px] text-gray-400 mt-0.5">{t("settings.rest_timer_hint")}</p>
                 </div>
                 <div>
-                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("Bar Weight ({})", unit_system.weight_label())}</label>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("{} ({})", t("settings.bar_weight"), unit_system.weight_label())}</label>
                     <input
                         type="number" step="0.5" autocomplete="off"
                         class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
                         value={(*bar_weight).clone()}
                         onchange={let bw = bar_weight.clone(); Callback::from(move |e: Event| bw.set(e.target_unchecked_into::<web_sys::HtmlInputElement>().value()))}
                     />
-                    <p class="text-[10px] text-gray-400 mt-0.5">{"Empty barbell weight, used for warm-ups and plate calculator"}</p>
+                    <p class="text-[10px] text-gray-400 mt-0.5">{t("settings.bar_weight_hint")}</p>
                 </div>
             </div>
             <button
@@ -241,7 +264,231 @@ fn profile_section() -> Html {
                 } else {
                     "w-full py-2.5 bg-gray-300 dark:bg-gray-700 text-gray-500 dark:text-gray-400 rounded-lg font-bold text-sm cursor-not-allowed transition-colors"
                 }}
-            >{"Update Profile"}</button>
+            >{t("settings.update_profile")}</button>
+        </div>
+    }
+}
+
+/// Load the stored config fresh, apply `f` to it, save it and refresh the state.
+fn edit_config(state: &UseStateHandle<UserConfig>, f: impl FnOnce(&mut UserConfig)) {
+    let mut new_config = storage::load_user_config();
+    f(&mut new_config);
+    storage::save_user_config(&new_config);
+    state.set(new_config);
+}
+
+fn edit_gym(state: &UseStateHandle<UserConfig>, id: &str, f: impl FnOnce(&mut GymProfile)) {
+    edit_config(state, |c| {
+        if let Some(gym) = c.gym_profiles.iter_mut().find(|g| g.id == id) {
+            f(gym);
+        }
+    });
+}
+
+#[function_component(GymProfilesSection)]
+fn gym_profiles_section() -> Html {
+    let config = use_state(storage::load_user_config);
+    let unit = config.unit_system.clone();
+
+    let on_select_active = {
+        let config = config.clone();
+        Callback::from(move |e: Event| {
+            let val = e.target_unchecked_into::<web_sys::HtmlSelectElement>().value();
+            edit_config(&config, |c| c.active_gym = Some(val).filter(|v| !v.is_empty()));
+        })
+    };
+
+    let on_add = {
+        let config = config.clone();
+        Callback::from(move |_: MouseEvent| {
+            edit_config(&config, |c| {
+                let gym = GymProfile {
+                    id: uuid::Uuid::new_v4().to_string(),
+                    name: format!("Gym {}", c.gym_profiles.len() + 1),
+                    bar_weight: c.bar_weight,
+                    plates: Vec::new(),
+                    equipment: Vec::new(),
+                    plate_pairs: std::collections::HashMap::new(),
+                };
+                if c.gym_profiles.is_empty() {
+                    c.active_gym = Some(gym.id.clone());
+                }
+                c.gym_profiles.push(gym);
+            });
+        })
+    };
+
+    let input_class = "w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed";
+
+    html! {
+        <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 space-y-4 neu-flat transition-colors">
+            <div>
+                <h2 class="text-lg font-bold text-gray-900 dark:text-gray-100">{"Gyms"}</h2>
+                <p class="text-xs text-gray-500 dark:text-gray-400">{"Bar weight, plates and equipment per gym. The active gym drives the plate calculator, warm-ups and exercise picker."}</p>
+            </div>
+            if !config.gym_profiles.is_empty() {
+                <div>
+                    <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Active gym"}</label>
+                    <select class={input_class} onchange={on_select_active}>
+                        <option value="" selected={config.active_gym().is_none()}>{"None (use defaults)"}</option>
+                        { for config.gym_profiles.iter().map(|g| html! {
+                            <option value={g.id.clone()} selected={config.active_gym.as_deref() == Some(g.id.as_str())}>{g.name.clone()}</option>
+                        })}
+                    </select>
+                </div>
+            }
+            { for config.gym_profiles.iter().map(|gym| {
+                let id = gym.id.clone();
+                let on_name = {
+                    let config = config.clone();
+                    let id = id.clone();
+                    Callback::from(move |e: Event| {
+                        let val = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                        let name = val.trim().to_string();
+                        if !name.is_empty() {
+                            edit_gym(&config, &id, |g| g.name = name);
+                        }
+                    })
+                };
+                let on_bar = {
+                    let config = config.clone();
+                    let id = id.clone();
+                    let unit = unit.clone();
+                    Callback::from(move |e: Event| {
+                        let val = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                        if let Ok(bar) = val.parse::<f64>() {
+                            let kg = unit.to_kg(bar.max(0.0));
+                            edit_gym(&config, &id, |g| g.bar_weight = kg);
+                        }
+                    })
+                };
+                let on_plates = {
+                    let config = config.clone();
+                    let id = id.clone();
+                    let unit = unit.clone();
+                    Callback::from(move |e: Event| {
+                        let val = e.target_unchecked_into::<web_sys::HtmlInputElement>().value();
+                        // "20x2" means two pairs of 20s owned; a bare size is unlimited
+                        let mut stock: Vec<(f64, Option<u32>)> = val
+                            .split(',')
+                            .filter_map(|p| {
+                                let mut parts = p.split(['x', 'X', '\u{d7}']);
+                                let size = parts.next()?.trim().parse::<f64>().ok()?;
+                                let pairs = parts.next().and_then(|n| n.trim().parse::<u32>().ok());
+                                Some((size, pairs))
+                            })
+                            .filter(|(p, _)| *p > 0.0)
+                            .map(|(p, pairs)| (unit.to_kg(p), pairs))
+                            .collect();
+                        stock.sort_by(|a, b| b.0.total_cmp(&a.0));
+                        stock.dedup_by(|a, b| (a.0 - b.0).abs() < 0.001);
+                        edit_gym(&config, &id, |g| {
+                            g.plates = stock.iter().map(|(p, _)| *p).collect();
+                            g.plate_pairs = stock
+                                .iter()
+                                .filter_map(|(p, pairs)| pairs.map(|n| (plate_key(*p), n)))
+                                .collect();
+                        });
+                    })
+                };
+                let on_delete = {
+                    let config = config.clone();
+                    let id = id.clone();
+                    Callback::from(move |_: MouseEvent| {
+                        edit_config(&config, |c| {
+                            c.gym_profiles.retain(|g| g.id != id);
+                            if c.active_gym.as_deref() == Some(id.as_str()) {
+                                c.active_gym = None;
+                            }
+                        });
+                    })
+                };
+                let plates_text = gym
+                    .plates
+                    .iter()
+                    .map(|p| {
+                        let v = unit.display_weight(*p);
+                        match gym.pairs_owned(*p) {
+                            Some(pairs) => format!("{}x{}", (v * 100.0).round() / 100.0, pairs),
+                            None => format!("{}", (v * 100.0).round() / 100.0),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let is_active = config.active_gym.as_deref() == Some(id.as_str());
+                html! {
+                    <div key={id.clone()} class={format!(
+                        "rounded-xl p-3 space-y-3 bg-white dark:bg-gray-900/40 border {}",
+                        if is_active { "border-blue-500" } else { "border-gray-200 dark:border-gray-700" }
+                    )}>
+                        <div class="flex items-center gap-2">
+                            <input type="text" class={input_class} value={gym.name.clone()} onchange={on_name} />
+                            <button
+                                class="px-3 py-2 text-xs font-bold text-red-600 dark:text-red-400 hover:bg-red-50 dark:hover:bg-red-900/20 rounded-lg transition-colors"
+                                onclick={on_delete}
+                            >{"Delete"}</button>
+                        </div>
+                        <div class="grid grid-cols-3 gap-2">
+                            <div>
+                                <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("Bar ({})", unit.weight_label())}</label>
+                                <input
+                                    type="number" step="0.5" autocomplete="off"
+                                    class={input_class}
+                                    value={format!("{:.1}", unit.display_weight(gym.bar_weight))}
+                                    onchange={on_bar}
+                                />
+                            </div>
+                            <div class="col-span-2">
+                                <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("Plates ({}, x pairs owned)", unit.weight_label())}</label>
+                                <input
+                                    type="text" autocomplete="off"
+                                    class={input_class}
+                                    placeholder="Standard set, e.g. 20x1, 10x2, 5"
+                                    value={plates_text}
+                                    onchange={on_plates}
+                                />
+                            </div>
+                        </div>
+                        <div>
+                            <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Equipment (none selected = everything)"}</label>
+                            <div class="flex flex-wrap gap-1.5">
+                                { for Equipment::all().into_iter().map(|eq| {
+                                    let selected = gym.equipment.contains(&eq);
+                                    let onclick = {
+                                        let config = config.clone();
+                                        let id = id.clone();
+                                        let eq = eq.clone();
+                                        Callback::from(move |_: MouseEvent| {
+                                            let eq = eq.clone();
+                                            edit_gym(&config, &id, |g| {
+                                                if let Some(pos) = g.equipment.iter().position(|e| *e == eq) {
+                                                    g.equipment.remove(pos);
+                                                } else {
+                                                    g.equipment.push(eq);
+                                                }
+                                            });
+                                        })
+                                    };
+                                    html! {
+                                        <button
+                                            class={if selected {
+                                                "px-3 py-1 rounded-full text-xs bg-blue-600 text-white font-medium neu-chip-active transition-colors"
+                                            } else {
+                                                "px-3 py-1 rounded-full text-xs bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-300 neu-chip transition-colors"
+                                            }}
+                                            {onclick}
+                                        >{eq.to_string()}</button>
+                                    }
+                                })}
+                            </div>
+                        </div>
+                    </div>
+                }
+            })}
+            <button
+                onclick={on_add}
+                class="w-full py-2.5 bg-blue-600 text-white rounded-lg font-bold text-sm hover:bg-blue-700 neu-btn transition-colors"
+            >{"+ Add gym"}</button>
         </div>
     }
 }
@@ -252,7 +499,9 @@ fn body_metrics_section() -> Html {
     let weight = use_state(String::new);
     let body_fat = use_state(String::new);
     let show_form = use_state(|| false);
-    let units = storage::load_user_config().unit_system;
+    let config = storage::load_user_config();
+    let units = config.body_units().clone();
+    let rounding = config.weight_rounding;
 
     let on_add = {
         let metrics_state = metrics.clone();
@@ -267,7 +516,7 @@ fn body_metrics_section() -> Html {
             let mut new_metrics = (*metrics_state).clone();
             new_metrics.push(BodyMetric {
                 id: uuid::Uuid::new_v4().to_string(),
-                date: chrono::Local::now().format("%Y-%m-%d").to_string(),
+                date: crate::dates::today_str(),
                 weight: weight.parse::<f64>().ok().map(|w| units.to_kg(w)),
                 body_fat: body_fat.parse().ok(),
             });
@@ -295,11 +544,11 @@ fn body_metrics_section() -> Html {
     html! {
         <div class="space-y-4">
             <div class="flex justify-between items-center px-1 transition-colors">
-                <h2 class="text-lg font-bold text-gray-900 dark:text-gray-100">{"Body Progress"}</h2>
+                <h2 class="text-lg font-bold text-gray-900 dark:text-gray-100">{t("settings.body_progress")}</h2>
                 <button
                     onclick={let s = show_form.clone(); Callback::from(move |_| s.set(!*s))}
                     class="text-xs font-bold text-blue-600 dark:text-blue-400 hover:underline"
-                >{if *show_form { "Cancel" } else { "+ Log Weight" }}</button>
+                >{if *show_form { t("common.cancel") } else { t("settings.log_weight") }}</button>
             </div>
 
             { if *show_form {
@@ -307,7 +556,7 @@ fn body_metrics_section() -> Html {
                     <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 border border-blue-500/30 space-y-3 neu-flat transition-colors">
                         <div class="grid grid-cols-2 gap-3">
                             <div>
-                                <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("Weight ({})", units.weight_label())}</label>
+                                <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{format!("{} ({})", t("settings.weight"), units.weight_label())}</label>
                                 <input
                                     type="number" step="0.1"
                                     class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
@@ -316,7 +565,7 @@ fn body_metrics_section() -> Html {
                                 />
                             </div>
                             <div>
-                                <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{"Body Fat %"}</label>
+                                <label class="block text-[10px] uppercase font-bold text-gray-500 mb-1">{t("settings.body_fat")}</label>
                                 <input
                                     type="number" step="0.1"
                                     class="w-full bg-white dark:bg-gray-700 rounded-lg px-3 py-2 text-sm text-gray-900 dark:text-white outline-none neu-pressed"
@@ -328,7 +577,7 @@ fn body_metrics_section() -> Html {
                         <button
                             onclick={on_add}
                             class="w-full py-2 bg-blue-600 text-white rounded-lg font-bold text-sm neu-btn hover:bg-blue-700 transition-colors"
-                        >{"Save Measurement"}</button>
+                        >{t("settings.save_measurement")}</button>
                     </div>
                 }
             } else { html! {} }}
@@ -341,7 +590,7 @@ fn body_metrics_section() -> Html {
                         <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-3 flex justify-between items-center neu-flat transition-colors">
                             <div>
                                 <div class="text-sm font-bold text-gray-900 dark:text-gray-100">
-                                    {m.weight.map(|w| format!("{:.1} {}", units.display_weight(w), units.weight_label())).unwrap_or_else(|| "--".to_string())}
+                                    {m.weight.map(|w| format!("{} {}", units.format_weight(w, rounding), units.weight_label())).unwrap_or_else(|| "--".to_string())}
                                     {m.body_fat.map(|bf| format!(" • {}% fat", bf)).unwrap_or_default()}
                                 </div>
                                 <div class="text-[10px] text-gray-500 dark:text-gray-500 font-mono uppercase tracking-wider">{&m.date}</div>
@@ -354,13 +603,110 @@ fn body_metrics_section() -> Html {
                     }
                 })}
                 { if sorted_metrics.is_empty() && !*show_form {
-                    html! { <p class="text-center py-8 text-gray-500 text-xs italic bg-gray-50 dark:bg-gray-800/20 rounded-xl border border-dashed border-gray-200 dark:border-gray-700 transition-colors">{"No measurements yet."}</p> }
+                    html! { <p class="text-center py-8 text-gray-500 text-xs italic bg-gray-50 dark:bg-gray-800/20 rounded-xl border border-dashed border-gray-200 dark:border-gray-700 transition-colors">{t("settings.no_measurements")}</p> }
                 } else { html! {} }}
             </div>
         </div>
     }
 }
 
+/// Warning shown when deleting a custom exercise that workouts or routines use:
+/// lists where it's used and reassigns those references before deleting.
+fn reassign_panel(
+    ex: &Exercise,
+    custom_exercises: &[Exercise],
+    pending_delete: &UseStateHandle<Option<String>>,
+    replacement: &UseStateHandle<String>,
+    on_delete: &Callback<String>,
+) -> Html {
+    let (workouts, routines) = storage::exercise_usage(&ex.id);
+    // Same category first, then by name
+    let mut candidates: Vec<Exercise> = crate::data::default_exercises()
+        .into_iter()
+        .chain(custom_exercises.iter().cloned())
+        .filter(|e| e.id != ex.id)
+        .collect();
+    candidates.sort_by(|a, b| {
+        (b.category == ex.category)
+            .cmp(&(a.category == ex.category))
+            .then(a.name.cmp(&b.name))
+    });
+    let chosen = if replacement.is_empty() {
+        candidates.first().map(|e| e.id.clone()).unwrap_or_default()
+    } else {
+        (**replacement).clone()
+    };
+    let mut dates: Vec<&str> = workouts.iter().map(|w| w.date.as_str()).collect();
+    dates.dedup();
+    let shown = dates.iter().take(5).copied().collect::<Vec<_>>().join(", ");
+    let more = dates.len().saturating_sub(5);
+
+    let on_select = {
+        let replacement = replacement.clone();
+        Callback::from(move |e: Event| {
+            replacement.set(e.target_unchecked_into::<web_sys::HtmlSelectElement>().value());
+        })
+    };
+    let on_reassign = {
+        let from = ex.id.clone();
+        let to = chosen.clone();
+        let pending_delete = pending_delete.clone();
+        let on_delete = on_delete.clone();
+        Callback::from(move |_: MouseEvent| {
+            if to.is_empty() {
+                return;
+            }
+            storage::reassign_exercise(&from, &to);
+            on_delete.emit(from.clone());
+            pending_delete.set(None);
+        })
+    };
+    let on_cancel = {
+        let pending_delete = pending_delete.clone();
+        Callback::from(move |_: MouseEvent| pending_delete.set(None))
+    };
+
+    html! {
+        <div class="mt-3 p-3 rounded-lg bg-yellow-500/10 border border-yellow-500/30 space-y-2 text-xs">
+            <p class="text-yellow-700 dark:text-yellow-400 font-bold">
+                {format!("Used in {} workout{} and {} routine{}", workouts.len(), if workouts.len() == 1 { "" } else { "s" }, routines.len(), if routines.len() == 1 { "" } else { "s" })}
+            </p>
+            if !dates.is_empty() {
+                <p class="text-gray-600 dark:text-gray-400">
+                    {shown}
+                    if more > 0 {
+                        {format!(" and {} more", more)}
+                    }
+                </p>
+            }
+            if !routines.is_empty() {
+                <p class="text-gray-600 dark:text-gray-400">
+                    {format!("Routines: {}", routines.iter().map(|r| r.name.as_str()).collect::<Vec<_>>().join(", "))}
+                </p>
+            }
+            <p class="text-gray-600 dark:text-gray-400">{"Move its history, routines and settings to another exercise before deleting it:"}</p>
+            <select
+                class="w-full bg-white dark:bg-gray-700 text-gray-900 dark:text-white text-sm rounded-lg px-2 py-1.5 outline-none neu-pressed"
+                onchange={on_select}
+            >
+                { for candidates.iter().map(|c| html! {
+                    <option value={c.id.clone()} selected={c.id == chosen}>{format!("{} ({})", c.name, c.category)}</option>
+                })}
+            </select>
+            <div class="flex gap-2">
+                <button
+                    class="flex-1 py-1.5 bg-red-600 text-white rounded-lg font-bold hover:bg-red-700 neu-btn transition-colors"
+                    onclick={on_reassign}
+                >{"Reassign & Delete"}</button>
+                <button
+                    class="flex-1 py-1.5 bg-gray-200 dark:bg-gray-700 text-gray-700 dark:text-gray-300 rounded-lg font-bold hover:bg-gray-300 dark:hover:bg-gray-600 transition-colors"
+                    onclick={on_cancel}
+                >{t("common.cancel")}</button>
+            </div>
+        </div>
+    }
+}
+
 #[function_component(SettingsPage)]
 pub fn settings_page() -> Html {
     let custom_exercises = use_state(storage::load_custom_exercises);
@@ -398,6 +744,25 @@ pub fn settings_page() -> Html {
         })
     };
 
+    // Deleting an exercise that history or routines still use asks for a
+    // replacement first, so those references keep resolving to a name
+    let pending_delete = use_state(|| None::<String>);
+    let replacement = use_state(String::new);
+    let on_request_delete = {
+        let pending_delete = pending_delete.clone();
+        let replacement = replacement.clone();
+        let on_delete = on_delete_custom.clone();
+        Callback::from(move |id: String| {
+            let (workouts, routines) = storage::exercise_usage(&id);
+            if workouts.is_empty() && routines.is_empty() {
+                on_delete.emit(id);
+            } else {
+                replacement.set(String::new());
+                pending_delete.set(Some(id));
+            }
+        })
+    };
+
     let app_version = use_state(|| String::from("..."));
     let build_date = use_state(String::new);
     {
@@ -425,12 +790,14 @@ pub fn settings_page() -> Html {
 
     html! {
         <div class="px-4 py-4 pb-20 space-y-8 transition-colors duration-200">
-            <h1 class="text-2xl font-bold text-gray-900 dark:text-gray-100">{"Settings"}</h1>
+            <h1 class="text-2xl font-bold text-gray-900 dark:text-gray-100">{t("settings.title")}</h1>
 
             <InstallButton />
 
             <ProfileSection />
 
+            <GymProfilesSection />
+
             <BodyMetricsSection />
 
             <SyncPanel />
@@ -438,14 +805,14 @@ pub fn settings_page() -> Html {
             <SettingsPanel on_import_complete={on_import_complete} />
 
             <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-4 neu-flat transition-colors">
-                <h3 class="font-semibold mb-3 text-gray-900 dark:text-gray-100">{"App Info"}</h3>
+                <h3 class="font-semibold mb-3 text-gray-900 dark:text-gray-100">{t("settings.app_info")}</h3>
                 <Link<Route> to={Route::Faq} classes="flex items-center justify-between py-2 text-blue-600 dark:text-blue-400 hover:text-blue-500 dark:hover:text-blue-300">
-                    <span>{"Frequently Asked Questions"}</span>
+                    <span>{t("settings.faq")}</span>
                     <span>{"→"}</span>
                 </Link<Route>>
                 <div class="pt-2 border-t border-gray-200 dark:border-gray-700 mt-2 space-y-1">
                     <div class="flex items-center gap-1">
-                        <span class="text-xs text-gray-500 dark:text-gray-400">{"Version: "}</span>
+                        <span class="text-xs text-gray-500 dark:text-gray-400">{t("settings.version")}</span>
                         <a href={format!("https://github.com/tonybenoy/treening/commit/{}", *app_version)}
                            target="_blank" rel="noopener noreferrer"
                            class="text-xs text-blue-600 dark:text-blue-400 hover:underline font-mono">
@@ -458,18 +825,18 @@ pub fn settings_page() -> Html {
                     <a href="https://github.com/tonybenoy/treening/releases"
                        target="_blank" rel="noopener noreferrer"
                        class="text-xs text-blue-600 dark:text-blue-400 hover:underline block">
-                        {"View Release Notes →"}
+                        {t("settings.release_notes")}
                     </a>
                 </div>
             </div>
 
             <div>
                 <div class="flex justify-between items-center mb-3 px-1">
-                    <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{"Custom Exercises"}</h2>
+                    <h2 class="text-lg font-semibold text-gray-900 dark:text-gray-100">{t("settings.custom_exercises")}</h2>
                     <button
                         class="px-3 py-1.5 bg-blue-600 text-white rounded-lg text-sm hover:bg-blue-700 neu-btn transition-colors"
                         onclick={let s = show_custom_form.clone(); Callback::from(move |_| s.set(true))}
-                    >{"+ Add"}</button>
+                    >{t("common.add")}</button>
                 </div>
 
                 { if *show_custom_form {
@@ -483,25 +850,33 @@ pub fn settings_page() -> Html {
 
                 <div class="space-y-2">
                     { for custom_exercises.iter().map(|ex| {
-                        let on_delete = on_delete_custom.clone();
+                        let on_delete = on_request_delete.clone();
                         let eid = ex.id.clone();
+                        let confirming = pending_delete.as_deref() == Some(ex.id.as_str());
                         html! {
-                            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-3 flex justify-between items-center neu-flat transition-colors">
-                                <div>
-                                    <div class="font-medium text-gray-800 dark:text-gray-200">{&ex.name}</div>
-                                    <div class="text-sm text-gray-500 dark:text-gray-400">
-                                        {ex.category.to_string()}{" · "}{ex.equipment.to_string()}
+                            <div class="bg-gray-100 dark:bg-gray-800 rounded-xl p-3 neu-flat transition-colors">
+                                <div class="flex justify-between items-center">
+                                    <div>
+                                        <div class="font-medium text-gray-800 dark:text-gray-200">{&ex.name}</div>
+                                        <div class="text-sm text-gray-500 dark:text-gray-400">
+                                            {ex.category.to_string()}{" · "}{ex.equipment.to_string()}
+                                        </div>
                                     </div>
+                                    if !confirming {
+                                        <button
+                                            class="text-red-600 dark:text-red-400 text-sm hover:text-red-500 dark:hover:text-red-300"
+                                            onclick={Callback::from(move |_| on_delete.emit(eid.clone()))}
+                                        >{t("common.delete")}</button>
+                                    }
                                 </div>
-                                <button
-                                    class="text-red-600 dark:text-red-400 text-sm hover:text-red-500 dark:hover:text-red-300"
-                                    onclick={Callback::from(move |_| on_delete.emit(eid.clone()))}
-                                >{"Delete"}</button>
+                                if confirming {
+                                    {reassign_panel(ex, &custom_exercises, &pending_delete, &replacement, &on_delete_custom)}
+                                }
                             </div>
                         }
                     })}
                     { if custom_exercises.is_empty() && !*show_custom_form {
-                        html! { <p class="text-gray-500 text-sm">{"No custom exercises yet."}</p> }
+                        html! { <p class="text-gray-500 text-sm">{t("settings.no_custom_exercises")}</p> }
                     } else { html! {} }}
                 </div>
             </div>
@@ -510,13 +885,13 @@ pub fn settings_page() -> Html {
                 <a href="https://buymeacoffee.com/tonybenoy" target="_blank" rel="noopener noreferrer"
                    class="inline-flex items-center gap-2 px-4 py-2 bg-yellow-400 hover:bg-yellow-500 text-gray-900 font-semibold rounded-lg neu-btn transition-colors text-sm">
                     <span>{"☕"}</span>
-                    <span>{"Buy Me a Coffee"}</span>
+                    <span>{t("settings.buy_coffee")}</span>
                 </a>
             </div>
 
             <details class="group bg-gray-100 dark:bg-gray-800 rounded-2xl neu-flat transition-colors">
                 <summary class="px-4 py-4 cursor-pointer font-semibold text-gray-900 dark:text-gray-100 list-none flex justify-between items-center">
-                    {"About Treening"}
+                    {t("settings.about")}
                     <span class="text-gray-400 group-open:rotate-180 transition-transform">{"\u{25be}"}</span>
                 </summary>
                 <div class="px-4 pb-4 space-y-3 border-t border-gray-200 dark:border-gray-700 pt-3 mt-1">
@@ -14,13 +14,13 @@ pub fn shared_page() -> Html {
             let window = gloo::utils::window();
             let hash = window.location().hash().unwrap_or_default();
             // Hash format: #/shared?d=ENCODED
-            let result = if let Some(idx) = hash.find("d=") {
-                let encoded = &hash[idx + 2..];
-                // strip any trailing & params
-                let encoded = encoded.split('&').next().unwrap_or(encoded);
-                sharing::decode(encoded)
-            } else {
-                Err("No shared data found in URL".to_string())
+            let encoded = hash
+                .split_once('?')
+                .and_then(|(_, query)| query.split('&').find_map(|kv| kv.strip_prefix("d=")))
+                .filter(|d| !d.is_empty());
+            let result = match encoded {
+                Some(encoded) => sharing::decode(encoded),
+                None => Err("No shared data found in URL".to_string()),
             };
             parsed.set(Some(result));
             || ()
@@ -59,6 +59,10 @@ fn render_workout(
     exercises: &[crate::models::Exercise],
     imported: &UseStateHandle<bool>,
 ) -> Html {
+    let config = storage::load_user_config();
+    let units = config.unit_system;
+    let rounding = config.weight_rounding;
+    let set_count: usize = workout.exercises.iter().map(|we| we.sets.len()).sum();
     let find_name = |id: &str| -> String {
         exercises
             .iter()
@@ -101,6 +105,7 @@ fn render_workout(
                     { if workout.duration_mins > 0 {
                         html! { <span>{" · "}{workout.duration_mins}{" min"}</span> }
                     } else { html! {} }}
+                    <span>{format!(" · {} exercises · {} sets", workout.exercises.len(), set_count)}</span>
                 </div>
 
                 { for workout.exercises.iter().map(|we| {
@@ -115,11 +120,11 @@ fn render_workout(
                                             <span class="font-medium">{"Set "}{i+1}{": "}</span>
                                             { if let Some(dist) = s.distance {
                                                 let dur = s.duration_secs.unwrap_or(0);
-                                                html! { <span class="font-bold text-gray-800 dark:text-gray-200">{format!("{:.1}km / {}:{:02}", dist, dur / 60, dur % 60)}</span> }
+                                                html! { <span class="font-bold text-gray-800 dark:text-gray-200">{format!("{:.1}{} / {}:{:02}", units.display_distance(dist), units.distance_label(), dur / 60, dur % 60)}</span> }
                                             } else if let Some(secs) = s.duration_secs {
                                                 html! { <span class="font-bold text-gray-800 dark:text-gray-200">{format!("{}:{:02}", secs / 60, secs % 60)}</span> }
                                             } else {
-                                                html! { <span class="font-bold text-gray-800 dark:text-gray-200">{s.weight}{"kg x "}{s.reps}</span> }
+                                                html! { <span class="font-bold text-gray-800 dark:text-gray-200">{format!("{}{} x {}", units.format_weight(s.weight, rounding), units.weight_label(), s.reps)}</span> }
                                             }}
                                             { if s.completed { html!{<span class="text-green-600 dark:text-green-400">{" ✓"}</span>} } else { html!{} } }
                                         </div>
@@ -131,7 +136,7 @@ fn render_workout(
                 })}
 
                 { if workout.total_volume() > 0.0 {
-                    html! { <div class="text-sm font-bold text-gray-700 dark:text-gray-300 border-t border-gray-200 dark:border-gray-700 pt-2 mt-2">{"Total volume: "}{format!("{:.0}", workout.total_volume())}{" kg"}</div> }
+                    html! { <div class="text-sm font-bold text-gray-700 dark:text-gray-300 border-t border-gray-200 dark:border-gray-700 pt-2 mt-2">{format!("Total volume: {:.0} {}", units.display_weight(workout.total_volume()), units.weight_label())}</div> }
                 } else { html! {} }}
             </div>
 
@@ -185,13 +190,18 @@ fn render_routine(
     html! {
         <div class="space-y-4">
             <div class="bg-gray-100 dark:bg-gray-800 rounded-2xl p-4 neu-flat">
-                <h2 class="text-xl font-bold text-gray-900 dark:text-gray-100 mb-3">{&routine.name}</h2>
+                <h2 class="text-xl font-bold text-gray-900 dark:text-gray-100 mb-1">{&routine.name}</h2>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-3">{format!("{} exercises", routine.exercise_ids.len())}</p>
                 <div class="space-y-2">
                     { for routine.exercise_ids.iter().map(|eid| {
                         if let Some(ex) = exercises.iter().find(|e| &e.id == eid) {
+                            let targets = routine.targets.get(eid).map(|t| t.len()).unwrap_or(0);
                             html! {
                                 <div class="flex items-center gap-2 text-sm text-gray-700 dark:text-gray-300">
                                     <span class="font-medium">{&ex.name}</span>
+                                    if targets > 0 {
+                                        <span class="text-xs text-gray-500">{format!("{} sets", targets)}</span>
+                                    }
                                     <span class="px-1.5 py-0.5 bg-blue-100 dark:bg-blue-900/40 text-blue-700 dark:text-blue-300 rounded text-[10px] font-bold uppercase">{ex.category.to_string()}</span>
                                     <span class="px-1.5 py-0.5 bg-gray-200 dark:bg-gray-700 text-gray-600 dark:text-gray-400 rounded text-[10px] font-bold uppercase">{ex.equipment.to_string()}</span>
                                 </div>
@@ -109,14 +109,13 @@ pub fn social_page() -> Html {
 
     let my_stats = {
         let workouts = storage::load_workouts();
-        let now = chrono::Local::now();
-        let week_ago = now - chrono::Duration::days(7);
+        let week_ago = crate::dates::today() - chrono::Duration::days(7);
 
         let this_week: Vec<_> = workouts
             .iter()
             .filter(|w| {
                 if let Ok(dt) = chrono::NaiveDate::parse_from_str(&w.date, "%Y-%m-%d") {
-                    dt >= week_ago.date_naive()
+                    dt >= week_ago
                 } else {
                     false
                 }
@@ -130,7 +129,7 @@ pub fn social_page() -> Html {
         FriendStats {
             workouts_this_week: this_week.len() as u32,
             total_volume_kg: total_volume,
-            last_active: now.format("%Y-%m-%d").to_string(),
+            last_active: crate::dates::today_str(),
             body_weight: latest_weight,
         }
     };
@@ -1,8 +1,12 @@
+use crate::components::custom_exercise::CustomExerciseForm;
 use crate::components::exercise_list::ExerciseList;
 use crate::components::toast::Toast;
-use crate::components::workout_log::WorkoutLog;
+use crate::components::workout_log::{standard_plates, WorkoutLog};
 use crate::data;
-use crate::models::{Exercise, ExerciseTrackingType, Workout, WorkoutExercise, WorkoutSet};
+use crate::models::{
+    Exercise, ExerciseTrackingType, PrefillMode, QuickAddSource, SetTarget, UserConfig, Workout, WorkoutExercise, WorkoutSet,
+    PREFILL_RECENT_SESSIONS,
+};
 use crate::storage;
 use crate::Route;
 use gloo::storage::{LocalStorage, Storage};
@@ -18,23 +22,87 @@ extern "C" {
 }
 
 const WIP_KEY: &str = "treening_wip_workout";
+/// Exercise ids queued from other pages, added when the workout page opens.
+const QUEUED_EXERCISES_KEY: &str = "treening_queued_exercises";
 
 #[derive(serde::Serialize, serde::Deserialize)]
 struct WipWorkout {
     name: String,
     exercises: Vec<WorkoutExercise>,
     started_at: f64, // js_sys::Date::now() in ms
+    #[serde(default)]
+    routine_id: Option<String>,
+    #[serde(default)]
+    notes: String,
 }
 
-fn try_vibrate() {
+/// Queue an exercise for the in-progress workout, or a new one if none is
+/// running. Picked up the next time the workout page opens.
+pub fn queue_exercise(exercise_id: &str) {
+    let mut ids: Vec<String> = LocalStorage::get(QUEUED_EXERCISES_KEY).unwrap_or_default();
+    if !ids.iter().any(|id| id == exercise_id) {
+        ids.push(exercise_id.to_string());
+    }
+    let _ = LocalStorage::set(QUEUED_EXERCISES_KEY, ids);
+}
+
+/// Buttons in the Workout page's quick-add row.
+const QUICK_ADD_COUNT: usize = 5;
+
+/// Exercises for the quick-add row per `UserConfig::quick_add`, most used first
+/// (counted in workouts), leaving out ones already in the session.
+fn quick_add_exercises(
+    config: &UserConfig,
+    previous: &[Workout],
+    all_exercises: &[Exercise],
+    current: &[WorkoutExercise],
+) -> Vec<Exercise> {
+    if config.quick_add == QuickAddSource::Off {
+        return Vec::new();
+    }
+    let mut uses: std::collections::HashMap<&str, usize> = std::collections::HashMap::new();
+    for w in previous {
+        let ids: std::collections::HashSet<&str> =
+            w.exercises.iter().map(|we| we.exercise_id.as_str()).collect();
+        for id in ids {
+            *uses.entry(id).or_default() += 1;
+        }
+    }
+    let mut picks: Vec<(&Exercise, usize)> = all_exercises
+        .iter()
+        .filter(|e| !current.iter().any(|we| we.exercise_id == e.id))
+        .filter_map(|e| {
+            let count = uses.get(e.id.as_str()).copied().unwrap_or(0);
+            let wanted = match config.quick_add {
+                QuickAddSource::Favorites => config.favorite_exercises.contains(&e.id),
+                _ => count > 0,
+            };
+            wanted.then_some((e, count))
+        })
+        .collect();
+    picks.sort_by(|(a, ca), (b, cb)| cb.cmp(ca).then(a.name.cmp(&b.name)));
+    picks.into_iter().take(QUICK_ADD_COUNT).map(|(e, _)| e.clone()).collect()
+}
+
+/// Whether the device exposes `navigator.vibrate` (most desktop and iOS browsers don't).
+pub fn vibration_supported() -> bool {
+    let nav_val: JsValue = web_sys::window().unwrap().navigator().into();
+    js_sys::Reflect::get(&nav_val, &"vibrate".into())
+        .map(|f| f.is_function())
+        .unwrap_or(false)
+}
+
+/// Vibrate for `ms` milliseconds where supported; a no-op elsewhere.
+pub fn try_vibrate(ms: u32) {
     let window = web_sys::window().unwrap();
     let navigator = window.navigator();
     let nav_val: JsValue = navigator.into();
     let _ = js_sys::Reflect::get(&nav_val, &"vibrate".into())
         .ok()
+        .filter(|f| f.is_function())
         .and_then(|f| {
             let func = js_sys::Function::from(f);
-            func.call1(&nav_val, &JsValue::from(200)).ok()
+            func.call1(&nav_val, &JsValue::from(ms)).ok()
         });
 }
 
@@ -72,38 +140,70 @@ fn try_notify(msg: &str) {
     }
 }
 
-/// Auto-fill a set from the most recent previous workout containing this exercise.
-fn autofill_set(previous: &[Workout], exercise_id: &str, all_exercises: &[Exercise]) -> WorkoutSet {
-    let tracking = all_exercises
-        .iter()
-        .find(|e| e.id == exercise_id)
+/// Auto-fill a set from previous workouts containing this exercise: the first
+/// set of the latest one, or the heaviest recent set under `PrefillMode::BestRecent`.
+fn autofill_set(
+    previous: &[Workout],
+    exercise_id: &str,
+    all_exercises: &[Exercise],
+    mode: PrefillMode,
+) -> WorkoutSet {
+    let exercise = all_exercises.iter().find(|e| e.id == exercise_id);
+    let tracking = exercise
         .map(|e| e.tracking_type.clone())
         .unwrap_or(ExerciseTrackingType::Strength);
+    let time_only = exercise.is_some_and(|e| e.time_only);
 
-    let prev_set = previous
+    let mut sessions = previous
         .iter()
         .rev()
         .flat_map(|w| w.exercises.iter())
-        .find(|we| we.exercise_id == exercise_id)
-        .and_then(|we| we.sets.first());
+        .filter(|we| we.exercise_id == exercise_id);
+    let prev_set = match mode {
+        PrefillMode::Last => sessions.next().and_then(|we| we.sets.first()),
+        PrefillMode::BestRecent => {
+            let recent: Vec<&WorkoutExercise> = sessions.take(PREFILL_RECENT_SESSIONS).collect();
+            recent
+                .iter()
+                .copied()
+                .flat_map(|we| we.sets.iter())
+                .filter(|s| s.completed && s.weight > 0.0 && s.reps > 0)
+                .max_by(|a, b| {
+                    a.weight
+                        .partial_cmp(&b.weight)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                        .then(a.reps.cmp(&b.reps))
+                })
+                // Nothing weighted to pick from; fall back to the latest session
+                .or_else(|| recent.first().copied().and_then(|we| we.sets.first()))
+        }
+    };
 
     match prev_set {
         Some(s) => WorkoutSet {
             weight: s.weight,
             reps: s.reps,
-            distance: s.distance,
+            distance: if time_only { None } else { s.distance },
             duration_secs: s.duration_secs,
             completed: false,
             note: None,
+            rest_taken_secs: None,
+            failure: None,
+            bodyweight: None,
+            completed_at: None,
         },
         None => match tracking {
             ExerciseTrackingType::Cardio => WorkoutSet {
                 weight: 0.0,
                 reps: 0,
                 completed: false,
-                distance: Some(0.0),
+                distance: if time_only { None } else { Some(0.0) },
                 duration_secs: Some(0),
                 note: None,
+                rest_taken_secs: None,
+                failure: None,
+                bodyweight: None,
+                completed_at: None,
             },
             ExerciseTrackingType::Duration => WorkoutSet {
                 weight: 0.0,
@@ -112,6 +212,10 @@ fn autofill_set(previous: &[Workout], exercise_id: &str, all_exercises: &[Exerci
                 distance: None,
                 duration_secs: Some(0),
                 note: None,
+                rest_taken_secs: None,
+                failure: None,
+                bodyweight: None,
+                completed_at: None,
             },
             _ => WorkoutSet {
                 weight: 0.0,
@@ -120,23 +224,28 @@ fn autofill_set(previous: &[Workout], exercise_id: &str, all_exercises: &[Exerci
                 distance: None,
                 duration_secs: None,
                 note: None,
+                rest_taken_secs: None,
+                failure: None,
+                bodyweight: None,
+                completed_at: None,
             },
         },
     }
 }
 
-/// Generate warm-up sets for a given working weight.
-/// Percentages: [40%, 60%, 75%, 90%], reps: [10, 6, 4, 2], rounded to nearest 2.5kg
-pub fn generate_warmup_sets(working_weight: f64) -> Vec<WorkoutSet> {
-    let percentages = [0.40, 0.60, 0.75, 0.90];
-    let reps = [10u32, 6, 4, 2];
+/// Warm-up ramp as (fraction of working weight, reps).
+const WARMUP_RAMP: [(f64, u32); 4] = [(0.40, 10), (0.60, 6), (0.75, 4), (0.90, 2)];
 
-    percentages
+/// Generate warm-up sets for a given working weight.
+/// Percentages: [40%, 60%, 75%, 90%], reps: [10, 6, 4, 2], rounded to the
+/// nearest `step` kg loadable on the `bar`.
+pub fn generate_warmup_sets(working_weight: f64, bar: f64, step: f64) -> Vec<WorkoutSet> {
+    WARMUP_RAMP
         .iter()
-        .zip(reps.iter())
-        .map(|(&pct, &r)| {
+        .map(|&(pct, r)| {
+            // Round to what the plates can load, and never below the empty bar
             let raw = working_weight * pct;
-            let rounded = (raw / 2.5).round() * 2.5;
+            let rounded = bar + ((raw - bar).max(0.0) / step).round() * step;
             WorkoutSet {
                 weight: rounded,
                 reps: r,
@@ -144,6 +253,90 @@ pub fn generate_warmup_sets(working_weight: f64) -> Vec<WorkoutSet> {
                 distance: None,
                 duration_secs: None,
                 note: None,
+                rest_taken_secs: None,
+                failure: None,
+                bodyweight: None,
+                completed_at: None,
+            }
+        })
+        .collect()
+}
+
+/// Warm-ups round to the smallest jump the plates allow (a pair of the lightest).
+pub fn warmup_step(config: &UserConfig) -> f64 {
+    config
+        .active_plates()
+        .unwrap_or_else(standard_plates)
+        .iter()
+        .map(|(p, _)| *p)
+        .fold(f64::MAX, f64::min)
+        * 2.0
+}
+
+/// Whether an exercise can take warm-ups: a strength lift whose first set is
+/// heavier than the bar and that doesn't already start with a warm-up ramp.
+pub fn needs_warmup(
+    we: &WorkoutExercise,
+    tracking: &ExerciseTrackingType,
+    bar: f64,
+    step: f64,
+) -> bool {
+    matches!(tracking, ExerciseTrackingType::Strength)
+        && we.sets.first().is_some_and(|s| s.weight > bar)
+        && !has_warmup_sets(&we.sets, bar, step)
+}
+
+/// True when the leading sets are exactly the ramp `generate_warmup_sets`
+/// builds for the set that follows them.
+fn has_warmup_sets(sets: &[WorkoutSet], bar: f64, step: f64) -> bool {
+    let Some(working) = sets.get(WARMUP_RAMP.len()) else {
+        return false;
+    };
+    generate_warmup_sets(working.weight, bar, step)
+        .iter()
+        .zip(sets)
+        .all(|(w, s)| (w.weight - s.weight).abs() < 0.01 && w.reps == s.reps)
+}
+
+/// Prepend warm-ups to every exercise that can take them, leaving ones that
+/// already have them alone.
+fn add_warmups_to_all(
+    exercises: &[WorkoutExercise],
+    all_exercises: &[Exercise],
+    config: &UserConfig,
+) -> Vec<WorkoutExercise> {
+    let step = warmup_step(config);
+    exercises
+        .iter()
+        .map(|we| {
+            let exercise = all_exercises.iter().find(|e| e.id == we.exercise_id);
+            let tracking = exercise
+                .map(|e| e.tracking_type.clone())
+                .unwrap_or(ExerciseTrackingType::Strength);
+            let bar = exercise
+                .and_then(|e| config.bar_weight_override(e))
+                .unwrap_or_else(|| config.effective_bar_weight());
+            let mut we = we.clone();
+            if needs_warmup(&we, &tracking, bar, step) {
+                let mut sets = generate_warmup_sets(we.sets[0].weight, bar, step);
+                sets.append(&mut we.sets);
+                we.sets = sets;
+            }
+            we
+        })
+        .collect()
+}
+
+/// Keep only completed sets, dropping exercises that end up with none.
+fn strip_incomplete_sets(exercises: &[WorkoutExercise]) -> Vec<WorkoutExercise> {
+    exercises
+        .iter()
+        .filter_map(|we| {
+            let sets: Vec<WorkoutSet> = we.sets.iter().filter(|s| s.completed).cloned().collect();
+            if sets.is_empty() {
+                None
+            } else {
+                Some(WorkoutExercise { sets, ..we.clone() })
             }
         })
         .collect()
@@ -247,7 +440,7 @@ pub fn rest_timer(props: &RestTimerProps) -> Html {
                     if r <= 1 {
                         remaining.set(0);
                         active_handle.set(false);
-                        try_vibrate();
+                        try_vibrate(200);
                         try_notify("Rest complete!");
                     } else {
                         remaining.set(r - 1);
@@ -298,7 +491,16 @@ pub fn rest_timer(props: &RestTimerProps) -> Html {
 pub fn workout_page() -> Html {
     let workout_exercises = use_state(Vec::<WorkoutExercise>::new);
     let workout_name = use_state(|| "Workout".to_string());
+    let session_notes = use_state(String::new);
+    // Routine this session was started from, for saving its targets back
+    let active_routine_id = use_state(|| None::<String>);
     let show_exercise_picker = use_state(|| false);
+    // Custom exercise form shown in place of the picker list
+    let show_new_exercise = use_state(|| false);
+    // Pre-save review of exercises with no ticked sets
+    let ghost_review = use_state(|| false);
+    // Exercise ids the user chose to keep despite having no ticked sets
+    let kept_ghosts = use_state(Vec::<String>::new);
     let elapsed_ref = use_mut_ref(|| 0u32);
     let workout_active = use_state(|| false);
     let saved = use_state(|| false);
@@ -309,9 +511,10 @@ pub fn workout_page() -> Html {
     // Rest timer trigger: incremented to signal RestTimer to start
     let rest_trigger = use_state(|| (0u32, 0u32)); // (counter, seconds)
 
-    // Toast state for PR celebrations
+    // Toast state for PR celebrations and warnings
     let toast_message = use_state(String::new);
     let toast_visible = use_state(|| false);
+    let toast_warning = use_state(|| false);
 
     // Undo state
     let undo_snapshot = use_state(|| None::<Vec<WorkoutExercise>>);
@@ -339,9 +542,12 @@ pub fn workout_page() -> Html {
     {
         let workout_exercises = workout_exercises.clone();
         let workout_name = workout_name.clone();
+        let session_notes = session_notes.clone();
         let workout_active = workout_active.clone();
+        let active_routine_id = active_routine_id.clone();
         let previous = (*previous_workouts).clone();
         let all_ex = all_exercises.clone();
+        let prefill_mode = config.prefill_mode;
         let started_at = started_at.clone();
         let elapsed_initial = elapsed_initial.clone();
         let elapsed_ref = elapsed_ref.clone();
@@ -354,17 +560,37 @@ pub fn workout_page() -> Html {
                 let routines = storage::load_routines();
                 if let Some(routine) = routines.iter().find(|r| r.id == routine_id) {
                     workout_name.set(routine.name.clone());
+                    active_routine_id.set(Some(routine.id.clone()));
                     let exs: Vec<WorkoutExercise> = routine
                         .exercise_ids
                         .iter()
                         .map(|eid| {
-                            let set = autofill_set(&previous, eid, &all_ex);
+                            // Prefer the routine's saved targets over last session's first set
+                            let sets = match routine.targets.get(eid) {
+                                Some(targets) if !targets.is_empty() => targets
+                                    .iter()
+                                    .map(|t| WorkoutSet {
+                                        weight: t.weight,
+                                        reps: t.reps,
+                                        distance: None,
+                                        duration_secs: None,
+                                        completed: false,
+                                        note: None,
+                                        rest_taken_secs: None,
+                                        failure: None,
+                                        bodyweight: None,
+                                        completed_at: None,
+                                    })
+                                    .collect(),
+                                _ => vec![autofill_set(&previous, eid, &all_ex, prefill_mode)],
+                            };
                             WorkoutExercise {
                                 exercise_id: eid.clone(),
-                                sets: vec![set],
+                                sets,
                                 notes: String::new(),
                                 superset_group: None,
                                 rest_seconds_override: None,
+                                tempo: None,
                             }
                         })
                         .collect();
@@ -385,6 +611,7 @@ pub fn workout_page() -> Html {
                             .map(|mut we| {
                                 for s in we.sets.iter_mut() {
                                     s.completed = false;
+                                    s.completed_at = None;
                                 }
                                 we
                             })
@@ -398,12 +625,16 @@ pub fn workout_page() -> Html {
             }
 
             // Restore WIP if no routine/repeat was loaded
+            let mut restored: Vec<WorkoutExercise> = Vec::new();
             if !loaded_from_intent {
                 if let Ok(json) = LocalStorage::get::<String>(WIP_KEY) {
                     if let Ok(wip) = serde_json::from_str::<WipWorkout>(&json) {
                         if !wip.exercises.is_empty() {
                             workout_name.set(wip.name);
-                            workout_exercises.set(wip.exercises);
+                            session_notes.set(wip.notes);
+                            active_routine_id.set(wip.routine_id);
+                            restored = wip.exercises;
+                            workout_exercises.set(restored.clone());
                             workout_active.set(true);
                             *started_at.borrow_mut() = wip.started_at;
                             let elapsed_secs =
@@ -413,6 +644,33 @@ pub fn workout_page() -> Html {
                         }
                     }
                 }
+
+                // Exercises queued elsewhere join the restored workout or start one
+                if let Ok(ids) = LocalStorage::get::<Vec<String>>(QUEUED_EXERCISES_KEY) {
+                    LocalStorage::delete(QUEUED_EXERCISES_KEY);
+                    let starting = restored.is_empty();
+                    let mut exs = restored;
+                    for id in ids {
+                        if exs.iter().any(|we| we.exercise_id == id) {
+                            continue;
+                        }
+                        exs.push(WorkoutExercise {
+                            sets: vec![autofill_set(&previous, &id, &all_ex, prefill_mode)],
+                            exercise_id: id,
+                            notes: String::new(),
+                            superset_group: None,
+                            rest_seconds_override: None,
+                            tempo: None,
+                        });
+                    }
+                    if !exs.is_empty() {
+                        if starting {
+                            *started_at.borrow_mut() = js_sys::Date::now();
+                        }
+                        workout_exercises.set(exs);
+                        workout_active.set(true);
+                    }
+                }
             }
 
             || ()
@@ -442,11 +700,13 @@ pub fn workout_page() -> Html {
     {
         let exs = (*workout_exercises).clone();
         let name = (*workout_name).clone();
+        let notes = (*session_notes).clone();
         let active = *workout_active;
+        let routine_id = (*active_routine_id).clone();
         let started_at = started_at.clone();
         use_effect_with(
-            (exs.clone(), name.clone(), active),
-            move |(exs, name, active)| {
+            (exs.clone(), name.clone(), notes, active, routine_id),
+            move |(exs, name, notes, active, routine_id)| {
                 if *active && !exs.is_empty() {
                     let mut sa = *started_at.borrow();
                     if sa == 0.0 {
@@ -457,6 +717,8 @@ pub fn workout_page() -> Html {
                         name: name.clone(),
                         exercises: exs.clone(),
                         started_at: sa,
+                        routine_id: routine_id.clone(),
+                        notes: notes.clone(),
                     };
                     if let Ok(json) = serde_json::to_string(&wip) {
                         let _ = LocalStorage::set(WIP_KEY, json);
@@ -471,8 +733,22 @@ pub fn workout_page() -> Html {
     let on_pr = {
         let toast_message = toast_message.clone();
         let toast_visible = toast_visible.clone();
+        let toast_warning = toast_warning.clone();
+        Callback::from(move |msg: String| {
+            toast_message.set(msg);
+            toast_warning.set(false);
+            toast_visible.set(true);
+        })
+    };
+
+    // Same toast, styled as a warning
+    let on_warning = {
+        let toast_message = toast_message.clone();
+        let toast_visible = toast_visible.clone();
+        let toast_warning = toast_warning.clone();
         Callback::from(move |msg: String| {
             toast_message.set(msg);
+            toast_warning.set(true);
             toast_visible.set(true);
         })
     };
@@ -498,16 +774,18 @@ pub fn workout_page() -> Html {
         let show = show_exercise_picker.clone();
         let active = workout_active.clone();
         let previous = (*previous_workouts).clone();
-        let all_ex = all_exercises.clone();
+        let prefill_mode = config.prefill_mode;
         Callback::from(move |ex: Exercise| {
             let mut exs = (*we).clone();
-            let set = autofill_set(&previous, &ex.id, &all_ex);
+            // Look up tracking from `ex` itself so exercises created in the picker work too
+            let set = autofill_set(&previous, &ex.id, std::slice::from_ref(&ex), prefill_mode);
             exs.push(WorkoutExercise {
                 exercise_id: ex.id,
                 sets: vec![set],
                 notes: String::new(),
                 superset_group: None,
                 rest_seconds_override: None,
+                tempo: None,
             });
             we.set(exs);
             show.set(false);
@@ -557,24 +835,42 @@ pub fn workout_page() -> Html {
         })
     };
 
-    let on_save = {
+    let save_workout = {
         let we = workout_exercises.clone();
         let name = workout_name.clone();
+        let notes = session_notes.clone();
         let elapsed_ref = elapsed_ref.clone();
+        let started_at = started_at.clone();
         let saved = saved.clone();
         let nav = navigator.clone();
-        Callback::from(move |_| {
+        let on_warning = on_warning.clone();
+        Callback::from(move |_: ()| {
             if we.is_empty() {
                 return;
             }
-            let now = chrono::Local::now();
+            let config = storage::load_user_config();
+            let exercises = if config.discard_incomplete_sets {
+                strip_incomplete_sets(&we)
+            } else {
+                (*we).clone()
+            };
+            if exercises.is_empty() {
+                on_warning.emit("No completed sets to save \u{2014} tick at least one set".to_string());
+                return;
+            }
+            // Date the session by when it started, so one that runs past
+            // midnight stays on the day it began
+            let start = crate::dates::session_start(*started_at.borrow());
             let elapsed = *elapsed_ref.borrow();
             let workout = Workout {
                 id: uuid::Uuid::new_v4().to_string(),
-                date: now.format("%Y-%m-%d").to_string(),
+                date: crate::dates::format_date(start.date_naive()),
+                started_at: Some(start.to_rfc3339()),
                 name: (*name).clone(),
-                exercises: (*we).clone(),
+                exercises,
                 duration_mins: elapsed / 60,
+                gym: config.active_gym().map(|g| g.name.clone()),
+                notes: notes.trim().to_string(),
             };
             let mut workouts = storage::load_workouts();
             workouts.push(workout);
@@ -585,8 +881,119 @@ pub fn workout_page() -> Html {
         })
     };
 
+    // Exercises that would be saved with no completed sets, unless kept. The
+    // discard setting already drops them, so nothing to ask then.
+    let unresolved_ghosts: Vec<(usize, String)> = if storage::load_user_config().discard_incomplete_sets {
+        Vec::new()
+    } else {
+        workout_exercises
+            .iter()
+            .enumerate()
+            .filter(|(_, we)| !we.sets.iter().any(|s| s.completed) && !kept_ghosts.contains(&we.exercise_id))
+            .map(|(i, we)| {
+                let name = all_exercises
+                    .iter()
+                    .find(|e| e.id == we.exercise_id)
+                    .map(|e| e.name.clone())
+                    .unwrap_or_else(|| we.exercise_id.clone());
+                (i, name)
+            })
+            .collect()
+    };
+
+    let on_save = {
+        let save_workout = save_workout.clone();
+        let ghost_review = ghost_review.clone();
+        let has_ghosts = !unresolved_ghosts.is_empty();
+        Callback::from(move |_: MouseEvent| {
+            if has_ghosts {
+                ghost_review.set(true);
+            } else {
+                ghost_review.set(false);
+                save_workout.emit(());
+            }
+        })
+    };
+
+    // Capture this session's sets as the starting targets for its routine
+    let on_save_targets = {
+        let we = workout_exercises.clone();
+        let active_routine_id = active_routine_id.clone();
+        let on_pr = on_pr.clone();
+        Callback::from(move |_: MouseEvent| {
+            let rid = match (*active_routine_id).clone() {
+                Some(rid) => rid,
+                None => return,
+            };
+            let mut routines = storage::load_routines();
+            let routine = match routines.iter_mut().find(|r| r.id == rid) {
+                Some(r) => r,
+                None => return,
+            };
+            let ids = routine.exercise_ids.clone();
+            for ex in we.iter().filter(|ex| ids.contains(&ex.exercise_id)) {
+                let targets: Vec<SetTarget> = ex
+                    .sets
+                    .iter()
+                    .filter(|s| s.reps > 0)
+                    .map(|s| SetTarget { weight: s.weight, reps: s.reps })
+                    .collect();
+                if targets.is_empty() {
+                    routine.targets.remove(&ex.exercise_id);
+                } else {
+                    routine.targets.insert(ex.exercise_id.clone(), targets);
+                }
+            }
+            let msg = format!("Saved targets to \"{}\"", routine.name);
+            storage::save_routines(&routines);
+            on_pr.emit(msg);
+        })
+    };
+
+    // One-tap buttons for staple exercises, shown above the picker and the log
+    let quick_add = quick_add_exercises(&config, &previous_workouts, &all_exercises, &workout_exercises);
+    let quick_add_html = if quick_add.is_empty() {
+        html! {}
+    } else {
+        html! {
+            <div class="flex flex-wrap gap-2">
+                { for quick_add.into_iter().map(|ex| {
+                    let on_add = on_add_exercise.clone();
+                    let name = ex.name.clone();
+                    html! {
+                        <button
+                            class="px-3 py-1.5 rounded-full text-xs font-medium bg-gray-200 dark:bg-gray-700 text-gray-700 dark:text-gray-200 hover:bg-gray-300 dark:hover:bg-gray-600 neu-chip transition-colors"
+                            onclick={Callback::from(move |_| on_add.emit(ex.clone()))}
+                        >{format!("+ {}", name)}</button>
+                    }
+                })}
+            </div>
+        }
+    };
+
     // Show exercise picker as a full page when explicitly opened OR when workout is empty
     let show_picker = *show_exercise_picker || (workout_exercises.is_empty() && !*saved);
+    if show_picker && *show_new_exercise {
+        let on_save_new = {
+            let show_new = show_new_exercise.clone();
+            let on_add = on_add_exercise.clone();
+            Callback::from(move |ex: Exercise| {
+                let mut customs = storage::load_custom_exercises();
+                customs.push(ex.clone());
+                storage::save_custom_exercises(&customs);
+                show_new.set(false);
+                on_add.emit(ex);
+            })
+        };
+        return html! {
+            <div class="px-4 py-4 pb-20 transition-colors duration-200">
+                <CustomExerciseForm
+                    on_save={on_save_new}
+                    on_cancel={let s = show_new_exercise.clone(); Callback::from(move |_| s.set(false))}
+                />
+            </div>
+        };
+    }
     if show_picker {
         return html! {
             <div class="pb-20 transition-colors duration-200">
@@ -594,15 +1001,22 @@ pub fn workout_page() -> Html {
                     <h2 class="text-xl font-bold text-gray-900 dark:text-gray-100">
                         { if workout_exercises.is_empty() { "Start Workout" } else { "Add Exercise" } }
                     </h2>
-                    { if !workout_exercises.is_empty() {
-                        html! {
-                            <button
-                                class="text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 font-medium"
-                                onclick={let s = show_exercise_picker.clone(); Callback::from(move |_| s.set(false))}
-                            >{"Cancel"}</button>
-                        }
-                    } else { html! {} }}
+                    <div class="flex items-center gap-3">
+                        <button
+                            class="px-3 py-1.5 bg-blue-600 text-white rounded-lg text-sm font-bold hover:bg-blue-700 neu-btn transition-colors"
+                            onclick={let s = show_new_exercise.clone(); Callback::from(move |_| s.set(true))}
+                        >{"+ New exercise"}</button>
+                        { if !workout_exercises.is_empty() {
+                            html! {
+                                <button
+                                    class="text-gray-500 dark:text-gray-400 hover:text-gray-700 dark:hover:text-gray-200 font-medium"
+                                    onclick={let s = show_exercise_picker.clone(); Callback::from(move |_| s.set(false))}
+                                >{"Cancel"}</button>
+                            }
+                        } else { html! {} }}
+                    </div>
                 </div>
+                <div class="px-4 pb-2">{quick_add_html}</div>
                 <ExerciseList
                     exercises={all_exercises.clone()}
                     on_select={on_add_exercise.clone()}
@@ -642,6 +1056,21 @@ pub fn workout_page() -> Html {
         html! {}
     };
 
+    // Shown while some lift still lacks warm-up sets
+    let with_warmups = add_warmups_to_all(&workout_exercises, &all_exercises, &config);
+    let warmups_html = if with_warmups != *workout_exercises {
+        let we = workout_exercises.clone();
+        html! {
+            <button
+                class="w-full py-2 text-sm font-bold text-orange-500 hover:text-orange-400 transition-colors"
+                title="Generate warm-up sets at 40/60/75/90% for every lift that doesn't have them yet"
+                onclick={Callback::from(move |_| we.set(with_warmups.clone()))}
+            >{"Add warm-ups to all"}</button>
+        }
+    } else {
+        html! {}
+    };
+
     html! {
         <div class="px-4 py-4 pb-24 space-y-6 transition-colors duration-200">
             <div class="flex justify-between items-center">
@@ -666,10 +1095,12 @@ pub fn workout_page() -> Html {
                                     let we = workout_exercises.clone();
                                     let name = workout_name.clone();
                                     let active = workout_active.clone();
+                                    let routine_id = active_routine_id.clone();
                                     Callback::from(move |_| {
                                         LocalStorage::delete(WIP_KEY);
                                         we.set(Vec::new());
                                         name.set("Workout".to_string());
+                                        routine_id.set(None);
                                         active.set(false);
                                     })
                                 }}
@@ -698,6 +1129,8 @@ pub fn workout_page() -> Html {
                 }
             } else { html! {} }}
 
+            {warmups_html}
+
             <WorkoutLog
                 workout_exercises={(*workout_exercises).clone()}
                 all_exercises={all_exercises.clone()}
@@ -705,30 +1138,124 @@ pub fn workout_page() -> Html {
                 on_remove_exercise={on_remove}
                 previous_workouts={(*previous_workouts).clone()}
                 rest_seconds={config.rest_seconds}
-                bar_weight={config.bar_weight}
+                category_rest_seconds={config.category_rest_seconds.clone()}
+                bar_weight={config.effective_bar_weight()}
                 on_set_completed={on_set_completed}
                 on_before_destructive={on_before_destructive}
                 unit_system={config.unit_system.clone()}
                 on_pr={on_pr}
+                on_warning={on_warning}
             />
 
+            {quick_add_html}
+
             <button
                 class="w-full py-4 bg-gray-100 dark:bg-gray-800/50 rounded-xl text-blue-600 dark:text-blue-400 font-bold hover:bg-gray-200 dark:hover:bg-gray-800 neu-pressed transition-all"
                 onclick={let s = show_exercise_picker.clone(); Callback::from(move |_| s.set(true))}
             >{"+ Add Exercise"}</button>
 
-            { if !workout_exercises.is_empty() {
+            { if active_routine_id.is_some() && !workout_exercises.is_empty() {
                 html! {
                     <button
-                        class="w-full py-4 bg-green-600 text-white rounded-2xl font-bold text-lg hover:bg-green-700 neu-btn transition-all"
-                        onclick={on_save}
-                    >{"Finish & Save Workout"}</button>
+                        class="w-full py-3 bg-gray-100 dark:bg-gray-800 rounded-xl text-sm text-gray-700 dark:text-gray-300 font-bold hover:bg-gray-200 dark:hover:bg-gray-700 neu-btn transition-all"
+                        onclick={on_save_targets}
+                    >{"\u{1f4cc} Save as Routine Targets"}</button>
+                }
+            } else { html! {} }}
+
+            { if !workout_exercises.is_empty() {
+                // Preview what "discard unchecked sets" will drop on save
+                let discard_note = storage::load_user_config().discard_incomplete_sets.then(|| {
+                    let unchecked: usize = workout_exercises
+                        .iter()
+                        .map(|we| we.sets.iter().filter(|s| !s.completed).count())
+                        .sum();
+                    let emptied: Vec<String> = workout_exercises
+                        .iter()
+                        .filter(|we| !we.sets.iter().any(|s| s.completed))
+                        .map(|we| {
+                            all_exercises
+                                .iter()
+                                .find(|e| e.id == we.exercise_id)
+                                .map(|e| e.name.clone())
+                                .unwrap_or_else(|| we.exercise_id.clone())
+                        })
+                        .collect();
+                    (unchecked, emptied)
+                }).filter(|(unchecked, _)| *unchecked > 0);
+                html! {
+                    <>
+                        if let Some((unchecked, emptied)) = discard_note {
+                            <p class="text-xs text-amber-600 dark:text-amber-400 text-center">
+                                {format!("{} unchecked set{} will be discarded on save", unchecked, if unchecked == 1 { "" } else { "s" })}
+                                if !emptied.is_empty() {
+                                    {format!(", removing {}", emptied.join(", "))}
+                                }
+                                {"."}
+                            </p>
+                        }
+                        if *ghost_review && !unresolved_ghosts.is_empty() {
+                            <div class="bg-amber-50 dark:bg-amber-900/20 border border-amber-300 dark:border-amber-700/50 rounded-2xl p-4 space-y-2">
+                                <div class="text-sm font-bold text-amber-700 dark:text-amber-400">{"Some exercises have no completed sets"}</div>
+                                { for unresolved_ghosts.iter().map(|(idx, name)| {
+                                    let on_keep = {
+                                        let kept = kept_ghosts.clone();
+                                        let id = workout_exercises[*idx].exercise_id.clone();
+                                        Callback::from(move |_: MouseEvent| {
+                                            let mut ids = (*kept).clone();
+                                            ids.push(id.clone());
+                                            kept.set(ids);
+                                        })
+                                    };
+                                    let on_remove = {
+                                        let we = workout_exercises.clone();
+                                        let idx = *idx;
+                                        Callback::from(move |_: MouseEvent| {
+                                            let mut exs = (*we).clone();
+                                            if idx < exs.len() {
+                                                exs.remove(idx);
+                                            }
+                                            we.set(exs);
+                                        })
+                                    };
+                                    html! {
+                                        <div class="flex items-center justify-between gap-2 text-sm">
+                                            <span class="text-gray-700 dark:text-gray-300 truncate">
+                                                {format!("{} has no completed sets \u{2014} keep or remove?", name)}
+                                            </span>
+                                            <div class="flex gap-2 flex-shrink-0">
+                                                <button
+                                                    class="px-2 py-1 rounded-lg text-xs font-bold bg-gray-200 dark:bg-gray-700 text-gray-700 dark:text-gray-300 neu-btn"
+                                                    onclick={on_keep}
+                                                >{"Keep"}</button>
+                                                <button
+                                                    class="px-2 py-1 rounded-lg text-xs font-bold bg-red-600 text-white hover:bg-red-700 neu-btn"
+                                                    onclick={on_remove}
+                                                >{"Remove"}</button>
+                                            </div>
+                                        </div>
+                                    }
+                                })}
+                            </div>
+                        }
+                        <textarea
+                            rows="2"
+                            placeholder="Session notes (optional) \u{2014} sleep, energy, how it felt"
+                            class="w-full px-3 py-2 bg-gray-100 dark:bg-gray-800 rounded-2xl text-sm text-gray-900 dark:text-gray-100 placeholder-gray-400 dark:placeholder-gray-500 outline-none neu-pressed transition-colors resize-none"
+                            value={(*session_notes).clone()}
+                            oninput={let n = session_notes.clone(); Callback::from(move |e: InputEvent| n.set(e.target_unchecked_into::<web_sys::HtmlTextAreaElement>().value()))}
+                        />
+                        <button
+                            class="w-full py-4 bg-green-600 text-white rounded-2xl font-bold text-lg hover:bg-green-700 neu-btn transition-all"
+                            onclick={on_save}
+                        >{"Finish & Save Workout"}</button>
+                    </>
                 }
             } else { html! {} }}
 
             <RestTimer trigger={rest_trigger_val} />
             {undo_html}
-            <Toast message={(*toast_message).clone()} visible={*toast_visible} on_dismiss={on_toast_dismiss} />
+            <Toast message={(*toast_message).clone()} visible={*toast_visible} warning={*toast_warning} on_dismiss={on_toast_dismiss} />
         </div>
     }
 }
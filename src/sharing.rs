@@ -24,15 +24,98 @@ pub fn encode(data: &ShareableData) -> Result<String, String> {
     Ok(base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(&compressed))
 }
 
+/// Longest encoded payload accepted from a link, well above what `build_share_url` emits.
+const MAX_ENCODED_LEN: usize = 16_000;
+/// Cap on the inflated JSON so a crafted link can't balloon in memory.
+const MAX_JSON_BYTES: usize = 256 * 1024;
+/// Most exercises, sets per exercise or routine entries a shared payload may carry.
+const MAX_ITEMS: usize = 100;
+/// Longest name, description or note accepted in a shared payload.
+const MAX_TEXT_LEN: usize = 2000;
+
 pub fn decode(encoded: &str) -> Result<ShareableData, String> {
+    if encoded.len() > MAX_ENCODED_LEN {
+        return Err("Shared link is too large".to_string());
+    }
     use base64::Engine;
     let compressed = base64::engine::general_purpose::URL_SAFE_NO_PAD
         .decode(encoded)
         .map_err(|e| format!("Base64 decode error: {}", e))?;
-    let json_bytes = miniz_oxide::inflate::decompress_to_vec(&compressed)
+    let json_bytes = miniz_oxide::inflate::decompress_to_vec_with_limit(&compressed, MAX_JSON_BYTES)
         .map_err(|e| format!("Decompress error: {:?}", e))?;
     let json = String::from_utf8(json_bytes).map_err(|e| e.to_string())?;
-    serde_json::from_str(&json).map_err(|e| format!("JSON parse error: {}", e))
+    let mut data: ShareableData =
+        serde_json::from_str(&json).map_err(|e| format!("JSON parse error: {}", e))?;
+    // Workouts can be saved without a name; call those by their date
+    if let ShareableData::Workout { workout, .. } = &mut data {
+        if workout.name.trim().is_empty() {
+            workout.name = format!("Workout {}", workout.date);
+        }
+    }
+    validate(&data)?;
+    // Images never travel in links; drop any a hand-crafted payload slipped in
+    match &mut data {
+        ShareableData::Workout { exercises, .. } | ShareableData::Routine { exercises, .. } => {
+            exercises.iter_mut().for_each(|e| e.image = None);
+        }
+        ShareableData::Exercise { exercise } => exercise.image = None,
+    }
+    Ok(data)
+}
+
+/// Reject payloads that parse but are nothing the app would produce:
+/// missing names, oversized lists or text.
+fn validate(data: &ShareableData) -> Result<(), String> {
+    let too_long = |s: &str| s.len() > MAX_TEXT_LEN;
+    let check_exercises = |exercises: &[Exercise]| -> Result<(), String> {
+        if exercises.len() > MAX_ITEMS {
+            return Err("Too many exercises in shared data".to_string());
+        }
+        for e in exercises {
+            if e.id.trim().is_empty() || e.name.trim().is_empty() {
+                return Err("Shared exercise is missing a name".to_string());
+            }
+            if too_long(&e.name)
+                || too_long(&e.description)
+                || e.instructions.as_deref().is_some_and(too_long)
+            {
+                return Err("Shared exercise text is too long".to_string());
+            }
+        }
+        Ok(())
+    };
+
+    match data {
+        ShareableData::Workout { workout, exercises } => {
+            if too_long(&workout.name) {
+                return Err("Shared workout has an invalid name".to_string());
+            }
+            if workout.exercises.len() > MAX_ITEMS
+                || workout.exercises.iter().any(|we| {
+                    we.sets.len() > MAX_ITEMS
+                        || too_long(&we.notes)
+                        || we.sets.iter().any(|s| {
+                            !s.weight.is_finite()
+                                || s.weight < 0.0
+                                || s.note.as_deref().is_some_and(too_long)
+                        })
+                })
+            {
+                return Err("Shared workout is too large or malformed".to_string());
+            }
+            check_exercises(exercises)
+        }
+        ShareableData::Routine { routine, exercises } => {
+            if routine.name.trim().is_empty() || too_long(&routine.name) {
+                return Err("Shared routine has an invalid name".to_string());
+            }
+            if routine.exercise_ids.is_empty() || routine.exercise_ids.len() > MAX_ITEMS {
+                return Err("Shared routine has no exercises or too many".to_string());
+            }
+            check_exercises(exercises)
+        }
+        ShareableData::Exercise { exercise } => check_exercises(std::slice::from_ref(exercise)),
+    }
 }
 
 const MAX_URL_LEN: usize = 2000;
@@ -77,7 +160,9 @@ pub fn collect_routine_exercises(routine: &Routine, all_exercises: &[Exercise])
 }
 
 pub fn format_workout_text(workout: &Workout, exercises: &[Exercise]) -> String {
-    let units = crate::storage::load_user_config().unit_system;
+    let config = crate::storage::load_user_config();
+    let units = config.unit_system.clone();
+    let rounding = config.weight_rounding;
     let wl = units.weight_label();
     let dl = units.distance_label();
     let mut lines = Vec::new();
@@ -89,11 +174,9 @@ pub fn format_workout_text(workout: &Workout, exercises: &[Exercise]) -> String
     lines.push(String::new());
 
     for we in &workout.exercises {
-        let name = exercises
-            .iter()
-            .find(|e| e.id == we.exercise_id)
-            .map(|e| e.name.as_str())
-            .unwrap_or(&we.exercise_id);
+        let exercise = exercises.iter().find(|e| e.id == we.exercise_id);
+        let name = exercise.map(|e| e.name.as_str()).unwrap_or(&we.exercise_id);
+        let hands = exercise.map_or(1.0, |e| config.hands(e));
         let superset_tag = if we.superset_group.is_some() {
             " [Superset]"
         } else {
@@ -116,10 +199,11 @@ pub fn format_workout_text(workout: &Workout, exercises: &[Exercise]) -> String
                     format!("    Set {}: {}:{:02}", i + 1, secs / 60, secs % 60)
                 } else {
                     format!(
-                        "    Set {}: {:.1}{} x {}",
+                        "    Set {}: {}{}{} x {}",
                         i + 1,
-                        units.display_weight(s.weight),
+                        units.format_weight(s.weight / hands, rounding),
                         wl,
+                        if hands > 1.0 { "/hand" } else { "" },
                         s.reps
                     )
                 };
@@ -1,9 +1,11 @@
 use crate::backup;
 use crate::models::{
-    AppData, BodyMetric, Exercise, Friend, Routine, TrustedDevice, UserConfig, Workout,
+    Annotation, AppData, BodyMetric, Exercise, Friend, Routine, TrustedDevice, UserConfig,
+    Workout,
 };
 use gloo::storage::{LocalStorage, Storage};
-use std::cell::Cell;
+use serde::de::DeserializeOwned;
+use std::cell::{Cell, RefCell};
 
 const WORKOUTS_KEY: &str = "treening_workouts";
 const ROUTINES_KEY: &str = "treening_routines";
@@ -12,12 +14,14 @@ const FRIENDS_KEY: &str = "treening_friends";
 const BODY_METRICS_KEY: &str = "treening_body_metrics";
 const USER_CONFIG_KEY: &str = "treening_user_config";
 const TRUSTED_DEVICES_KEY: &str = "treening_trusted_devices";
+const ANNOTATIONS_KEY: &str = "treening_annotations";
 
 const BACKUP_DEBOUNCE_MS: f64 = 5000.0;
 
 thread_local! {
     static SAVE_FAILED: Cell<bool> = const { Cell::new(false) };
     static LAST_BACKUP_TIME: Cell<f64> = const { Cell::new(0.0) };
+    static CORRUPT_KEYS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
 }
 
 pub fn has_save_failed() -> bool {
@@ -35,7 +39,48 @@ fn check_save_result<T>(result: Result<(), T>) {
     }
 }
 
+/// Keys whose stored JSON failed to parse this session.
+pub fn corrupt_keys() -> Vec<&'static str> {
+    CORRUPT_KEYS.with(|k| k.borrow().clone())
+}
+
+pub fn clear_corrupt_keys() {
+    CORRUPT_KEYS.with(|k| k.borrow_mut().clear());
+}
+
+fn has_corrupt_data() -> bool {
+    CORRUPT_KEYS.with(|k| !k.borrow().is_empty())
+}
+
+/// Read and parse `key`. Missing keys yield `None`. A value that fails to parse
+/// is copied verbatim to `<key>_corrupt_backup` before being reported, so the
+/// defaults that callers fall back to can never silently replace it.
+fn load_key<T: DeserializeOwned>(key: &'static str) -> Option<T> {
+    let raw = LocalStorage::raw().get_item(key).ok().flatten()?;
+    match serde_json::from_str(&raw) {
+        Ok(value) => Some(value),
+        Err(e) => {
+            log::error!("Corrupted data in LocalStorage key '{}': {}", key, e);
+            let backup_key = format!("{}_corrupt_backup", key);
+            if LocalStorage::raw().set_item(&backup_key, &raw).is_err() {
+                log::error!("Could not preserve corrupted value of '{}'", key);
+            }
+            CORRUPT_KEYS.with(|k| {
+                let mut keys = k.borrow_mut();
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+            });
+            None
+        }
+    }
+}
+
 fn trigger_backup_debounced() {
+    // Don't let a snapshot built from fallback defaults overwrite the last good backup
+    if has_corrupt_data() {
+        return;
+    }
     let now = js_sys::Date::now();
     let should_backup = LAST_BACKUP_TIME.with(|t| {
         if now - t.get() > BACKUP_DEBOUNCE_MS {
@@ -48,11 +93,12 @@ fn trigger_backup_debounced() {
     if should_backup {
         let data = export_all_data();
         backup::save_backup(&data);
+        backup::push_remote_backup(&data);
     }
 }
 
 pub fn load_workouts() -> Vec<Workout> {
-    LocalStorage::get(WORKOUTS_KEY).unwrap_or_default()
+    load_key(WORKOUTS_KEY).unwrap_or_default()
 }
 
 pub fn save_workouts(workouts: &[Workout]) {
@@ -61,7 +107,7 @@ pub fn save_workouts(workouts: &[Workout]) {
 }
 
 pub fn load_friends() -> Vec<Friend> {
-    LocalStorage::get(FRIENDS_KEY).unwrap_or_default()
+    load_key(FRIENDS_KEY).unwrap_or_default()
 }
 
 pub fn save_friends(friends: &[Friend]) {
@@ -70,7 +116,19 @@ pub fn save_friends(friends: &[Friend]) {
 }
 
 pub fn load_body_metrics() -> Vec<BodyMetric> {
-    LocalStorage::get(BODY_METRICS_KEY).unwrap_or_default()
+    load_key(BODY_METRICS_KEY).unwrap_or_default()
+}
+
+/// Current body weight (kg): the most recently dated body metric, falling
+/// back to the weight set in Settings.
+pub fn latest_body_weight(config: &UserConfig) -> Option<f64> {
+    load_body_metrics()
+        .into_iter()
+        .filter_map(|m| m.weight.map(|w| (m.date, w)))
+        .max_by(|a, b| a.0.cmp(&b.0))
+        .map(|(_, w)| w)
+        .or(config.body_weight)
+        .filter(|w| *w > 0.0)
 }
 
 pub fn save_body_metrics(metrics: &[BodyMetric]) {
@@ -79,7 +137,7 @@ pub fn save_body_metrics(metrics: &[BodyMetric]) {
 }
 
 pub fn load_user_config() -> UserConfig {
-    LocalStorage::get(USER_CONFIG_KEY).unwrap_or_else(|_| {
+    load_key(USER_CONFIG_KEY).unwrap_or_else(|| {
         let config = UserConfig {
             nickname: "Athlete".to_string(),
             peer_id: format!("tr-{}", &uuid::Uuid::new_v4().to_string()[..8]),
@@ -94,19 +152,77 @@ pub fn load_user_config() -> UserConfig {
             ai_enabled: false,
             ai_model: crate::models::AiModel::default(),
             muscle_thresholds: None,
+            count_fractional_volume: true,
+            language: crate::models::Language::En,
+            weight_rounding: crate::models::WeightRounding::Off,
+            auto_backup_days: 0,
+            category_rest_seconds: std::collections::HashMap::new(),
+            custom_milestones: Vec::new(),
+            favorite_exercises: std::collections::HashSet::new(),
+            muscle_set_targets: std::collections::HashMap::new(),
+            ai_auto_speak: false,
+            mixed_plates: false,
+            routine_schedule: std::collections::HashMap::new(),
+            reduce_motion: None,
+            gym_profiles: Vec::new(),
+            active_gym: None,
+            auto_complete_sets: false,
+            discard_incomplete_sets: false,
+            haptic_feedback: false,
+            body_weight: None,
+            body_unit: None,
+            category_mets: std::collections::HashMap::new(),
+            default_tracking_type: None,
+            last_tracking_type: None,
+            rest_days_per_week: 3,
+            ai_persona: crate::models::AiPersona::default(),
+            ai_custom_persona: String::new(),
+            prefill_mode: crate::models::PrefillMode::default(),
+            quick_add: crate::models::QuickAddSource::default(),
+            volume_metric: crate::models::VolumeMetric::default(),
+            category_colors: std::collections::HashMap::new(),
+            per_hand_overrides: std::collections::HashMap::new(),
+            bar_weight_overrides: std::collections::HashMap::new(),
+            experience_level: crate::models::Difficulty::default(),
+            min_rest_warning: false,
+            min_rest_secs: std::collections::HashMap::new(),
+            e1rm_goals: std::collections::HashMap::new(),
+            ai_max_threads: 20,
+            ai_thread_max_age_days: 0,
         };
-        let _ = LocalStorage::set(USER_CONFIG_KEY, &config);
+        // Only seed a missing key; a corrupted one stays in place for recovery
+        let missing = LocalStorage::raw()
+            .get_item(USER_CONFIG_KEY)
+            .ok()
+            .flatten()
+            .is_none();
+        if missing {
+            let _ = LocalStorage::set(USER_CONFIG_KEY, &config);
+        }
         config
     })
 }
 
+/// Whether decorative animations should be left out: the user's explicit
+/// choice, otherwise the OS `prefers-reduced-motion` preference.
+pub fn reduce_motion(config: &UserConfig) -> bool {
+    config.reduce_motion.unwrap_or_else(|| {
+        gloo::utils::window()
+            .match_media("(prefers-reduced-motion: reduce)")
+            .ok()
+            .flatten()
+            .map(|m| m.matches())
+            .unwrap_or(false)
+    })
+}
+
 pub fn save_user_config(config: &UserConfig) {
     check_save_result(LocalStorage::set(USER_CONFIG_KEY, config));
     trigger_backup_debounced();
 }
 
 pub fn load_routines() -> Vec<Routine> {
-    LocalStorage::get(ROUTINES_KEY).unwrap_or_default()
+    load_key(ROUTINES_KEY).unwrap_or_default()
 }
 
 pub fn save_routines(routines: &[Routine]) {
@@ -115,7 +231,7 @@ pub fn save_routines(routines: &[Routine]) {
 }
 
 pub fn load_custom_exercises() -> Vec<Exercise> {
-    LocalStorage::get(CUSTOM_EXERCISES_KEY).unwrap_or_default()
+    load_key(CUSTOM_EXERCISES_KEY).unwrap_or_default()
 }
 
 pub fn save_custom_exercises(exercises: &[Exercise]) {
@@ -124,7 +240,7 @@ pub fn save_custom_exercises(exercises: &[Exercise]) {
 }
 
 pub fn load_trusted_devices() -> Vec<TrustedDevice> {
-    LocalStorage::get(TRUSTED_DEVICES_KEY).unwrap_or_default()
+    load_key(TRUSTED_DEVICES_KEY).unwrap_or_default()
 }
 
 pub fn save_trusted_devices(devices: &[TrustedDevice]) {
@@ -132,6 +248,121 @@ pub fn save_trusted_devices(devices: &[TrustedDevice]) {
     trigger_backup_debounced();
 }
 
+pub fn load_annotations() -> Vec<Annotation> {
+    load_key(ANNOTATIONS_KEY).unwrap_or_default()
+}
+
+pub fn save_annotations(annotations: &[Annotation]) {
+    check_save_result(LocalStorage::set(ANNOTATIONS_KEY, annotations));
+    trigger_backup_debounced();
+}
+
+/// Workouts (newest first) and routines that reference an exercise, so deleting
+/// it can warn instead of silently orphaning them.
+pub fn exercise_usage(exercise_id: &str) -> (Vec<Workout>, Vec<Routine>) {
+    let mut workouts: Vec<Workout> = load_workouts()
+        .into_iter()
+        .filter(|w| w.exercises.iter().any(|we| we.exercise_id == exercise_id))
+        .collect();
+    workouts.sort_by(|a, b| b.date.cmp(&a.date));
+    let routines = load_routines()
+        .into_iter()
+        .filter(|r| r.exercise_ids.iter().any(|id| id == exercise_id))
+        .collect();
+    (workouts, routines)
+}
+
+/// Point every reference to exercise `from` at `to`: workout history, routines
+/// and per-exercise settings. Settings `to` already has are kept.
+/// Switch per-hand entry for `exercise` and rescale what was logged for it to
+/// match: turning it on reads past weights as one dumbbell and doubles them to
+/// the two-dumbbell total, turning it off halves them back.
+pub fn set_per_hand(exercise: &Exercise, per_hand: bool) {
+    let mut config = load_user_config();
+    if config.is_per_hand(exercise) == per_hand {
+        return;
+    }
+    let factor = if per_hand { 2.0 } else { 0.5 };
+
+    let mut workouts = load_workouts();
+    for we in workouts.iter_mut().flat_map(|w| w.exercises.iter_mut()) {
+        if we.exercise_id == exercise.id {
+            for s in we.sets.iter_mut() {
+                s.weight *= factor;
+            }
+        }
+    }
+    save_workouts(&workouts);
+
+    let mut routines = load_routines();
+    for t in routines.iter_mut().filter_map(|r| r.targets.get_mut(&exercise.id)) {
+        for target in t.iter_mut() {
+            target.weight *= factor;
+        }
+    }
+    save_routines(&routines);
+
+    if let Some(goal) = config.e1rm_goals.get_mut(&exercise.id) {
+        *goal *= factor;
+    }
+    if per_hand == exercise.per_hand {
+        config.per_hand_overrides.remove(&exercise.id);
+    } else {
+        config.per_hand_overrides.insert(exercise.id.clone(), per_hand);
+    }
+    save_user_config(&config);
+}
+
+pub fn reassign_exercise(from: &str, to: &str) {
+    let mut workouts = load_workouts();
+    for we in workouts.iter_mut().flat_map(|w| w.exercises.iter_mut()) {
+        if we.exercise_id == from {
+            we.exercise_id = to.to_string();
+        }
+    }
+    save_workouts(&workouts);
+
+    let mut routines = load_routines();
+    for r in routines.iter_mut() {
+        if !r.exercise_ids.iter().any(|id| id == from) {
+            continue;
+        }
+        if r.exercise_ids.iter().any(|id| id == to) {
+            r.exercise_ids.retain(|id| id != from);
+        } else {
+            for id in r.exercise_ids.iter_mut() {
+                if *id == from {
+                    *id = to.to_string();
+                }
+            }
+        }
+        if let Some(targets) = r.targets.remove(from) {
+            r.targets.entry(to.to_string()).or_insert(targets);
+        }
+    }
+    save_routines(&routines);
+
+    let mut config = load_user_config();
+    if config.favorite_exercises.remove(from) {
+        config.favorite_exercises.insert(to.to_string());
+    }
+    if let Some(v) = config.per_hand_overrides.remove(from) {
+        config.per_hand_overrides.entry(to.to_string()).or_insert(v);
+    }
+    if let Some(v) = config.bar_weight_overrides.remove(from) {
+        config.bar_weight_overrides.entry(to.to_string()).or_insert(v);
+    }
+    if let Some(v) = config.e1rm_goals.remove(from) {
+        config.e1rm_goals.entry(to.to_string()).or_insert(v);
+    }
+    for m in config.custom_milestones.iter_mut() {
+        if m.exercise_id.as_deref() == Some(from) {
+            m.exercise_id = Some(to.to_string());
+        }
+    }
+    save_user_config(&config);
+}
+
 fn csv_escape(s: &str) -> String {
     if s.contains(',') || s.contains('"') || s.contains('\n') {
         format!("\"{}\"", s.replace('"', "\"\""))
@@ -143,7 +374,7 @@ fn csv_escape(s: &str) -> String {
 pub fn export_csv() -> String {
     let workouts = load_workouts();
     let mut lines = Vec::new();
-    lines.push("date,workout_name,duration_mins,exercise,set_number,weight_kg,reps,distance_km,duration_secs,completed,note".to_string());
+    lines.push("date,workout_name,duration_mins,exercise,set_number,weight_kg,reps,distance_km,duration_secs,completed,note,session_note".to_string());
 
     let exercises = {
         let mut exs = crate::data::default_exercises();
@@ -166,7 +397,7 @@ pub fn export_csv() -> String {
                     .unwrap_or_default();
                 let note = s.note.as_deref().unwrap_or("");
                 lines.push(format!(
-                    "{},{},{},{},{},{},{},{},{},{},{}",
+                    "{},{},{},{},{},{},{},{},{},{},{},{}",
                     csv_escape(&w.date),
                     csv_escape(&w.name),
                     w.duration_mins,
@@ -178,6 +409,7 @@ pub fn export_csv() -> String {
                     dur,
                     s.completed,
                     csv_escape(note),
+                    csv_escape(&w.notes),
                 ));
             }
         }
@@ -194,6 +426,7 @@ pub fn export_all_data() -> String {
         body_metrics: load_body_metrics(),
         user_config: Some(load_user_config()),
         trusted_devices: load_trusted_devices(),
+        annotations: load_annotations(),
     };
     serde_json::to_string_pretty(&data).unwrap_or_default()
 }
@@ -206,6 +439,7 @@ pub fn import_all_data(json: &str) -> Result<(), String> {
     save_friends(&data.friends);
     save_body_metrics(&data.body_metrics);
     save_trusted_devices(&data.trusted_devices);
+    save_annotations(&data.annotations);
     if let Some(config) = data.user_config {
         save_user_config(&config);
     }
@@ -249,14 +483,57 @@ pub fn try_restore_from_backup() {
     });
 }
 
-pub fn merge_all_data(json: &str) -> Result<(), String> {
+/// What a merge added versus skipped as already present.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MergeSummary {
+    pub workouts_added: usize,
+    pub workouts_skipped: usize,
+    pub routines_added: usize,
+    pub routines_skipped: usize,
+    pub exercises_added: usize,
+    pub exercises_skipped: usize,
+}
+
+impl std::fmt::Display for MergeSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Added {} workouts, {} routines, {} exercises. Skipped {} duplicates.",
+            self.workouts_added,
+            self.routines_added,
+            self.exercises_added,
+            self.workouts_skipped + self.routines_skipped + self.exercises_skipped
+        )
+    }
+}
+
+/// Content fingerprint for a workout so the same session logged on two devices
+/// (with different IDs) is still recognised as a duplicate.
+fn workout_fingerprint(w: &Workout) -> String {
+    format!(
+        "{}|{}|{}",
+        w.date,
+        w.name,
+        serde_json::to_string(&w.exercises).unwrap_or_default()
+    )
+}
+
+pub fn merge_all_data(json: &str) -> Result<MergeSummary, String> {
     let incoming: AppData = serde_json::from_str(json).map_err(|e| e.to_string())?;
+    let mut summary = MergeSummary::default();
 
-    // Merge Workouts (deduplicate by ID)
+    // Merge Workouts (deduplicate by ID or by date + name + exercises)
     let mut current_workouts = load_workouts();
+    let mut fingerprints: std::collections::HashSet<String> =
+        current_workouts.iter().map(workout_fingerprint).collect();
     for incoming_w in incoming.workouts {
-        if !current_workouts.iter().any(|w| w.id == incoming_w.id) {
+        let fp = workout_fingerprint(&incoming_w);
+        if current_workouts.iter().any(|w| w.id == incoming_w.id) || fingerprints.contains(&fp) {
+            summary.workouts_skipped += 1;
+        } else {
+            fingerprints.insert(fp);
             current_workouts.push(incoming_w);
+            summary.workouts_added += 1;
         }
     }
     save_workouts(&current_workouts);
@@ -264,8 +541,11 @@ pub fn merge_all_data(json: &str) -> Result<(), String> {
     // Merge Routines (deduplicate by ID)
     let mut current_routines = load_routines();
     for incoming_r in incoming.routines {
-        if !current_routines.iter().any(|r| r.id == incoming_r.id) {
+        if current_routines.iter().any(|r| r.id == incoming_r.id) {
+            summary.routines_skipped += 1;
+        } else {
             current_routines.push(incoming_r);
+            summary.routines_added += 1;
         }
     }
     save_routines(&current_routines);
@@ -273,8 +553,11 @@ pub fn merge_all_data(json: &str) -> Result<(), String> {
     // Merge Custom Exercises (deduplicate by ID)
     let mut current_custom = load_custom_exercises();
     for incoming_ex in incoming.custom_exercises {
-        if !current_custom.iter().any(|e| e.id == incoming_ex.id) {
+        if current_custom.iter().any(|e| e.id == incoming_ex.id) {
+            summary.exercises_skipped += 1;
+        } else {
             current_custom.push(incoming_ex);
+            summary.exercises_added += 1;
         }
     }
     save_custom_exercises(&current_custom);
@@ -309,6 +592,15 @@ pub fn merge_all_data(json: &str) -> Result<(), String> {
     }
     save_trusted_devices(&current_devices);
 
+    // Merge Annotations (deduplicate by ID)
+    let mut current_annotations = load_annotations();
+    for incoming_a in incoming.annotations {
+        if !current_annotations.iter().any(|a| a.id == incoming_a.id) {
+            current_annotations.push(incoming_a);
+        }
+    }
+    save_annotations(&current_annotations);
+
     // Merge User Config (keep local peer_id, but take incoming profile info if it's set)
     if let Some(incoming_config) = incoming.user_config {
         let mut local_config = load_user_config();
@@ -327,5 +619,5 @@ pub fn merge_all_data(json: &str) -> Result<(), String> {
         save_user_config(&local_config);
     }
 
-    Ok(())
+    Ok(summary)
 }